@@ -38,10 +38,12 @@ impl AppController {
 }
 
 #[sword::main]
-async fn main() {
-    let app = Application::builder()
+async fn main() -> Result<(), ApplicationError> {
+    let app = Application::builder()?
         .with_controller::<AppController>()
-        .build();
+        .build()?;
 
     app.run().await;
+
+    Ok(())
 }
@@ -1,19 +1,10 @@
 use middleware::*;
-use serde::Deserialize;
 use sword::prelude::*;
 
 mod middleware;
 
-#[derive(Clone, Deserialize)]
-#[config(key = "cookies")]
-struct CookiesConfig {
-    key: String,
-}
-
 #[controller("/cookies")]
-struct CookieController {
-    cookies_config: CookiesConfig,
-}
+struct CookieController;
 
 #[routes]
 impl CookieController {
@@ -34,21 +25,17 @@ impl CookieController {
 
     #[get("/with_middleware")]
     #[middleware(SetCookieMw)]
-    async fn with_middleware(&self, mut ctx: Context) -> HttpResult<HttpResponse> {
-        let cookies = ctx.cookies_mut()?;
-
-        let session_cookie = cookies.get("session_id").ok_or_else(|| {
+    async fn with_middleware(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let session_id = ctx.cookie("session_id").ok_or_else(|| {
             HttpResponse::Unauthorized().message("Session cookie not found")
         })?;
 
-        Ok(HttpResponse::Ok()
-            .message(format!("Session ID: {}", session_cookie.value())))
+        Ok(HttpResponse::Ok().message(format!("Session ID: {session_id}")))
     }
 
     #[get("/private-counter")]
-    async fn private_counter(&self, mut ctx: Context) -> HttpResult<HttpResponse> {
-        let key = Key::from(self.cookies_config.key.as_bytes());
-        let private = ctx.cookies_mut()?.private(&key);
+    async fn private_counter(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let private = ctx.private_cookies()?;
 
         let count = private
             .get("visited_private")
@@ -70,10 +57,12 @@ impl CookieController {
 }
 
 #[sword::main]
-async fn main() {
-    let app = Application::builder()
+async fn main() -> Result<(), ApplicationError> {
+    let app = Application::builder()?
         .with_controller::<CookieController>()
-        .build();
+        .build()?;
 
     app.run().await;
+
+    Ok(())
 }
@@ -45,9 +45,9 @@ impl TasksController {
 }
 
 #[sword::main]
-async fn main() {
-    let app = Application::builder();
-    let db_config = app.config.get::<DatabaseConfig>().unwrap();
+async fn main() -> Result<(), ApplicationError> {
+    let app = Application::builder()?;
+    let db_config = app.config.get::<DatabaseConfig>()?;
 
     let db = Database::new(db_config).await;
 
@@ -60,7 +60,9 @@ async fn main() {
     let app = app
         .with_dependency_container(container)
         .with_controller::<TasksController>()
-        .build();
+        .build()?;
 
     app.run().await;
+
+    Ok(())
 }
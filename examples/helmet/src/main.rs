@@ -13,16 +13,18 @@ impl MyController {
 }
 
 #[sword::main]
-async fn main() {
+async fn main() -> Result<(), ApplicationError> {
     let helmet = Helmet::builder()
         .with_header(XContentTypeOptions::nosniff())
         .with_header(XXSSProtection::on())
         .build();
 
-    let app = Application::builder()
+    let app = Application::builder()?
         .with_controller::<MyController>()
         .with_layer(helmet)
-        .build();
+        .build()?;
 
     app.run().await;
+
+    Ok(())
 }
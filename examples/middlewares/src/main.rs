@@ -37,10 +37,12 @@ impl TestController {
 }
 
 #[sword::main]
-async fn main() {
-    let app = Application::builder()
+async fn main() -> Result<(), ApplicationError> {
+    let app = Application::builder()?
         .with_controller::<TestController>()
-        .build();
+        .build()?;
 
     app.run().await;
+
+    Ok(())
 }
@@ -17,13 +17,15 @@ module! {
 }
 
 #[sword::main]
-async fn main() {
+async fn main() -> Result<(), ApplicationError> {
     let module = AppModule::builder().build();
 
-    let app = Application::builder()
+    let app = Application::builder()?
         .with_shaku_di_module(module)
         .with_controller::<UserController>()
-        .build();
+        .build()?;
 
     app.run().await;
+
+    Ok(())
 }
@@ -1,7 +1,4 @@
-mod cors;
-use crate::cors::{CorsConfig, CorsMiddleware};
-
-use sword::prelude::*;
+use sword::{prelude::*, web::cors::Cors};
 use tokio::time::{sleep, Duration};
 use tower_http::timeout::TimeoutLayer;
 
@@ -24,19 +21,15 @@ impl AppController {
 }
 
 #[sword::main]
-async fn main() {
-    let mut app = Application::builder();
-
-    let cors_config = app
-        .config
-        .get::<CorsConfig>()
-        .expect("Failed to load CORS config");
-
-    let cors_middleware = CorsMiddleware::new(&cors_config);
+async fn main() -> Result<(), ApplicationError> {
+    let app = Application::builder()?;
+    let cors = Cors::from_config(&app.config)?;
 
-    app = app
-        .with_controller::<AppController>()
-        .with_layer(cors_middleware.layer);
+    app.with_controller::<AppController>()
+        .with_cors(cors)
+        .build()?
+        .run()
+        .await;
 
-    app.build().run().await;
+    Ok(())
 }
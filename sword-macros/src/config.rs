@@ -1,34 +1,69 @@
 use proc_macro::TokenStream;
 use proc_macro_error::emit_error;
 use quote::quote;
-use syn::{Expr, ItemStruct, Lit, Meta, parse_macro_input};
+use syn::{
+    Expr, ItemStruct, Lit, Meta, parse::Parser, parse_macro_input,
+    punctuated::Punctuated, token::Comma,
+};
 
 pub fn expand_config_struct(attr: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as ItemStruct);
-    let meta = parse_macro_input!(attr as Meta);
 
-    let toml_key_str = match meta {
-        Meta::NameValue(nv) if nv.path.is_ident("key") => {
-            if let Expr::Lit(expr) = nv.value {
-                if let Lit::Str(lit_str) = expr.lit {
-                    lit_str.value()
+    let metas = match Punctuated::<Meta, Comma>::parse_terminated.parse(attr) {
+        Ok(metas) => metas,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let mut toml_key_str = None;
+    let mut use_default = false;
+
+    for meta in metas {
+        match meta {
+            Meta::NameValue(nv) if nv.path.is_ident("key") => {
+                if let Expr::Lit(expr) = nv.value {
+                    if let Lit::Str(lit_str) = expr.lit {
+                        toml_key_str = Some(lit_str.value());
+                    } else {
+                        emit_error!(
+                            expr,
+                            "Expected a string literal for the toml key"
+                        );
+                        return TokenStream::new();
+                    }
                 } else {
-                    emit_error!(expr, "Expected a string literal for the toml key");
+                    emit_error!(nv.value, "Expected a literal for the toml key");
                     return TokenStream::new();
                 }
-            } else {
-                emit_error!(nv.value, "Expected a literal for the toml key");
+            }
+            Meta::Path(path) if path.is_ident("default") => use_default = true,
+            _ => {
+                emit_error!(
+                    meta,
+                    "Expected a `key = \"...\"` attribute, optionally followed by `default`"
+                );
                 return TokenStream::new();
             }
         }
-        _ => {
-            emit_error!(meta, "Expected a `key = \"...\"` attribute");
-            return TokenStream::new();
-        }
+    }
+
+    let Some(toml_key_str) = toml_key_str else {
+        emit_error!(
+            proc_macro2::Span::call_site(),
+            "Expected a `key = \"...\"` attribute"
+        );
+        return TokenStream::new();
     };
 
     let struct_name = &input.ident;
 
+    let default_value = use_default.then(|| {
+        quote! {
+            fn default_value() -> Option<Self> {
+                Some(<Self as ::std::default::Default>::default())
+            }
+        }
+    });
+
     let expanded = quote! {
         #input
 
@@ -36,6 +71,8 @@ pub fn expand_config_struct(attr: TokenStream, item: TokenStream) -> TokenStream
             fn toml_key() -> &'static str {
                 #toml_key_str
             }
+
+            #default_value
         }
 
         impl TryFrom<&::sword::core::State> for #struct_name {
@@ -43,8 +80,9 @@ pub fn expand_config_struct(attr: TokenStream, item: TokenStream) -> TokenStream
 
             fn try_from(state: &::sword::core::State) -> Result<Self, Self::Error> {
                 let config = state.get::<::sword::core::Config>()
-                    .map_err(|_| ::sword::errors::DependencyInjectionError::DependencyNotFound {
+                    .map_err(|error| ::sword::errors::DependencyInjectionError::DependencyNotFound {
                         type_name: "Config".to_string(),
+                        registered: error.registered_types().to_vec(),
                     })?;
 
                 config.get::<Self>()
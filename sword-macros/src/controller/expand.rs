@@ -1,16 +1,15 @@
-use proc_macro::TokenStream;
-use quote::quote;
-use syn::ItemStruct;
-
 use crate::controller::{
-    generation::generate_controller_builder, parsing::parse_controller_input,
+    generation::generate_controller_builder,
+    parsing::{parse_controller_input, parse_controller_struct},
 };
+use proc_macro::TokenStream;
+use quote::quote;
 
 pub fn expand_controller(
     attr: TokenStream,
     item: TokenStream,
 ) -> Result<TokenStream, syn::Error> {
-    let input = syn::parse::<ItemStruct>(item.clone())?;
+    let input = parse_controller_struct(item.clone())?;
     let parsed_input = parse_controller_input(attr, item)?;
     let builder = generate_controller_builder(&parsed_input);
 
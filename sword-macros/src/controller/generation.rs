@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Ident, Type};
 
-use crate::controller::parsing::ControllerInput;
+use crate::controller::parsing::{ControllerInput, FieldsStyle};
 use crate::middleware::expand_middleware_args;
 use crate::shared::generate_field_extraction_from_state;
 
@@ -13,21 +13,101 @@ pub fn generate_controller_builder(input: &ControllerInput) -> TokenStream {
     let controller_middlewares = &input.middlewares;
 
     let field_extractions = generate_field_extraction_from_state(self_fields);
-    let field_assignments = generate_field_assignments(self_fields);
+    let construction = generate_construction(self_fields, &input.fields_style);
 
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Layers added later wrap the earlier ones, so they are added from the
+    // last middleware to the first to make the first listed the outermost,
+    // like the route middlewares.
     let processed_middlewares: Vec<TokenStream> = controller_middlewares
         .iter()
+        .rev()
         .map(expand_middleware_args)
         .collect();
 
+    // Controllers built by hand have fields the state may not know about,
+    // so they can't be built from it.
+    let (build, try_from_state) = match input.instance {
+        true => {
+            let reason = format!(
+                "'{self_name}' is declared with `instance`, register it with `with_controller_instance`"
+            );
+
+            let build = quote! {
+                let _ = state;
+
+                Err(::sword::errors::DependencyInjectionError::BuildFailed {
+                    type_name: ::std::any::type_name::<Self>().to_string(),
+                    reason: #reason.to_string(),
+                })
+            };
+
+            (build, quote! {})
+        }
+        false => {
+            let try_from_state = quote! {
+                impl #impl_generics TryFrom<&::sword::core::State> for #self_name #ty_generics #where_clause {
+                    type Error = ::sword::errors::DependencyInjectionError;
+
+                    fn try_from(state: &::sword::core::State) -> Result<Self, Self::Error> {
+                        #field_extractions
+
+                        Ok(#construction)
+                    }
+                }
+            };
+
+            (quote! { Self::try_from(&state) }, try_from_state)
+        }
+    };
+
+    let base_path = match &input.parent {
+        Some(parent) => quote! {
+            static BASE_PATH: ::std::sync::LazyLock<String> =
+                ::std::sync::LazyLock::new(|| {
+                    ::sword::__internal::join_paths(
+                        <#parent as ::sword::web::ControllerBuilder>::base_path(),
+                        #base_path,
+                    )
+                });
+
+            BASE_PATH.as_str()
+        },
+        None => quote! { #base_path },
+    };
+
+    let (tag, tag_layer) = match &input.tag {
+        Some(tag) => (
+            quote! { Some(#tag) },
+            quote! {
+                result = result.layer(::sword::__internal::mw_with_state(
+                    ::sword::web::RouteTag(#tag),
+                    ::sword::web::RouteTag::layer,
+                ));
+            },
+        ),
+        None => (quote! { None }, quote! {}),
+    };
+
+    let controller_impl = match input.generics.params.is_empty() {
+        true => generate_controller_impl(self_name),
+        false => quote! {},
+    };
+
     quote! {
+        #controller_impl
 
-        impl ::sword::web::ControllerBuilder for #self_name {
+        impl #impl_generics ::sword::web::ControllerBuilder for #self_name #ty_generics #where_clause {
 
             fn base_path() -> &'static str {
                 #base_path
             }
 
+            fn tag() -> Option<&'static str> {
+                #tag
+            }
+
             fn apply_controller_middlewares(
                 router: ::sword::__internal::AxumRouter,
                 state: ::sword::core::State,
@@ -38,34 +118,94 @@ pub fn generate_controller_builder(input: &ControllerInput) -> TokenStream {
                     result = result.layer(#processed_middlewares);
                 )*
 
+                // Outermost, so responses of the controller middlewares are
+                // tagged too.
+                #tag_layer
+
                 result
             }
 
             fn build(state: ::sword::core::State) -> Result<Self, ::sword::errors::DependencyInjectionError> {
-                Self::try_from(&state)
+                #build
             }
         }
 
-        impl TryFrom<&::sword::core::State> for #self_name {
-            type Error = ::sword::errors::DependencyInjectionError;
+        #try_from_state
+    }
+}
 
-            fn try_from(state: &::sword::core::State) -> Result<Self, Self::Error> {
-                #field_extractions
+/// Implements `Controller` for non-generic controllers by merging the
+/// routes submitted by each of their `#[routes]` impls.
+fn generate_controller_impl(self_name: &Ident) -> TokenStream {
+    quote! {
+        impl ::sword::web::Controller for #self_name {
+            fn router(state: ::sword::core::State) -> ::sword::__internal::AxumRouter {
+                let controller = Self::build(state.clone()).unwrap_or_else(|err| {
+                    panic!("\n❌ Failed to build controller\n\n{}\n", err)
+                });
 
-                Ok(Self {
-                    #field_assignments
+                Self::instance_router(controller, state)
+            }
+
+            fn instance_router(
+                controller: Self,
+                state: ::sword::core::State,
+            ) -> ::sword::__internal::AxumRouter {
+                let controller = std::sync::Arc::new(controller);
+
+                let base_path = <Self as ::sword::web::ControllerBuilder>::base_path();
+
+                let base_router = ::sword::__internal::controller_routes::<Self>()
+                    .fold(::sword::__internal::AxumRouter::new(), |router, routes| {
+                        (routes.add_routes)(router, base_path, controller.clone(), &state)
+                    })
+                    .with_state(state.clone());
+
+                let router = <Self as ::sword::web::ControllerBuilder>::apply_controller_middlewares(base_router, state);
+
+                ::sword::__internal::mount_controller_router(base_path, router)
+            }
+
+            fn route_count() -> usize {
+                ::sword::__internal::controller_routes::<Self>()
+                    .map(|routes| routes.route_count)
+                    .sum()
+            }
+
+            fn body_limits() -> &'static [::sword::web::RouteBodyLimit] {
+                static BODY_LIMITS: ::std::sync::OnceLock<Vec<::sword::web::RouteBodyLimit>> =
+                    ::std::sync::OnceLock::new();
+
+                BODY_LIMITS.get_or_init(|| {
+                    ::sword::__internal::controller_routes::<Self>()
+                        .flat_map(|routes| routes.body_limits.iter().copied())
+                        .collect()
+                })
+            }
+
+            fn routes() -> &'static [::sword::web::ControllerRoute] {
+                static ROUTES: ::std::sync::OnceLock<Vec<::sword::web::ControllerRoute>> =
+                    ::std::sync::OnceLock::new();
+
+                ROUTES.get_or_init(|| {
+                    ::sword::__internal::controller_routes::<Self>()
+                        .flat_map(|routes| routes.routes.iter().copied())
+                        .collect()
                 })
             }
         }
     }
 }
 
-fn generate_field_assignments(fields: &[(Ident, Type)]) -> TokenStream {
-    let assignments = fields.iter().map(|(name, _)| {
-        quote! { #name }
-    });
+fn generate_construction(
+    fields: &[(Ident, Type)],
+    style: &FieldsStyle,
+) -> TokenStream {
+    let names = fields.iter().map(|(name, _)| name);
 
-    quote! {
-        #(#assignments),*
+    match style {
+        FieldsStyle::Named => quote! { Self { #(#names),* } },
+        FieldsStyle::Unnamed => quote! { Self( #(#names),* ) },
+        FieldsStyle::Unit => quote! { Self },
     }
 }
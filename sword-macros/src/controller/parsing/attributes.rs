@@ -1,7 +1,7 @@
 use regex_lite::Regex;
 use std::sync::LazyLock;
 use syn::{
-    Ident, LitStr, Token,
+    Ident, LitStr, Path, Token,
     parse::{Parse, ParseStream},
 };
 
@@ -9,17 +9,23 @@ static VERSION_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"v\d+").expect("Failed to compile version regex"));
 
 // #[controller("/", version = "v1")]
+// #[controller("/comments", parent = PostsController)]
+// #[controller("/users", instance)]
 pub struct ControllerArgs {
     pub base_path: String,
     pub version: Option<String>,
+    pub parent: Option<Path>,
+    pub instance: bool,
 }
 
 impl Parse for ControllerArgs {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let base_path = input.parse::<LitStr>()?.value();
         let mut version = None;
+        let mut parent = None;
+        let mut instance = false;
 
-        if input.parse::<Token![,]>().is_ok() && input.peek(Ident) {
+        while input.parse::<Token![,]>().is_ok() && input.peek(Ident) {
             let ident = input.parse::<Ident>()?;
 
             if ident == "version" {
@@ -35,9 +41,31 @@ impl Parse for ControllerArgs {
                 }
 
                 version = Some(ver_str);
+            } else if ident == "parent" {
+                input.parse::<Token![=]>()?;
+                parent = Some(input.parse::<Path>()?);
+            } else if ident == "instance" {
+                instance = true;
+            } else {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "Unknown controller argument. Expected `version`, `parent` or `instance`",
+                ));
             }
         }
 
-        Ok(ControllerArgs { base_path, version })
+        if let (Some(_), Some(parent)) = (&version, &parent) {
+            return Err(syn::Error::new_spanned(
+                parent,
+                "A controller with a parent can't set a version, it uses the base path of its parent",
+            ));
+        }
+
+        Ok(ControllerArgs {
+            base_path,
+            version,
+            parent,
+            instance,
+        })
     }
 }
@@ -1,34 +1,127 @@
 mod attributes;
 
 use proc_macro::TokenStream;
-use syn::{Ident, ItemStruct, Type};
+use quote::format_ident;
+use syn::{Fields, GenericParam, Generics, Ident, Item, ItemStruct, Path, Type};
 
 use crate::{
-    controller::parsing::attributes::ControllerArgs,
-    middleware::parse::MiddlewareArgs, shared::collect_struct_fields,
+    controller::{parsing::attributes::ControllerArgs, routes::parse_tag},
+    middleware::parse::MiddlewareArgs,
 };
 
 pub struct ControllerInput {
     pub struct_name: Ident,
+    pub generics: Generics,
     pub base_path: String,
+    pub parent: Option<Path>,
+    /// Whether the controller is only built by hand and registered with
+    /// `with_controller_instance`, so its fields aren't read from the state.
+    pub instance: bool,
     pub fields: Vec<(Ident, Type)>,
+    pub fields_style: FieldsStyle,
     pub middlewares: Vec<MiddlewareArgs>,
+    /// The `#[tag]` of the controller, used by its routes without their own.
+    pub tag: Option<String>,
+}
+
+/// How the controller struct declares its fields, which decides how the
+/// generated `TryFrom<&State>` implementation constructs it.
+pub enum FieldsStyle {
+    Named,
+    Unnamed,
+    Unit,
+}
+
+/// Parses the item as a struct, rejecting the forms a controller can't take.
+pub fn parse_controller_struct(item: TokenStream) -> Result<ItemStruct, syn::Error> {
+    let input = match syn::parse::<Item>(item)? {
+        Item::Struct(input) => input,
+        Item::Enum(item) => {
+            return Err(syn::Error::new(
+                item.enum_token.span,
+                "#[controller] can only be used on structs, found an enum",
+            ));
+        }
+        Item::Union(item) => {
+            return Err(syn::Error::new(
+                item.union_token.span,
+                "#[controller] can only be used on structs, found a union",
+            ));
+        }
+        other => {
+            return Err(syn::Error::new_spanned(
+                other,
+                "#[controller] can only be used on structs",
+            ));
+        }
+    };
+
+    if let Some(GenericParam::Lifetime(param)) = input
+        .generics
+        .params
+        .iter()
+        .find(|param| matches!(param, GenericParam::Lifetime(_)))
+    {
+        return Err(syn::Error::new_spanned(
+            param,
+            "Controllers cannot have lifetime parameters, they are shared across requests and must be 'static",
+        ));
+    }
+
+    Ok(input)
 }
 
 pub fn parse_controller_input(
     attr: TokenStream,
     item: TokenStream,
 ) -> Result<ControllerInput, syn::Error> {
-    let input = syn::parse::<ItemStruct>(item)?;
+    let input = parse_controller_struct(item)?;
     let args = syn::parse::<ControllerArgs>(attr)?;
 
     let mut middlewares = vec![];
-    let fields = collect_struct_fields(&input);
+    let mut tag = None;
+
+    let (fields, fields_style) = match &input.fields {
+        Fields::Named(named) => {
+            let fields = named
+                .named
+                .iter()
+                .filter_map(|field| {
+                    let ident = field.ident.clone()?;
+                    Some((ident, field.ty.clone()))
+                })
+                .collect();
+
+            (fields, FieldsStyle::Named)
+        }
+        Fields::Unnamed(unnamed) => {
+            let fields = unnamed
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    (format_ident!("__field_{index}"), field.ty.clone())
+                })
+                .collect();
+
+            (fields, FieldsStyle::Unnamed)
+        }
+        Fields::Unit => (vec![], FieldsStyle::Unit),
+    };
 
     for attr in &input.attrs {
         if attr.path().is_ident("middleware") {
             let args = attr.parse_args::<MiddlewareArgs>()?;
             middlewares.push(args);
+        } else if attr.path().is_ident("tag") {
+            if tag.is_some() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "A controller can only have one tag",
+                ));
+            }
+
+            tag = Some(parse_tag(attr)?);
         }
     }
 
@@ -46,6 +139,10 @@ pub fn parse_controller_input(
         ));
     }
 
+    if !catch_all_is_last(&args.base_path) {
+        return Err(syn::Error::new(input.ident.span(), CATCH_ALL_NOT_LAST));
+    }
+
     let base_path = match args.version {
         Some(ver) => format!("/{}/{}", ver, args.base_path.trim_start_matches('/')),
         None => args.base_path,
@@ -53,8 +150,24 @@ pub fn parse_controller_input(
 
     Ok(ControllerInput {
         base_path,
+        parent: args.parent,
+        instance: args.instance,
         struct_name: input.ident,
+        generics: input.generics,
         fields,
+        fields_style,
         middlewares,
+        tag,
     })
 }
+
+pub const CATCH_ALL_NOT_LAST: &str =
+    "A wildcard segment like {*path} must be the last segment of the path";
+
+/// Whether the path has no catch-all, or only one as its last segment.
+pub fn catch_all_is_last(path: &str) -> bool {
+    match path.find("{*") {
+        Some(index) => !path[index..].contains('/') && path.ends_with('}'),
+        None => true,
+    }
+}
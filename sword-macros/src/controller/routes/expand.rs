@@ -1,6 +1,6 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::ItemImpl;
+use syn::{ImplItem, ItemImpl};
 
 use crate::controller::routes::*;
 
@@ -8,13 +8,27 @@ pub fn expand_controller_routes(
     _: TokenStream,
     item: TokenStream,
 ) -> Result<TokenStream, syn::Error> {
-    let item = syn::parse::<ItemImpl>(item)?;
+    let mut item = syn::parse::<ItemImpl>(item)?;
     let parsed = parse_routes(&item)?;
-    let generated = generate_controller_routes(&item.self_ty, &parsed)?;
+
+    // `#[feature]` is only read here, there is no attribute macro behind it,
+    // since it would be ambiguous with the built-in `feature` attribute.
+    for impl_item in &mut item.items {
+        if let ImplItem::Fn(handler) = impl_item {
+            handler
+                .attrs
+                .retain(|attr| !attr.path().is_ident("feature"));
+        }
+    }
+
+    let generated =
+        generate_controller_routes(&item.self_ty, &item.generics, &parsed)?;
+    let url_helpers = generate_url_helpers(&item.self_ty, &item.generics, &parsed);
 
     let expanded = quote! {
         #item
         #generated
+        #url_helpers
     };
 
     Ok(TokenStream::from(expanded))
@@ -1,64 +1,254 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::Type;
+use syn::{Generics, Type, parse_quote};
 
 use crate::{
     controller::routes::{HTTP_METHODS, parsing::RouteInfo},
     middleware::expand_middleware_args,
 };
 
+/// The route registrations of a `#[routes]` impl and the metadata of its
+/// routes, shared by both ways of generating the controller router.
+struct GeneratedRoutes {
+    handlers: Vec<TokenStream>,
+    body_limits: Vec<TokenStream>,
+    declared_routes: Vec<TokenStream>,
+    route_count: usize,
+}
+
+/// Generates the routes of a `#[routes]` impl.
+///
+/// Impls of non-generic controllers submit their routes to the `inventory`
+/// of the controller, which `#[controller]` merges, so one controller can
+/// have several `#[routes]` impls. Generic impls can't be submitted, since
+/// statics can't be generic, and implement `Controller` themselves.
 pub fn generate_controller_routes(
     struct_self: &Type,
+    generics: &Generics,
     routes: &[RouteInfo],
 ) -> Result<TokenStream, syn::Error> {
-    let mut handlers = vec![];
+    let generated = generate_routes(routes)?;
 
-    for route in routes.iter() {
-        let routing_function = match route.method.as_str() {
-            "get" => quote! { axum_get_fn },
-            "post" => quote! { axum_post_fn },
-            "put" => quote! { axum_put_fn },
-            "patch" => quote! { axum_patch_fn },
-            "delete" => quote! { axum_delete_fn },
-            _ => {
-                return Err(syn::Error::new(
-                    proc_macro2::Span::call_site(),
-                    format!(
-                        "Unsupported HTTP method. Only {} are supported",
-                        HTTP_METHODS.join(", ")
-                    ),
-                ));
+    match generics.params.is_empty() && !has_generic_arguments(struct_self) {
+        true => Ok(generate_routes_submission(struct_self, generated)),
+        false => Ok(generate_controller_impl(struct_self, generics, generated)),
+    }
+}
+
+fn has_generic_arguments(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| !segment.arguments.is_none())
+}
+
+fn generate_routes_submission(
+    struct_self: &Type,
+    generated: GeneratedRoutes,
+) -> TokenStream {
+    let GeneratedRoutes {
+        handlers,
+        body_limits,
+        declared_routes,
+        route_count,
+    } = generated;
+
+    quote! {
+        const _: () = {
+            fn controller() -> ::std::any::TypeId {
+                ::std::any::TypeId::of::<#struct_self>()
+            }
+
+            // The state is only used by the route middlewares and feature flags.
+            #[allow(unused_variables)]
+            fn add_routes(
+                router: ::sword::__internal::AxumRouter<::sword::core::State>,
+                base_path: &'static str,
+                controller: ::std::sync::Arc<dyn ::std::any::Any + Send + Sync>,
+                state: &::sword::core::State,
+            ) -> ::sword::__internal::AxumRouter<::sword::core::State> {
+                let Ok(controller) = controller.downcast::<#struct_self>() else {
+                    unreachable!("routes submitted for another controller");
+                };
+
+                #(let router = #handlers;)*
+
+                router
+            }
+
+            ::sword::__internal::inventory::submit! {
+                ::sword::__internal::ControllerRoutes {
+                    controller,
+                    route_count: #route_count,
+                    routes: &[#(#declared_routes),*],
+                    body_limits: &[#(#body_limits),*],
+                    add_routes,
+                }
             }
         };
+    }
+}
+
+fn generate_controller_impl(
+    struct_self: &Type,
+    generics: &Generics,
+    generated: GeneratedRoutes,
+) -> TokenStream {
+    let GeneratedRoutes {
+        handlers,
+        body_limits,
+        declared_routes,
+        route_count,
+    } = generated;
+
+    // The controller is shared by every handler through an `Arc`, so generic
+    // controllers must be thread safe for any of their type parameters.
+    let mut generics = generics.clone();
+
+    generics.make_where_clause().predicates.push(parse_quote! {
+        Self: ::sword::web::ControllerBuilder + Send + Sync + 'static
+    });
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
 
+    quote! {
+        impl #impl_generics ::sword::web::Controller for #struct_self #where_clause {
+            fn router(state: ::sword::core::State) -> ::sword::__internal::AxumRouter {
+                let controller = Self::build(state.clone()).unwrap_or_else(|err| {
+                    panic!("\n❌ Failed to build controller\n\n{}\n", err)
+                });
+
+                Self::instance_router(controller, state)
+            }
+
+            fn instance_router(
+                controller: Self,
+                state: ::sword::core::State,
+            ) -> ::sword::__internal::AxumRouter {
+                let controller = std::sync::Arc::new(controller);
+
+                let base_path = <Self as ::sword::web::ControllerBuilder>::base_path();
+
+                let router: ::sword::__internal::AxumRouter<::sword::core::State> =
+                    ::sword::__internal::AxumRouter::new();
+
+                #(let router = #handlers;)*
+
+                let base_router = router.with_state(state.clone());
+
+                let router = <Self as ::sword::web::ControllerBuilder>::apply_controller_middlewares(base_router, state);
+
+                ::sword::__internal::mount_controller_router(base_path, router)
+            }
+
+            fn route_count() -> usize {
+                #route_count
+            }
+
+            fn body_limits() -> &'static [::sword::web::RouteBodyLimit] {
+                &[#(#body_limits),*]
+            }
+
+            fn routes() -> &'static [::sword::web::ControllerRoute] {
+                &[#(#declared_routes),*]
+            }
+        }
+    }
+}
+
+fn generate_routes(routes: &[RouteInfo]) -> Result<GeneratedRoutes, syn::Error> {
+    let mut handlers = vec![];
+    let mut body_limits = vec![];
+    let mut declared_routes = vec![];
+
+    for route in routes.iter() {
         let route_path = &route.path;
         let handler_name = &route.handler_name;
 
-        let mut handler = if route.needs_context {
-            quote! {
-                ::sword::__internal::#routing_function({
-                    let ctrl = std::sync::Arc::clone(&controller);
+        let feature = match &route.feature {
+            Some(feature) => quote! { Some(#feature) },
+            None => quote! { None },
+        };
 
-                    move |ctx: ::sword::web::Context| {
-                        async move {
-                            use ::sword::__internal::IntoResponse;
-                            ctrl.#handler_name(ctx).await.into_response()
-                        }
-                    }
-                })
-            }
+        let tag = match &route.tag {
+            Some(tag) => quote! { Some(#tag) },
+            None => quote! { None },
+        };
+
+        let stream_body = route.stream_body;
+
+        for method in &route.methods {
+            let method = method.to_uppercase();
+
+            declared_routes.push(quote! {
+                ::sword::web::ControllerRoute {
+                    method: #method,
+                    path: #route_path,
+                    feature: #feature,
+                    tag: #tag,
+                    stream_body: #stream_body,
+                }
+            });
+        }
+
+        let (ctx_arg, handler_call) = if route.needs_context {
+            (quote! { ctx }, quote! { ctrl.#handler_name(ctx) })
         } else {
-            quote! {
-                ::sword::__internal::#routing_function({
-                    let ctrl = std::sync::Arc::clone(&controller);
-
-                    move |_: ::sword::web::Context| {
-                        async move {
-                            use ::sword::__internal::IntoResponse;
-                            ctrl.#handler_name().await.into_response()
-                        }
+            (quote! { _ }, quote! { ctrl.#handler_name() })
+        };
+
+        let response = match &route.response_timeout {
+            Some(timeout) => {
+                let millis = timeout.millis;
+                let raw = &timeout.raw;
+
+                quote! {
+                    ::sword::__internal::with_response_timeout(#millis, #raw, #handler_call).await
+                }
+            }
+            None => quote! { #handler_call.await.into_response() },
+        };
+
+        let handler_fn = quote! {
+            {
+                let ctrl = std::sync::Arc::clone(&controller);
+
+                move |#ctx_arg: ::sword::web::Context| {
+                    async move {
+                        use ::sword::__internal::IntoResponse;
+                        #response
                     }
-                })
+                }
+            }
+        };
+
+        let mut handler = match route.methods.as_slice() {
+            [method] => {
+                let routing_function = single_method_router(method)?;
+
+                quote! {
+                    ::sword::__internal::#routing_function(#handler_fn)
+                }
+            }
+            methods => {
+                let mut filters = methods.iter().map(|method| {
+                    let filter =
+                        Ident::new(&method.to_uppercase(), Span::call_site());
+                    quote! { ::sword::__internal::AxumMethodFilter::#filter }
+                });
+
+                let first = filters.next();
+
+                quote! {
+                    ::sword::__internal::axum_on_fn(
+                        #first #(.or(#filters))*,
+                        #handler_fn
+                    )
+                }
             }
         };
 
@@ -70,37 +260,95 @@ pub fn generate_controller_routes(
             };
         }
 
-        handlers.push(quote! {
-            .route(#route_path, #handler)
-        });
-    }
+        // Outside the route middlewares, so their responses are marked too.
+        if let Some(deprecation) = &route.deprecation {
+            let sunset = match &deprecation.sunset {
+                Some(sunset) => quote! { Some(#sunset) },
+                None => quote! { None },
+            };
+            let log = deprecation.log;
 
-    Ok(quote! {
-        impl ::sword::web::Controller for #struct_self
-        where
-            Self: ::sword::web::ControllerBuilder
-        {
-            fn router(state: ::sword::core::State) -> ::sword::__internal::AxumRouter {
-                let controller = std::sync::Arc::new(
-                    Self::build(state.clone()).unwrap_or_else(|err| {
-                        panic!("\n❌ Failed to build controller\n\n{}\n", err)
-                    })
-                );
+            handler = quote! {
+                #handler.layer(::sword::__internal::mw_with_state(
+                    ::sword::__internal::DeprecatedRoute::new(#sunset, #log),
+                    ::sword::__internal::DeprecatedRoute::layer,
+                ))
+            };
+        }
 
-                let base_router = ::sword::__internal::AxumRouter::new()
-                    #(#handlers)*
-                    .with_state(state.clone());
+        // Outermost, so responses of the route middlewares are tagged too.
+        if let Some(tag) = &route.tag {
+            handler = quote! {
+                #handler.layer(::sword::__internal::mw_with_state(
+                    ::sword::web::RouteTag(#tag),
+                    ::sword::web::RouteTag::layer,
+                ))
+            };
+        }
 
+        if let Some(limit) = route.body_limit {
+            let limit = limit as usize;
 
-                let base_path = #struct_self::base_path();
-                let router = #struct_self::apply_controller_middlewares(base_router, state);
+            for method in &route.methods {
+                let method = method.to_uppercase();
 
-                match base_path {
-                    "/" => router,
-                    _ => ::sword::__internal::AxumRouter::new()
-                        .nest(base_path, router),
-                }
+                body_limits.push(quote! {
+                    ::sword::web::RouteBodyLimit {
+                        method: #method,
+                        path: #route_path,
+                        limit: #limit,
+                    }
+                });
             }
         }
+
+        let add_route = quote! {
+            router.route(
+                &::sword::__internal::controller_route_path(base_path, #route_path),
+                #handler
+            )
+        };
+
+        // Routes of disabled flags are never added, so they answer like
+        // paths that don't exist.
+        handlers.push(match &route.feature {
+            Some(feature) => quote! {
+                match ::sword::__internal::feature_enabled(&state, #feature) {
+                    true => #add_route,
+                    false => router,
+                }
+            },
+            None => add_route,
+        });
+    }
+
+    Ok(GeneratedRoutes {
+        handlers,
+        body_limits,
+        declared_routes,
+        route_count: routes.len(),
     })
 }
+
+fn single_method_router(method: &str) -> Result<TokenStream, syn::Error> {
+    let routing_function = match method {
+        "get" => quote! { axum_get_fn },
+        "post" => quote! { axum_post_fn },
+        "put" => quote! { axum_put_fn },
+        "patch" => quote! { axum_patch_fn },
+        "delete" => quote! { axum_delete_fn },
+        "head" => quote! { axum_head_fn },
+        "options" => quote! { axum_options_fn },
+        _ => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!(
+                    "Unsupported HTTP method. Only {} are supported",
+                    HTTP_METHODS.join(", ")
+                ),
+            ));
+        }
+    };
+
+    Ok(routing_function)
+}
@@ -1,29 +1,67 @@
+use byte_unit::Byte;
 use proc_macro2::Ident;
 use quote::ToTokens;
 use regex_lite::Regex;
-use std::sync::LazyLock;
+use std::{str::FromStr, sync::LazyLock};
 use syn::{
-    Attribute, Error, ImplItem, ImplItemFn, ItemImpl, LitStr, parse as syn_parse,
-    spanned::Spanned,
+    Attribute, Error, Expr, ExprLit, ImplItem, ImplItemFn, ItemImpl, Lit, LitStr,
+    Meta, MetaNameValue, parse as syn_parse, punctuated::Punctuated,
+    spanned::Spanned, token::Comma,
 };
 
-use crate::middleware::parse::MiddlewareArgs;
+use crate::{
+    controller::parsing::{CATCH_ALL_NOT_LAST, catch_all_is_last},
+    middleware::parse::MiddlewareArgs,
+};
 
-const VALID_ROUTE_MACROS: &[&str; 6] =
-    &["get", "post", "put", "patch", "delete", "middleware"];
+const VALID_ROUTE_MACROS: &[&str; 15] = &[
+    "get",
+    "post",
+    "put",
+    "patch",
+    "delete",
+    "head",
+    "options",
+    "route",
+    "middleware",
+    "response_timeout",
+    "body_limit",
+    "stream_body",
+    "feature",
+    "deprecated_route",
+    "tag",
+];
 
-pub const HTTP_METHODS: [&str; 5] = ["get", "post", "put", "delete", "patch"];
+pub const HTTP_METHODS: [&str; 7] =
+    ["get", "post", "put", "delete", "patch", "head", "options"];
 
 static PATH_KIND_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"^\/(?:[^\/{}:]+|\{[^*{}][^{}]*\}|\{\*[^{}]+\})*(?:\/(?:[^\/{}:]+|\{[^*{}][^{}]*\}|\{\*[^{}]+\}))*$").unwrap()
 });
 
 pub struct RouteInfo {
-    pub method: String,
+    pub methods: Vec<String>,
     pub path: String,
     pub handler_name: Ident,
     pub middlewares: Vec<MiddlewareArgs>,
     pub needs_context: bool,
+    pub response_timeout: Option<ResponseTimeout>,
+    pub body_limit: Option<u64>,
+    pub stream_body: bool,
+    pub feature: Option<String>,
+    pub deprecation: Option<Deprecation>,
+    pub tag: Option<String>,
+}
+
+pub struct Deprecation {
+    /// The sunset date as an HTTP date, e.g. `Mon, 01 Dec 2025 00:00:00 GMT`.
+    pub sunset: Option<String>,
+    pub log: bool,
+}
+
+pub struct ResponseTimeout {
+    pub raw: String,
+    pub millis: u64,
 }
 
 pub fn parse_routes(input: &ItemImpl) -> Result<Vec<RouteInfo>, syn::Error> {
@@ -40,8 +78,14 @@ pub fn parse_routes(input: &ItemImpl) -> Result<Vec<RouteInfo>, syn::Error> {
         };
 
         let mut route_path = String::new();
-        let mut route_method = String::new();
+        let mut route_methods = vec![];
         let mut middlewares: Vec<MiddlewareArgs> = vec![];
+        let mut response_timeout = None;
+        let mut body_limit = None;
+        let mut stream_body = false;
+        let mut feature = None;
+        let mut deprecation = None;
+        let mut tag = None;
 
         for attr in &handler.attrs {
             let Some(ident) = attr.path().get_ident() else {
@@ -55,12 +99,60 @@ pub fn parse_routes(input: &ItemImpl) -> Result<Vec<RouteInfo>, syn::Error> {
             if ident == "middleware" {
                 let args = attr.parse_args::<MiddlewareArgs>()?;
                 middlewares.push(args);
+            } else if ident == "response_timeout" {
+                response_timeout = Some(parse_response_timeout(attr)?);
+            } else if ident == "body_limit" {
+                body_limit = Some(parse_body_limit(attr)?);
+            } else if ident == "stream_body" {
+                attr.meta.require_path_only()?;
+                stream_body = true;
+            } else if ident == "feature" {
+                if feature.is_some() {
+                    return Err(Error::new(
+                        attr.span(),
+                        "A route can only be gated by one feature flag",
+                    ));
+                }
+
+                feature = Some(parse_feature(attr)?);
+            } else if ident == "deprecated_route" {
+                deprecation = Some(parse_deprecated_route(attr)?);
+            } else if ident == "tag" {
+                if tag.is_some() {
+                    return Err(Error::new(
+                        attr.span(),
+                        "A route can only have one tag",
+                    ));
+                }
+
+                tag = Some(parse_tag(attr)?);
+            } else if ident == "route" {
+                (route_methods, route_path) = parse_multi_method_route(attr)?;
             } else if HTTP_METHODS.contains(&ident.to_string().as_str()) {
-                route_method = ident.to_string();
+                route_methods = vec![ident.to_string()];
                 route_path = parse_route_path(attr)?.value();
             }
         }
 
+        // Functions without a method attribute are helpers of the handlers,
+        // unless they declare route attributes that need one.
+        if route_methods.is_empty() {
+            let route_attr = handler.attrs.iter().find(|attr| {
+                attr.path().get_ident().is_some_and(|ident| {
+                    VALID_ROUTE_MACROS.contains(&ident.to_string().as_str())
+                })
+            });
+
+            if let Some(attr) = route_attr {
+                return Err(Error::new(
+                    attr.span(),
+                    "Route attributes require an HTTP method attribute on the handler, e.g., #[get(\"/path\")]",
+                ));
+            }
+
+            continue;
+        }
+
         let needs_context = handler
             .sig
             .inputs
@@ -68,11 +160,17 @@ pub fn parse_routes(input: &ItemImpl) -> Result<Vec<RouteInfo>, syn::Error> {
             .any(|arg| matches!(arg, syn::FnArg::Typed(_)));
 
         routes.push(RouteInfo {
-            method: route_method,
+            methods: route_methods,
             path: route_path,
             handler_name: handler.sig.ident.clone(),
             middlewares,
             needs_context,
+            response_timeout,
+            body_limit,
+            stream_body,
+            feature,
+            deprecation,
+            tag,
         });
     }
 
@@ -87,6 +185,99 @@ pub fn parse_route_path(attr: &Attribute) -> Result<LitStr, syn::Error> {
         ));
     };
 
+    validate_route_path(&path)?;
+
+    Ok(path)
+}
+
+/// Parses `#[route(methods = ["GET", "POST"], path = "/search")]` into the
+/// lowercase method names and the path.
+pub fn parse_multi_method_route(
+    attr: &Attribute,
+) -> Result<(Vec<String>, String), syn::Error> {
+    let invalid = |span| {
+        Error::new(
+            span,
+            "Expected methods and a path, e.g., #[route(methods = [\"GET\", \"POST\"], path = \"/search\")]",
+        )
+    };
+
+    let args = attr
+        .parse_args_with(Punctuated::<MetaNameValue, Comma>::parse_terminated)
+        .map_err(|_| invalid(attr.span()))?;
+
+    let mut methods: Option<Vec<String>> = None;
+    let mut path: Option<LitStr> = None;
+
+    for arg in args {
+        if arg.path.is_ident("methods") {
+            let Expr::Array(array) = &arg.value else {
+                return Err(invalid(arg.value.span()));
+            };
+
+            let mut parsed = vec![];
+
+            for elem in &array.elems {
+                let Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) = elem
+                else {
+                    return Err(invalid(elem.span()));
+                };
+
+                let method = lit.value().to_lowercase();
+
+                if !HTTP_METHODS.contains(&method.as_str()) {
+                    return Err(Error::new(
+                        lit.span(),
+                        format!(
+                            "Unsupported HTTP method '{}'. Only {} are supported",
+                            lit.value(),
+                            HTTP_METHODS.map(str::to_uppercase).join(", ")
+                        ),
+                    ));
+                }
+
+                if parsed.contains(&method) {
+                    return Err(Error::new(
+                        lit.span(),
+                        format!("HTTP method '{}' is listed twice", lit.value()),
+                    ));
+                }
+
+                parsed.push(method);
+            }
+
+            if parsed.is_empty() {
+                return Err(Error::new(
+                    array.span(),
+                    "At least one HTTP method is required",
+                ));
+            }
+
+            methods = Some(parsed);
+        } else if arg.path.is_ident("path") {
+            let Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) = &arg.value
+            else {
+                return Err(invalid(arg.value.span()));
+            };
+
+            validate_route_path(lit)?;
+            path = Some(lit.clone());
+        } else {
+            return Err(invalid(arg.path.span()));
+        }
+    }
+
+    match (methods, path) {
+        (Some(methods), Some(path)) => Ok((methods, path.value())),
+        _ => Err(invalid(attr.span())),
+    }
+}
+
+fn validate_route_path(path: &LitStr) -> Result<(), syn::Error> {
     let value = path.value();
 
     if !PATH_KIND_REGEX.is_match(&value) {
@@ -100,5 +291,245 @@ pub fn parse_route_path(attr: &Attribute) -> Result<LitStr, syn::Error> {
         ));
     }
 
-    Ok(path)
+    if !catch_all_is_last(&value) {
+        return Err(Error::new(path.span(), CATCH_ALL_NOT_LAST));
+    }
+
+    Ok(())
+}
+
+pub fn parse_response_timeout(
+    attr: &Attribute,
+) -> Result<ResponseTimeout, syn::Error> {
+    let invalid = |span| {
+        Error::new(
+            span,
+            "Expected a duration like \"500ms\", \"3s\", \"1m\" or \"1h\", e.g., #[response_timeout(\"3s\")]",
+        )
+    };
+
+    let Ok(lit) = attr.parse_args::<LitStr>() else {
+        return Err(invalid(attr.span()));
+    };
+
+    let raw = lit.value();
+    let value = raw.trim();
+
+    let split_at = value
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| invalid(lit.span()))?;
+
+    let (amount, unit) = value.split_at(split_at);
+    let amount: u64 = amount.parse().map_err(|_| invalid(lit.span()))?;
+
+    let multiplier = match unit.trim() {
+        "ms" => 1,
+        "s" => 1_000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        _ => return Err(invalid(lit.span())),
+    };
+
+    if amount == 0 {
+        return Err(Error::new(
+            lit.span(),
+            "Response timeout must be greater than zero",
+        ));
+    }
+
+    let millis = amount
+        .checked_mul(multiplier)
+        .ok_or_else(|| invalid(lit.span()))?;
+
+    Ok(ResponseTimeout {
+        raw: value.to_string(),
+        millis,
+    })
+}
+
+pub fn parse_body_limit(attr: &Attribute) -> Result<u64, syn::Error> {
+    let invalid = |span| {
+        Error::new(
+            span,
+            "Expected a size like \"16KB\", \"2MiB\" or \"50MB\", e.g., #[body_limit(\"50MB\")]",
+        )
+    };
+
+    let Ok(lit) = attr.parse_args::<LitStr>() else {
+        return Err(invalid(attr.span()));
+    };
+
+    let bytes = Byte::from_str(lit.value().trim())
+        .map_err(|_| invalid(lit.span()))?
+        .as_u64();
+
+    if bytes == 0 {
+        return Err(Error::new(
+            lit.span(),
+            "Body limit must be greater than zero",
+        ));
+    }
+
+    Ok(bytes)
+}
+
+pub fn parse_feature(attr: &Attribute) -> Result<String, syn::Error> {
+    let invalid = |span| {
+        Error::new(
+            span,
+            "Expected the name of a feature flag, e.g., #[feature(\"beta_api\")]",
+        )
+    };
+
+    let Ok(lit) = attr.parse_args::<LitStr>() else {
+        return Err(invalid(attr.span()));
+    };
+
+    let name = lit.value();
+
+    if name.trim().is_empty() {
+        return Err(invalid(lit.span()));
+    }
+
+    Ok(name)
+}
+
+/// Parses `#[tag("billing")]`, on a route or a controller.
+///
+/// Tags end up as metric labels and log fields, so they are restricted to
+/// ASCII letters, digits, `_`, `-` and `.`.
+pub fn parse_tag(attr: &Attribute) -> Result<String, syn::Error> {
+    let invalid = |span| {
+        Error::new(
+            span,
+            "Expected a tag made of ASCII letters, digits, '_', '-' or '.', e.g., #[tag(\"billing\")]",
+        )
+    };
+
+    let Ok(lit) = attr.parse_args::<LitStr>() else {
+        return Err(invalid(attr.span()));
+    };
+
+    let tag = lit.value();
+
+    let valid = !tag.is_empty()
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+
+    if !valid {
+        return Err(invalid(lit.span()));
+    }
+
+    Ok(tag)
+}
+
+/// Parses `#[deprecated_route]`, `#[deprecated_route(sunset = "2025-12-01")]`
+/// and `#[deprecated_route(sunset = "2025-12-01", log = true)]`.
+pub fn parse_deprecated_route(attr: &Attribute) -> Result<Deprecation, syn::Error> {
+    let invalid = |span| {
+        Error::new(
+            span,
+            "Expected an optional sunset date and log flag, e.g., #[deprecated_route(sunset = \"2025-12-01\", log = true)]",
+        )
+    };
+
+    let mut deprecation = Deprecation {
+        sunset: None,
+        log: false,
+    };
+
+    if matches!(attr.meta, Meta::Path(_)) {
+        return Ok(deprecation);
+    }
+
+    let args = attr
+        .parse_args_with(Punctuated::<MetaNameValue, Comma>::parse_terminated)
+        .map_err(|_| invalid(attr.span()))?;
+
+    for arg in args {
+        match (&arg.value, arg.path.get_ident()) {
+            (
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }),
+                Some(ident),
+            ) if ident == "sunset" => {
+                deprecation.sunset = Some(sunset_http_date(lit)?);
+            }
+            (
+                Expr::Lit(ExprLit {
+                    lit: Lit::Bool(lit),
+                    ..
+                }),
+                Some(ident),
+            ) if ident == "log" => {
+                deprecation.log = lit.value;
+            }
+            _ => return Err(invalid(arg.span())),
+        }
+    }
+
+    Ok(deprecation)
+}
+
+/// Converts a `YYYY-MM-DD` date into the HTTP date the `Sunset` header
+/// expects, at midnight UTC.
+fn sunset_http_date(lit: &LitStr) -> Result<String, syn::Error> {
+    const DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov",
+        "Dec",
+    ];
+
+    let invalid = || {
+        Error::new(
+            lit.span(),
+            "Expected a sunset date like \"2025-12-01\" (YYYY-MM-DD)",
+        )
+    };
+
+    let value = lit.value();
+    let parts: Vec<&str> = value.trim().split('-').collect();
+
+    let [year, month, day] = parts.as_slice() else {
+        return Err(invalid());
+    };
+
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return Err(invalid());
+    }
+
+    let year: u32 = year.parse().map_err(|_| invalid())?;
+    let month: u32 = month.parse().map_err(|_| invalid())?;
+    let day: u32 = day.parse().map_err(|_| invalid())?;
+
+    if year == 0 {
+        return Err(invalid());
+    }
+
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return Err(invalid()),
+    };
+
+    if day == 0 || day > days_in_month {
+        return Err(invalid());
+    }
+
+    // Sakamoto's method, 0 is Sunday.
+    const OFFSETS: [u32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let y = if month < 3 { year - 1 } else { year };
+    let weekday =
+        (y + y / 4 - y / 100 + y / 400 + OFFSETS[month as usize - 1] + day) % 7;
+
+    Ok(format!(
+        "{}, {day:02} {} {year} 00:00:00 GMT",
+        DAYS[weekday as usize],
+        MONTHS[month as usize - 1],
+    ))
 }
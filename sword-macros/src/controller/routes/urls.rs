@@ -0,0 +1,122 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Generics, Ident, Type};
+
+use crate::controller::routes::RouteInfo;
+
+/// Generates a `<handler>_url` function for every route, taking one argument
+/// per parameter of the route path, so that a missing or extra parameter
+/// doesn't compile, and a `<handler>_url_in` function that also takes the
+/// parameters of the base path, which are only known once the parent
+/// controllers are.
+pub fn generate_url_helpers(
+    struct_self: &Type,
+    generics: &Generics,
+    routes: &[RouteInfo],
+) -> TokenStream {
+    let helpers = routes.iter().map(generate_url_helper);
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #struct_self #where_clause {
+            #(#helpers)*
+        }
+    }
+}
+
+fn generate_url_helper(route: &RouteInfo) -> TokenStream {
+    let path = &route.path;
+    let handler = route.handler_name.to_string();
+    let handler = handler.trim_start_matches("r#");
+    let helper_name = format_ident!("{handler}_url");
+    let nested_helper_name = format_ident!("{handler}_url_in");
+
+    let params: Vec<Ident> = path_params(path)
+        .iter()
+        .map(|name| param_ident(name))
+        .collect();
+
+    let doc = format!(
+        "The URL of `{handler}`, built from `{path}` under the controller base path."
+    );
+
+    let nested_doc = format!(
+        "The URL of `{handler}`, built from `{path}` under the controller base \
+         path, whose parameters are replaced by `base_params` in order."
+    );
+
+    quote! {
+        #[doc = #doc]
+        ///
+        /// Panics if the base path has parameters, use the `_url_in` variant.
+        #[allow(dead_code)]
+        pub fn #helper_name(#(#params: impl ::std::fmt::Display),*) -> String {
+            Self::#nested_helper_name(&[], #(#params),*)
+        }
+
+        #[doc = #nested_doc]
+        #[allow(dead_code)]
+        pub fn #nested_helper_name(
+            base_params: &[&dyn ::std::fmt::Display],
+            #(#params: impl ::std::fmt::Display),*
+        ) -> String {
+            ::sword::__internal::route_url(
+                <Self as ::sword::web::ControllerBuilder>::base_path(),
+                base_params,
+                #path,
+                &[#(&#params),*],
+            )
+        }
+    }
+}
+
+/// The names of the `{param}` and `{*param}` segments of a path, skipping
+/// the `{{` and `}}` escapes.
+fn path_params(path: &str) -> Vec<String> {
+    let mut params = vec![];
+    let mut chars = path.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '{' => {
+                let name: String =
+                    chars.by_ref().take_while(|char| *char != '}').collect();
+                params.push(name.trim_start_matches('*').to_string());
+            }
+            _ => {}
+        }
+    }
+
+    params
+}
+
+fn param_ident(name: &str) -> Ident {
+    let name: String = name
+        .chars()
+        .map(|char| match char.is_alphanumeric() || char == '_' {
+            true => char,
+            false => '_',
+        })
+        .collect();
+
+    // Keywords are used as raw identifiers, except the ones that can't be.
+    match syn::parse_str::<Ident>(&name) {
+        Ok(ident) => ident,
+        Err(_)
+            if matches!(
+                name.as_str(),
+                "self" | "Self" | "super" | "crate" | "_"
+            ) =>
+        {
+            format_ident!("{name}_")
+        }
+        Err(_) if !name.starts_with(|char: char| char.is_ascii_digit()) => {
+            Ident::new_raw(&name, Span::call_site())
+        }
+        Err(_) => format_ident!("_{name}"),
+    }
+}
@@ -14,8 +14,9 @@ pub fn generate_provider_trait(parsed: &DependencyStuctInput) -> TokenStream {
 
             fn try_from(state: &::sword::core::State) -> Result<Self, Self::Error> {
                 state.get::<Self>()
-                    .map_err(|_| ::sword::errors::DependencyInjectionError::DependencyNotFound {
+                    .map_err(|error| ::sword::errors::DependencyInjectionError::DependencyNotFound {
                         type_name: stringify!(#struct_name).to_string(),
+                        registered: error.registered_types().to_vec(),
                     })
             }
         }
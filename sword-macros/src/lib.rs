@@ -20,10 +20,12 @@ mod controller {
         mod expand;
         mod generation;
         mod parsing;
+        mod urls;
 
         pub use expand::*;
         pub use generation::*;
         pub use parsing::*;
+        pub use urls::*;
     }
 
     pub use expand::expand_controller;
@@ -139,6 +141,92 @@ pub fn delete(attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
 
+/// Defines a handler for HTTP HEAD requests.
+/// This macro should be used inside an `impl` block of a struct annotated with the `#[controller]` macro.
+///
+/// `#[get]` routes already answer HEAD requests with their body removed, so
+/// this is only needed to answer them without running the GET handler,
+/// e.g. for cheap existence checks.
+///
+/// ### Parameters
+/// - `path`: The path for the HEAD request, e.g., `"/item/{id}"`
+///
+/// ## Usage
+/// ```rust,ignore
+/// #[controller("/api")]
+/// struct MyController {}
+///
+/// #[routes]
+/// impl MyController {
+///     #[head("/item/{id}")]
+///     async fn item_exists(&self, ctx: Context) -> HttpResult<HttpResponse> {
+///         Ok(HttpResponse::Ok())
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn head(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let _ = attr;
+    item
+}
+
+/// Defines a handler for HTTP OPTIONS requests.
+/// This macro should be used inside an `impl` block of a struct annotated with the `#[controller]` macro.
+///
+/// Useful to answer CORS preflight requests by hand. These handlers take
+/// precedence over the CORS configuration of the application, which only
+/// answers preflights for routes without an OPTIONS handler.
+///
+/// ### Parameters
+/// - `path`: The path for the OPTIONS request, e.g., `"/item/{id}"`
+///
+/// ## Usage
+/// ```rust,ignore
+/// #[controller("/api")]
+/// struct MyController {}
+///
+/// #[routes]
+/// impl MyController {
+///     #[options("/item/{id}")]
+///     async fn item_preflight(&self, ctx: Context) -> HttpResult<HttpResponse> {
+///         Ok(HttpResponse::NoContent()
+///             .add_header("access-control-allow-origin", "https://example.com")
+///             .add_header("access-control-allow-methods", "GET, PUT"))
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn options(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let _ = attr;
+    item
+}
+
+/// Defines a handler for several HTTP methods on the same path.
+/// This macro should be used inside an `impl` block of a struct annotated with the `#[controller]` macro.
+///
+/// ### Parameters
+/// - `methods`: The HTTP methods the handler answers, e.g., `["GET", "POST"]`
+/// - `path`: The path of the route, e.g., `"/search"`
+///
+/// ## Usage
+/// ```rust,ignore
+/// #[controller("/api")]
+/// struct MyController {}
+///
+/// #[routes]
+/// impl MyController {
+///     #[route(methods = ["GET", "POST"], path = "/search")]
+///     async fn search(&self, ctx: Context) -> HttpResult<HttpResponse> {
+///         Ok(HttpResponse::Ok().message("Search results"))
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn route(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let _ = attr;
+    item
+}
+
 /// Defines a handler for PATCH DELETE requests.
 /// This macro should be used inside an `impl` block of a struct annotated with the `#[controller]` macro.
 ///
@@ -183,6 +271,66 @@ pub fn patch(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// Tuple structs and generic structs are supported too. Fields are injected
+/// from the application state, except `PhantomData` markers. Lifetime
+/// parameters are not allowed since controllers live for the whole application.
+///
+/// A field of a generic type needs the bounds the injection relies on: the
+/// type must be `Send + Sync + 'static` and built from the state, like the
+/// `#[injectable]`, `#[provider]` and `#[config]` types are.
+///
+/// ```rust,ignore
+/// use sword::{core::State, errors::DependencyInjectionError};
+///
+/// trait Repository: Send + Sync + 'static {
+///     fn all(&self) -> Vec<Item>;
+/// }
+///
+/// #[controller("/items")]
+/// struct ItemsController<R>(R)
+/// where
+///     R: Repository + for<'a> TryFrom<&'a State, Error = DependencyInjectionError>;
+///
+/// #[routes]
+/// impl<R> ItemsController<R>
+/// where
+///     R: Repository + for<'a> TryFrom<&'a State, Error = DependencyInjectionError>,
+/// {
+///     #[get("/")]
+///     async fn list(&self) -> HttpResponse {
+///         HttpResponse::Ok().data(self.0.all())
+///     }
+/// }
+///
+/// // `PgRepository` is an `#[injectable]` implementing `Repository`.
+/// Application::builder()?.with_controller::<ItemsController<PgRepository>>();
+/// ```
+///
+/// A controller can be nested under another one with `parent`, which
+/// prefixes its base path with the base path of the parent. The path
+/// parameters of the parent are available with `ctx.param`. The child is
+/// registered on its own, and the parent's middlewares don't apply to it.
+///
+/// ```rust,ignore
+/// #[controller("/posts/{post_id}")]
+/// struct PostsController;
+///
+/// // Routes are mounted under `/posts/{post_id}/comments`.
+/// #[controller("/comments", parent = PostsController)]
+/// struct CommentsController;
+/// ```
+///
+/// Controllers declared with `instance` are built by hand and registered with
+/// `with_controller_instance`, so their fields can have any type instead of
+/// being injected from the state.
+///
+/// ```rust,ignore
+/// #[controller("/users", instance)]
+/// struct UsersController {
+///     repository: Arc<dyn UsersRepository>,
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn controller(attr: TokenStream, item: TokenStream) -> TokenStream {
     controller::expand_controller(attr, item)
@@ -204,6 +352,39 @@ pub fn controller(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// Every route also gets a `<handler>_url` function that builds its URL,
+/// with one argument per path parameter, percent-encoded. The global prefix
+/// is not added. When the base path has parameters, such as under a parent
+/// controller, use `<handler>_url_in`, which takes them first as a slice,
+/// `<handler>_url` panics.
+///
+/// ```rust,ignore
+/// #[routes]
+/// impl UsersController {
+///     #[get("/{id}")]
+///     async fn get_user(&self, ctx: Context) -> HttpResult<HttpResponse> { ... }
+/// }
+///
+/// assert_eq!(UsersController::get_user_url(42), "/api/users/42");
+///
+/// // With `#[controller("/{post_id}/comments", parent = PostsController)]`
+/// assert_eq!(CommentsController::comment_url_in(&[&7], 3), "/posts/7/comments/3");
+/// ```
+///
+/// A route marked with `#[feature("name")]` is only added when the flag is
+/// enabled in the `[features]` section of the configuration. Otherwise the
+/// route doesn't exist, and requests to it get the same `404 Not Found` as
+/// any unknown path. Percentage rollouts only enable the route at 100%.
+///
+/// ```rust,ignore
+/// #[routes]
+/// impl SearchController {
+///     #[get("/v2")]
+///     #[feature("beta_search")]
+///     async fn search_v2(&self, ctx: Context) -> HttpResult<HttpResponse> { ... }
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn routes(attr: TokenStream, item: TokenStream) -> TokenStream {
     controller::expand_controller_routes(attr, item)
@@ -220,6 +401,20 @@ pub fn routes(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// - `config`: (Optional) Configuration parameters for the middleware,
 ///
+/// ### Execution order
+/// Middlewares run in a fixed order, from the outermost to the innermost:
+///
+/// 1. The middlewares of the controller, declared on the struct, in the
+///    order they are listed.
+/// 2. The middlewares of the route, declared on the handler, in the order
+///    they are listed.
+///
+/// A middleware that returns an error stops the request there, so the
+/// middlewares after it and the handler don't run. For example, with an auth
+/// middleware on the controller and a logging one on the route, rejected
+/// requests are never logged; list both on the route, logging first, to log
+/// them.
+///
 /// ### Handle errors
 /// To throw an error from a middleware, simply return an `Err` with an `HttpResponse`
 /// struct in the same way as a controller handler.
@@ -251,6 +446,165 @@ pub fn middleware(attr: TokenStream, item: TokenStream) -> TokenStream {
     let _ = attr;
     item
 }
+
+/// Bounds the execution time of a single handler.
+///
+/// The timer starts when the handler is called, after the request body has
+/// been read, so it only measures the handler itself. If it expires, the
+/// request is answered with `504 Gateway Timeout`. This is independent of
+/// the global `request_timeout_seconds`, which also covers body reading.
+///
+/// Accepted units are `ms`, `s`, `m` and `h`.
+///
+/// ### Usage
+/// ```rust,ignore
+/// #[controller("/reports")]
+/// struct ReportsController {}
+///
+/// #[routes]
+/// impl ReportsController {
+///     #[get("/daily")]
+///     #[response_timeout("3s")]
+///     async fn daily(&self) -> HttpResult<HttpResponse> {
+///         Ok(HttpResponse::Ok().data(build_report().await))
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn response_timeout(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let _ = attr;
+    item
+}
+
+/// Overrides the maximum request body size of a single route.
+///
+/// The route accepts bodies up to the given size instead of the global
+/// `body_limit` of the configuration, which can be larger or smaller.
+/// Sizes are parsed like `body_limit`, e.g. `"16KB"`, `"2MiB"` or `"50MB"`,
+/// and an invalid size is a compile error.
+///
+/// ### Usage
+/// ```rust,ignore
+/// #[controller("/files")]
+/// struct FilesController {}
+///
+/// #[routes]
+/// impl FilesController {
+///     #[post("/upload")]
+///     #[body_limit("50MB")]
+///     async fn upload(&self, ctx: Context) -> HttpResult<HttpResponse> {
+///         Ok(HttpResponse::Created().data(ctx.body_len()))
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn body_limit(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let _ = attr;
+    item
+}
+
+/// Leaves the request body of a route unread when the `Context` is
+/// extracted, so the handler can consume it as it arrives with
+/// `Context::json_array_stream`.
+///
+/// The global `body_limit` of the configuration doesn't apply to the route,
+/// while a `#[body_limit]` on it still does. The buffered body accessors,
+/// like `body` or `body_raw`, see an empty body.
+///
+/// ### Usage
+/// ```rust,ignore
+/// #[controller("/events")]
+/// struct EventsController {}
+///
+/// #[routes]
+/// impl EventsController {
+///     #[post("/import")]
+///     #[stream_body]
+///     async fn import(&self, ctx: Context) -> HttpResult<HttpResponse> {
+///         let mut events = std::pin::pin!(ctx.json_array_stream::<Event>());
+///
+///         while let Some(event) = events.next().await {
+///             self.events.insert(event?).await;
+///         }
+///
+///         Ok(HttpResponse::Ok())
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn stream_body(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let _ = attr;
+    item
+}
+
+/// Marks a route as deprecated without removing it.
+///
+/// Every response of the route carries a `Deprecation: true` header and,
+/// with a `sunset` date, a `Sunset` header with that date at midnight UTC,
+/// so clients know they have to migrate and until when. With `log = true`
+/// each request is also logged as a warning with the number of requests
+/// the route has received since startup, to track lingering usage.
+///
+/// The date is written as `YYYY-MM-DD`, and an invalid date is a compile
+/// error.
+///
+/// ### Usage
+/// ```rust,ignore
+/// #[controller("/users")]
+/// struct UsersController {}
+///
+/// #[routes]
+/// impl UsersController {
+///     #[get("/legacy")]
+///     #[deprecated_route(sunset = "2025-12-01", log = true)]
+///     async fn legacy(&self) -> HttpResponse {
+///         HttpResponse::Ok()
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn deprecated_route(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let _ = attr;
+    item
+}
+
+/// Tags a route, or every route of a controller, with a business domain.
+///
+/// The tag is added as a `tag` label to the per-route statistics of
+/// `Metrics` and as a `tag` field to the lines of the request logger, to
+/// slice them by domain rather than by route. It is also listed in
+/// `Application::routes`. A tag on a route takes precedence over the tag
+/// of its controller.
+///
+/// Tags are string literals made of ASCII letters, digits, `_`, `-` and
+/// `.`, so the set of label values is fixed at compile time.
+///
+/// ### Usage
+/// ```rust,ignore
+/// #[controller("/invoices")]
+/// #[tag("billing")]
+/// struct InvoicesController {}
+///
+/// #[routes]
+/// impl InvoicesController {
+///     #[get("/")]
+///     async fn list(&self) -> HttpResponse {
+///         HttpResponse::Ok()
+///     }
+///
+///     #[post("/refunds")]
+///     #[tag("refunds")]
+///     async fn refund(&self) -> HttpResponse {
+///         HttpResponse::Accepted()
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn tag(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let _ = attr;
+    item
+}
+
 /// Defines a configuration struct for the application.
 /// This macro generates the necessary code to deserialize the struct from
 /// the configuration toml file.
@@ -259,6 +613,9 @@ pub fn middleware(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// ### Parameters
 /// - `key`: The key in the configuration file where the struct is located.
+/// - `default`: Optional. If the section is absent, `Default::default()` is
+///   used instead of failing with `ConfigError::KeyNotFound`. The struct must
+///   implement `Default`. A present but invalid section still fails.
 ///
 /// ### Usage
 /// ```rust,ignore
@@ -597,7 +954,7 @@ pub fn main(_args: TokenStream, item: TokenStream) -> TokenStream {
     let fn_body = input.block.clone();
     let fn_attrs = input.attrs.clone();
     let fn_vis = input.vis.clone();
-    let _fn_sig = input.sig.clone();
+    let fn_output = input.sig.output.clone();
 
     #[allow(unused)]
     let mut output = quote! {};
@@ -605,10 +962,18 @@ pub fn main(_args: TokenStream, item: TokenStream) -> TokenStream {
     if cfg!(feature = "hot-reload") {
         output = quote! {
 
-            async fn __internal_main() {
+            async fn __internal_user_main() #fn_output {
                 #fn_body
             }
 
+            // The hot-reload server discards the output of `main`, so errors
+            // are reported here the same way a regular `main` would.
+            async fn __internal_main() {
+                let _ = ::std::process::Termination::report(
+                    __internal_user_main().await
+                );
+            }
+
             #(#fn_attrs)*
             #fn_vis fn main() {
                 ::sword::__internal::tokio_runtime::Builder::new_multi_thread()
@@ -621,7 +986,7 @@ pub fn main(_args: TokenStream, item: TokenStream) -> TokenStream {
     } else {
         output = quote! {
             #(#fn_attrs)*
-            #fn_vis fn main() {
+            #fn_vis fn main() #fn_output {
                 ::sword::__internal::tokio_runtime::Builder::new_multi_thread()
                     .enable_all()
                     .build()
@@ -8,10 +8,18 @@ pub fn generate_field_extraction_from_state(
     let extractions = fields.iter().map(|(field_name, field_type)| {
         let type_str = quote!(#field_type).to_string();
 
+        // Markers for unused type parameters hold no dependency.
+        if is_phantom_data(field_type) {
+            return quote! {
+                let #field_name = ::std::marker::PhantomData;
+            };
+        }
+
         quote! {
-            let #field_name = #field_type::try_from(state).map_err(|_| {
+            let #field_name = <#field_type>::try_from(state).map_err(|error| {
                 ::sword::errors::DependencyInjectionError::DependencyNotFound {
                     type_name: #type_str.to_string(),
+                    registered: error.registered_types().to_vec(),
                 }
             })?;
         }
@@ -31,3 +39,14 @@ pub fn generate_struct_field_assignments(fields: &[(Ident, Type)]) -> TokenStrea
         #(#assignments),*
     }
 }
+
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
@@ -0,0 +1,128 @@
+use axum_test::TestServer;
+use serde::Deserialize;
+use sword::{errors::ConfigError, prelude::*};
+
+#[derive(Deserialize)]
+#[config(key = "missing-section")]
+struct MissingConfig {
+    #[allow(dead_code)]
+    value: String,
+}
+
+#[controller("/builder")]
+struct BuilderController;
+
+#[routes]
+impl BuilderController {
+    #[get("/ping")]
+    async fn ping(&self) -> HttpResponse {
+        HttpResponse::Ok().message("pong")
+    }
+}
+
+#[controller("/builder")]
+struct DuplicateBuilderController;
+
+#[routes]
+impl DuplicateBuilderController {
+    #[get("/ping")]
+    async fn ping(&self) -> HttpResponse {
+        HttpResponse::Ok().message("duplicate")
+    }
+}
+
+#[controller("/status")]
+struct StatusController;
+
+#[routes]
+impl StatusController {
+    #[get("/")]
+    async fn status(&self) -> HttpResponse {
+        HttpResponse::Ok().message("up")
+    }
+}
+
+fn build_app() -> Result<Application, ApplicationError> {
+    let app = Application::builder()?
+        .with_controller::<BuilderController>()
+        .build()?;
+
+    Ok(app)
+}
+
+fn build_app_with_missing_config() -> Result<Application, ApplicationError> {
+    let builder = Application::builder()?;
+    builder.config.get::<MissingConfig>()?;
+
+    builder.with_controller::<BuilderController>().build()
+}
+
+#[tokio::test]
+async fn fallible_builder_chain_propagates_with_question_mark() {
+    let app = build_app().expect("the builder chain should succeed");
+    let server = TestServer::new(app.router()).unwrap();
+
+    let response = server.get("/builder/ping").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ResponseBody>().message.as_ref(), "pong");
+}
+
+#[test]
+fn config_errors_stop_the_builder_chain() {
+    let error = build_app_with_missing_config()
+        .err()
+        .expect("a missing config section should fail the chain");
+
+    assert!(matches!(
+        error,
+        ApplicationError::ConfigError {
+            source: ConfigError::KeyNotFound(_)
+        }
+    ));
+}
+
+#[tokio::test]
+async fn with_controllers_registers_every_controller() {
+    let builder = Application::builder().unwrap();
+    let app = with_controllers!(builder, [BuilderController, StatusController])
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+
+    assert_eq!(
+        server.get("/builder/ping").await.status_code(),
+        StatusCode::OK
+    );
+    assert_eq!(server.get("/status").await.status_code(), StatusCode::OK);
+}
+
+#[test]
+#[should_panic(
+    expected = "of 'sword_tests::application::builder::BuilderController'"
+)]
+fn with_controllers_registers_in_the_listed_order() {
+    let builder = Application::builder().unwrap();
+
+    let _ = with_controllers!(
+        builder,
+        [
+            BuilderController,
+            StatusController,
+            DuplicateBuilderController,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn default_builder_loads_the_configuration() {
+    let app = ApplicationBuilder::default()
+        .with_controller::<StatusController>()
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+
+    assert_eq!(server.get("/status").await.status_code(), StatusCode::OK);
+}
@@ -2,6 +2,7 @@ use std::process::Command;
 
 use axum_test::TestServer;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use sword::prelude::*;
 
 #[derive(Serialize, Deserialize)]
@@ -29,8 +30,10 @@ impl TestController {
 #[tokio::test]
 async fn test_application() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
 
@@ -59,3 +62,163 @@ async fn test_application() {
 
     assert_eq!(data["custom_key"], expected.custom_key);
 }
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[config(key = "missing-cache-section", default)]
+struct CacheConfig {
+    ttl_seconds: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self { ttl_seconds: 60 }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[config(key = "my-custom-section", default)]
+struct InvalidCustomConfig {
+    #[allow(dead_code)]
+    custom_key: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[config(key = "missing-cache-section")]
+struct RequiredCacheConfig {
+    #[allow(dead_code)]
+    ttl_seconds: u64,
+}
+
+#[test]
+fn absent_section_falls_back_to_default() {
+    let app = Application::builder().unwrap();
+    let config = app.config.get::<CacheConfig>().unwrap();
+
+    assert_eq!(config, CacheConfig { ttl_seconds: 60 });
+}
+
+#[test]
+fn absent_section_without_default_is_not_found() {
+    let app = Application::builder().unwrap();
+    let result = app.config.get::<RequiredCacheConfig>();
+
+    assert!(
+        matches!(result, Err(ConfigError::KeyNotFound(key)) if key == "missing-cache-section")
+    );
+}
+
+#[test]
+fn present_invalid_section_still_errors_with_default() {
+    let app = Application::builder().unwrap();
+    let result = app.config.get::<InvalidCustomConfig>();
+
+    assert!(matches!(result, Err(ConfigError::DeserializeError(_))));
+}
+
+#[tokio::test]
+async fn builder_loads_config_from_custom_path() {
+    let app = ApplicationBuilder::with_config_path("config/custom.toml")
+        .unwrap()
+        .with_controller::<TestController>()
+        .build()
+        .unwrap();
+
+    let test = TestServer::new(app.router()).unwrap();
+
+    let response = test.get("/test/hello").await;
+    let json_body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), 200);
+    assert_eq!(
+        json_body.data,
+        Some(serde_json::json!({
+            "custom_key": "custom value",
+            "env_user": "custom user",
+        }))
+    );
+}
+
+#[test]
+fn builder_fails_on_missing_custom_path() {
+    let result = ApplicationBuilder::with_config_path("config/missing.toml");
+
+    let Err(ApplicationError::ConfigError {
+        source: ConfigError::FileNotFound(path),
+    }) = result
+    else {
+        panic!("expected a missing configuration file error");
+    };
+
+    assert_eq!(path, "config/missing.toml");
+}
+
+#[derive(Deserialize)]
+#[config(key = "dotenv-section")]
+struct DotenvConfig {
+    greeting: String,
+    package: String,
+}
+
+#[test]
+fn dotenv_values_are_used_in_interpolation() {
+    let builder = ApplicationBuilder::with_dotenv().unwrap();
+    let config = builder.config.get::<DotenvConfig>().unwrap();
+
+    assert_eq!(config.greeting, "hello from .env");
+}
+
+#[test]
+fn environment_variables_take_precedence_over_dotenv() {
+    let builder = ApplicationBuilder::with_dotenv().unwrap();
+    let config = builder.config.get::<DotenvConfig>().unwrap();
+
+    assert_eq!(config.package, env!("CARGO_PKG_NAME"));
+}
+
+#[test]
+fn dotenv_is_only_read_when_enabled() {
+    let builder = ApplicationBuilder::new().unwrap();
+    let config = builder.config.get::<DotenvConfig>().unwrap();
+
+    assert_eq!(config.greeting, "unset");
+}
+
+#[test]
+fn get_value_reads_nested_keys_dynamically() {
+    let app = Application::builder().unwrap();
+    let config = &app.config;
+
+    assert_eq!(config.get_value("application.port"), Some(json!(8080)));
+    assert_eq!(
+        config.get_value("my-custom-section.custom_key"),
+        Some(json!("value"))
+    );
+    assert_eq!(
+        config.get_value("features.beta_search"),
+        Some(json!({ "percentage": 50 }))
+    );
+    assert_eq!(
+        config.get_value("features.beta_search.percentage"),
+        Some(json!(50))
+    );
+}
+
+#[test]
+fn get_value_returns_interpolated_values() {
+    let app = Application::builder().unwrap();
+    let expected = std::env::var("USER").unwrap_or("default_value".into());
+
+    assert_eq!(
+        app.config.get_value("my-custom-section.env_user"),
+        Some(json!(expected))
+    );
+}
+
+#[test]
+fn get_value_is_none_for_missing_keys() {
+    let app = Application::builder().unwrap();
+
+    assert_eq!(app.config.get_value("application.missing"), None);
+    assert_eq!(app.config.get_value("application.port.inner"), None);
+    assert_eq!(app.config.get_value("missing-section"), None);
+}
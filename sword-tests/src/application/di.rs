@@ -108,9 +108,11 @@ async fn test_get_tasks_empty() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_dependency_container(container)
         .with_controller::<TasksController>()
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
 
@@ -136,9 +138,11 @@ async fn test_create_task() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_dependency_container(container)
         .with_controller::<TasksController>()
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
 
@@ -156,3 +160,21 @@ async fn test_create_task() {
     assert_eq!(task["id"], 1);
     assert_eq!(task["title"], "Task 1");
 }
+
+#[test]
+fn try_with_dependency_container_returns_missing_dependencies() {
+    let container = DependencyContainer::builder()
+        .register::<TaskRepository>()
+        .build();
+
+    let error = Application::builder()
+        .unwrap()
+        .try_with_dependency_container(container)
+        .expect_err("the repository's database is not registered");
+
+    assert!(matches!(
+        error,
+        DependencyInjectionError::BuildFailed { type_name, reason }
+            if type_name.ends_with("TaskRepository") && reason.contains("'Database'")
+    ));
+}
@@ -0,0 +1,155 @@
+use std::{convert::Infallible, time::Duration};
+
+use axum_test::TestServer;
+use serde_json::Value;
+use sword::prelude::*;
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+
+struct AuthMiddleware;
+
+impl Middleware for AuthMiddleware {
+    async fn handle(ctx: Context, nxt: Next) -> MiddlewareResult {
+        ctx.report_user("ada");
+
+        next!(ctx, nxt)
+    }
+}
+
+#[controller("/reports")]
+struct ReportsController;
+
+#[routes]
+impl ReportsController {
+    #[get("/failing")]
+    #[middleware(AuthMiddleware)]
+    async fn failing(&self) -> HttpResult<HttpResponse> {
+        Err(HttpResponse::InternalServerError().error("database is down"))
+    }
+
+    #[get("/panicking")]
+    async fn panicking(&self) -> HttpResponse {
+        panic!("handler exploded");
+    }
+
+    #[get("/streaming")]
+    async fn streaming(&self) -> Response {
+        let chunks = vec!["x".repeat(48 * 1024), "y".repeat(48 * 1024)];
+        let stream =
+            futures::stream::iter(chunks.into_iter().map(Ok::<_, Infallible>));
+
+        (
+            StatusCode::BAD_GATEWAY,
+            axum::body::Body::from_stream(stream),
+        )
+            .into_response()
+    }
+
+    #[get("/large")]
+    async fn large(&self) -> HttpResult<HttpResponse> {
+        Err(HttpResponse::InternalServerError().error("x".repeat(80 * 1024)))
+    }
+
+    #[get("/missing")]
+    async fn missing(&self) -> HttpResult<HttpResponse> {
+        Err(HttpResponse::NotFound())
+    }
+}
+
+fn test_server() -> (TestServer, UnboundedReceiver<ErrorReport>) {
+    let (sender, receiver) = unbounded_channel();
+
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<ReportsController>()
+        .with_request_id(RequestId::new())
+        .on_error(move |report| {
+            let sender = sender.clone();
+
+            async move {
+                let _ = sender.send(report);
+            }
+        })
+        .build()
+        .unwrap();
+
+    (TestServer::new(app.router()).unwrap(), receiver)
+}
+
+async fn next_report(receiver: &mut UnboundedReceiver<ErrorReport>) -> ErrorReport {
+    tokio::time::timeout(Duration::from_secs(1), receiver.recv())
+        .await
+        .expect("the hook should be called")
+        .expect("the hook should send a report")
+}
+
+#[tokio::test]
+async fn hook_receives_handler_errors_with_request_metadata() {
+    let (server, mut receiver) = test_server();
+
+    let response = server
+        .get("/reports/failing")
+        .add_header("x-request-id", "req-42")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let report = next_report(&mut receiver).await;
+
+    assert_eq!(report.status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(report.method, Method::GET);
+    assert_eq!(report.path, "/reports/failing");
+    assert_eq!(report.request_id.as_deref(), Some("req-42"));
+    assert_eq!(report.user.as_deref(), Some("ada"));
+    assert_eq!(report.message.as_deref(), Some("database is down"));
+    assert!(report.panic.is_none());
+}
+
+#[tokio::test]
+async fn panics_are_reported_and_answered_with_500() {
+    let (server, mut receiver) = test_server();
+
+    let response = server.get("/reports/panicking").await;
+
+    assert_eq!(response.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(response.json::<Value>()["message"], "Internal server error");
+
+    let report = next_report(&mut receiver).await;
+
+    assert_eq!(report.panic.as_deref(), Some("handler exploded"));
+    assert!(report.user.is_none());
+}
+
+#[tokio::test]
+async fn client_errors_are_not_reported() {
+    let (server, mut receiver) = test_server();
+
+    let response = server.get("/reports/missing").await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert!(
+        tokio::time::timeout(Duration::from_millis(100), receiver.recv())
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn large_and_streamed_bodies_are_sent_untouched() {
+    let (server, mut receiver) = test_server();
+
+    let response = server.get("/reports/streaming").await;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_GATEWAY);
+    assert_eq!(response.as_bytes().len(), 96 * 1024);
+
+    let report = next_report(&mut receiver).await;
+
+    assert_eq!(report.status, StatusCode::BAD_GATEWAY);
+    assert_eq!(report.message, None);
+
+    let response = server.get("/reports/large").await;
+    let body = response.json::<Value>();
+
+    assert_eq!(body["error"].as_str().unwrap().len(), 80 * 1024);
+    assert_eq!(next_report(&mut receiver).await.message, None);
+}
@@ -0,0 +1,114 @@
+use axum_test::TestServer;
+use serde_json::Value;
+use sword::{core::ApplicationBuilder, prelude::*};
+
+#[controller("/error-status")]
+struct ErrorStatusController;
+
+#[routes]
+impl ErrorStatusController {
+    #[post("/echo")]
+    async fn echo(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let body: Value = ctx.body()?;
+        Ok(HttpResponse::Ok().data(body))
+    }
+}
+
+fn test_server(builder: ApplicationBuilder) -> TestServer {
+    let app = builder
+        .with_controller::<ErrorStatusController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[test]
+fn request_errors_expose_their_default_status() {
+    let error = RequestError::UnsupportedMediaType {
+        received: Some("text/plain".to_string()),
+        allowed: &["application/json"],
+    };
+    assert_eq!(error.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    let error = RequestError::ParseError("Invalid request body", String::new());
+    assert_eq!(error.status_code(), StatusCode::BAD_REQUEST);
+
+    assert_eq!(
+        RequestError::BodyTooLarge {
+            limit: 1024,
+            received: None,
+            field: None,
+        }
+        .status_code(),
+        StatusCode::PAYLOAD_TOO_LARGE
+    );
+}
+
+#[tokio::test]
+async fn overridden_variant_uses_custom_status() {
+    let builder = Application::builder().unwrap().with_request_error_status(
+        |error| match error {
+            RequestError::UnsupportedMediaType { .. } => {
+                Some(StatusCode::BAD_REQUEST)
+            }
+            _ => None,
+        },
+    );
+
+    let server = test_server(builder);
+
+    let response = server
+        .post("/error-status/echo")
+        .text("plain text")
+        .content_type("text/plain")
+        .await;
+
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(body.code, 400);
+}
+
+#[tokio::test]
+async fn other_variants_keep_default_status() {
+    let builder = Application::builder().unwrap().with_request_error_status(
+        |error| match error {
+            RequestError::UnsupportedMediaType { .. } => {
+                Some(StatusCode::BAD_REQUEST)
+            }
+            _ => None,
+        },
+    );
+
+    let server = test_server(builder);
+
+    let response = server.post("/error-status/echo").await;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response.json::<ResponseBody>().message.as_ref(),
+        "Request body is empty"
+    );
+
+    let response = server
+        .post("/error-status/echo")
+        .text("{ invalid")
+        .content_type("application/json")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn without_overrides_default_status_is_used() {
+    let server = test_server(Application::builder().unwrap());
+
+    let response = server
+        .post("/error-status/echo")
+        .text("plain text")
+        .content_type("text/plain")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
@@ -0,0 +1,95 @@
+use axum_test::TestServer;
+use serde_json::json;
+use sword::prelude::*;
+
+#[controller("/features")]
+struct FeaturesController;
+
+#[routes]
+impl FeaturesController {
+    #[get("/{flag}")]
+    async fn flag(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let flag: String = ctx.param("flag")?;
+
+        Ok(HttpResponse::Ok().data(ctx.feature_enabled(&flag)))
+    }
+
+    #[get("/{flag}/{key}")]
+    async fn flag_for_key(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let flag: String = ctx.param("flag")?;
+        let key: String = ctx.param("key")?;
+
+        Ok(HttpResponse::Ok().data(json!({
+            "first": ctx.feature_enabled_for(&flag, &key),
+            "second": ctx.feature_enabled_for(&flag, &key),
+        })))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<FeaturesController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+async fn enabled(server: &TestServer, path: &str) -> bool {
+    let body = server.get(path).await.json::<ResponseBody>();
+    body.data.unwrap().as_bool().unwrap()
+}
+
+#[tokio::test]
+async fn boolean_flags_follow_config() {
+    let server = test_server();
+
+    assert!(enabled(&server, "/features/new_checkout").await);
+    assert!(!enabled(&server, "/features/legacy_export").await);
+    assert!(!enabled(&server, "/features/unknown_flag").await);
+}
+
+#[tokio::test]
+async fn partial_rollout_is_disabled_without_a_key() {
+    let server = test_server();
+
+    assert!(!enabled(&server, "/features/beta_search").await);
+}
+
+#[tokio::test]
+async fn percentage_rollout_is_stable_per_key() {
+    let server = test_server();
+    let mut enabled_keys = 0;
+
+    for user_id in 0..200 {
+        let path = format!("/features/beta_search/user-{user_id}");
+        let data = server.get(&path).await.json::<ResponseBody>().data.unwrap();
+
+        let first = data["first"].as_bool().unwrap();
+        let again = server.get(&path).await.json::<ResponseBody>().data.unwrap();
+
+        assert_eq!(first, data["second"].as_bool().unwrap());
+        assert_eq!(first, again["first"].as_bool().unwrap());
+
+        if first {
+            enabled_keys += 1;
+        }
+    }
+
+    assert!(
+        (60..=140).contains(&enabled_keys),
+        "expected roughly half of the keys enabled, got {enabled_keys}/200"
+    );
+}
+
+#[tokio::test]
+async fn boolean_flags_ignore_the_key() {
+    let server = test_server();
+    let body = server
+        .get("/features/new_checkout/any-user")
+        .await
+        .json::<ResponseBody>();
+
+    assert_eq!(body.data.unwrap()["first"], true);
+}
@@ -0,0 +1,89 @@
+use std::{net::SocketAddr, time::Duration};
+
+use sword::prelude::*;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::oneshot,
+    time::timeout,
+};
+
+#[controller("/listener")]
+struct ListenerController;
+
+#[routes]
+impl ListenerController {
+    #[get("/ping")]
+    async fn ping(&self) -> HttpResponse {
+        HttpResponse::Ok().message("pong")
+    }
+}
+
+const PING_REQUEST: &[u8] =
+    b"GET /listener/ping HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n";
+
+fn app() -> Application {
+    Application::builder()
+        .unwrap()
+        .with_controller::<ListenerController>()
+        .build()
+        .unwrap()
+}
+
+async fn get_ping(addr: SocketAddr) -> String {
+    let mut client = TcpStream::connect(addr).await.unwrap();
+
+    client.write_all(PING_REQUEST).await.unwrap();
+
+    let mut response = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+
+    String::from_utf8_lossy(&response).into_owned()
+}
+
+#[tokio::test]
+async fn serves_on_a_pre_bound_std_listener_until_shutdown() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let app = app();
+
+    let (trigger, signal) = oneshot::channel::<()>();
+
+    let server = tokio::spawn(async move {
+        app.serve_on_listener_with_graceful_shutdown(listener, async move {
+            let _ = signal.await;
+        })
+        .await;
+    });
+
+    let response = get_ping(addr).await;
+
+    assert!(response.starts_with("HTTP/1.1 200"));
+    assert!(response.contains("pong"));
+
+    trigger.send(()).unwrap();
+
+    timeout(Duration::from_secs(5), server)
+        .await
+        .expect("server did not exit")
+        .unwrap();
+
+    assert!(TcpStream::connect(addr).await.is_err());
+}
+
+#[tokio::test]
+async fn serves_on_a_pre_bound_tokio_listener() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let app = app();
+
+    let server = tokio::spawn(async move {
+        app.serve_on_listener(listener).await;
+    });
+
+    let response = get_ping(addr).await;
+
+    assert!(response.starts_with("HTTP/1.1 200"));
+
+    server.abort();
+}
@@ -0,0 +1,107 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use serde_json::{Value, json};
+use sword::prelude::*;
+
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedLogs {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn log_with(config: &LoggingConfig) -> String {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+
+    let subscriber = config.subscriber_with_writer(move || writer.clone());
+
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info!(user_id = 42, "User signed in");
+        tracing::debug!("Debug details");
+    });
+
+    logs.contents()
+}
+
+#[test]
+fn logging_config_defaults_to_pretty() {
+    let config: LoggingConfig = serde_json::from_value(json!({})).unwrap();
+
+    assert_eq!(config.format, LogFormat::Pretty);
+    assert!(config.filter.is_none());
+}
+
+#[test]
+fn json_format_writes_one_object_per_line() {
+    let config: LoggingConfig = serde_json::from_value(json!({
+        "format": "json",
+        "filter": "info",
+    }))
+    .unwrap();
+
+    let output = log_with(&config);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 1);
+
+    let line: Value = serde_json::from_str(lines[0]).unwrap();
+
+    assert_eq!(line["level"], "INFO");
+    assert_eq!(line["fields"]["message"], "User signed in");
+    assert_eq!(line["fields"]["user_id"], 42);
+}
+
+#[test]
+fn pretty_format_writes_readable_lines() {
+    let config: LoggingConfig = serde_json::from_value(json!({
+        "format": "pretty",
+        "filter": "debug",
+    }))
+    .unwrap();
+
+    let output = log_with(&config);
+
+    assert!(output.contains("User signed in"));
+    assert!(output.contains("Debug details"));
+    assert!(output.contains("user_id"));
+    assert!(serde_json::from_str::<Value>(output.lines().next().unwrap()).is_err());
+}
+
+#[test]
+fn builder_keeps_a_scoped_subscriber() {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    tracing::subscriber::with_default(subscriber, || {
+        ApplicationBuilder::with_config_path("config/logging.toml").unwrap();
+
+        tracing::info!("Logged after building");
+    });
+
+    let output = logs.contents();
+
+    assert!(output.contains("Logged after building"));
+    assert!(serde_json::from_str::<Value>(output.lines().next().unwrap()).is_err());
+}
@@ -0,0 +1,88 @@
+use axum::http::{Method, header};
+use axum_test::TestServer;
+use sword::prelude::*;
+
+#[controller("/items")]
+struct ItemsController;
+
+#[routes]
+impl ItemsController {
+    #[get("/")]
+    async fn list(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+
+    #[post("/")]
+    async fn create(&self) -> HttpResponse {
+        HttpResponse::Created()
+    }
+
+    #[get("/legacy")]
+    async fn legacy(&self) -> HttpResponse {
+        HttpResponse::MethodNotAllowed().message("Handled by the route")
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<ItemsController>()
+        .with_method_not_allowed_handler(|allowed| {
+            let allowed = allowed.iter().map(Method::as_str).collect::<Vec<_>>();
+
+            HttpResponse::MethodNotAllowed()
+                .message(format!("Use one of: {}", allowed.join(", ")))
+        })
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn custom_handler_builds_the_405_body() {
+    let response = test_server().delete("/items").await;
+
+    let body = response.json::<ResponseBody>();
+    let allow = response.header(header::ALLOW);
+
+    assert_eq!(response.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(body.message.as_ref(), "Use one of: GET, HEAD, POST");
+    assert_eq!(allow, "GET,HEAD,POST");
+}
+
+#[tokio::test]
+async fn allowed_methods_are_untouched() {
+    let response = test_server().post("/items").await;
+
+    assert_eq!(response.status_code(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn handler_returned_405_is_not_replaced() {
+    let response = test_server().get("/items/legacy").await;
+
+    assert_eq!(response.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(
+        response.json::<ResponseBody>().message.as_ref(),
+        "Handled by the route"
+    );
+}
+
+#[tokio::test]
+async fn default_405_is_kept_without_handler() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<ItemsController>()
+        .build()
+        .unwrap();
+
+    let response = TestServer::new(app.router())
+        .unwrap()
+        .delete("/items")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+    assert!(response.as_bytes().is_empty());
+    assert!(response.maybe_header(header::ALLOW).is_some());
+}
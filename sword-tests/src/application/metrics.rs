@@ -0,0 +1,192 @@
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+};
+
+use axum::{body::Body, response::Response};
+use axum_test::TestServer;
+use serde_json::{Value, json};
+use sword::{
+    prelude::*,
+    web::metrics::{Metrics, RequestMetrics, RouteStats},
+};
+
+#[controller("/metrics")]
+struct MetricsController;
+
+#[routes]
+impl MetricsController {
+    #[post("/echo")]
+    async fn echo(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let body: Value = ctx.body()?;
+        Ok(HttpResponse::Ok().data(body).message("Echo"))
+    }
+
+    #[get("/stream")]
+    async fn stream(&self) -> Response {
+        let chunks = vec!["first chunk;", "second;", "third and last chunk"];
+        let stream =
+            futures::stream::iter(chunks.into_iter().map(Ok::<_, Infallible>));
+
+        Response::new(Body::from_stream(stream))
+    }
+
+    #[get("/users/{id}")]
+    async fn user(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let id: u32 = ctx.param("id")?;
+
+        match id {
+            1 => Ok(HttpResponse::Ok().data(id)),
+            _ => Err(HttpResponse::NotFound()),
+        }
+    }
+
+    #[get("/failure")]
+    async fn failure(&self) -> HttpResponse {
+        HttpResponse::InternalServerError()
+    }
+}
+
+fn test_server() -> (TestServer, Metrics, Arc<Mutex<Vec<RequestMetrics>>>) {
+    test_server_with(Metrics::new())
+}
+
+fn test_server_with(
+    metrics: Metrics,
+) -> (TestServer, Metrics, Arc<Mutex<Vec<RequestMetrics>>>) {
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let sink = records.clone();
+
+    let metrics = metrics.on_request(move |record| {
+        sink.lock().unwrap().push(record.clone());
+    });
+
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<MetricsController>()
+        .with_metrics(metrics.clone())
+        .build()
+        .unwrap();
+
+    (TestServer::new(app.router()).unwrap(), metrics, records)
+}
+
+#[tokio::test]
+async fn metrics_count_request_and_response_bytes() {
+    let (server, metrics, records) = test_server();
+    let payload = json!({ "name": "sword", "tags": ["web", "rust"] });
+    let payload_len = serde_json::to_vec(&payload).unwrap().len() as u64;
+
+    let response = server.post("/metrics/echo").json(&payload).await;
+    let response_len = response.as_bytes().len() as u64;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let records = records.lock().unwrap();
+    let record = records.first().unwrap();
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(record.method, Method::POST);
+    assert_eq!(record.path, "/metrics/echo");
+    assert_eq!(record.status, StatusCode::OK);
+    assert_eq!(record.bytes_in, payload_len);
+    assert_eq!(record.bytes_out, response_len);
+
+    assert_eq!(metrics.requests(), 1);
+    assert_eq!(metrics.bytes_in(), payload_len);
+    assert_eq!(metrics.bytes_out(), response_len);
+}
+
+#[tokio::test]
+async fn metrics_count_streaming_response_chunks() {
+    let (server, metrics, records) = test_server();
+
+    let response = server.get("/metrics/stream").await;
+    let expected = "first chunk;second;third and last chunk";
+
+    assert_eq!(response.text(), expected);
+
+    let records = records.lock().unwrap();
+    let record = records.first().unwrap();
+
+    assert_eq!(record.bytes_in, 0);
+    assert_eq!(record.bytes_out, expected.len() as u64);
+    assert_eq!(metrics.bytes_out(), expected.len() as u64);
+}
+
+async fn send_mixed_requests(server: &TestServer) {
+    server.get("/metrics/users/1").await;
+    server.get("/metrics/users/1").await;
+    server.get("/metrics/users/2").await;
+    server.get("/metrics/users/abc").await;
+    server.get("/metrics/failure").await;
+    server.get("/metrics/missing").await;
+}
+
+fn stat<'a>(stats: &'a [RouteStats], route: &str, status: &str) -> &'a RouteStats {
+    stats
+        .iter()
+        .find(|stat| stat.route == route && stat.status == status)
+        .unwrap_or_else(|| panic!("no stats for {route} {status}"))
+}
+
+#[tokio::test]
+async fn metrics_label_routes_by_template_and_status_class() {
+    let (server, _, records) = test_server();
+
+    send_mixed_requests(&server).await;
+
+    let records = records.lock().unwrap();
+    let user = records.first().unwrap();
+
+    assert_eq!(user.path, "/metrics/users/1");
+    assert_eq!(user.route.as_deref(), Some("/metrics/users/{id}"));
+
+    let missing = records.last().unwrap();
+    assert_eq!(missing.route, None);
+}
+
+#[tokio::test]
+async fn metrics_render_route_error_rates() {
+    let (server, metrics, _) = test_server();
+
+    send_mixed_requests(&server).await;
+
+    let stats = metrics.route_stats();
+
+    assert_eq!(stats.len(), 4);
+    assert_eq!(stat(&stats, "/metrics/users/{id}", "2xx").requests, 2);
+    assert_eq!(stat(&stats, "/metrics/users/{id}", "4xx").requests, 2);
+    assert_eq!(stat(&stats, "/metrics/failure", "5xx").requests, 1);
+    assert_eq!(stat(&stats, "unmatched", "4xx").requests, 1);
+
+    let scraped = metrics.render();
+
+    assert!(scraped.contains(
+        "sword_http_requests_total{method=\"GET\",route=\"/metrics/users/{id}\",status=\"2xx\"} 2"
+    ));
+    assert!(scraped.contains(
+        "sword_http_requests_total{method=\"GET\",route=\"/metrics/users/{id}\",status=\"4xx\"} 2"
+    ));
+    assert!(scraped.contains(
+        "sword_http_requests_total{method=\"GET\",route=\"/metrics/failure\",status=\"5xx\"} 1"
+    ));
+    assert!(scraped.contains(
+        "sword_http_request_duration_seconds_count{method=\"GET\",route=\"unmatched\",status=\"4xx\"} 1"
+    ));
+    assert!(scraped.contains("# TYPE sword_http_request_duration_seconds summary"));
+}
+
+#[tokio::test]
+async fn metrics_can_label_exact_status_codes() {
+    let (server, metrics, _) = test_server_with(Metrics::new().exact_status_codes());
+
+    send_mixed_requests(&server).await;
+
+    let stats = metrics.route_stats();
+
+    assert_eq!(stat(&stats, "/metrics/users/{id}", "200").requests, 2);
+    assert_eq!(stat(&stats, "/metrics/users/{id}", "400").requests, 1);
+    assert_eq!(stat(&stats, "/metrics/users/{id}", "404").requests, 1);
+    assert_eq!(stat(&stats, "/metrics/failure", "500").requests, 1);
+}
@@ -0,0 +1,106 @@
+use axum::{http::header, response::Html};
+use axum_test::TestServer;
+use sword::prelude::*;
+
+const BROWSER_ACCEPT: &str =
+    "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8";
+
+#[controller("/items")]
+struct ItemsController;
+
+#[routes]
+impl ItemsController {
+    #[get("/")]
+    async fn list(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<ItemsController>()
+        .with_not_found_handler(|path, format| match format {
+            NotFoundFormat::Html => (
+                StatusCode::NOT_FOUND,
+                Html(format!("<h1>Nothing at {path}</h1>")),
+            )
+                .into_response(),
+            NotFoundFormat::Json => HttpResponse::NotFound()
+                .message(format!("Nothing at {path}"))
+                .into_response(),
+        })
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn api_clients_get_json() {
+    let response = test_server()
+        .get("/missing")
+        .add_header(header::ACCEPT, "application/json")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert_eq!(response.header(header::CONTENT_TYPE), "application/json");
+    assert_eq!(
+        response.json::<ResponseBody>().message.as_ref(),
+        "Nothing at /missing"
+    );
+}
+
+#[tokio::test]
+async fn browsers_get_html() {
+    let response = test_server()
+        .get("/missing")
+        .add_header(header::ACCEPT, BROWSER_ACCEPT)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert!(
+        response
+            .header(header::CONTENT_TYPE)
+            .to_str()
+            .unwrap()
+            .starts_with("text/html")
+    );
+    assert_eq!(response.text(), "<h1>Nothing at /missing</h1>");
+    assert_eq!(response.header(header::VARY), "accept");
+}
+
+#[tokio::test]
+async fn wildcards_and_missing_accept_get_json() {
+    let server = test_server();
+
+    for accept in ["*/*", "text/html;q=0.5, application/json"] {
+        let response = server
+            .get("/missing")
+            .add_header(header::ACCEPT, accept)
+            .await;
+        assert_eq!(response.header(header::CONTENT_TYPE), "application/json");
+    }
+
+    let response = server.get("/missing").await;
+    assert_eq!(response.header(header::CONTENT_TYPE), "application/json");
+}
+
+#[tokio::test]
+async fn default_404_is_json_without_handler() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<ItemsController>()
+        .build()
+        .unwrap();
+
+    let response = TestServer::new(app.router())
+        .unwrap()
+        .get("/missing")
+        .add_header(header::ACCEPT, BROWSER_ACCEPT)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert_eq!(response.header(header::CONTENT_TYPE), "application/json");
+    assert!(response.maybe_header(header::VARY).is_none());
+}
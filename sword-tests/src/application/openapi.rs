@@ -0,0 +1,181 @@
+use axum_test::TestServer;
+use serde_json::{Value, json};
+use sword::{prelude::*, web::openapi::ApiSchema};
+
+#[controller("/users")]
+struct UsersController;
+
+#[routes]
+impl UsersController {
+    #[get("/")]
+    async fn list(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+
+    #[post("/")]
+    async fn create(&self) -> HttpResponse {
+        HttpResponse::Created()
+    }
+
+    #[get("/{id}")]
+    async fn show(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+#[controller("/files")]
+struct FilesController;
+
+#[routes]
+impl FilesController {
+    #[get("/{*path}")]
+    async fn download(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+struct CreateUser;
+
+impl ApiSchema for CreateUser {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": { "name": { "type": "string" } },
+            "required": ["name"],
+        })
+    }
+}
+
+struct UserFilters;
+
+impl ApiSchema for UserFilters {
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "page": { "type": "integer" },
+                "role": { "type": "string" },
+            },
+            "required": ["page"],
+        })
+    }
+}
+
+fn app() -> Application {
+    Application::builder()
+        .unwrap()
+        .with_controller::<UsersController>()
+        .with_controller::<FilesController>()
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn spec_lists_the_controller_routes() {
+    let spec = serde_json::to_value(app().openapi_spec()).unwrap();
+
+    assert_eq!(spec["openapi"], "3.1.0");
+    assert_eq!(spec["info"], json!({ "title": "API", "version": "1.0.0" }));
+
+    let paths = spec["paths"].as_object().unwrap();
+    let mut listed: Vec<&str> = paths.keys().map(String::as_str).collect();
+    listed.sort();
+
+    assert_eq!(listed, vec!["/files/{path}", "/users", "/users/{id}"]);
+    assert!(paths["/users"]["get"].is_object());
+    assert!(paths["/users"]["post"].is_object());
+    assert_eq!(
+        paths["/users/{id}"]["get"]["tags"],
+        json!(["UsersController"])
+    );
+    assert!(spec.get("components").is_none());
+}
+
+#[test]
+fn spec_declares_path_parameters() {
+    let spec = serde_json::to_value(app().openapi_spec()).unwrap();
+
+    let expected = json!([{
+        "name": "path",
+        "in": "path",
+        "required": true,
+        "schema": { "type": "string" },
+    }]);
+
+    assert_eq!(
+        spec["paths"]["/files/{path}"]["get"]["parameters"],
+        expected
+    );
+    assert!(spec["paths"]["/users"]["get"].get("parameters").is_none());
+}
+
+#[test]
+fn spec_takes_request_bodies_and_queries_from_api_schemas() {
+    let spec = app()
+        .openapi_spec()
+        .info("Users API", "2.0.0")
+        .request_body::<CreateUser>(Method::POST, "/users")
+        .query::<UserFilters>(Method::GET, "/users");
+
+    let spec = serde_json::to_value(spec).unwrap();
+
+    assert_eq!(spec["info"]["title"], "Users API");
+    assert_eq!(
+        spec["paths"]["/users"]["post"]["requestBody"]["content"]["application/json"]
+            ["schema"]["$ref"],
+        "#/components/schemas/CreateUser"
+    );
+    assert_eq!(
+        spec["components"]["schemas"]["CreateUser"],
+        CreateUser::schema()
+    );
+
+    let parameters = spec["paths"]["/users"]["get"]["parameters"]
+        .as_array()
+        .unwrap();
+
+    assert_eq!(parameters.len(), 2);
+    assert_eq!(parameters[0]["name"], "page");
+    assert_eq!(parameters[0]["in"], "query");
+    assert_eq!(parameters[0]["required"], true);
+    assert_eq!(parameters[1]["name"], "role");
+    assert_eq!(parameters[1]["required"], false);
+}
+
+#[tokio::test]
+async fn openapi_route_serves_the_spec() {
+    let app = Application::builder()
+        .unwrap()
+        .with_prefix("/api")
+        .with_controller::<UsersController>()
+        .with_openapi_route("/openapi.json")
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+    let response = server.get("/api/openapi.json").await;
+
+    assert_eq!(response.status_code(), 200);
+
+    let spec = response.json::<Value>();
+
+    assert!(spec["paths"]["/api/users/{id}"]["get"].is_object());
+    assert!(spec["paths"].get("/api/openapi.json").is_none());
+}
+
+#[test]
+fn openapi_route_conflicting_with_a_controller_is_an_error() {
+    let error = Application::builder()
+        .unwrap()
+        .with_controller::<UsersController>()
+        .with_openapi_route("/users")
+        .build()
+        .err()
+        .expect("GET /users is already a controller route");
+
+    assert!(matches!(
+        error,
+        ApplicationError::RouteConflict { route, controller: "with_openapi_route", .. }
+            if route == "GET /users"
+    ));
+}
@@ -0,0 +1,148 @@
+use axum_test::TestServer;
+use serde_json::Value;
+use sword::prelude::*;
+
+#[controller("/files")]
+struct CatchAllController;
+
+#[routes]
+impl CatchAllController {
+    #[get("/{*path}")]
+    async fn any_file(&self) -> HttpResponse {
+        HttpResponse::Ok().data("catch-all")
+    }
+}
+
+#[controller("/files")]
+struct ReadmeController;
+
+#[routes]
+impl ReadmeController {
+    #[get("/readme")]
+    async fn readme(&self) -> HttpResponse {
+        HttpResponse::Ok().data("readme")
+    }
+
+    #[post("/{*path}")]
+    async fn upload(&self) -> HttpResponse {
+        HttpResponse::Created()
+    }
+}
+
+#[controller("/files")]
+struct DuplicateReadmeController;
+
+#[routes]
+impl DuplicateReadmeController {
+    #[get("/readme")]
+    async fn readme(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+#[controller("/users")]
+struct UsersByIdController;
+
+#[routes]
+impl UsersByIdController {
+    #[get("/{id}")]
+    async fn user(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+#[controller("/users")]
+struct UsersByNameController;
+
+#[routes]
+impl UsersByNameController {
+    #[delete("/{name}")]
+    async fn delete_user(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+async fn data(server: &TestServer, path: &str) -> Option<Value> {
+    server.get(path).await.json::<ResponseBody>().data
+}
+
+#[tokio::test]
+async fn most_specific_route_wins_regardless_of_order() {
+    for specific_first in [true, false] {
+        let builder = Application::builder().unwrap();
+
+        let builder = match specific_first {
+            true => builder
+                .with_controller::<ReadmeController>()
+                .with_controller::<CatchAllController>(),
+            false => builder
+                .with_controller::<CatchAllController>()
+                .with_controller::<ReadmeController>(),
+        };
+
+        let server = TestServer::new(builder.build().unwrap().router()).unwrap();
+
+        assert_eq!(data(&server, "/files/readme").await, Some("readme".into()));
+        assert_eq!(
+            data(&server, "/files/docs/guide.md").await,
+            Some("catch-all".into())
+        );
+
+        let response = server.post("/files/docs/guide.md").await;
+        assert_eq!(response.status_code(), StatusCode::CREATED);
+    }
+}
+
+#[test]
+fn same_method_and_path_is_a_conflict() {
+    let result = Application::builder()
+        .unwrap()
+        .with_controller::<ReadmeController>()
+        .try_with_controller::<DuplicateReadmeController>();
+
+    let Err(ApplicationError::RouteConflict {
+        route,
+        controller,
+        conflicts_with,
+        existing,
+    }) = result
+    else {
+        panic!("expected a route conflict");
+    };
+
+    assert_eq!(route, "GET /files/readme");
+    assert_eq!(conflicts_with, "GET /files/readme");
+    assert!(controller.ends_with("DuplicateReadmeController"));
+    assert!(existing.ends_with("::ReadmeController"));
+}
+
+#[test]
+fn indistinguishable_paths_are_a_conflict() {
+    let result = Application::builder()
+        .unwrap()
+        .with_controller::<UsersByIdController>()
+        .try_with_controller::<UsersByNameController>();
+
+    let Err(ApplicationError::RouteConflict {
+        route,
+        conflicts_with,
+        ..
+    }) = result
+    else {
+        panic!("expected a route conflict");
+    };
+
+    assert_eq!(route, "DELETE /users/{name}");
+    assert_eq!(conflicts_with, "/users/{id}");
+}
+
+#[test]
+#[should_panic(
+    expected = "Failed to register controller: Route 'GET /files/readme'"
+)]
+fn with_controller_panics_on_conflict() {
+    let _ = Application::builder()
+        .unwrap()
+        .with_controller::<ReadmeController>()
+        .with_controller::<DuplicateReadmeController>();
+}
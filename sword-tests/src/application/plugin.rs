@@ -0,0 +1,155 @@
+use std::sync::{Arc, Mutex};
+
+use axum_test::TestServer;
+use serde::Deserialize;
+use serde_json::json;
+use sword::prelude::*;
+
+#[provider]
+struct AuditLog {
+    entries: Arc<Mutex<Vec<String>>>,
+}
+
+impl AuditLog {
+    fn new() -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn record(&self, entry: &str) {
+        self.entries.lock().unwrap().push(entry.to_string());
+    }
+
+    fn entries(&self) -> Vec<String> {
+        self.entries.lock().unwrap().clone()
+    }
+}
+
+#[controller("/admin/users")]
+struct AdminUsersController {
+    audit: AuditLog,
+}
+
+#[routes]
+impl AdminUsersController {
+    #[post("/")]
+    async fn create(&self) -> HttpResponse {
+        self.audit.record("user created");
+
+        HttpResponse::Created().message("User created")
+    }
+}
+
+#[controller("/admin/audit")]
+struct AdminAuditController {
+    audit: AuditLog,
+}
+
+#[routes]
+impl AdminAuditController {
+    #[get("/")]
+    async fn entries(&self) -> HttpResponse {
+        HttpResponse::Ok().data(self.audit.entries())
+    }
+}
+
+#[derive(Deserialize)]
+#[config(key = "my-custom-section")]
+struct AdminConfig {
+    #[allow(dead_code)]
+    custom_key: String,
+}
+
+struct AdminPlugin;
+
+impl RoutesPlugin for AdminPlugin {
+    fn validate_config(config: &Config) -> Result<(), ConfigError> {
+        config.get::<AdminConfig>().map(|_| ())
+    }
+
+    fn register(self, app: ApplicationBuilder) -> ApplicationBuilder {
+        let container = DependencyContainer::builder()
+            .register_provider(AuditLog::new())
+            .build();
+
+        app.with_dependency_container(container)
+            .with_controller::<AdminUsersController>()
+            .with_controller::<AdminAuditController>()
+    }
+}
+
+#[derive(Deserialize)]
+#[config(key = "billing-dashboard")]
+struct BillingConfig {}
+
+struct BillingPlugin;
+
+impl RoutesPlugin for BillingPlugin {
+    fn validate_config(config: &Config) -> Result<(), ConfigError> {
+        config.get::<BillingConfig>().map(|_| ())
+    }
+
+    fn register(self, app: ApplicationBuilder) -> ApplicationBuilder {
+        app
+    }
+}
+
+#[tokio::test]
+async fn plugin_routes_share_its_state() {
+    let app = Application::builder()
+        .unwrap()
+        .with_plugin(AdminPlugin)
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+
+    let created = server.post("/admin/users").await;
+    assert_eq!(created.status_code(), StatusCode::CREATED);
+
+    let audit = server.get("/admin/audit").await;
+
+    assert_eq!(audit.status_code(), StatusCode::OK);
+    assert_eq!(
+        audit.json::<ResponseBody>().data.unwrap(),
+        json!(["user created"])
+    );
+}
+
+#[tokio::test]
+async fn plugin_routes_get_the_global_prefix() {
+    let app = Application::builder()
+        .unwrap()
+        .with_plugin(AdminPlugin)
+        .with_prefix("/api")
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+
+    let response = server.get("/api/admin/audit").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[test]
+fn missing_plugin_config_fails_the_build() {
+    let result = Application::builder()
+        .unwrap()
+        .with_plugin(AdminPlugin)
+        .with_plugin(BillingPlugin)
+        .build();
+
+    let Err(error) = result else {
+        panic!("expected the build to fail");
+    };
+
+    let ApplicationError::PluginConfigError { plugin, source } = error else {
+        panic!("expected a plugin config error, got {error}");
+    };
+
+    assert!(plugin.ends_with("BillingPlugin"));
+    assert!(
+        matches!(source, ConfigError::KeyNotFound(key) if key == "billing-dashboard")
+    );
+}
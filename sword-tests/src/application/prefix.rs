@@ -4,9 +4,11 @@ use sword::prelude::*;
 
 static APPLICATION: LazyLock<TestServer> = LazyLock::new(|| {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<RootController>()
         .with_prefix("/api")
-        .build();
+        .build()
+        .unwrap();
 
     TestServer::new(app.router()).unwrap()
 });
@@ -0,0 +1,129 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use axum_test::TestServer;
+use sword::{core::State, prelude::*};
+use tokio::sync::Notify;
+
+#[derive(Clone, Default)]
+struct Cache {
+    warm: Arc<AtomicBool>,
+}
+
+#[controller("/orders")]
+struct OrdersController;
+
+#[routes]
+impl OrdersController {
+    #[get("/")]
+    async fn list(&self) -> HttpResponse {
+        HttpResponse::Ok().data("orders")
+    }
+}
+
+/// Builds an application whose startup hook waits for `release` before
+/// warming the cache.
+fn slow_application(probe: ReadinessProbe, release: Arc<Notify>) -> Application {
+    Application::builder()
+        .unwrap()
+        .with_controller::<OrdersController>()
+        .with_state(Cache::default())
+        .with_prefix("/api")
+        .on_startup(move |state: State| {
+            let release = release.clone();
+
+            async move {
+                release.notified().await;
+                state
+                    .get::<Cache>()
+                    .unwrap()
+                    .warm
+                    .store(true, Ordering::SeqCst);
+            }
+        })
+        .with_readiness_probe(probe)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn probe_is_unavailable_until_startup_completes() {
+    let release = Arc::new(Notify::new());
+    let app = Arc::new(slow_application(
+        ReadinessProbe::new("/readyz"),
+        release.clone(),
+    ));
+    let server = TestServer::new(app.router()).unwrap();
+
+    let startup = tokio::spawn({
+        let app = app.clone();
+        async move { app.startup().await }
+    });
+
+    let response = server.get("/readyz").await;
+    assert_eq!(response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+
+    let response = server.get("/api/orders").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    release.notify_one();
+    startup.await.unwrap();
+
+    let response = server.get("/readyz").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ResponseBody>().message, "Ready".into());
+}
+
+#[tokio::test]
+async fn traffic_can_be_held_until_ready() {
+    let release = Arc::new(Notify::new());
+    let probe = ReadinessProbe::new("/readyz").hold_traffic();
+    let app = slow_application(probe, release.clone());
+    let server = TestServer::new(app.router()).unwrap();
+
+    let response = server.get("/api/orders").await;
+    assert_eq!(response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+
+    release.notify_one();
+    app.startup().await;
+
+    let response = server.get("/api/orders").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn startup_hooks_run_once_in_order_with_the_state() {
+    let calls = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let record = |name: &'static str| {
+        let calls = calls.clone();
+
+        move |state: State| {
+            let calls = calls.clone();
+
+            async move {
+                let cache = state.get::<Cache>().unwrap();
+                cache.warm.store(true, Ordering::SeqCst);
+                calls.lock().unwrap().push(name);
+            }
+        }
+    };
+
+    let cache = Cache::default();
+
+    let app = Application::builder()
+        .unwrap()
+        .with_state(cache.clone())
+        .on_startup(record("first"))
+        .on_startup(record("second"))
+        .build()
+        .unwrap();
+
+    app.startup().await;
+    app.startup().await;
+
+    assert!(cache.warm.load(Ordering::SeqCst));
+    assert_eq!(*calls.lock().unwrap(), ["first", "second"]);
+}
@@ -0,0 +1,98 @@
+use axum_test::TestServer;
+use serde::{Deserialize, Serialize};
+use sword::prelude::*;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct SearchQuery {
+    q: Option<String>,
+}
+
+#[controller("/v1")]
+struct RewriteController;
+
+#[routes]
+impl RewriteController {
+    #[get("/new")]
+    async fn new_route(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let query: SearchQuery = ctx.query()?.unwrap_or_default();
+
+        Ok(HttpResponse::Ok().message("New route").data(query))
+    }
+
+    #[get("/users/{id}")]
+    async fn user(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let id: u32 = ctx.param("id")?;
+
+        Ok(HttpResponse::Ok().message("User route").data(id))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<RewriteController>()
+        .with_rewrite("/v1/old", "/v1/new")
+        .with_rewrite("/legacy/members/{id}", "/v1/users/{id}")
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn rewritten_path_hits_new_route() {
+    let server = test_server();
+    let response = server.get("/v1/old").await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(body.message.as_ref(), "New route");
+}
+
+#[tokio::test]
+async fn rewrite_preserves_query_string() {
+    let server = test_server();
+    let response = server.get("/v1/old?q=sword").await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(body.data.unwrap().get("q").unwrap(), "sword");
+}
+
+#[tokio::test]
+async fn rewrite_forwards_captured_params() {
+    let server = test_server();
+    let response = server.get("/legacy/members/42").await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(body.message.as_ref(), "User route");
+    assert_eq!(body.data.unwrap(), 42);
+}
+
+#[tokio::test]
+async fn unmatched_paths_are_not_rewritten() {
+    let server = test_server();
+    let response = server.get("/v1/missing").await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        body.message.as_ref(),
+        "The requested resource was not found"
+    );
+}
+
+#[test]
+fn conflicting_rewrites_are_returned_by_try_with_rewrite() {
+    let error = Application::builder()
+        .unwrap()
+        .with_rewrite("/v1/old", "/v1/new")
+        .try_with_rewrite("/v1/old", "/v1/users/1")
+        .expect_err("the second rewrite conflicts with the first");
+
+    assert!(matches!(
+        error,
+        ApplicationError::InvalidRewrite { pattern, .. } if pattern == "/v1/old"
+    ));
+}
@@ -0,0 +1,85 @@
+use axum::{Router, routing::get};
+use sword::{core::RouteInfo, prelude::*};
+
+#[controller("/users")]
+struct UsersController;
+
+#[routes]
+impl UsersController {
+    #[get("/{id}")]
+    async fn show(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+
+    #[route(methods = ["POST", "PUT"], path = "/")]
+    async fn save(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+
+    #[get("/beta")]
+    #[feature("beta_api")]
+    async fn beta(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+#[controller("/")]
+struct HealthController;
+
+#[routes]
+impl HealthController {
+    #[get("/health")]
+    async fn health(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+fn route(method: Method, path: &str, controller: &'static str) -> RouteInfo {
+    RouteInfo {
+        method,
+        path: path.to_string(),
+        controller,
+        tag: None,
+    }
+}
+
+#[test]
+fn routes_list_every_controller_route() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<UsersController>()
+        .with_controller::<HealthController>()
+        .with_router(
+            "/legacy",
+            Router::new().route("/ping", get(|| async { "pong" })),
+        )
+        .build()
+        .unwrap();
+
+    let users = std::any::type_name::<UsersController>();
+    let health = std::any::type_name::<HealthController>();
+
+    assert_eq!(
+        app.routes(),
+        vec![
+            route(Method::GET, "/health", health),
+            route(Method::POST, "/users", users),
+            route(Method::PUT, "/users", users),
+            route(Method::GET, "/users/{id}", users),
+        ]
+    );
+}
+
+#[test]
+fn routes_include_the_global_prefix() {
+    let app = Application::builder()
+        .unwrap()
+        .with_prefix("/api/v1")
+        .with_controller::<HealthController>()
+        .build()
+        .unwrap();
+
+    let paths: Vec<String> = app.routes().into_iter().map(|r| r.path).collect();
+
+    assert_eq!(paths, vec!["/api/v1/health"]);
+}
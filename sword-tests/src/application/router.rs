@@ -0,0 +1,102 @@
+use axum::{
+    Router,
+    extract::State as AxumState,
+    http::header,
+    routing::{get, post},
+};
+use axum_test::TestServer;
+use serde_json::Value;
+use sword::{core::State, prelude::*};
+use tower_http::set_header::SetResponseHeaderLayer;
+
+#[controller("/native")]
+struct NativeController;
+
+#[routes]
+impl NativeController {
+    #[get("/")]
+    async fn index(&self) -> HttpResponse {
+        HttpResponse::Ok().message("From a controller")
+    }
+}
+
+async fn app_name(AxumState(state): AxumState<State>) -> String {
+    let config = state.get::<Config>().unwrap();
+    let app_config = config.get::<ApplicationConfig>().unwrap();
+
+    app_config.port.to_string()
+}
+
+async fn echo(ctx: Context) -> HttpResult<HttpResponse> {
+    let body: Value = ctx.body()?;
+    Ok(HttpResponse::Ok().data(body))
+}
+
+fn legacy_router() -> Router<State> {
+    Router::new()
+        .route("/health", get(|| async { "OK" }))
+        .route("/port", get(app_name))
+        .route("/echo", post(echo))
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<NativeController>()
+        .with_router("/legacy", legacy_router())
+        .with_layer(SetResponseHeaderLayer::overriding(
+            header::SERVER,
+            header::HeaderValue::from_static("sword"),
+        ))
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn mounted_router_routes_are_served() {
+    let response = test_server().get("/legacy/health").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.text(), "OK");
+}
+
+#[tokio::test]
+async fn mounted_router_shares_the_application_state() {
+    let response = test_server().get("/legacy/port").await;
+
+    assert_eq!(response.text(), "8080");
+}
+
+#[tokio::test]
+async fn mounted_router_handlers_can_use_context() {
+    let response = test_server()
+        .post("/legacy/echo")
+        .json(&serde_json::json!({ "ok": true }))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        response.json::<ResponseBody>().data.unwrap(),
+        serde_json::json!({ "ok": true })
+    );
+}
+
+#[tokio::test]
+async fn layers_and_fallback_still_apply() {
+    let server = test_server();
+
+    let mounted = server.get("/legacy/health").await;
+    let native = server.get("/native").await;
+    let missing = server.get("/legacy/missing").await;
+
+    assert_eq!(mounted.header(header::SERVER), "sword");
+    assert_eq!(native.header(header::SERVER), "sword");
+
+    assert_eq!(missing.status_code(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        missing.json::<ResponseBody>().message.as_ref(),
+        "The requested resource was not found"
+    );
+}
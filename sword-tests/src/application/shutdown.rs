@@ -0,0 +1,148 @@
+use std::{
+    sync::{
+        Arc, LazyLock, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use sword::prelude::*;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    sync::oneshot,
+    time::{sleep, timeout},
+};
+
+static EVENTS: LazyLock<Mutex<Vec<&'static str>>> =
+    LazyLock::new(|| Mutex::new(Vec::new()));
+
+fn record(event: &'static str) {
+    EVENTS.lock().unwrap().push(event);
+}
+
+#[controller("/shutdown")]
+struct ShutdownController;
+
+#[routes]
+impl ShutdownController {
+    #[get("/slow")]
+    async fn slow(&self) -> HttpResponse {
+        record("request started");
+        sleep(Duration::from_millis(300)).await;
+        record("request finished");
+
+        HttpResponse::Ok()
+    }
+}
+
+#[tokio::test]
+async fn background_task_completes_after_signal() {
+    let coordinator = ShutdownCoordinator::new();
+    let completed = Arc::new(AtomicBool::new(false));
+    let flag = completed.clone();
+
+    coordinator.spawn("worker", |mut signal| async move {
+        signal.wait().await;
+        sleep(Duration::from_millis(50)).await;
+        flag.store(true, Ordering::SeqCst);
+    });
+
+    assert!(!coordinator.is_shutting_down());
+
+    let report = coordinator.shutdown().await;
+
+    assert!(completed.load(Ordering::SeqCst));
+    assert_eq!(report.completed, vec!["worker".to_string()]);
+    assert!(report.aborted.is_empty());
+}
+
+#[tokio::test]
+async fn stuck_tasks_are_aborted_after_timeout() {
+    let coordinator =
+        ShutdownCoordinator::new().with_timeout(Duration::from_millis(100));
+
+    coordinator.spawn("well-behaved", |mut signal| async move {
+        signal.wait().await;
+    });
+
+    coordinator.spawn("stuck", |_| async move {
+        sleep(Duration::from_secs(60)).await;
+    });
+
+    let report = timeout(Duration::from_secs(5), coordinator.shutdown())
+        .await
+        .expect("shutdown should not wait for stuck tasks");
+
+    assert_eq!(report.completed, vec!["well-behaved".to_string()]);
+    assert_eq!(report.aborted, vec!["stuck".to_string()]);
+}
+
+#[tokio::test]
+async fn background_tasks_stop_after_http_drains() {
+    let coordinator = ShutdownCoordinator::new();
+
+    coordinator.spawn("recorder", |mut signal| async move {
+        signal.wait().await;
+        record("task signaled");
+    });
+
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<ShutdownController>()
+        .with_shutdown_coordinator(coordinator)
+        .build()
+        .unwrap();
+
+    let (trigger, signal) = oneshot::channel::<()>();
+
+    let server = tokio::spawn(async move {
+        app.run_with_graceful_shutdown(async move {
+            let _ = signal.await;
+        })
+        .await;
+
+        record("server exited");
+    });
+
+    let mut client = loop {
+        if let Ok(stream) = TcpStream::connect("127.0.0.1:8080").await {
+            break stream;
+        }
+
+        sleep(Duration::from_millis(20)).await;
+    };
+
+    client
+        .write_all(b"GET /shutdown/slow HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .await
+        .unwrap();
+
+    while !EVENTS.lock().unwrap().contains(&"request started") {
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    trigger.send(()).unwrap();
+
+    let mut buffer = [0_u8; 1024];
+    let read = client.read(&mut buffer).await.unwrap();
+
+    assert!(String::from_utf8_lossy(&buffer[..read]).starts_with("HTTP/1.1 200"));
+
+    drop(client);
+
+    timeout(Duration::from_secs(5), server)
+        .await
+        .expect("server did not exit")
+        .unwrap();
+
+    assert_eq!(
+        *EVENTS.lock().unwrap(),
+        vec![
+            "request started",
+            "request finished",
+            "task signaled",
+            "server exited"
+        ]
+    );
+}
@@ -0,0 +1,122 @@
+use std::{
+    io,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use sword::prelude::*;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::oneshot,
+    time::{sleep, timeout},
+};
+
+static STARTED: AtomicBool = AtomicBool::new(false);
+
+#[controller("/stuck")]
+struct StuckController;
+
+#[routes]
+impl StuckController {
+    #[get("/")]
+    async fn stuck(&self) -> HttpResponse {
+        STARTED.store(true, Ordering::SeqCst);
+        sleep(Duration::from_secs(60)).await;
+
+        HttpResponse::Ok()
+    }
+}
+
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedLogs {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// The test config sets `graceful_shutdown_timeout_seconds = 1`.
+#[tokio::test]
+async fn stuck_requests_are_force_closed_after_the_timeout() {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    // The runtime of the test is single-threaded, so the spawned server
+    // logs to this subscriber too.
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<StuckController>()
+        .build()
+        .unwrap();
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let (trigger, signal) = oneshot::channel::<()>();
+
+    let server = tokio::spawn(async move {
+        app.serve_on_listener_with_graceful_shutdown(listener, async move {
+            let _ = signal.await;
+        })
+        .await;
+    });
+
+    let mut client = TcpStream::connect(addr).await.unwrap();
+
+    client
+        .write_all(b"GET /stuck HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .await
+        .unwrap();
+
+    while !STARTED.load(Ordering::SeqCst) {
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    trigger.send(()).unwrap();
+
+    timeout(Duration::from_secs(5), server)
+        .await
+        .expect("server did not stop after the shutdown timeout")
+        .unwrap();
+
+    let mut buffer = [0_u8; 1024];
+    let read = timeout(Duration::from_secs(5), client.read(&mut buffer))
+        .await
+        .expect("the stuck request was not answered")
+        .unwrap();
+
+    let response = String::from_utf8_lossy(&buffer[..read]);
+    assert!(response.starts_with("HTTP/1.1 503"));
+
+    let output = logs.contents();
+
+    assert!(output.contains("WARN"));
+    assert!(output.contains("sword::shutdown"));
+    assert!(
+        output.contains("Graceful shutdown timed out, force-closing connections")
+    );
+    assert!(output.contains("connections=1"));
+}
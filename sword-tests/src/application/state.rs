@@ -0,0 +1,224 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use axum::{body::Body, extract::Request};
+use axum_test::TestServer;
+use sword::prelude::*;
+use tower::ServiceExt;
+
+static INITIALIZATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct WarmCache {
+    hits: AtomicUsize,
+}
+
+impl WarmCache {
+    fn warm_up() -> Self {
+        INITIALIZATIONS.fetch_add(1, Ordering::SeqCst);
+
+        // Widen the race window so concurrent requests overlap here.
+        std::thread::sleep(Duration::from_millis(50));
+
+        Self {
+            hits: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[controller("/state")]
+struct StateController;
+
+#[routes]
+impl StateController {
+    #[get("/cache")]
+    async fn cache(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let cache = ctx.state_or_init(WarmCache::warm_up)?;
+        let hits = cache.hits.fetch_add(1, Ordering::SeqCst) + 1;
+
+        Ok(HttpResponse::Ok().data(hits))
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn state_or_init_initializes_once_under_concurrency() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<StateController>()
+        .build()
+        .unwrap();
+
+    let router = app.router();
+
+    let requests = (0..16).map(|_| {
+        let router = router.clone();
+        let request = Request::get("/state/cache").body(Body::empty()).unwrap();
+
+        tokio::spawn(async move { router.oneshot(request).await.unwrap().status() })
+    });
+
+    for request in futures::future::join_all(requests).await {
+        assert_eq!(request.unwrap(), StatusCode::OK);
+    }
+
+    let server = TestServer::new(router).unwrap();
+    let body = server.get("/state/cache").await.json::<ResponseBody>();
+
+    assert_eq!(INITIALIZATIONS.load(Ordering::SeqCst), 1);
+    assert_eq!(body.data.unwrap(), 17);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct AppVersion(&'static str);
+
+#[controller("/version")]
+struct VersionController;
+
+#[routes]
+impl VersionController {
+    #[get("/")]
+    async fn version(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let AppVersion(version) = ctx.di::<AppVersion>()?;
+
+        Ok(HttpResponse::Ok().data(version))
+    }
+}
+
+struct Banner(String);
+
+#[controller("/banner")]
+struct BannerController;
+
+#[routes]
+impl BannerController {
+    #[get("/")]
+    async fn banner(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let banner = ctx.state_or_init(|| {
+            let AppVersion(version) = ctx.di::<AppVersion>().unwrap();
+            Banner(format!("sword {version}"))
+        })?;
+
+        Ok(HttpResponse::Ok().data(&banner.0))
+    }
+}
+
+#[tokio::test]
+async fn state_or_init_can_read_the_state_while_initializing() {
+    let app = Application::builder()
+        .unwrap()
+        .with_state(AppVersion("1.4.0"))
+        .with_controller::<BannerController>()
+        .build()
+        .unwrap();
+
+    let response = TestServer::new(app.router()).unwrap().get("/banner").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), "sword 1.4.0");
+}
+
+#[derive(Clone)]
+struct UnregisteredClient;
+
+#[controller("/missing-state")]
+struct MissingStateController;
+
+#[routes]
+impl MissingStateController {
+    #[get("/")]
+    async fn missing(&self, ctx: Context) -> HttpResponse {
+        let error = ctx.di::<UnregisteredClient>().err().unwrap();
+
+        HttpResponse::Ok().data(serde_json::json!({
+            "message": error.to_string(),
+            "registered": error.registered_types(),
+        }))
+    }
+}
+
+#[tokio::test]
+async fn missing_state_error_lists_registered_types() {
+    let app = Application::builder()
+        .unwrap()
+        .with_state(AppVersion("1.4.0"))
+        .with_controller::<MissingStateController>()
+        .build()
+        .unwrap();
+
+    let response = TestServer::new(app.router())
+        .unwrap()
+        .get("/missing-state")
+        .await;
+    let data = response.json::<ResponseBody>().data.unwrap();
+
+    let message = data["message"].as_str().unwrap();
+    let registered: Vec<&str> = data["registered"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|name| name.as_str().unwrap())
+        .collect();
+
+    assert!(message.contains("UnregisteredClient"));
+    assert!(message.contains("Registered types:"));
+    assert!(message.contains("state::AppVersion"));
+    assert!(registered.is_sorted());
+    assert!(
+        registered
+            .iter()
+            .any(|name| name.ends_with("state::AppVersion"))
+    );
+}
+
+#[tokio::test]
+async fn with_state_registers_shared_values() {
+    let app = Application::builder()
+        .unwrap()
+        .with_state(AppVersion("1.4.0"))
+        .with_controller::<VersionController>()
+        .build()
+        .unwrap();
+
+    let response = TestServer::new(app.router()).unwrap().get("/version").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), "1.4.0");
+}
+
+#[test]
+fn try_with_state_rejects_duplicate_types() {
+    let builder = Application::builder()
+        .unwrap()
+        .try_with_state(AppVersion("1.4.0"))
+        .unwrap();
+
+    let Err(error) = builder.try_with_state(AppVersion("2.0.0")) else {
+        panic!("expected a duplicate state error");
+    };
+
+    let StateError::DuplicateType { type_name } = &error else {
+        panic!("expected a duplicate state error, got {error}");
+    };
+
+    assert!(type_name.ends_with("AppVersion"));
+    assert!(error.to_string().contains("already registered"));
+}
+
+#[test]
+fn try_with_state_rejects_framework_types() {
+    let result = Application::builder()
+        .unwrap()
+        .try_with_state(Config::default());
+
+    assert!(matches!(result, Err(StateError::DuplicateType { .. })));
+}
+
+#[test]
+#[should_panic(expected = "Failed to register state")]
+fn with_state_panics_on_duplicate_types() {
+    let _ = Application::builder()
+        .unwrap()
+        .with_state(AppVersion("1.4.0"))
+        .with_state(AppVersion("2.0.0"));
+}
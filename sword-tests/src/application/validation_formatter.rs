@@ -0,0 +1,148 @@
+use axum_test::TestServer;
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+use sword::{errors::ValidationErrorFormatter, prelude::*};
+use validator::{Validate, ValidationErrors};
+
+#[derive(Deserialize, Validate)]
+struct SignupBody {
+    #[validate(email(message = "Must be a valid email address"))]
+    email: String,
+
+    #[validate(length(min = 8, message = "Must be at least 8 characters long"))]
+    password: String,
+}
+
+#[derive(Deserialize, Validate)]
+struct SignupQuery {
+    #[validate(length(min = 2, message = "Must be at least 2 characters long"))]
+    referrer: String,
+}
+
+#[controller("/signup")]
+struct SignupController;
+
+#[routes]
+impl SignupController {
+    #[post("/")]
+    async fn signup(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let body: SignupBody = ctx.body_validator()?;
+
+        Ok(HttpResponse::Ok().data(body.email))
+    }
+
+    #[post("/referred")]
+    async fn referred(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let (_, body) = ctx.validate_all::<SignupQuery, SignupBody>()?;
+
+        Ok(HttpResponse::Ok().data(body.email))
+    }
+}
+
+/// Lists the messages of each field, the way the frontend expects them.
+struct MessagesOnly;
+
+impl ValidationErrorFormatter for MessagesOnly {
+    fn format(
+        &self,
+        _: StatusCode,
+        message: &'static str,
+        errors: ValidationErrors,
+    ) -> HttpResponse {
+        let fields = errors
+            .field_errors()
+            .into_iter()
+            .map(|(field, errors)| {
+                let messages = errors
+                    .iter()
+                    .filter_map(|error| error.message.clone())
+                    .map(|message| Value::String(message.into()))
+                    .collect();
+
+                (field.to_string(), Value::Array(messages))
+            })
+            .collect::<Map<_, _>>();
+
+        HttpResponse::UnprocessableEntity()
+            .message(message)
+            .errors(fields)
+    }
+}
+
+fn test_server(builder: ApplicationBuilder) -> TestServer {
+    let app = builder
+        .with_controller::<SignupController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+fn invalid_body() -> Value {
+    json!({ "email": "not-an-email", "password": "short" })
+}
+
+#[tokio::test]
+async fn registered_formatter_builds_the_response() {
+    let builder = Application::builder()
+        .unwrap()
+        .with_validation_formatter(MessagesOnly);
+
+    let response = test_server(builder)
+        .post("/signup")
+        .json(&invalid_body())
+        .await;
+    let body = response.json::<Value>();
+
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["message"], "Invalid request body");
+    assert_eq!(
+        body["errors"],
+        json!({
+            "email": ["Must be a valid email address"],
+            "password": ["Must be at least 8 characters long"],
+        })
+    );
+}
+
+#[tokio::test]
+async fn default_formatter_is_used_without_one() {
+    let builder = Application::builder().unwrap();
+
+    let response = test_server(builder)
+        .post("/signup")
+        .json(&invalid_body())
+        .await;
+    let body = response.json::<Value>();
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(body["errors"]["email"][0]["code"], "email");
+    assert_eq!(
+        body["errors"]["email"][0]["message"],
+        "Must be a valid email address"
+    );
+}
+
+#[tokio::test]
+async fn registered_formatter_builds_validate_all_responses() {
+    let builder = Application::builder()
+        .unwrap()
+        .with_validation_formatter(MessagesOnly);
+
+    let response = test_server(builder)
+        .post("/signup/referred?referrer=x")
+        .json(&invalid_body())
+        .await;
+    let body = response.json::<Value>();
+
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["message"], "Invalid request");
+    assert_eq!(
+        body["errors"],
+        json!({
+            "query.referrer": ["Must be at least 2 characters long"],
+            "body.email": ["Must be a valid email address"],
+            "body.password": ["Must be at least 8 characters long"],
+        })
+    );
+}
@@ -4,11 +4,13 @@ use sword::prelude::*;
 
 fn test_server() -> TestServer {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<V1UsersController>()
         .with_controller::<V2UsersController>()
         .with_controller::<V1ProductsController>()
         .with_controller::<V2ProductsController>()
-        .build();
+        .build()
+        .unwrap();
 
     TestServer::new(app.router()).unwrap()
 }
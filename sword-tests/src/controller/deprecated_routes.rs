@@ -0,0 +1,110 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use axum_test::TestServer;
+use sword::prelude::*;
+
+#[controller("/users")]
+struct UsersController;
+
+#[routes]
+impl UsersController {
+    #[get("/legacy")]
+    #[deprecated_route(sunset = "2025-12-01", log = true)]
+    async fn legacy(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+
+    #[get("/old")]
+    #[deprecated_route]
+    async fn old(&self) -> HttpResponse {
+        HttpResponse::NotFound()
+    }
+
+    #[get("/current")]
+    async fn current(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<UsersController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn deprecated_route_sends_deprecation_and_sunset_headers() {
+    let response = test_server().get("/users/legacy").await;
+
+    assert_eq!(response.status_code(), 200);
+    assert_eq!(response.header("deprecation"), "true");
+    assert_eq!(response.header("sunset"), "Mon, 01 Dec 2025 00:00:00 GMT");
+}
+
+#[tokio::test]
+async fn deprecated_route_without_sunset_marks_every_response() {
+    let response = test_server().get("/users/old").await;
+
+    assert_eq!(response.status_code(), 404);
+    assert_eq!(response.header("deprecation"), "true");
+    assert!(response.maybe_header("sunset").is_none());
+}
+
+#[tokio::test]
+async fn other_routes_are_not_marked() {
+    let response = test_server().get("/users/current").await;
+
+    assert_eq!(response.status_code(), 200);
+    assert!(response.maybe_header("deprecation").is_none());
+}
+
+#[tokio::test]
+async fn deprecated_route_logs_the_number_of_requests() {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    let _guard = tracing::subscriber::set_default(subscriber);
+    let server = test_server();
+
+    server.get("/users/legacy").await;
+    server.get("/users/legacy").await;
+    server.get("/users/old").await;
+
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    let lines: Vec<&str> = logs
+        .lines()
+        .filter(|line| line.contains("sword::deprecated"))
+        .collect();
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains("WARN sword::deprecated: Deprecated route requested"));
+    assert!(lines[0].contains("path=/users/legacy"));
+    assert!(lines[0].contains("hits=1"));
+    assert!(lines[1].contains("hits=2"));
+}
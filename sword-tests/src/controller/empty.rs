@@ -0,0 +1,81 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use sword::prelude::*;
+
+#[controller("/empty")]
+struct EmptyController;
+
+#[routes]
+impl EmptyController {}
+
+#[controller("/filled")]
+struct FilledController;
+
+#[routes]
+impl FilledController {
+    #[get("/")]
+    async fn index(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedLogs {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn register<C: Controller>() -> String {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    tracing::subscriber::with_default(subscriber, || {
+        Application::builder().unwrap().with_controller::<C>();
+    });
+
+    logs.contents()
+}
+
+#[test]
+fn route_count_matches_the_routes_impl() {
+    assert_eq!(EmptyController::route_count(), 0);
+    assert_eq!(FilledController::route_count(), 1);
+}
+
+#[test]
+fn registering_an_empty_controller_warns() {
+    let output = register::<EmptyController>();
+
+    assert!(output.contains("WARN"));
+    assert!(output.contains("sword::controllers"));
+    assert!(output.contains("Registered controller has no routes"));
+    assert!(output.contains("EmptyController"));
+    assert!(output.contains("base_path=\"/empty\""));
+}
+
+#[test]
+fn registering_a_controller_with_routes_is_silent() {
+    assert!(register::<FilledController>().is_empty());
+}
@@ -0,0 +1,104 @@
+use std::marker::PhantomData;
+
+use axum_test::TestServer;
+use sword::prelude::*;
+
+#[controller("/reports")]
+struct ReportsController;
+
+#[routes]
+impl ReportsController {
+    #[get("/")]
+    async fn list(&self) -> HttpResponse {
+        HttpResponse::Ok().data("stable")
+    }
+
+    #[get("/beta")]
+    #[feature("beta_api")]
+    async fn beta(&self) -> HttpResponse {
+        HttpResponse::Ok().data("beta")
+    }
+
+    #[get("/checkout")]
+    #[feature("new_checkout")]
+    async fn checkout(&self) -> HttpResponse {
+        HttpResponse::Ok().data("checkout")
+    }
+}
+
+#[controller("/exports")]
+struct ExportsController<T: Send + Sync + 'static> {
+    _format: PhantomData<T>,
+}
+
+#[routes]
+impl<T: Send + Sync + 'static> ExportsController<T> {
+    #[get("/beta")]
+    #[feature("beta_api")]
+    async fn beta(&self) -> HttpResponse {
+        HttpResponse::Ok().data("export")
+    }
+}
+
+fn test_server(builder: ApplicationBuilder) -> TestServer {
+    let app = builder
+        .with_controller::<ReportsController>()
+        .with_controller::<ExportsController<()>>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+fn with_beta_api() -> ApplicationBuilder {
+    ApplicationBuilder::with_config_path("config/beta_features.toml").unwrap()
+}
+
+#[tokio::test]
+async fn enabled_flags_add_their_routes() {
+    let server = test_server(with_beta_api());
+
+    let response = server.get("/reports/beta").await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(body.data, Some("beta".into()));
+
+    let response = server.get("/exports/beta").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn disabled_flags_hide_their_routes() {
+    let server = test_server(Application::builder().unwrap());
+
+    assert_eq!(server.get("/reports").await.status_code(), StatusCode::OK);
+    assert_eq!(
+        server.get("/reports/checkout").await.status_code(),
+        StatusCode::OK
+    );
+
+    for path in ["/reports/beta", "/exports/beta"] {
+        let response = server.get(path).await;
+        assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    }
+
+    let server = test_server(with_beta_api());
+    let response = server.get("/reports/checkout").await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn disabled_routes_look_like_unknown_paths() {
+    let server = test_server(Application::builder().unwrap());
+
+    for method in [Method::GET, Method::POST, Method::HEAD] {
+        let disabled = server.method(method.clone(), "/reports/beta").await;
+        let unknown = server.method(method, "/reports/missing").await;
+
+        assert_eq!(disabled.status_code(), unknown.status_code());
+        assert_eq!(disabled.text(), unknown.text());
+        assert_eq!(disabled.headers().get("allow"), None);
+    }
+}
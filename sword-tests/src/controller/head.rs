@@ -0,0 +1,144 @@
+use axum_test::TestServer;
+use sword::prelude::*;
+
+struct ExistsHeaderMiddleware;
+
+impl Middleware for ExistsHeaderMiddleware {
+    async fn handle(ctx: Context, nxt: Next) -> MiddlewareResult {
+        let mut response = nxt.run(ctx.try_into()?).await;
+
+        response
+            .headers_mut()
+            .insert("x-checked", header::HeaderValue::from_static("true"));
+
+        Ok(response)
+    }
+}
+
+#[controller("/files")]
+struct FilesController;
+
+#[routes]
+impl FilesController {
+    #[get("/{name}")]
+    async fn download(&self) -> HttpResponse {
+        HttpResponse::Ok().data("file contents")
+    }
+
+    #[head("/{name}")]
+    async fn exists(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let name = ctx.param::<String>("name")?;
+
+        match name.as_str() {
+            "report.pdf" => Ok(HttpResponse::Ok().add_header("x-file-size", "1024")),
+            _ => Err(HttpResponse::NotFound()),
+        }
+    }
+
+    #[head("/checked/{name}")]
+    #[middleware(ExistsHeaderMiddleware)]
+    async fn checked(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+#[controller("/reports")]
+struct ReportsController;
+
+#[routes]
+impl ReportsController {
+    #[get("/latest")]
+    async fn latest(&self) -> HttpResponse {
+        HttpResponse::Ok()
+            .add_header("x-report-id", "42")
+            .data("report contents")
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<FilesController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+fn reports_server(auto_head: bool) -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<ReportsController>()
+        .with_auto_head(auto_head)
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn head_route_responds_without_body() {
+    let server = test_server();
+    let response = server.method(Method::HEAD, "/files/report.pdf").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header("x-file-size"), "1024");
+    assert!(response.as_bytes().is_empty());
+}
+
+#[tokio::test]
+async fn head_route_can_return_errors() {
+    let server = test_server();
+    let response = server.method(Method::HEAD, "/files/missing.pdf").await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert!(response.as_bytes().is_empty());
+}
+
+#[tokio::test]
+async fn head_route_coexists_with_get_on_same_path() {
+    let server = test_server();
+    let response = server.get("/files/report.pdf").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert!(response.maybe_header("x-file-size").is_none());
+
+    let body = response.json::<ResponseBody>();
+    assert_eq!(body.data, Some("file contents".into()));
+}
+
+#[tokio::test]
+async fn head_route_applies_middlewares() {
+    let server = test_server();
+    let response = server.method(Method::HEAD, "/files/checked/a.txt").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header("x-checked"), "true");
+}
+
+#[tokio::test]
+async fn head_runs_get_handlers_without_body() {
+    let server = reports_server(true);
+
+    let get = server.get("/reports/latest").await;
+    let head = server.method(Method::HEAD, "/reports/latest").await;
+
+    assert_eq!(head.status_code(), StatusCode::OK);
+    assert_eq!(head.header("x-report-id"), "42");
+    assert_eq!(
+        head.header("content-length"),
+        get.as_bytes().len().to_string()
+    );
+    assert!(head.as_bytes().is_empty());
+}
+
+#[tokio::test]
+async fn head_to_get_routes_is_rejected_without_auto_head() {
+    let response = reports_server(false)
+        .method(Method::HEAD, "/reports/latest")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(response.header("allow"), "GET");
+    assert!(response.maybe_header("x-report-id").is_none());
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use axum_test::TestServer;
+use serde_json::Value;
+use sword::prelude::*;
+
+trait GreetingRepository: Send + Sync {
+    fn greeting(&self) -> String;
+}
+
+struct FakeGreetings;
+
+impl GreetingRepository for FakeGreetings {
+    fn greeting(&self) -> String {
+        "hello from a test double".to_string()
+    }
+}
+
+#[controller("/greetings", instance)]
+struct GreetingsController {
+    repository: Arc<dyn GreetingRepository>,
+}
+
+#[routes]
+impl GreetingsController {
+    #[get("/")]
+    async fn greeting(&self) -> HttpResponse {
+        HttpResponse::Ok().data(self.repository.greeting())
+    }
+}
+
+#[tokio::test]
+async fn instance_is_used_without_resolving_its_fields() {
+    let controller = GreetingsController {
+        repository: Arc::new(FakeGreetings),
+    };
+
+    let app = Application::builder()
+        .unwrap()
+        .with_controller_instance(controller)
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+    let response = server.get("/greetings").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<Value>()["data"], "hello from a test double");
+}
+
+#[test]
+#[should_panic(expected = "register it with `with_controller_instance`")]
+fn instance_controllers_are_not_built_from_state() {
+    let _ = Application::builder()
+        .unwrap()
+        .with_controller::<GreetingsController>();
+}
@@ -0,0 +1,65 @@
+use axum_test::TestServer;
+use serde_json::Value;
+use sword::prelude::*;
+
+#[controller("/forms")]
+struct FormsController;
+
+#[routes]
+impl FormsController {
+    #[route(methods = ["GET", "POST"], path = "/search")]
+    async fn search(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(ctx.method().as_str())
+    }
+
+    #[route(methods = ["put", "patch"], path = "/profile")]
+    #[body_limit("16B")]
+    async fn profile(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<FormsController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn route_answers_every_listed_method() {
+    let server = test_server();
+
+    for (method, expected) in [(Method::GET, "GET"), (Method::POST, "POST")] {
+        let response = server.method(method, "/forms/search").await;
+        let body = response.json::<ResponseBody>();
+
+        assert_eq!(response.status_code(), StatusCode::OK);
+        assert_eq!(body.data, Some(Value::from(expected)));
+    }
+}
+
+#[tokio::test]
+async fn route_rejects_unlisted_methods() {
+    let server = test_server();
+    let response = server.delete("/forms/search").await;
+
+    assert_eq!(response.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+}
+
+#[tokio::test]
+async fn route_applies_body_limit_to_every_method() {
+    let server = test_server();
+
+    for method in [Method::PUT, Method::PATCH] {
+        let response = server
+            .method(method, "/forms/profile")
+            .text("this body is longer than sixteen bytes")
+            .await;
+
+        assert_eq!(response.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -0,0 +1,121 @@
+use axum_test::TestServer;
+use serde_json::{Value, json};
+use sword::prelude::*;
+
+#[controller("/posts")]
+struct PostsController;
+
+#[routes]
+impl PostsController {
+    #[get("/{post_id}")]
+    async fn post(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let post_id = ctx.param::<u32>("post_id")?;
+
+        Ok(HttpResponse::Ok().data(post_id))
+    }
+}
+
+#[controller("/{post_id}/comments", parent = PostsController)]
+struct CommentsController;
+
+#[routes]
+impl CommentsController {
+    #[get("/")]
+    async fn list(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let post_id = ctx.param::<u32>("post_id")?;
+
+        Ok(HttpResponse::Ok().data(json!({ "post_id": post_id })))
+    }
+
+    #[get("/{comment_id}")]
+    async fn comment(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let post_id = ctx.param::<u32>("post_id")?;
+        let comment_id = ctx.param::<u32>("comment_id")?;
+
+        Ok(HttpResponse::Ok()
+            .data(json!({ "post_id": post_id, "comment_id": comment_id })))
+    }
+}
+
+#[controller("/reactions", parent = CommentsController)]
+struct ReactionsController;
+
+#[routes]
+impl ReactionsController {
+    #[get("/")]
+    async fn list(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let post_id = ctx.param::<u32>("post_id")?;
+
+        Ok(HttpResponse::Ok().data(post_id))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<PostsController>()
+        .with_controller::<CommentsController>()
+        .with_controller::<ReactionsController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[test]
+fn child_base_path_is_prefixed_with_the_parent() {
+    assert_eq!(CommentsController::base_path(), "/posts/{post_id}/comments");
+    assert_eq!(
+        ReactionsController::base_path(),
+        "/posts/{post_id}/comments/reactions"
+    );
+}
+
+#[test]
+fn child_urls_take_the_base_path_params() {
+    assert_eq!(
+        CommentsController::comment_url_in(&[&7], 3),
+        "/posts/7/comments/3"
+    );
+    assert_eq!(CommentsController::list_url_in(&[&7]), "/posts/7/comments");
+    assert_eq!(
+        ReactionsController::list_url_in(&[&"a b"]),
+        "/posts/a%20b/comments/reactions"
+    );
+}
+
+#[test]
+#[should_panic(expected = "has 1 parameters but 0 were given")]
+fn child_urls_without_the_base_path_params_panic() {
+    CommentsController::comment_url(3);
+}
+
+#[tokio::test]
+async fn child_routes_read_the_parent_params() {
+    let server = test_server();
+
+    let list = server.get("/posts/7/comments").await;
+    assert_eq!(list.status_code(), StatusCode::OK);
+    assert_eq!(list.json::<Value>()["data"], json!({ "post_id": 7 }));
+
+    let comment = server
+        .get(&CommentsController::comment_url_in(&[&7], 3))
+        .await;
+    assert_eq!(comment.status_code(), StatusCode::OK);
+    assert_eq!(
+        comment.json::<Value>()["data"],
+        json!({ "post_id": 7, "comment_id": 3 })
+    );
+}
+
+#[tokio::test]
+async fn parent_and_grandchild_routes_are_mounted() {
+    let server = test_server();
+
+    let post = server.get("/posts/7").await;
+    assert_eq!(post.json::<Value>()["data"], 7);
+
+    let reactions = server.get("/posts/7/comments/reactions").await;
+    assert_eq!(reactions.status_code(), StatusCode::OK);
+    assert_eq!(reactions.json::<Value>()["data"], 7);
+}
@@ -0,0 +1,98 @@
+use axum::http::{HeaderValue, Method, header};
+use axum_test::TestServer;
+use sword::{
+    prelude::*,
+    web::cors::{Any, CorsLayer},
+};
+
+struct VaryOriginMiddleware;
+
+impl Middleware for VaryOriginMiddleware {
+    async fn handle(ctx: Context, nxt: Next) -> MiddlewareResult {
+        let mut response = nxt.run(ctx.try_into()?).await;
+
+        response
+            .headers_mut()
+            .insert(header::VARY, HeaderValue::from_static("origin"));
+
+        Ok(response)
+    }
+}
+
+#[controller("/items")]
+#[middleware(VaryOriginMiddleware)]
+struct ItemsController;
+
+#[routes]
+impl ItemsController {
+    #[get("/")]
+    async fn list(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+
+    #[options("/")]
+    async fn preflight(&self, ctx: Context) -> HttpResponse {
+        let origin = ctx.header("origin").unwrap_or("*").to_string();
+
+        HttpResponse::NoContent()
+            .add_header("access-control-allow-origin", &origin)
+            .add_header("access-control-allow-methods", "GET")
+    }
+}
+
+fn test_server(cors: Option<CorsLayer>) -> TestServer {
+    let mut builder = Application::builder()
+        .unwrap()
+        .with_controller::<ItemsController>();
+
+    if let Some(cors) = cors {
+        builder = builder.with_cors(cors);
+    }
+
+    TestServer::new(builder.build().unwrap().router()).unwrap()
+}
+
+#[tokio::test]
+async fn options_route_answers_preflight() {
+    let server = test_server(None);
+
+    let response = server
+        .method(Method::OPTIONS, "/items")
+        .add_header(header::ORIGIN, "https://example.com")
+        .add_header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NO_CONTENT);
+    assert_eq!(
+        response.header(header::ACCESS_CONTROL_ALLOW_ORIGIN),
+        "https://example.com"
+    );
+    assert_eq!(response.header(header::ACCESS_CONTROL_ALLOW_METHODS), "GET");
+}
+
+#[tokio::test]
+async fn options_route_is_wrapped_by_controller_middleware() {
+    let server = test_server(None);
+    let response = server.method(Method::OPTIONS, "/items").await;
+
+    assert_eq!(response.status_code(), StatusCode::NO_CONTENT);
+    assert_eq!(response.header(header::VARY), "origin");
+}
+
+#[tokio::test]
+async fn options_route_takes_precedence_over_cors_config() {
+    let cors = CorsLayer::new()
+        .allow_origin(Any)
+        .allow_methods([Method::GET, Method::POST]);
+
+    let server = test_server(Some(cors));
+
+    let response = server
+        .method(Method::OPTIONS, "/items")
+        .add_header(header::ORIGIN, "https://example.com")
+        .add_header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NO_CONTENT);
+    assert_eq!(response.header(header::ACCESS_CONTROL_ALLOW_METHODS), "GET");
+}
@@ -0,0 +1,166 @@
+use std::marker::PhantomData;
+
+use axum_test::TestServer;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sword::{core::State, prelude::*};
+
+trait Resource: Send + Sync + 'static {
+    const NAME: &'static str;
+}
+
+struct Users;
+
+impl Resource for Users {
+    const NAME: &'static str = "users";
+}
+
+#[controller("/resources")]
+struct ResourceController<R: Resource> {
+    _resource: PhantomData<R>,
+}
+
+#[routes]
+impl<R: Resource> ResourceController<R> {
+    #[get("/name")]
+    async fn name(&self) -> HttpResponse {
+        HttpResponse::Ok().data(R::NAME)
+    }
+}
+
+trait Greeter: Send + Sync + 'static {
+    fn greet(&self) -> String;
+}
+
+#[provider]
+struct EnglishGreeter;
+
+impl Greeter for EnglishGreeter {
+    fn greet(&self) -> String {
+        "hello".to_string()
+    }
+}
+
+#[controller("/greeter")]
+struct GreeterController<G>(G)
+where
+    G: Greeter
+        + Clone
+        + for<'a> TryFrom<&'a State, Error = DependencyInjectionError>;
+
+#[routes]
+impl<G> GreeterController<G>
+where
+    G: Greeter
+        + Clone
+        + for<'a> TryFrom<&'a State, Error = DependencyInjectionError>,
+{
+    #[get("/")]
+    async fn greet(&self) -> HttpResponse {
+        HttpResponse::Ok().data(self.0.greet())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[config(key = "my-custom-section")]
+struct SectionConfig {
+    custom_key: String,
+}
+
+#[controller("/tuple")]
+struct TupleController(SectionConfig);
+
+#[routes]
+impl TupleController {
+    #[get("/config")]
+    async fn config(&self) -> HttpResponse {
+        HttpResponse::Ok().data(&self.0.custom_key)
+    }
+}
+
+#[controller("/unit")]
+struct UnitController;
+
+#[routes]
+impl UnitController {
+    #[get("/")]
+    async fn index(&self) -> HttpResponse {
+        HttpResponse::Ok().data(self.greeting())
+    }
+
+    fn greeting(&self) -> &'static str {
+        "hello"
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_dependency_container(
+            DependencyContainer::builder()
+                .register_provider(EnglishGreeter)
+                .build(),
+        )
+        .with_controller::<ResourceController<Users>>()
+        .with_controller::<GreeterController<EnglishGreeter>>()
+        .with_controller::<TupleController>()
+        .with_controller::<UnitController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn generic_controller_registers_successfully() {
+    let server = test_server();
+    let response = server.get("/resources/name").await;
+
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(body.data, Some(Value::from("users")));
+}
+
+#[tokio::test]
+async fn generic_field_is_injected_from_the_state() {
+    let server = test_server();
+    let response = server.get("/greeter").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        response.json::<ResponseBody>().data,
+        Some(Value::from("hello"))
+    );
+}
+
+#[tokio::test]
+async fn tuple_controller_injects_its_fields() {
+    let server = test_server();
+    let response = server.get("/tuple/config").await;
+
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(body.data, Some(Value::from("value")));
+}
+
+#[tokio::test]
+async fn unit_controller_still_works() {
+    let server = test_server();
+    let response = server.get("/unit").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn helper_methods_are_not_routes() {
+    let server = test_server();
+    let response = server.get("/unit").await;
+
+    assert_eq!(
+        response.json::<ResponseBody>().data,
+        Some(Value::from("hello"))
+    );
+    assert_eq!(UnitController::route_count(), 1);
+}
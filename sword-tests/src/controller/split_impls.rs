@@ -0,0 +1,84 @@
+use axum_test::TestServer;
+use serde_json::Value;
+use sword::prelude::*;
+
+struct TagMiddleware;
+
+impl Middleware for TagMiddleware {
+    async fn handle(mut ctx: Context, nxt: Next) -> MiddlewareResult {
+        ctx.extensions.insert::<&'static str>("tagged");
+
+        next!(ctx, nxt)
+    }
+}
+
+#[controller("/accounts")]
+struct AccountsController;
+
+#[routes]
+impl AccountsController {
+    #[get("/")]
+    async fn list(&self) -> HttpResponse {
+        HttpResponse::Ok().data("list")
+    }
+}
+
+#[routes]
+impl AccountsController {
+    #[get("/tagged")]
+    #[middleware(TagMiddleware)]
+    async fn tagged(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(ctx.extensions.get::<&'static str>().copied())
+    }
+
+    #[post("/import")]
+    #[body_limit("16B")]
+    async fn import(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<AccountsController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[test]
+fn routes_of_every_impl_are_counted() {
+    assert_eq!(AccountsController::route_count(), 3);
+    assert_eq!(AccountsController::routes().len(), 3);
+}
+
+#[tokio::test]
+async fn routes_of_every_impl_are_mounted() {
+    let server = test_server();
+
+    let list = server.get("/accounts").await;
+    let body = list.json::<Value>();
+
+    assert_eq!(list.status_code(), StatusCode::OK);
+    assert_eq!(body["data"], "list");
+
+    let tagged = server.get("/accounts/tagged").await;
+    let body = tagged.json::<Value>();
+
+    assert_eq!(tagged.status_code(), StatusCode::OK);
+    assert_eq!(body["data"], "tagged");
+}
+
+#[tokio::test]
+async fn body_limits_of_every_impl_apply() {
+    let server = test_server();
+
+    let response = server
+        .post("/accounts/import")
+        .bytes("a".repeat(32).into())
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+}
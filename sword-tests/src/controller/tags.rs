@@ -0,0 +1,158 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use axum_test::TestServer;
+use sword::{prelude::*, web::metrics::Metrics};
+use tracing::level_filters::LevelFilter;
+
+#[controller("/invoices")]
+#[tag("billing")]
+struct InvoicesController;
+
+#[routes]
+impl InvoicesController {
+    #[get("/")]
+    async fn list(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+
+    #[post("/refunds")]
+    #[tag("refunds")]
+    async fn refund(&self) -> HttpResponse {
+        HttpResponse::Accepted()
+    }
+
+    #[get("/tag")]
+    async fn current_tag(&self, ctx: Context) -> HttpResponse {
+        let tag = ctx.extensions.get::<RouteTag>().map(|tag| tag.0);
+        HttpResponse::Ok().data(tag)
+    }
+}
+
+#[controller("/health")]
+struct HealthController;
+
+#[routes]
+impl HealthController {
+    #[get("/")]
+    async fn health(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedLogs {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn test_app(metrics: Metrics) -> Application {
+    Application::builder()
+        .unwrap()
+        .with_controller::<InvoicesController>()
+        .with_controller::<HealthController>()
+        .with_metrics(metrics)
+        .with_logger()
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn tags_label_metrics() {
+    let metrics = Metrics::new();
+    let server = TestServer::new(test_app(metrics.clone()).router()).unwrap();
+
+    server.get("/invoices").await;
+    server.post("/invoices/refunds").await;
+    server.get("/health").await;
+
+    let tags: Vec<_> = metrics
+        .route_stats()
+        .into_iter()
+        .map(|stat| (stat.route, stat.tag))
+        .collect();
+
+    assert!(tags.contains(&("/invoices".to_string(), Some("billing"))));
+    assert!(tags.contains(&("/invoices/refunds".to_string(), Some("refunds"))));
+    assert!(tags.contains(&("/health".to_string(), None)));
+
+    let rendered = metrics.render();
+
+    assert!(rendered.contains(
+        "sword_http_requests_total{method=\"GET\",route=\"/invoices\",status=\"2xx\",tag=\"billing\"} 1"
+    ));
+    assert!(rendered.contains(
+        "sword_http_requests_total{method=\"GET\",route=\"/health\",status=\"2xx\"} 1"
+    ));
+}
+
+#[tokio::test]
+async fn tags_are_logged_by_the_request_logger() {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_max_level(LevelFilter::INFO)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    let _guard = tracing::subscriber::set_default(subscriber);
+    let server = TestServer::new(test_app(Metrics::new()).router()).unwrap();
+
+    server.post("/invoices/refunds").await;
+    server.get("/health").await;
+
+    let logs = logs.contents();
+    let mut lines = logs.lines().filter(|line| line.contains("sword::requests"));
+
+    assert!(lines.next().unwrap().contains("tag=\"refunds\""));
+    assert!(lines.next().unwrap().contains("tag=\"-\""));
+}
+
+#[tokio::test]
+async fn handlers_see_the_tag_of_their_route() {
+    let server = TestServer::new(test_app(Metrics::new()).router()).unwrap();
+
+    let response = server.get("/invoices/tag").await;
+    let body = response.json::<serde_json::Value>();
+
+    assert_eq!(body["data"], "billing");
+}
+
+#[test]
+fn routes_list_their_tag() {
+    let app = test_app(Metrics::new());
+
+    let tags: Vec<_> = app
+        .routes()
+        .into_iter()
+        .map(|route| (route.path, route.tag))
+        .collect();
+
+    assert_eq!(
+        tags,
+        vec![
+            ("/health".to_string(), None),
+            ("/invoices".to_string(), Some("billing")),
+            ("/invoices/refunds".to_string(), Some("refunds")),
+            ("/invoices/tag".to_string(), Some("billing")),
+        ]
+    );
+}
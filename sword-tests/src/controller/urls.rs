@@ -0,0 +1,72 @@
+use axum_test::TestServer;
+use sword::prelude::*;
+
+#[controller("/api/users")]
+struct UserController;
+
+#[routes]
+impl UserController {
+    #[get("/")]
+    async fn list_users(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+
+    #[get("/{id}")]
+    async fn get_user(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+
+    #[get("/{id}/posts/{post_id}")]
+    async fn get_user_post(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+
+    #[route(methods = ["GET", "PUT"], path = "/{id}/files/{*path}")]
+    async fn user_file(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+#[test]
+fn urls_substitute_the_route_params() {
+    assert_eq!(UserController::list_users_url(), "/api/users");
+    assert_eq!(UserController::get_user_url(42), "/api/users/42");
+    assert_eq!(
+        UserController::get_user_post_url(42, "hello"),
+        "/api/users/42/posts/hello"
+    );
+}
+
+#[test]
+fn url_params_are_percent_encoded() {
+    assert_eq!(
+        UserController::get_user_url("ada lovelace/1"),
+        "/api/users/ada%20lovelace%2F1"
+    );
+    assert_eq!(
+        UserController::user_file_url(7, "docs/a b.txt"),
+        "/api/users/7/files/docs/a%20b.txt"
+    );
+}
+
+#[tokio::test]
+async fn generated_urls_match_the_registered_routes() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<UserController>()
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+
+    for url in [
+        UserController::list_users_url(),
+        UserController::get_user_url(42),
+        UserController::get_user_post_url(42, 7),
+        UserController::user_file_url(42, "docs/readme.md"),
+    ] {
+        let response = server.get(&url).await;
+
+        assert_eq!(response.status_code(), StatusCode::OK, "{url}");
+    }
+}
@@ -0,0 +1,120 @@
+use axum_test::TestServer;
+use sword::prelude::*;
+
+#[controller("/assets/{*path}")]
+struct AssetsController;
+
+#[routes]
+impl AssetsController {
+    #[get("/")]
+    async fn asset(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let path = ctx.param::<String>("path")?;
+
+        Ok(HttpResponse::Ok().data(path))
+    }
+}
+
+#[controller("/downloads/{*path}", version = "v1")]
+struct DownloadsController;
+
+#[routes]
+impl DownloadsController {
+    #[get("/")]
+    async fn download(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let path = ctx.param::<String>("path")?;
+
+        Ok(HttpResponse::Ok().data(path))
+    }
+}
+
+#[controller("/docs")]
+struct DocsController;
+
+#[routes]
+impl DocsController {
+    #[get("/{*path}")]
+    async fn page(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let path = ctx.param::<String>("path")?;
+
+        Ok(HttpResponse::Ok().data(format!("page {path}")))
+    }
+
+    #[get("/index")]
+    async fn index(&self) -> HttpResponse {
+        HttpResponse::Ok().data("index")
+    }
+}
+
+#[controller("/broken/{*path}")]
+struct BrokenController;
+
+#[routes]
+impl BrokenController {
+    #[get("/raw")]
+    async fn raw(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<AssetsController>()
+        .with_controller::<DownloadsController>()
+        .with_controller::<DocsController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn wildcard_base_path_captures_the_tail() {
+    let server = test_server();
+    let response = server.get("/assets/css/site/main.css").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        response.json::<ResponseBody>().data.unwrap(),
+        "css/site/main.css"
+    );
+}
+
+#[tokio::test]
+async fn wildcard_base_path_goes_after_the_version() {
+    let server = test_server();
+    let response = server.get("/v1/downloads/releases/1.0.tar.gz").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        response.json::<ResponseBody>().data.unwrap(),
+        "releases/1.0.tar.gz"
+    );
+}
+
+#[tokio::test]
+async fn wildcard_route_does_not_shadow_sibling_routes() {
+    let server = test_server();
+
+    let response = server.get("/docs/index").await;
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), "index");
+
+    let response = server.get("/docs/guides/routing").await;
+    assert_eq!(
+        response.json::<ResponseBody>().data.unwrap(),
+        "page guides/routing"
+    );
+}
+
+#[test]
+fn routes_after_a_wildcard_base_path_are_rejected() {
+    let result = Application::builder()
+        .unwrap()
+        .try_with_controller::<BrokenController>();
+
+    let Err(ApplicationError::InvalidRoute { route, .. }) = result else {
+        panic!("expected an invalid route error");
+    };
+
+    assert_eq!(route, "GET /broken/{*path}/raw");
+}
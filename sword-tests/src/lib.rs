@@ -1,23 +1,100 @@
 #[cfg(test)]
 mod request {
+    mod abort;
+    mod auth;
+    mod body;
+    mod body_limit;
+    mod cancellation;
+    mod client_cert;
     mod cookies;
+    mod extract;
+    mod form;
+    mod headers;
+    mod json_stream;
     mod multipart;
+    mod params;
     mod query;
+    mod remote_addr;
+    mod validate_all;
+    mod websocket;
+}
+
+#[cfg(test)]
+mod response {
+    mod batch;
+    mod envelope;
+    mod file;
+    mod json_value;
+    mod negotiation;
+    mod pretty_json;
+    mod size_limit;
+    mod skip_nulls;
+    mod sse;
+    mod stream;
+    mod text;
+}
+
+#[cfg(test)]
+mod controller {
+    mod deprecated_routes;
+    mod empty;
+    mod feature_gates;
+    mod head;
+    mod instance;
+    mod multi_method;
+    mod nested;
+    mod options;
+    mod shapes;
+    mod split_impls;
+    mod tags;
+    mod urls;
+    mod wildcard;
 }
 
 #[cfg(test)]
 mod middlewares {
+    mod boxed;
     mod built_in;
     mod controller_level;
+    mod cors;
+    mod execution_order;
     mod handler_level;
+    mod header_limits;
     mod helmet;
+    mod layer_ordering;
+    mod rejections;
+    mod request_id;
+    mod request_logger;
+    mod response_timeout;
+    mod server_timing;
+    mod short_circuit;
 }
 
 #[cfg(test)]
 mod application {
+    mod builder;
     mod config;
     mod di;
+    mod error_reporting;
+    mod error_status;
+    mod features;
+    mod listener;
+    mod logging;
+    mod method_not_allowed;
+    mod metrics;
+    mod not_found;
+    mod openapi;
+    mod overlapping_routes;
+    mod plugin;
     mod prefix;
+    mod readiness;
+    mod rewrite;
+    mod route_listing;
+    mod router;
+    mod shutdown;
+    mod shutdown_timeout;
+    mod state;
+    mod validation_formatter;
     mod versioning;
 }
 
@@ -0,0 +1,124 @@
+use axum_test::TestServer;
+use sword::prelude::*;
+
+/// Appends its name to the trace of the request.
+struct Trace(String);
+
+impl DynMiddleware for Trace {
+    fn call<'a>(&'a self, mut ctx: Context, nxt: Next) -> BoxedMiddlewareFuture<'a> {
+        Box::pin(async move {
+            let mut trace =
+                ctx.extensions.remove::<Vec<String>>().unwrap_or_default();
+            trace.push(self.0.clone());
+            ctx.extensions.insert(trace);
+
+            next!(ctx, nxt)
+        })
+    }
+}
+
+/// Rejects requests without an `Authorization` header.
+struct Auth;
+
+impl Middleware for Auth {
+    async fn handle(ctx: Context, nxt: Next) -> MiddlewareResult {
+        if ctx.header("authorization").is_none() {
+            return Err(HttpResponse::Unauthorized());
+        }
+
+        next!(ctx, nxt)
+    }
+}
+
+/// Appends `route` to the trace, through `#[middleware]`.
+struct RouteTrace;
+
+impl Middleware for RouteTrace {
+    async fn handle(mut ctx: Context, nxt: Next) -> MiddlewareResult {
+        let mut trace = ctx.extensions.remove::<Vec<String>>().unwrap_or_default();
+        trace.push("route".to_string());
+        ctx.extensions.insert(trace);
+
+        next!(ctx, nxt)
+    }
+}
+
+#[controller("/pipeline")]
+struct PipelineController;
+
+#[routes]
+impl PipelineController {
+    #[get("/")]
+    #[middleware(RouteTrace)]
+    async fn trace(&self, ctx: Context) -> HttpResponse {
+        let trace = ctx.extensions.get::<Vec<String>>().cloned();
+
+        HttpResponse::Ok().data(trace.unwrap_or_default())
+    }
+}
+
+fn test_server(middlewares: Vec<Box<dyn DynMiddleware>>) -> TestServer {
+    let mut builder = Application::builder()
+        .unwrap()
+        .with_controller::<PipelineController>();
+
+    for middleware in middlewares {
+        builder = builder.with_boxed_middleware(middleware);
+    }
+
+    TestServer::new(builder.build().unwrap().router()).unwrap()
+}
+
+fn trace_stack(names: &[&str]) -> Vec<Box<dyn DynMiddleware>> {
+    names
+        .iter()
+        .map(|name| Box::new(Trace(name.to_string())) as Box<dyn DynMiddleware>)
+        .collect()
+}
+
+#[tokio::test]
+async fn runtime_stacks_run_around_macro_middlewares() {
+    let server = test_server(trace_stack(&["first", "second"]));
+    let response = server.get("/pipeline").await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        body.data,
+        Some(serde_json::json!(["second", "first", "route"]))
+    );
+}
+
+#[tokio::test]
+async fn boxed_middlewares_can_stop_the_request() {
+    let enable_auth = true;
+    let mut stack = trace_stack(&["trace"]);
+
+    if enable_auth {
+        stack.push(Box::new(Auth));
+    }
+
+    let server = test_server(stack);
+
+    let rejected = server.get("/pipeline").await;
+    assert_eq!(rejected.status_code(), StatusCode::UNAUTHORIZED);
+
+    let allowed = server
+        .get("/pipeline")
+        .add_header("Authorization", "Bearer token")
+        .await;
+
+    let body = allowed.json::<ResponseBody>();
+
+    assert_eq!(allowed.status_code(), StatusCode::OK);
+    assert_eq!(body.data, Some(serde_json::json!(["trace", "route"])));
+}
+
+#[tokio::test]
+async fn empty_stacks_leave_the_routes_untouched() {
+    let response = test_server(Vec::new()).get("/pipeline").await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(body.data, Some(serde_json::json!(["route"])));
+}
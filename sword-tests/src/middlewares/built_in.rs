@@ -65,8 +65,10 @@ impl TestController {
 #[tokio::test]
 async fn timeout() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
 
@@ -74,9 +76,9 @@ async fn timeout() {
     let json = response.json::<ResponseBody>();
 
     let expected = ResponseBody {
-        code: 408,
+        code: 503,
         success: false,
-        message: "Request Timeout".into(),
+        message: "Request timed out".into(),
         data: None,
         error: None,
         errors: None,
@@ -87,31 +89,39 @@ async fn timeout() {
     assert_eq!(json.success, expected.success);
     assert_eq!(json.message, expected.message);
     assert_eq!(json.data, expected.data);
+    assert_eq!(
+        response.header("content-type").to_str().unwrap(),
+        "application/json"
+    );
 }
 
 #[tokio::test]
 async fn timeout_boundary_exact() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
 
     let response = test_app.get("/test/timeout-boundary").await;
 
-    assert_eq!(response.status_code(), 408);
+    assert_eq!(response.status_code(), 503);
 
     let json = response.json::<ResponseBody>();
-    assert_eq!(json.code, 408);
+    assert_eq!(json.code, 503);
     assert!(!json.success);
-    assert_eq!(json.message, "Request Timeout".into());
+    assert_eq!(json.message, "Request timed out".into());
 }
 
 #[tokio::test]
 async fn timeout_just_under_limit() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
     let response = test_app.get("/test/timeout-just-under").await;
@@ -128,26 +138,30 @@ async fn timeout_just_under_limit() {
 #[tokio::test]
 async fn timeout_just_over_limit() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
     let response = test_app.get("/test/timeout-just-over").await;
 
-    assert_eq!(response.status_code(), 408);
+    assert_eq!(response.status_code(), 503);
 
     let json = response.json::<ResponseBody>();
 
-    assert_eq!(json.code, 408);
+    assert_eq!(json.code, 503);
     assert!(!json.success);
-    assert_eq!(json.message, "Request Timeout".into());
+    assert_eq!(json.message, "Request timed out".into());
 }
 
 #[tokio::test]
 async fn no_timeout_quick_response() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
     let response = test_app.get("/test/no-timeout").await;
@@ -164,8 +178,10 @@ async fn no_timeout_quick_response() {
 #[tokio::test]
 async fn content_type_json_valid() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
 
@@ -186,8 +202,10 @@ async fn content_type_json_valid() {
 #[tokio::test]
 async fn content_type_multipart_valid() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
 
@@ -208,8 +226,10 @@ async fn content_type_multipart_valid() {
 #[tokio::test]
 async fn content_type_invalid() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
 
@@ -225,15 +245,17 @@ async fn content_type_invalid() {
     assert_eq!(json.code, 415);
     assert!(!json.success);
     assert!(json.message.contains(
-        "Only application/json and multipart/form-data content types are supported"
+        "Only application/json, multipart/form-data and application/x-www-form-urlencoded content types are supported"
     ));
 }
 
 #[tokio::test]
 async fn content_type_xml_invalid() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
 
@@ -250,15 +272,17 @@ async fn content_type_xml_invalid() {
     assert_eq!(json.code, 415);
     assert!(!json.success);
     assert!(json.message.contains(
-        "Only application/json and multipart/form-data content types are supported"
+        "Only application/json, multipart/form-data and application/x-www-form-urlencoded content types are supported"
     ));
 }
 
 #[tokio::test]
-async fn content_type_form_urlencoded_invalid() {
+async fn content_type_form_urlencoded_passes_check() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
 
@@ -268,22 +292,24 @@ async fn content_type_form_urlencoded_invalid() {
         .content_type("application/x-www-form-urlencoded")
         .await;
 
+    // The body reaches the handler, where `body()` only accepts JSON.
     assert_eq!(response.status_code(), 415);
 
     let json = response.json::<ResponseBody>();
 
-    assert_eq!(json.code, 415);
-    assert!(!json.success);
-    assert!(json.message.contains(
-        "Only application/json and multipart/form-data content types are supported"
-    ));
+    assert_eq!(
+        json.message,
+        "Only application/json content types are supported.".into()
+    );
 }
 
 #[tokio::test]
 async fn content_type_no_body_allowed() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
     let response = test_app.get("/test/no-body").await;
@@ -299,8 +325,10 @@ async fn content_type_no_body_allowed() {
 #[tokio::test]
 async fn content_type_missing_header_with_body() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
 
@@ -316,15 +344,17 @@ async fn content_type_missing_header_with_body() {
     assert_eq!(json.code, 415);
     assert!(!json.success);
     assert!(json.message.contains(
-        "Only application/json and multipart/form-data content types are supported"
+        "Only application/json, multipart/form-data and application/x-www-form-urlencoded content types are supported"
     ));
 }
 
 #[tokio::test]
 async fn content_type_case_sensitivity() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
 
@@ -340,8 +370,10 @@ async fn content_type_case_sensitivity() {
 #[tokio::test]
 async fn content_type_json_with_charset() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test_app = TestServer::new(app.router()).unwrap();
 
@@ -358,3 +390,86 @@ async fn content_type_json_with_charset() {
     assert_eq!(json.code, 415);
     assert!(!json.success);
 }
+
+#[tokio::test]
+async fn unsupported_media_type_reports_received_and_allowed_types() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<TestController>()
+        .build()
+        .unwrap();
+
+    let test_app = TestServer::new(app.router()).unwrap();
+
+    let response = test_app
+        .post("/test/content-type-any")
+        .bytes(Bytes::from("<xml>data</xml>"))
+        .content_type("application/xml")
+        .await;
+
+    assert_eq!(response.status_code(), 415);
+
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(
+        json.error.unwrap(),
+        "Received 'application/xml', expected one of: application/json, multipart/form-data, application/x-www-form-urlencoded"
+    );
+}
+
+#[tokio::test]
+async fn body_too_large_reports_limit_and_declared_size() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<TestController>()
+        .build()
+        .unwrap();
+
+    let test_app = TestServer::new(app.router()).unwrap();
+    let payload = format!("\"{}\"", "a".repeat(2_499_998));
+
+    let response = test_app
+        .post("/test/content-type-json")
+        .add_header("Content-Length", payload.len().to_string())
+        .bytes(Bytes::from(payload))
+        .content_type("application/json")
+        .await;
+
+    assert_eq!(response.status_code(), 413);
+
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(json.code, 413);
+    assert_eq!(
+        json.message.as_ref(),
+        "The request body exceeds the maximum allowed size by the server"
+    );
+    assert_eq!(
+        json.error.unwrap(),
+        "Maximum allowed size is 1.0 MB, received 2.5 MB"
+    );
+}
+
+#[tokio::test]
+async fn body_too_large_without_declared_size_reports_limit() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<TestController>()
+        .build()
+        .unwrap();
+
+    let test_app = TestServer::new(app.router()).unwrap();
+    let payload = format!("\"{}\"", "a".repeat(1_500_000));
+
+    let response = test_app
+        .post("/test/content-type-json")
+        .bytes(Bytes::from(payload))
+        .content_type("application/json")
+        .await;
+
+    assert_eq!(response.status_code(), 413);
+
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(json.error.unwrap(), "Maximum allowed size is 1.0 MB");
+}
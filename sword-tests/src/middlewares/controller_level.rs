@@ -80,8 +80,10 @@ impl TestController {
 #[tokio::test]
 async fn extensions_mw_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/extensions-test").await;
@@ -99,8 +101,10 @@ async fn extensions_mw_test() {
 #[tokio::test]
 async fn middleware_state() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/middleware-state").await;
@@ -120,8 +124,10 @@ async fn middleware_state() {
 #[tokio::test]
 async fn role_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/role-test").await;
@@ -0,0 +1,220 @@
+use axum::http::{HeaderName, HeaderValue, Method, header};
+use axum_test::TestServer;
+use sword::{
+    prelude::*,
+    web::cors::{Any, Cors, CorsLayer},
+};
+
+#[controller("/cors")]
+struct CorsController;
+
+#[routes]
+impl CorsController {
+    #[get("/items")]
+    async fn list(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+
+    #[post("/items")]
+    async fn create(&self) -> HttpResponse {
+        HttpResponse::Created()
+    }
+}
+
+struct RequireAuth;
+
+impl Middleware for RequireAuth {
+    async fn handle(ctx: Context, next: Next) -> MiddlewareResult {
+        if ctx.header("authorization").is_none() {
+            return Err(HttpResponse::Unauthorized());
+        }
+
+        next!(ctx, next)
+    }
+}
+
+#[controller("/secure")]
+#[middleware(RequireAuth)]
+struct SecureController;
+
+#[routes]
+impl SecureController {
+    #[get("/items")]
+    async fn list(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+fn cors_server() -> TestServer {
+    let cors = CorsLayer::new()
+        .allow_origin(Any)
+        .allow_methods([Method::GET, Method::POST])
+        .allow_headers([header::CONTENT_TYPE]);
+
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<CorsController>()
+        .with_controller::<SecureController>()
+        .with_cors(cors)
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+fn header(name: HeaderName, value: &'static str) -> (HeaderName, HeaderValue) {
+    (name, HeaderValue::from_static(value))
+}
+
+#[tokio::test]
+async fn preflight_succeeds_on_route_without_options_handler() {
+    let server = cors_server();
+
+    let (origin, origin_value) = header(header::ORIGIN, "https://example.com");
+    let (method, method_value) =
+        header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST");
+
+    let response = server
+        .method(Method::OPTIONS, "/cors/items")
+        .add_header(origin, origin_value)
+        .add_header(method, method_value)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NO_CONTENT);
+    assert_eq!(response.header(header::ACCESS_CONTROL_ALLOW_ORIGIN), "*");
+
+    let allowed = response.header(header::ACCESS_CONTROL_ALLOW_METHODS);
+    let allowed = allowed.to_str().unwrap();
+
+    assert!(allowed.contains("GET"));
+    assert!(allowed.contains("POST"));
+}
+
+#[tokio::test]
+async fn preflight_skips_rejecting_controller_middlewares() {
+    let server = cors_server();
+
+    let response = server
+        .method(Method::OPTIONS, "/secure/items")
+        .add_header(header::ORIGIN, "https://example.com")
+        .add_header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+        .add_header(header::ACCESS_CONTROL_REQUEST_HEADERS, "authorization")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NO_CONTENT);
+    assert_eq!(response.header(header::ACCESS_CONTROL_ALLOW_ORIGIN), "*");
+
+    let response = server
+        .get("/secure/items")
+        .add_header(header::ORIGIN, "https://example.com")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn preflight_on_unknown_route_is_not_found() {
+    let server = cors_server();
+
+    let (origin, origin_value) = header(header::ORIGIN, "https://example.com");
+    let (method, method_value) =
+        header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET");
+
+    let response = server
+        .method(Method::OPTIONS, "/cors/missing")
+        .add_header(origin, origin_value)
+        .add_header(method, method_value)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn plain_options_without_preflight_headers_is_not_allowed() {
+    let server = cors_server();
+
+    let response = server.method(Method::OPTIONS, "/cors/items").await;
+
+    assert_eq!(response.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+}
+
+#[tokio::test]
+async fn regular_responses_include_cors_headers() {
+    let server = cors_server();
+
+    let (origin, origin_value) = header(header::ORIGIN, "https://example.com");
+
+    let response = server
+        .get("/cors/items")
+        .add_header(origin, origin_value)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header(header::ACCESS_CONTROL_ALLOW_ORIGIN), "*");
+}
+
+fn config_server() -> TestServer {
+    let builder = ApplicationBuilder::with_config_path("config/cors.toml").unwrap();
+    let cors = Cors::from_config(&builder.config).unwrap();
+
+    let app = builder
+        .with_controller::<CorsController>()
+        .with_cors(cors)
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn policy_from_config_answers_preflight_requests() {
+    let response = config_server()
+        .method(Method::OPTIONS, "/cors/items")
+        .add_header(header::ORIGIN, "http://localhost:5173")
+        .add_header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NO_CONTENT);
+    assert_eq!(
+        response.header(header::ACCESS_CONTROL_ALLOW_ORIGIN),
+        "http://localhost:5173"
+    );
+    assert_eq!(
+        response.header(header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+        "true"
+    );
+    assert_eq!(response.header(header::ACCESS_CONTROL_MAX_AGE), "600");
+    assert_eq!(
+        response.header(header::ACCESS_CONTROL_ALLOW_METHODS),
+        "GET,POST"
+    );
+}
+
+#[tokio::test]
+async fn policy_from_config_ignores_other_origins() {
+    let response = config_server()
+        .get("/cors/items")
+        .add_header(header::ORIGIN, "https://evil.example.com")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert!(
+        response
+            .maybe_header(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn malformed_origins_fail_at_startup() {
+    let builder =
+        ApplicationBuilder::with_config_path("config/cors_invalid.toml").unwrap();
+
+    let error = Cors::from_config(&builder.config).unwrap_err();
+
+    assert!(matches!(
+        error,
+        ConfigError::InvalidValue { ref key, ref value, .. }
+            if key == "cors.allowed_origins" && value == "app.example.com"
+    ));
+}
@@ -0,0 +1,109 @@
+use axum_test::TestServer;
+use sword::prelude::*;
+
+/// Appends its name to the trace of the request.
+struct Trace;
+
+impl MiddlewareWithConfig<&'static str> for Trace {
+    async fn handle(
+        name: &'static str,
+        mut ctx: Context,
+        nxt: Next,
+    ) -> MiddlewareResult {
+        let mut trace = ctx.extensions.remove::<Vec<&str>>().unwrap_or_default();
+        trace.push(name);
+        ctx.extensions.insert(trace);
+
+        next!(ctx, nxt)
+    }
+}
+
+/// Rejects requests without an `Authorization` header.
+struct Auth;
+
+impl Middleware for Auth {
+    async fn handle(ctx: Context, nxt: Next) -> MiddlewareResult {
+        if ctx.header("authorization").is_none() {
+            return Err(HttpResponse::Unauthorized());
+        }
+
+        next!(ctx, nxt)
+    }
+}
+
+#[controller("/ordered")]
+#[middleware(Trace, config = "controller first")]
+#[middleware(Trace, config = "controller second")]
+struct OrderedController;
+
+#[routes]
+impl OrderedController {
+    #[get("/")]
+    #[middleware(Trace, config = "route first")]
+    #[middleware(Trace, config = "route second")]
+    async fn trace(&self, ctx: Context) -> HttpResponse {
+        let trace = ctx.extensions.get::<Vec<&str>>().cloned();
+
+        HttpResponse::Ok().data(trace.unwrap_or_default())
+    }
+}
+
+#[controller("/guarded")]
+#[middleware(Auth)]
+struct GuardedController;
+
+#[routes]
+impl GuardedController {
+    #[get("/")]
+    #[middleware(Trace, config = "route")]
+    async fn trace(&self, ctx: Context) -> HttpResponse {
+        let trace = ctx.extensions.get::<Vec<&str>>().cloned();
+
+        HttpResponse::Ok().data(trace.unwrap_or_default())
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<OrderedController>()
+        .with_controller::<GuardedController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn controller_middlewares_wrap_route_middlewares_in_listed_order() {
+    let response = test_server().get("/ordered").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        response.json::<ResponseBody>().data.unwrap(),
+        serde_json::json!([
+            "controller first",
+            "controller second",
+            "route first",
+            "route second"
+        ])
+    );
+}
+
+#[tokio::test]
+async fn controller_rejections_skip_route_middlewares() {
+    let server = test_server();
+
+    let rejected = server.get("/guarded").await;
+    assert_eq!(rejected.status_code(), StatusCode::UNAUTHORIZED);
+
+    let allowed = server
+        .get("/guarded")
+        .add_header("Authorization", "Bearer token")
+        .await;
+
+    assert_eq!(
+        allowed.json::<ResponseBody>().data.unwrap(),
+        serde_json::json!(["route"])
+    );
+}
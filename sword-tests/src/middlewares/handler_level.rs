@@ -722,8 +722,10 @@ impl TestController {
 #[tokio::test]
 async fn extensions_mw_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/extensions-test").await;
@@ -740,8 +742,10 @@ async fn extensions_mw_test() {
 #[tokio::test]
 async fn middleware_state() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/middleware-state").await;
@@ -761,8 +765,10 @@ async fn middleware_state() {
 #[tokio::test]
 async fn role_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/role-test").await;
@@ -779,8 +785,10 @@ async fn role_middleware_test() {
 #[tokio::test]
 async fn tower_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/tower-middleware-test").await;
@@ -797,8 +805,10 @@ async fn tower_middleware_test() {
 #[tokio::test]
 async fn tuple_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/tuple-config-test").await;
@@ -813,8 +823,10 @@ async fn tuple_config_middleware_test() {
 #[tokio::test]
 async fn array_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/array-config-test").await;
@@ -829,8 +841,10 @@ async fn array_config_middleware_test() {
 #[tokio::test]
 async fn string_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/string-config-test").await;
@@ -845,8 +859,10 @@ async fn string_config_middleware_test() {
 #[tokio::test]
 async fn str_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/str-config-test").await;
@@ -859,8 +875,10 @@ async fn str_config_middleware_test() {
 #[tokio::test]
 async fn number_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/number-config-test").await;
@@ -875,8 +893,10 @@ async fn number_config_middleware_test() {
 #[tokio::test]
 async fn bool_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/bool-config-test").await;
@@ -891,8 +911,10 @@ async fn bool_config_middleware_test() {
 #[tokio::test]
 async fn complex_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/complex-config-test").await;
@@ -905,8 +927,10 @@ async fn complex_config_middleware_test() {
 #[tokio::test]
 async fn function_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/function-config-test").await;
@@ -919,8 +943,10 @@ async fn function_config_middleware_test() {
 #[tokio::test]
 async fn macro_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/macro-config-test").await;
@@ -933,8 +959,10 @@ async fn macro_config_middleware_test() {
 #[tokio::test]
 async fn nested_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/nested-config-test").await;
@@ -947,8 +975,10 @@ async fn nested_config_middleware_test() {
 #[tokio::test]
 async fn math_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/math-config-test").await;
@@ -961,8 +991,10 @@ async fn math_config_middleware_test() {
 #[tokio::test]
 async fn const_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/const-config-test").await;
@@ -975,8 +1007,10 @@ async fn const_config_middleware_test() {
 #[tokio::test]
 async fn multiline_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/multiline-config-test").await;
@@ -989,8 +1023,10 @@ async fn multiline_config_middleware_test() {
 #[tokio::test]
 async fn closure_config_middleware_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/closure-config-test").await;
@@ -1003,8 +1039,10 @@ async fn closure_config_middleware_test() {
 #[tokio::test]
 async fn enum_simple_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/enum-simple-test").await;
@@ -1017,8 +1055,10 @@ async fn enum_simple_test() {
 #[tokio::test]
 async fn enum_with_data_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/enum-with-data-test").await;
@@ -1031,8 +1071,10 @@ async fn enum_with_data_test() {
 #[tokio::test]
 async fn enum_struct_variant_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/enum-struct-variant-test").await;
@@ -1045,8 +1087,10 @@ async fn enum_struct_variant_test() {
 #[tokio::test]
 async fn enum_unit_variant_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/enum-unit-variant-test").await;
@@ -1059,8 +1103,10 @@ async fn enum_unit_variant_test() {
 #[tokio::test]
 async fn enum_nested_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/enum-nested-test").await;
@@ -1073,8 +1119,10 @@ async fn enum_nested_test() {
 #[tokio::test]
 async fn enum_option_some_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/enum-option-some-test").await;
@@ -1087,8 +1135,10 @@ async fn enum_option_some_test() {
 #[tokio::test]
 async fn enum_option_none_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/enum-option-none-test").await;
@@ -1101,8 +1151,10 @@ async fn enum_option_none_test() {
 #[tokio::test]
 async fn enum_vec_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/enum-vec-test").await;
@@ -1115,8 +1167,10 @@ async fn enum_vec_test() {
 #[tokio::test]
 async fn enum_match_test() {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
     let response = test.get("/test/enum-match-test").await;
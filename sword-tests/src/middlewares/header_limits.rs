@@ -0,0 +1,80 @@
+use axum_test::TestServer;
+use sword::prelude::*;
+
+#[controller("/items")]
+struct ItemsController;
+
+#[routes]
+impl ItemsController {
+    #[get("/")]
+    async fn list(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+}
+
+fn test_server(config: &str) -> TestServer {
+    let app = ApplicationBuilder::with_config_path(config)
+        .unwrap()
+        .with_controller::<ItemsController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn too_many_headers_are_rejected() {
+    let mut request = test_server("config/header_limits.toml").get("/items");
+
+    for i in 0..10 {
+        request = request.add_header(format!("x-extra-{i}"), "1");
+    }
+
+    let response = request.await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(
+        response.status_code(),
+        StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE
+    );
+    assert_eq!(body.message, "The request has more than 8 headers".into());
+}
+
+#[tokio::test]
+async fn oversized_headers_are_rejected() {
+    let response = test_server("config/header_limits.toml")
+        .get("/items")
+        .add_header("x-token", "a".repeat(300))
+        .await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(
+        response.status_code(),
+        StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE
+    );
+    assert_eq!(
+        body.message,
+        "The request headers exceed the maximum size of 256 bytes".into()
+    );
+}
+
+#[tokio::test]
+async fn headers_within_the_limits_pass() {
+    let response = test_server("config/header_limits.toml")
+        .get("/items")
+        .add_header("x-token", "abc")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn headers_are_unlimited_without_config() {
+    let mut request = test_server("config/config.toml").get("/items");
+
+    for i in 0..20 {
+        request = request.add_header(format!("x-extra-{i}"), "a".repeat(100));
+    }
+
+    assert_eq!(request.await.status_code(), StatusCode::OK);
+}
@@ -21,9 +21,11 @@ async fn test_basic_security_headers() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -43,9 +45,11 @@ async fn test_x_content_type_options() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -62,9 +66,11 @@ async fn test_x_frame_options() {
     let helmet_deny = Helmet::builder().with_header(XFrameOptions::deny()).build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet_deny)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -77,9 +83,11 @@ async fn test_x_frame_options() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet_sameorigin)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -96,9 +104,11 @@ async fn test_x_xss_protection() {
     let helmet_on = Helmet::builder().with_header(XXSSProtection::on()).build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet_on)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -109,9 +119,11 @@ async fn test_x_xss_protection() {
     let helmet_off = Helmet::builder().with_header(XXSSProtection::off()).build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet_off)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -127,9 +139,11 @@ async fn test_strict_transport_security() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -149,9 +163,11 @@ async fn test_referrer_policy() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -170,9 +186,11 @@ async fn test_x_dns_prefetch_control() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet_off)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -188,9 +206,11 @@ async fn test_x_dns_prefetch_control() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet_on)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -209,9 +229,11 @@ async fn test_x_download_options() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -230,9 +252,11 @@ async fn test_x_powered_by() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -251,9 +275,11 @@ async fn test_x_permitted_cross_domain_policies() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -275,9 +301,11 @@ async fn test_cross_origin_embedder_policy() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -299,9 +327,11 @@ async fn test_cross_origin_opener_policy() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -323,9 +353,11 @@ async fn test_cross_origin_resource_policy() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -347,9 +379,11 @@ async fn test_origin_agent_cluster() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -368,9 +402,11 @@ async fn test_content_security_policy() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -401,9 +437,11 @@ async fn test_multiple_security_headers() {
         .build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
@@ -442,9 +480,11 @@ async fn test_empty_helmet() {
     let helmet = Helmet::builder().build();
 
     let app = Application::builder()
+        .unwrap()
         .with_controller::<HelmetTestController>()
         .with_layer(helmet)
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router()).unwrap();
     let response = server.get("/test").await;
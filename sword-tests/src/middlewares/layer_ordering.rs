@@ -0,0 +1,105 @@
+use axum::{
+    body::Body,
+    extract::Request,
+    http::header,
+    middleware::{Next, from_fn},
+    response::Response,
+};
+use axum_test::TestServer;
+use serde_json::Value;
+use sword::prelude::*;
+
+#[controller("/layers")]
+struct LayerController;
+
+#[routes]
+impl LayerController {
+    #[post("/upload")]
+    async fn upload(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let body: Value = ctx.body()?;
+        Ok(HttpResponse::Ok().data(body))
+    }
+}
+
+/// Stands in for a decompression layer: swaps the oversized body for a
+/// small one, which only helps if it runs before the body limit.
+async fn shrink_body(request: Request, next: Next) -> Response {
+    let small = r#"{"shrunk":true}"#;
+    let (mut parts, _) = request.into_parts();
+
+    parts
+        .headers
+        .insert(header::CONTENT_LENGTH, small.len().into());
+
+    next.run(Request::from_parts(parts, Body::from(small)))
+        .await
+}
+
+fn oversized_payload() -> String {
+    format!(r#"{{"data":"{}"}}"#, "x".repeat(2 * 1024 * 1024))
+}
+
+#[tokio::test]
+async fn outer_layer_runs_before_body_limit() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<LayerController>()
+        .with_outer_layer(from_fn(shrink_body))
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+
+    let response = server
+        .post("/layers/upload")
+        .text(oversized_payload())
+        .content_type("application/json")
+        .await;
+
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(body.data.unwrap().get("shrunk").unwrap(), true);
+}
+
+#[tokio::test]
+async fn inner_layer_runs_after_body_limit() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<LayerController>()
+        .with_layer(from_fn(shrink_body))
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+
+    let response = server
+        .post("/layers/upload")
+        .text(oversized_payload())
+        .content_type("application/json")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn layers_apply_to_controllers_registered_after_them() {
+    let app = Application::builder()
+        .unwrap()
+        .with_layer(from_fn(shrink_body))
+        .with_controller::<LayerController>()
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+
+    let response = server
+        .post("/layers/upload")
+        .json(&serde_json::json!({ "original": true }))
+        .await;
+
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(body.data.unwrap().get("shrunk").unwrap(), true);
+}
@@ -0,0 +1,143 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use axum_test::TestServer;
+use serde::Deserialize;
+use serde_json::json;
+use sword::prelude::*;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+struct CreateUser {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[controller("/rejections")]
+struct RejectionsController;
+
+#[routes]
+impl RejectionsController {
+    #[post("/users")]
+    async fn create(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let user: CreateUser = ctx.body_validator()?;
+        Ok(HttpResponse::Created().message(user.name))
+    }
+
+    #[get("/admin")]
+    async fn admin(&self) -> HttpResponse {
+        HttpResponse::Unauthorized()
+    }
+}
+
+/// Collects everything written by the subscriber so tests can inspect it.
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedLogs {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn capture_logs() -> (CapturedLogs, tracing::subscriber::DefaultGuard) {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    (logs, tracing::subscriber::set_default(subscriber))
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<RejectionsController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn validation_failure_logs_rejection_reason() {
+    let (logs, _guard) = capture_logs();
+    let server = test_server();
+
+    let response = server
+        .post("/rejections/users")
+        .json(&json!({ "name": "secret-value-x" }))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::CREATED);
+    assert!(logs.contents().is_empty());
+
+    let response = server
+        .post("/rejections/users")
+        .json(&json!({ "name": "ab" }))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+
+    let output = logs.contents();
+
+    assert!(output.contains("Request rejected"));
+    assert!(output.contains("sword::rejections"));
+    assert!(output.contains("status=400"));
+    assert!(output.contains("kind=\"ValidatorError\""));
+    assert!(output.contains("path=/rejections/users"));
+    assert!(output.contains("name"));
+    assert!(!output.contains("\"ab\""));
+}
+
+#[tokio::test]
+async fn rejections_without_request_error_log_status_reason() {
+    let (logs, _guard) = capture_logs();
+    let server = test_server();
+
+    let response = server.get("/rejections/admin").await;
+
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+
+    let output = logs.contents();
+
+    assert!(output.contains("status=401"));
+    assert!(output.contains("kind=\"-\""));
+    assert!(output.contains("reason=Unauthorized"));
+}
+
+#[tokio::test]
+async fn content_type_rejections_log_request_error_kind() {
+    let (logs, _guard) = capture_logs();
+    let server = test_server();
+
+    let response = server
+        .post("/rejections/users")
+        .text("name=ab")
+        .content_type("text/plain")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    let output = logs.contents();
+
+    assert!(output.contains("status=415"));
+    assert!(output.contains("kind=\"UnsupportedMediaType\""));
+    assert!(!output.contains("name=ab"));
+}
@@ -0,0 +1,163 @@
+use axum_test::TestServer;
+use serde_json::json;
+use sword::prelude::*;
+
+#[controller("/orders")]
+struct OrdersController;
+
+#[routes]
+impl OrdersController {
+    #[get("/current")]
+    async fn current(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(ctx.request_id())
+    }
+
+    #[get("/forwarded")]
+    async fn forwarded(&self, ctx: Context) -> HttpResponse {
+        let header = ctx.request_id_header_value();
+
+        HttpResponse::Ok().data(header.map(|(name, value)| {
+            json!({ "name": name.as_str(), "value": value.to_str().unwrap() })
+        }))
+    }
+}
+
+fn test_server(request_id: RequestId) -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<OrdersController>()
+        .with_request_id(request_id)
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn generates_a_uuid_and_echoes_it() {
+    let response = test_server(RequestId::new()).get("/orders/current").await;
+
+    let header = response.header("x-request-id");
+    let id = header.to_str().unwrap();
+
+    assert!(uuid::Uuid::parse_str(id).is_ok());
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), id);
+}
+
+#[tokio::test]
+async fn reuses_the_incoming_id() {
+    let response = test_server(RequestId::new())
+        .get("/orders/current")
+        .add_header("X-Request-Id", "checkout-42")
+        .await;
+
+    assert_eq!(response.header("x-request-id"), "checkout-42");
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), "checkout-42");
+}
+
+#[tokio::test]
+async fn replaces_invalid_incoming_ids() {
+    let response = test_server(RequestId::new())
+        .get("/orders/current")
+        .add_header("X-Request-Id", "a".repeat(200))
+        .await;
+
+    let header = response.header("x-request-id");
+    assert!(uuid::Uuid::parse_str(header.to_str().unwrap()).is_ok());
+}
+
+#[tokio::test]
+async fn generates_nanoids_in_a_custom_header() {
+    let request_id = RequestId::new()
+        .format(RequestIdFormat::NanoId)
+        .header("x-correlation-id");
+
+    let response = test_server(request_id).get("/orders/current").await;
+    let header = response.header("x-correlation-id");
+    let id = header.to_str().unwrap();
+
+    assert_eq!(id.len(), 21);
+    assert!(response.maybe_header("x-request-id").is_none());
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), id);
+}
+
+#[tokio::test]
+async fn not_found_responses_carry_the_id() {
+    let response = test_server(RequestId::new()).get("/missing").await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert!(response.maybe_header("x-request-id").is_some());
+}
+
+#[tokio::test]
+async fn works_as_a_regular_layer() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<OrdersController>()
+        .with_layer(RequestId::new())
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+
+    let response = server
+        .get("/orders/current")
+        .add_header("X-Request-Id", "from-layer")
+        .await;
+
+    assert_eq!(response.header("x-request-id"), "from-layer");
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), "from-layer");
+}
+
+#[tokio::test]
+async fn request_id_is_none_when_disabled() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<OrdersController>()
+        .build()
+        .unwrap();
+
+    let response = TestServer::new(app.router())
+        .unwrap()
+        .get("/orders/current")
+        .await;
+
+    assert!(response.json::<ResponseBody>().data.is_none());
+    assert!(response.maybe_header("x-request-id").is_none());
+}
+
+#[tokio::test]
+async fn incoming_and_outgoing_headers_can_differ() {
+    let request_id = RequestId::new()
+        .incoming_header("X-Correlation-Id")
+        .outgoing_header("X-Request-Id");
+
+    let response = test_server(request_id)
+        .get("/orders/forwarded")
+        .add_header("X-Correlation-Id", "gateway-7")
+        .await;
+
+    let data = response.json::<ResponseBody>().data.unwrap();
+
+    assert_eq!(response.header("x-request-id"), "gateway-7");
+    assert!(response.maybe_header("x-correlation-id").is_none());
+    assert_eq!(
+        data,
+        json!({ "name": "x-request-id", "value": "gateway-7" })
+    );
+}
+
+#[tokio::test]
+async fn header_value_uses_the_configured_name() {
+    let response = test_server(RequestId::new().header("X-Correlation-Id"))
+        .get("/orders/forwarded")
+        .add_header("X-Request-Id", "ignored")
+        .await;
+
+    let data = response.json::<ResponseBody>().data.unwrap();
+    let id = response.header("x-correlation-id");
+
+    assert_eq!(data["name"], "x-correlation-id");
+    assert_eq!(data["value"], id.to_str().unwrap());
+    assert_ne!(data["value"], "ignored");
+}
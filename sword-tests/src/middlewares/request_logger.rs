@@ -0,0 +1,147 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use axum_test::TestServer;
+use sword::prelude::*;
+use tracing::level_filters::LevelFilter;
+
+#[controller("/orders")]
+struct OrdersController;
+
+#[routes]
+impl OrdersController {
+    #[get("/")]
+    async fn list(&self) -> HttpResponse {
+        HttpResponse::Ok().data(Vec::<u32>::new())
+    }
+
+    #[get("/missing")]
+    async fn missing(&self) -> HttpResponse {
+        HttpResponse::NotFound()
+    }
+}
+
+/// Collects everything written by the subscriber so tests can inspect it.
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedLogs {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn capture_logs(
+    level: LevelFilter,
+) -> (CapturedLogs, tracing::subscriber::DefaultGuard) {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_max_level(level)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    (logs, tracing::subscriber::set_default(subscriber))
+}
+
+fn test_server(builder: ApplicationBuilder) -> TestServer {
+    let app = builder
+        .with_controller::<OrdersController>()
+        .with_logger()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn requests_are_logged_with_status_latency_and_id() {
+    let (logs, _guard) = capture_logs(LevelFilter::INFO);
+    let builder = Application::builder()
+        .unwrap()
+        .with_request_id(RequestId::new());
+
+    let response = test_server(builder)
+        .get("/orders/missing")
+        .add_header("X-Request-Id", "req-42")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+
+    let logs = logs.contents();
+    let lines = logs.lines().filter(|line| line.contains("sword::requests"));
+
+    assert_eq!(lines.count(), 1);
+    assert!(logs.contains("INFO sword::requests: Request handled"));
+    assert!(logs.contains("method=GET"));
+    assert!(logs.contains("path=\"/orders/missing\""));
+    assert!(logs.contains("status=404"));
+    assert!(logs.contains("latency_ms="));
+    assert!(logs.contains("request_id=\"req-42\""));
+}
+
+#[tokio::test]
+async fn query_strings_are_left_out_by_default() {
+    let (logs, _guard) = capture_logs(LevelFilter::INFO);
+    let server = test_server(Application::builder().unwrap());
+
+    server
+        .get("/orders")
+        .add_query_param("email", "a@b.c")
+        .await;
+
+    let logs = logs.contents();
+
+    assert!(logs.contains("path=\"/orders\""));
+    assert!(logs.contains("request_id=\"-\""));
+    assert!(!logs.contains("a%40b.c"));
+}
+
+#[tokio::test]
+async fn config_sets_the_level_and_includes_query_strings() {
+    let builder =
+        ApplicationBuilder::with_config_path("config/request_logging.toml").unwrap();
+
+    let server = test_server(builder);
+
+    let (logs, guard) = capture_logs(LevelFilter::INFO);
+    server.get("/orders").await;
+    drop(guard);
+
+    assert!(logs.contents().is_empty());
+
+    let (logs, _guard) = capture_logs(LevelFilter::DEBUG);
+    server.get("/orders").add_query_param("page", "2").await;
+
+    let logs = logs.contents();
+
+    assert!(logs.contains("DEBUG sword::requests: Request handled"));
+    assert!(logs.contains("path=\"/orders?page=2\""));
+    assert!(logs.contains("status=200"));
+}
+
+#[tokio::test]
+async fn invalid_levels_fail_the_build() {
+    let result =
+        ApplicationBuilder::with_config_path("config/request_logging_invalid.toml")
+            .unwrap()
+            .with_logger()
+            .build();
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,88 @@
+use std::{convert::Infallible, time::Duration};
+
+use axum::{body::Body, extract::Request, http::header};
+use axum_test::TestServer;
+use futures::StreamExt;
+use serde_json::Value;
+use sword::prelude::*;
+use tokio::time::sleep;
+use tower::ServiceExt;
+
+#[controller("/response-timeout")]
+struct ResponseTimeoutController;
+
+#[routes]
+impl ResponseTimeoutController {
+    #[post("/slow-handler")]
+    #[response_timeout("200ms")]
+    async fn slow_handler(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let _body: Value = ctx.body()?;
+        sleep(Duration::from_millis(600)).await;
+
+        Ok(HttpResponse::Ok().message("Too late"))
+    }
+
+    #[post("/fast-handler")]
+    #[response_timeout("200ms")]
+    async fn fast_handler(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let body: Value = ctx.body()?;
+        Ok(HttpResponse::Ok().data(body))
+    }
+}
+
+fn application() -> Application {
+    Application::builder()
+        .unwrap()
+        .with_controller::<ResponseTimeoutController>()
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn slow_handler_returns_gateway_timeout() {
+    let server = TestServer::new(application().router()).unwrap();
+
+    let response = server
+        .post("/response-timeout/slow-handler")
+        .json(&serde_json::json!({ "fast": "body" }))
+        .await;
+
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::GATEWAY_TIMEOUT);
+    assert_eq!(
+        body.message.as_ref(),
+        "The handler did not respond within 200ms"
+    );
+}
+
+#[tokio::test]
+async fn fast_handler_is_not_affected() {
+    let server = TestServer::new(application().router()).unwrap();
+
+    let response = server
+        .post("/response-timeout/fast-handler")
+        .json(&serde_json::json!({ "ok": true }))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn slow_body_upload_does_not_count_against_handler_timeout() {
+    let chunks = ["{\"slow\":", "\"upload\"}"];
+
+    let stream = futures::stream::iter(chunks).then(|chunk| async move {
+        sleep(Duration::from_millis(250)).await;
+        Ok::<_, Infallible>(chunk)
+    });
+
+    let request = Request::post("/response-timeout/fast-handler")
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from_stream(stream))
+        .unwrap();
+
+    let response = application().router().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use axum_test::TestServer;
+use sword::prelude::*;
+
+struct AuthTimingMiddleware;
+
+impl Middleware for AuthTimingMiddleware {
+    async fn handle(ctx: Context, nxt: Next) -> MiddlewareResult {
+        ctx.record_timing("auth", Duration::from_millis(3));
+        next!(ctx, nxt)
+    }
+}
+
+#[controller("/timing")]
+struct TimingController;
+
+#[routes]
+impl TimingController {
+    #[get("/plain")]
+    async fn plain(&self) -> HttpResponse {
+        HttpResponse::Ok()
+    }
+
+    #[get("/db")]
+    #[middleware(AuthTimingMiddleware)]
+    async fn db(&self, ctx: Context) -> HttpResponse {
+        ctx.record_timing("db", Duration::from_micros(4_250));
+        ctx.record_timing("not a token", Duration::from_millis(1));
+
+        HttpResponse::Ok()
+    }
+}
+
+fn test_server(server_timing: bool) -> TestServer {
+    let mut builder = Application::builder()
+        .unwrap()
+        .with_controller::<TimingController>();
+
+    if server_timing {
+        builder = builder.with_server_timing();
+    }
+
+    TestServer::new(builder.build().unwrap().router()).unwrap()
+}
+
+fn metric_names(header: &str) -> Vec<&str> {
+    header
+        .split(", ")
+        .filter_map(|metric| metric.split(';').next())
+        .collect()
+}
+
+#[tokio::test]
+async fn server_timing_is_off_by_default() {
+    let server = test_server(false);
+    let response = server.get("/timing/plain").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert!(response.maybe_header("server-timing").is_none());
+}
+
+#[tokio::test]
+async fn server_timing_reports_total() {
+    let server = test_server(true);
+    let response = server.get("/timing/plain").await;
+
+    let header = response.header("server-timing");
+    let header = header.to_str().unwrap();
+
+    assert_eq!(metric_names(header), ["total"]);
+    assert!(header.starts_with("total;dur="));
+}
+
+#[tokio::test]
+async fn server_timing_reports_recorded_phases() {
+    let server = test_server(true);
+    let response = server.get("/timing/db").await;
+
+    let header = response.header("server-timing");
+    let header = header.to_str().unwrap();
+
+    assert_eq!(metric_names(header), ["auth", "db", "total"]);
+    assert!(header.starts_with("auth;dur=3.0, db;dur=4.2"));
+}
+
+#[tokio::test]
+async fn server_timing_covers_not_found_responses() {
+    let server = test_server(true);
+    let response = server.get("/timing/missing").await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert!(response.maybe_header("server-timing").is_some());
+}
@@ -0,0 +1,91 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use axum_test::TestServer;
+use sword::prelude::*;
+
+struct AuthMiddleware;
+
+impl Middleware for AuthMiddleware {
+    async fn handle(ctx: Context, nxt: Next) -> MiddlewareResult {
+        let Some(token) = ctx.header("authorization") else {
+            return Err(HttpResponse::Unauthorized().message("Missing token"));
+        };
+
+        if token == "Bearer revoked" {
+            return Ok(HttpResponse::Forbidden().message("no").into_response());
+        }
+
+        next!(ctx, nxt)
+    }
+}
+
+#[derive(Clone, Default)]
+struct Calls(Arc<AtomicUsize>);
+
+#[controller("/vault")]
+struct VaultController;
+
+#[routes]
+impl VaultController {
+    #[get("/")]
+    #[middleware(AuthMiddleware)]
+    async fn open(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let calls = ctx.di::<Calls>()?;
+        calls.0.fetch_add(1, Ordering::SeqCst);
+
+        Ok(HttpResponse::Ok().message("Opened"))
+    }
+}
+
+fn test_server(calls: Calls) -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_state(calls)
+        .with_controller::<VaultController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn err_responses_stop_the_request() {
+    let calls = Calls::default();
+    let response = test_server(calls.clone()).get("/vault").await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+    assert_eq!(body.message, "Missing token".into());
+    assert_eq!(calls.0.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn ok_responses_stop_the_request() {
+    let calls = Calls::default();
+    let response = test_server(calls.clone())
+        .get("/vault")
+        .add_header("Authorization", "Bearer revoked")
+        .await;
+
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+    assert_eq!(body.code, 403);
+    assert_eq!(body.message, "no".into());
+    assert_eq!(calls.0.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn allowed_requests_reach_the_handler() {
+    let calls = Calls::default();
+    let response = test_server(calls.clone())
+        .get("/vault")
+        .add_header("Authorization", "Bearer valid")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(calls.0.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,77 @@
+use axum_test::TestServer;
+use sword::prelude::*;
+
+#[controller("/posts")]
+struct PostsController;
+
+#[routes]
+impl PostsController {
+    #[delete("/{id}")]
+    async fn delete_post(&self, ctx: Context) -> Result<HttpResponse, RequestError> {
+        if ctx.header("x-role") != Some("admin") {
+            return Err(
+                ctx.abort(StatusCode::FORBIDDEN, "Only admins can delete posts")
+            );
+        }
+
+        Ok(HttpResponse::Ok().message("Post deleted"))
+    }
+
+    #[get("/{id}")]
+    async fn post(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let id = ctx.param::<u32>("id")?;
+
+        if id > 10 {
+            return Err(ctx.abort(StatusCode::NOT_FOUND, "Post not found").into());
+        }
+
+        Ok(HttpResponse::Ok().data(id))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<PostsController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn aborted_handler_returns_status_and_message() {
+    let server = test_server();
+    let response = server.delete("/posts/1").await;
+
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(body.code, 403);
+    assert!(!body.success);
+    assert_eq!(body.message, "Only admins can delete posts".into());
+}
+
+#[tokio::test]
+async fn handler_continues_when_not_aborted() {
+    let server = test_server();
+
+    let response = server
+        .delete("/posts/1")
+        .add_header("x-role", "admin")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn abort_converts_into_http_response() {
+    let server = test_server();
+    let response = server.get("/posts/42").await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+
+    let body = response.json::<ResponseBody>();
+    assert_eq!(body.message, "Post not found".into());
+}
@@ -0,0 +1,92 @@
+use axum_test::TestServer;
+use serde_json::{Value, json};
+use sword::prelude::*;
+
+#[controller("/auth")]
+struct AuthController;
+
+#[routes]
+impl AuthController {
+    #[get("/bearer")]
+    async fn bearer(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(ctx.bearer_token())
+    }
+
+    #[get("/basic")]
+    async fn basic(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(ctx.basic_auth())
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<AuthController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+async fn data(path: &str, authorization: Option<&str>) -> Value {
+    let mut request = test_server().get(path);
+
+    if let Some(value) = authorization {
+        request = request.add_header("authorization", value);
+    }
+
+    request
+        .await
+        .json::<ResponseBody>()
+        .data
+        .unwrap_or(Value::Null)
+}
+
+#[tokio::test]
+async fn bearer_token_is_extracted() {
+    assert_eq!(
+        data("/auth/bearer", Some("Bearer abc.def")).await,
+        "abc.def"
+    );
+    assert_eq!(
+        data("/auth/bearer", Some("bearer abc.def")).await,
+        "abc.def"
+    );
+    assert_eq!(
+        data("/auth/bearer", Some("BEARER  abc.def")).await,
+        "abc.def"
+    );
+}
+
+#[tokio::test]
+async fn malformed_bearer_tokens_are_none() {
+    assert_eq!(data("/auth/bearer", None).await, Value::Null);
+    assert_eq!(data("/auth/bearer", Some("Bearer")).await, Value::Null);
+    assert_eq!(data("/auth/bearer", Some("Bearer ")).await, Value::Null);
+    assert_eq!(data("/auth/bearer", Some("Token abc")).await, Value::Null);
+    assert_eq!(data("/auth/bearer", Some("Bearerabc")).await, Value::Null);
+}
+
+#[tokio::test]
+async fn basic_credentials_are_decoded() {
+    let credentials = data("/auth/basic", Some("basic YWxpY2U6czM6Y3IzdA==")).await;
+
+    assert_eq!(credentials, json!(["alice", "s3:cr3t"]));
+}
+
+#[tokio::test]
+async fn malformed_basic_credentials_are_none() {
+    assert_eq!(data("/auth/basic", None).await, Value::Null);
+    assert_eq!(
+        data("/auth/basic", Some("Basic not-base64!")).await,
+        Value::Null
+    );
+    assert_eq!(
+        data("/auth/basic", Some("Basic bm9jb2xvbg==")).await,
+        Value::Null
+    );
+    assert_eq!(
+        data("/auth/basic", Some("Bearer YWxpY2U6czM6Y3IzdA==")).await,
+        Value::Null
+    );
+}
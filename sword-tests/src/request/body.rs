@@ -0,0 +1,429 @@
+use axum_test::TestServer;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sword::prelude::*;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Address {
+    city: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct CreateUser {
+    name: String,
+    address: Address,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Transfer {
+    from: String,
+    to: String,
+    amount: u64,
+}
+
+#[controller("/body")]
+struct BodyController;
+
+#[routes]
+impl BodyController {
+    #[post("/inspect")]
+    async fn inspect(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(json!({
+            "empty": ctx.is_body_empty(),
+            "len": ctx.body_len(),
+        }))
+    }
+
+    #[post("/raw")]
+    async fn raw(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(json!({ "bytes": ctx.body_raw().to_vec() }))
+    }
+
+    #[post("/string")]
+    async fn string(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        Ok(HttpResponse::Ok().data(json!({ "body": ctx.body_string()? })))
+    }
+
+    #[post("/lenient")]
+    async fn lenient(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let user: CreateUser = ctx.body()?;
+        Ok(HttpResponse::Ok().data(user))
+    }
+
+    #[post("/strict")]
+    async fn strict(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let user: CreateUser = ctx.body_strict()?;
+        Ok(HttpResponse::Ok().data(user))
+    }
+
+    #[post("/echo")]
+    async fn echo(&self, ctx: Context) -> HttpResult<Response> {
+        let user: CreateUser = ctx.body()?;
+        Ok(ctx.respond(user))
+    }
+
+    #[post("/transfers")]
+    async fn transfer(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let transfer = ctx.validate_with(|transfer: &Transfer| {
+            let mut errors = Vec::new();
+
+            if transfer.from == transfer.to {
+                errors.push("Cannot transfer to the same account".to_string());
+            }
+
+            if transfer.amount == 0 {
+                errors.push("Amount must be positive".to_string());
+            }
+
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors)
+            }
+        })?;
+
+        Ok(HttpResponse::Created().data(transfer))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<BodyController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+/// Parses JSON with `//` line comments, which `serde_json` rejects, and
+/// writes indented JSON.
+struct JsonWithComments;
+
+impl JsonEngine for JsonWithComments {
+    fn serialize(
+        &self,
+        value: &dyn erased_serde::Serialize,
+        writer: &mut Vec<u8>,
+    ) -> Result<(), JsonEngineError> {
+        Ok(serde_json::to_writer_pretty(writer, value)?)
+    }
+
+    fn deserialize(
+        &self,
+        bytes: &[u8],
+        visit: &mut dyn FnMut(
+            &mut dyn erased_serde::Deserializer<'_>,
+        ) -> Result<(), erased_serde::Error>,
+    ) -> Result<(), JsonEngineError> {
+        let text = std::str::from_utf8(bytes)?
+            .lines()
+            .map(|line| line.split("//").next().unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut deserializer = serde_json::Deserializer::from_str(&text);
+
+        visit(&mut <dyn erased_serde::Deserializer>::erase(
+            &mut deserializer,
+        ))?;
+        deserializer.end()?;
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn empty_body_reports_zero_length() {
+    let server = test_server();
+    let response = server.post("/body/inspect").await;
+    let data = response.json::<ResponseBody>().data.unwrap();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(data.get("empty").unwrap(), true);
+    assert_eq!(data.get("len").unwrap(), 0);
+}
+
+#[tokio::test]
+async fn non_empty_body_reports_its_length() {
+    let server = test_server();
+    let payload = json!({ "name": "sword" });
+    let expected_len = serde_json::to_vec(&payload).unwrap().len();
+
+    let response = server.post("/body/inspect").json(&payload).await;
+    let data = response.json::<ResponseBody>().data.unwrap();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(data.get("empty").unwrap(), false);
+    assert_eq!(data.get("len").unwrap(), expected_len);
+}
+
+#[tokio::test]
+async fn body_raw_returns_the_exact_bytes_received() {
+    let server = test_server();
+    let payload = b"{ \"event\":  \"push\" }\n";
+
+    let response = server
+        .post("/body/raw")
+        .content_type("application/json")
+        .bytes(payload.as_slice().into())
+        .await;
+    let data = response.json::<ResponseBody>().data.unwrap();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(data.get("bytes").unwrap(), &json!(payload.to_vec()));
+}
+
+#[tokio::test]
+async fn body_string_returns_utf8_bodies() {
+    let server = test_server();
+
+    let response = server
+        .post("/body/string")
+        .content_type("application/json")
+        .bytes("{\"name\":\"Añañuca\"}".as_bytes().to_vec().into())
+        .await;
+    let data = response.json::<ResponseBody>().data.unwrap();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(data.get("body").unwrap(), "{\"name\":\"Añañuca\"}");
+}
+
+#[tokio::test]
+async fn body_string_rejects_invalid_utf8() {
+    let server = test_server();
+
+    let response = server
+        .post("/body/string")
+        .content_type("application/json")
+        .bytes(vec![0x7b, 0xff, 0xfe, 0x7d].into())
+        .await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(body.message.as_ref(), "Invalid request body");
+}
+
+#[tokio::test]
+async fn lenient_body_ignores_unknown_fields() {
+    let server = test_server();
+    let payload =
+        json!({ "name": "Ana", "admin": true, "address": { "city": "Temuco" } });
+
+    let response = server.post("/body/lenient").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn strict_body_accepts_known_fields() {
+    let server = test_server();
+    let payload = json!({ "name": "Ana", "address": { "city": "Temuco" } });
+
+    let response = server.post("/body/strict").json(&payload).await;
+    let data = response.json::<ResponseBody>().data.unwrap();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(data.get("name").unwrap(), "Ana");
+}
+
+#[tokio::test]
+async fn strict_body_rejects_unknown_fields() {
+    let server = test_server();
+    let payload = json!({
+        "name": "Ana",
+        "admin": true,
+        "address": { "city": "Temuco", "zip": "4780000" }
+    });
+
+    let response = server.post("/body/strict").json(&payload).await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(body.message.as_ref(), "Unknown fields in request body");
+    assert_eq!(
+        body.error.unwrap(),
+        "Unexpected fields in request body: address.zip, admin"
+    );
+}
+
+#[tokio::test]
+async fn strict_body_rejects_trailing_data() {
+    let server = test_server();
+    let payload = r#"{ "name": "Ana", "address": { "city": "Temuco" } } garbage"#;
+
+    for path in ["/body/lenient", "/body/strict"] {
+        let response = server
+            .post(path)
+            .bytes(payload.into())
+            .content_type("application/json")
+            .await;
+
+        assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            response.json::<ResponseBody>().message.as_ref(),
+            "Invalid request body"
+        );
+    }
+}
+
+#[tokio::test]
+async fn validate_with_accepts_bodies_passing_the_rule() {
+    let server = test_server();
+    let payload = json!({ "from": "alice", "to": "bob", "amount": 10 });
+
+    let response = server.post("/body/transfers").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::CREATED);
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), payload);
+}
+
+#[tokio::test]
+async fn validate_with_rejects_business_rule_violations() {
+    let server = test_server();
+    let payload = json!({ "from": "alice", "to": "alice", "amount": 0 });
+
+    let response = server.post("/body/transfers").json(&payload).await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body.message.as_ref(), "Invalid request body");
+    assert_eq!(
+        body.errors.unwrap(),
+        json!([
+            { "code": "custom", "message": "Cannot transfer to the same account" },
+            { "code": "custom", "message": "Amount must be positive" },
+        ])
+    );
+}
+
+#[tokio::test]
+async fn validate_with_still_rejects_unparseable_bodies() {
+    let server = test_server();
+    let payload = json!({ "from": "alice" });
+
+    let response = server.post("/body/transfers").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn body_rejects_non_json_content_types() {
+    let server = test_server();
+    let form = [("name", "ana"), ("address", "lima")];
+
+    for path in ["/body/lenient", "/body/strict"] {
+        let response = server.post(path).form(&form).await;
+
+        assert_eq!(response.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        let body = response.json::<ResponseBody>();
+
+        assert_eq!(
+            body.message,
+            "Only application/json content types are supported.".into()
+        );
+    }
+}
+
+#[tokio::test]
+async fn body_accepts_json_suffixes() {
+    let server = test_server();
+    let payload = json!({ "name": "Ana", "address": { "city": "Lima" } });
+
+    for content_type in ["application/merge-patch+json", "application/vnd.api+json"]
+    {
+        let response = server
+            .post("/body/lenient")
+            .bytes(payload.to_string().into())
+            .content_type(content_type)
+            .await;
+
+        assert_eq!(response.status_code(), StatusCode::OK, "{content_type}");
+    }
+}
+
+#[tokio::test]
+async fn registered_json_engine_parses_request_bodies() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<BodyController>()
+        .with_json_engine(JsonWithComments)
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+
+    let user = "{\n  \"name\": \"Ana\", // display name\n  \"address\": { \"city\": \"Temuco\" }\n}";
+    let transfer =
+        "{ \"from\": \"alice\", \"to\": \"bob\", // accounts\n \"amount\": 10 }";
+
+    for (path, payload, status) in [
+        ("/body/lenient", user, StatusCode::OK),
+        ("/body/strict", user, StatusCode::OK),
+        ("/body/transfers", transfer, StatusCode::CREATED),
+    ] {
+        let response = server
+            .post(path)
+            .bytes(payload.into())
+            .content_type("application/json")
+            .await;
+
+        assert_eq!(response.status_code(), status, "{path}");
+
+        let default_response = test_server()
+            .post(path)
+            .bytes(payload.into())
+            .content_type("application/json")
+            .await;
+
+        assert_eq!(default_response.status_code(), StatusCode::BAD_REQUEST);
+    }
+
+    let unknown = "{ \"name\": \"Ana\", \"admin\": true, // sneaky\n \"address\": { \"city\": \"Temuco\" } }";
+
+    let response = server
+        .post("/body/strict")
+        .bytes(unknown.into())
+        .content_type("application/json")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response.json::<ResponseBody>().error.unwrap(),
+        "Unexpected fields in request body: admin"
+    );
+}
+
+#[tokio::test]
+async fn registered_json_engine_serializes_responses() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<BodyController>()
+        .with_json_engine(JsonWithComments)
+        .with_pretty_json(false)
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+    let payload = "{ \"name\": \"Ana\", // display name\n \"address\": { \"city\": \"Temuco\" } }";
+
+    let response = server
+        .post("/body/echo")
+        .bytes(payload.into())
+        .content_type("application/json")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header("content-type"), "application/json");
+    assert!(response.text().starts_with("{\n  \"code\": 200,"));
+
+    let body = response.json::<serde_json::Value>();
+
+    assert_eq!(body["success"], true);
+    assert_eq!(body["message"], "OK");
+    assert_eq!(
+        body["data"],
+        json!({ "name": "Ana", "address": { "city": "Temuco" } })
+    );
+}
@@ -0,0 +1,247 @@
+use std::{
+    convert::Infallible,
+    io,
+    sync::{Arc, Mutex},
+};
+
+use axum::{body::Body, extract::Request};
+use axum_test::TestServer;
+use serde_json::json;
+use sword::prelude::*;
+use tower::ServiceExt;
+
+const KB: usize = 1000;
+
+#[controller("/uploads")]
+struct UploadsController;
+
+#[routes]
+impl UploadsController {
+    #[post("/large")]
+    #[body_limit("2MB")]
+    async fn large(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(ctx.body_len())
+    }
+
+    #[post("/small")]
+    #[body_limit("16KB")]
+    async fn small(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(ctx.body_len())
+    }
+
+    #[post("/default")]
+    async fn default(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(ctx.body_len())
+    }
+}
+
+fn test_server(prefix: Option<&str>) -> TestServer {
+    let mut builder = Application::builder()
+        .unwrap()
+        .with_controller::<UploadsController>();
+
+    if let Some(prefix) = prefix {
+        builder = builder.with_prefix(prefix);
+    }
+
+    TestServer::new(builder.build().unwrap().router()).unwrap()
+}
+
+/// A JSON string literal that takes exactly `size` bytes.
+fn payload(size: usize) -> serde_json::Value {
+    json!("a".repeat(size - 2))
+}
+
+#[tokio::test]
+async fn route_limit_can_exceed_the_global_limit() {
+    let server = test_server(None);
+
+    let response = server
+        .post("/uploads/large")
+        .json(&payload(1500 * KB))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), 1500 * KB);
+
+    let response = server
+        .post("/uploads/large")
+        .json(&payload(1500 * KB))
+        .add_header("Content-Length", (1500 * KB).to_string())
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn route_limit_can_be_below_the_global_limit() {
+    let server = test_server(None);
+
+    let streamed = server.post("/uploads/small").json(&payload(20 * KB)).await;
+    assert_eq!(streamed.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    let declared = server
+        .post("/uploads/small")
+        .json(&payload(20 * KB))
+        .add_header("Content-Length", (20 * KB).to_string())
+        .await;
+
+    let body = declared.json::<ResponseBody>();
+
+    assert_eq!(declared.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(
+        body.error.unwrap(),
+        "Maximum allowed size is 16.0 KB, received 20.0 KB"
+    );
+
+    let within = server.post("/uploads/small").json(&payload(10 * KB)).await;
+    assert_eq!(within.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn routes_without_limit_use_the_global_limit() {
+    let response = test_server(None)
+        .post("/uploads/default")
+        .json(&payload(1500 * KB))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn route_limits_apply_under_a_global_prefix() {
+    let server = test_server(Some("/api"));
+
+    let large = server
+        .post("/api/uploads/large")
+        .json(&payload(1500 * KB))
+        .await;
+    assert_eq!(large.status_code(), StatusCode::OK);
+
+    let small = server
+        .post("/api/uploads/small")
+        .json(&payload(20 * KB))
+        .add_header("Content-Length", (20 * KB).to_string())
+        .await;
+    assert_eq!(small.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn oversized_requests_get_the_json_envelope() {
+    let server = test_server(None);
+
+    for path in ["/uploads/small", "/uploads/default"] {
+        let size = if path == "/uploads/small" {
+            20 * KB
+        } else {
+            1500 * KB
+        };
+
+        let response = server
+            .post(path)
+            .json(&payload(size))
+            .add_header("Content-Length", size.to_string())
+            .await;
+
+        assert_eq!(response.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(
+            response.header("content-type").to_str().unwrap(),
+            "application/json"
+        );
+
+        let body = response.json::<serde_json::Value>();
+
+        assert_eq!(body["code"], 413);
+        assert_eq!(body["success"], false);
+        assert!(body["message"].is_string());
+        assert!(
+            body["error"]
+                .as_str()
+                .unwrap()
+                .starts_with("Maximum allowed size")
+        );
+    }
+}
+
+#[tokio::test]
+async fn oversized_requests_are_handled_when_logging() {
+    let app = ApplicationBuilder::with_config_path("config/body_limit_log.toml")
+        .unwrap()
+        .with_controller::<UploadsController>()
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router()).unwrap();
+
+    let declared = server
+        .post("/uploads/small")
+        .json(&payload(20 * KB))
+        .add_header("Content-Length", (20 * KB).to_string())
+        .await;
+
+    assert_eq!(declared.status_code(), StatusCode::OK);
+    assert_eq!(declared.json::<ResponseBody>().data.unwrap(), 20 * KB);
+
+    let streamed = server
+        .post("/uploads/default")
+        .json(&payload(1500 * KB))
+        .await;
+
+    assert_eq!(streamed.status_code(), StatusCode::OK);
+}
+
+/// Collects everything written by the subscriber so tests can inspect it.
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn streamed_bodies_past_the_limit_are_logged() {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let app = ApplicationBuilder::with_config_path("config/body_limit_log.toml")
+        .unwrap()
+        .with_controller::<UploadsController>()
+        .build()
+        .unwrap();
+
+    let chunks = (0..15).map(|_| Ok::<_, Infallible>(vec![b'a'; 100 * KB]));
+
+    let request = Request::post("/uploads/default")
+        .header("Content-Type", "application/json")
+        .body(Body::from_stream(futures::stream::iter(chunks)))
+        .unwrap();
+
+    let response = app.router().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+
+    assert_eq!(
+        output
+            .matches("Streamed request body exceeds the body limit")
+            .count(),
+        1
+    );
+    assert!(output.contains("sword::body_limit"));
+    assert!(output.contains("path=/uploads/default"));
+}
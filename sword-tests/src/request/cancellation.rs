@@ -0,0 +1,69 @@
+use std::{sync::LazyLock, time::Duration};
+
+use sword::prelude::*;
+use tokio::{
+    io::AsyncWriteExt,
+    net::{TcpListener, TcpStream},
+    sync::Notify,
+    time::{sleep, timeout},
+};
+
+static HANDLER_STARTED: LazyLock<Notify> = LazyLock::new(Notify::new);
+static CANCELLED: LazyLock<Notify> = LazyLock::new(Notify::new);
+
+#[controller("/cancellation")]
+struct CancellationController;
+
+#[routes]
+impl CancellationController {
+    #[get("/slow")]
+    async fn slow(&self, ctx: Context) -> HttpResponse {
+        let cancelled = ctx.cancelled();
+
+        let worker = tokio::spawn(async move {
+            tokio::select! {
+                _ = cancelled => CANCELLED.notify_one(),
+                _ = sleep(Duration::from_secs(30)) => {}
+            }
+        });
+
+        HANDLER_STARTED.notify_one();
+
+        let _ = worker.await;
+
+        HttpResponse::Ok().message("Finished")
+    }
+}
+
+#[tokio::test]
+async fn client_disconnect_fires_cancellation_future() {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<CancellationController>()
+        .build()
+        .unwrap();
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        axum::serve(listener, app.router()).await.unwrap();
+    });
+
+    let mut client = TcpStream::connect(address).await.unwrap();
+
+    client
+        .write_all(b"GET /cancellation/slow HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .await
+        .unwrap();
+
+    timeout(Duration::from_secs(5), HANDLER_STARTED.notified())
+        .await
+        .expect("handler did not start");
+
+    drop(client);
+
+    timeout(Duration::from_secs(5), CANCELLED.notified())
+        .await
+        .expect("cancellation future did not resolve after disconnect");
+}
@@ -0,0 +1,57 @@
+use axum::Extension;
+use axum_test::TestServer;
+use serde_json::json;
+use sword::prelude::*;
+
+#[controller("/whoami")]
+struct WhoAmIController;
+
+#[routes]
+impl WhoAmIController {
+    #[get("/")]
+    async fn whoami(&self, ctx: Context) -> HttpResponse {
+        let cert = ctx.client_cert();
+
+        HttpResponse::Ok().data(json!({
+            "subject": cert.map(|cert| &cert.subject),
+            "fingerprint": cert.map(|cert| &cert.fingerprint),
+        }))
+    }
+}
+
+fn test_server(cert: Option<ClientCert>) -> TestServer {
+    let mut builder = Application::builder()
+        .unwrap()
+        .with_controller::<WhoAmIController>();
+
+    // Stands in for the TLS acceptor, which inserts the verified certificate.
+    if let Some(cert) = cert {
+        builder = builder.with_outer_layer(Extension(cert));
+    }
+
+    TestServer::new(builder.build().unwrap().router()).unwrap()
+}
+
+#[tokio::test]
+async fn verified_certificate_is_available() {
+    let cert = ClientCert::new("CN=billing-service,O=Acme", "3f7a0c5d");
+    let response = test_server(Some(cert)).get("/whoami").await;
+
+    assert_eq!(
+        response.json::<ResponseBody>().data.unwrap(),
+        json!({
+            "subject": "CN=billing-service,O=Acme",
+            "fingerprint": "3f7a0c5d",
+        })
+    );
+}
+
+#[tokio::test]
+async fn connections_without_certificate_have_none() {
+    let response = test_server(None).get("/whoami").await;
+
+    assert_eq!(
+        response.json::<ResponseBody>().data.unwrap(),
+        json!({ "subject": null, "fingerprint": null })
+    );
+}
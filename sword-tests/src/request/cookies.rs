@@ -1,3 +1,5 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use axum_test::TestServer;
 use sword::prelude::*;
 
@@ -19,6 +21,18 @@ impl Middleware for SetCookieMw {
     }
 }
 
+struct RequireSessionMw {}
+
+impl Middleware for RequireSessionMw {
+    async fn handle(ctx: Context, next: Next) -> MiddlewareResult {
+        if ctx.cookie("session_id").is_none() {
+            return Err(HttpResponse::Unauthorized());
+        }
+
+        next!(ctx, next)
+    }
+}
+
 #[controller("/cookies")]
 struct CookieController {}
 
@@ -39,6 +53,44 @@ impl CookieController {
         Ok(HttpResponse::Ok())
     }
 
+    #[post("/logout")]
+    async fn logout(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        ctx.cookies()?.remove_matching(
+            "session_id",
+            "/account",
+            Some("example.com"),
+        );
+
+        Ok(HttpResponse::Ok())
+    }
+
+    #[post("/signed")]
+    async fn sign(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        ctx.signed_cookies()?.add(Cookie::new("theme", "dark"));
+        ctx.private_cookies()?.add(Cookie::new("secret", "s3cr3t"));
+
+        Ok(HttpResponse::Ok())
+    }
+
+    #[get("/signed")]
+    async fn read_signed(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let theme = ctx.signed_cookies()?.get("theme");
+        let secret = ctx.private_cookies()?.get("secret");
+
+        Ok(HttpResponse::Ok().data(serde_json::json!({
+            "theme": theme.map(|cookie| cookie.value().to_string()),
+            "secret": secret.map(|cookie| cookie.value().to_string()),
+        })))
+    }
+
+    #[get("/session")]
+    #[middleware(RequireSessionMw)]
+    async fn session(&self, ctx: Context) -> HttpResponse {
+        let session_id = ctx.cookie("session_id").unwrap_or_default();
+
+        HttpResponse::Ok().message(format!("Session ID: {session_id}"))
+    }
+
     #[get("/with_middleware")]
     #[middleware(SetCookieMw)]
     async fn with_middleware(&self, mut ctx: Context) -> HttpResult<HttpResponse> {
@@ -56,8 +108,10 @@ impl CookieController {
 #[tokio::test]
 async fn test_set_cookie() -> Result<(), Box<dyn std::error::Error>> {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<CookieController>()
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router())?;
 
@@ -83,8 +137,10 @@ async fn test_set_cookie() -> Result<(), Box<dyn std::error::Error>> {
 #[tokio::test]
 async fn test_with_middleware() -> Result<(), Box<dyn std::error::Error>> {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<CookieController>()
-        .build();
+        .build()
+        .unwrap();
 
     let server = TestServer::new(app.router())?;
 
@@ -106,3 +162,134 @@ async fn test_with_middleware() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_remove_matching() -> Result<(), Box<dyn std::error::Error>> {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<CookieController>()
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router())?;
+
+    let response = server
+        .post("/cookies/logout")
+        .add_header("cookie", "session_id=abc123")
+        .await;
+
+    assert_eq!(response.status_code(), 200);
+
+    let set_cookie = response.header("set-cookie");
+    let set_cookie = set_cookie.to_str()?;
+    let removal = Cookie::parse(set_cookie)?;
+
+    assert_eq!(removal.name(), "session_id");
+    assert_eq!(removal.value(), "");
+    assert_eq!(removal.path(), Some("/account"));
+    assert_eq!(removal.domain(), Some("example.com"));
+    assert_eq!(removal.max_age().map(|age| age.whole_seconds()), Some(0));
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+    let expires = removal.expires_datetime().expect("removal has no expiry");
+
+    assert!(expires.unix_timestamp() < now);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_read_cookie() -> Result<(), Box<dyn std::error::Error>> {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<CookieController>()
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router())?;
+
+    let response = server
+        .get("/cookies/session")
+        .add_header("cookie", "theme=dark; session_id=abc123")
+        .await;
+
+    assert_eq!(response.status_code(), 200);
+    assert_eq!(
+        response.json::<ResponseBody>().message.as_ref(),
+        "Session ID: abc123"
+    );
+
+    let response = server
+        .get("/cookies/session")
+        .add_header("cookie", "theme=dark")
+        .await;
+
+    assert_eq!(response.status_code(), 401);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_signed_and_private_cookies() -> Result<(), Box<dyn std::error::Error>>
+{
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<CookieController>()
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router())?;
+
+    let response = server.post("/cookies/signed").await;
+    assert_eq!(response.status_code(), 200);
+
+    let cookies = response.cookies();
+    let theme = cookies.get("theme").expect("Cookie 'theme' not found");
+    let secret = cookies.get("secret").expect("Cookie 'secret' not found");
+
+    assert_ne!(theme.value(), "dark");
+    assert!(!secret.value().contains("s3cr3t"));
+
+    let response = server
+        .get("/cookies/signed")
+        .add_header(
+            "cookie",
+            &format!("{}; {}", theme.stripped(), secret.stripped()),
+        )
+        .await;
+
+    assert_eq!(
+        response.json::<ResponseBody>().data,
+        Some(serde_json::json!({ "theme": "dark", "secret": "s3cr3t" }))
+    );
+
+    let response = server
+        .get("/cookies/signed")
+        .add_header("cookie", "theme=light; secret=s3cr3t")
+        .await;
+
+    assert_eq!(
+        response.json::<ResponseBody>().data,
+        Some(serde_json::json!({ "theme": null, "secret": null }))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_signed_cookies_without_key() -> Result<(), Box<dyn std::error::Error>>
+{
+    let app = ApplicationBuilder::with_config_path("config/custom.toml")
+        .unwrap()
+        .with_controller::<CookieController>()
+        .build()
+        .unwrap();
+
+    let server = TestServer::new(app.router())?;
+
+    let response = server.post("/cookies/signed").await;
+
+    assert_eq!(response.status_code(), 500);
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use axum::{
+    Extension,
+    extract::{FromRequestParts, Query},
+    http::{StatusCode as AxumStatusCode, request::Parts},
+};
+use axum_test::TestServer;
+use serde::Deserialize;
+use sword::prelude::*;
+
+/// A custom extractor that requires the `X-Tenant` header.
+struct Tenant(String);
+
+impl<S: Send + Sync> FromRequestParts<S> for Tenant {
+    type Rejection = (AxumStatusCode, &'static str);
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        parts
+            .headers
+            .get("x-tenant")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| Tenant(value.to_string()))
+            .ok_or((AxumStatusCode::BAD_REQUEST, "Missing X-Tenant header"))
+    }
+}
+
+#[derive(Clone)]
+struct Region(&'static str);
+
+#[derive(Deserialize)]
+struct Pagination {
+    page: u32,
+}
+
+#[controller("/extract")]
+struct ExtractController;
+
+#[routes]
+impl ExtractController {
+    #[get("/tenant")]
+    async fn tenant(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let Tenant(tenant) = ctx.extract::<Tenant>().await?;
+
+        Ok(HttpResponse::Ok().data(tenant))
+    }
+
+    #[get("/page")]
+    async fn page(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let Query(pagination) = ctx.extract::<Query<Pagination>>().await?;
+
+        Ok(HttpResponse::Ok().data(pagination.page))
+    }
+
+    #[get("/region")]
+    async fn region(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let Extension(Region(region)) = ctx.extract::<Extension<Region>>().await?;
+
+        Ok(HttpResponse::Ok().data(region))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<ExtractController>()
+        .with_layer(Extension(Region("eu-west")))
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn runs_custom_extractors() {
+    let response = test_server()
+        .get("/extract/tenant")
+        .add_header("X-Tenant", "acme")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), "acme");
+}
+
+#[tokio::test]
+async fn rejections_become_parse_errors() {
+    let response = test_server().get("/extract/tenant").await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(body.message, "Invalid request".into());
+    assert_eq!(body.error.unwrap(), "Missing X-Tenant header");
+}
+
+#[tokio::test]
+async fn runs_axum_extractors() {
+    let server = test_server();
+
+    let response = server.get("/extract/page?page=3").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), 3);
+
+    let response = server.get("/extract/page?page=first").await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    let error = body.error.unwrap();
+
+    assert!(
+        error
+            .as_str()
+            .unwrap()
+            .starts_with("Failed to deserialize query string")
+    );
+}
+
+#[tokio::test]
+async fn extractors_see_the_request_extensions() {
+    let response = test_server().get("/extract/region").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), "eu-west");
+}
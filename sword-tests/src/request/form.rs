@@ -0,0 +1,98 @@
+use axum_test::TestServer;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sword::prelude::*;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct SignupForm {
+    username: String,
+    age: u32,
+    newsletter: Option<bool>,
+}
+
+#[controller("/forms")]
+struct FormController;
+
+#[routes]
+impl FormController {
+    #[post("/signup")]
+    async fn signup(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let form: SignupForm = ctx.form()?;
+        Ok(HttpResponse::Ok().data(form))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<FormController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn form_deserializes_urlencoded_body() {
+    let server = test_server();
+
+    let response = server
+        .post("/forms/signup")
+        .content_type("application/x-www-form-urlencoded")
+        .bytes("username=Ana+Maria&age=31&newsletter=true".into())
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(
+        body.data,
+        Some(json!({ "username": "Ana Maria", "age": 31, "newsletter": true }))
+    );
+}
+
+#[tokio::test]
+async fn form_accepts_charset_parameter() {
+    let server = test_server();
+
+    let response = server
+        .post("/forms/signup")
+        .content_type("application/x-www-form-urlencoded; charset=utf-8")
+        .bytes("username=ana&age=20".into())
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn form_rejects_json_body() {
+    let server = test_server();
+
+    let response = server
+        .post("/forms/signup")
+        .json(&json!({ "username": "ana", "age": 20 }))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(
+        body.message,
+        "Only application/x-www-form-urlencoded content types are supported.".into()
+    );
+}
+
+#[tokio::test]
+async fn form_rejects_mismatched_fields() {
+    let server = test_server();
+
+    let response = server
+        .post("/forms/signup")
+        .content_type("application/x-www-form-urlencoded")
+        .bytes("username=ana&age=twenty".into())
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+}
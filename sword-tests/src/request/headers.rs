@@ -0,0 +1,178 @@
+use axum_test::TestServer;
+use serde_json::json;
+use sword::prelude::*;
+
+enum Priority {
+    Low,
+    High,
+}
+
+impl std::str::FromStr for Priority {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "low" => Ok(Priority::Low),
+            "high" => Ok(Priority::High),
+            _ => Err(()),
+        }
+    }
+}
+
+#[controller("/headers")]
+struct HeadersController;
+
+#[routes]
+impl HeadersController {
+    #[get("/page")]
+    async fn page(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let page: u32 = ctx.header_parsed("X-Page").transpose()?.unwrap_or(1);
+
+        Ok(HttpResponse::Ok().data(page))
+    }
+
+    #[get("/priority")]
+    async fn priority(&self, ctx: Context) -> HttpResponse {
+        let priority = match ctx.header_parsed::<Priority>("x-priority") {
+            None => "absent",
+            Some(Err(_)) => "invalid",
+            Some(Ok(Priority::Low)) => "low",
+            Some(Ok(Priority::High)) => "high",
+        };
+
+        HttpResponse::Ok().data(priority)
+    }
+
+    #[get("/attempts")]
+    async fn attempts(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let attempts: u8 = ctx.typed_header("X-Retry-Count")?;
+
+        Ok(HttpResponse::Ok().data(attempts))
+    }
+
+    #[post("/describe")]
+    async fn describe(&self, ctx: Context) -> HttpResponse {
+        let content_type = ctx
+            .content_type()
+            .map(|mime| mime.essence_str().to_string());
+
+        HttpResponse::Ok().data(json!({
+            "content_type": content_type,
+            "content_length": ctx.content_length(),
+        }))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<HeadersController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn absent_header_falls_back_to_default() {
+    let response = test_server().get("/headers/page").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn present_header_is_parsed() {
+    let response = test_server()
+        .get("/headers/page")
+        .add_header("X-Page", "7")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ResponseBody>().data.unwrap(), 7);
+}
+
+#[tokio::test]
+async fn unparseable_header_is_rejected() {
+    let response = test_server()
+        .get("/headers/page")
+        .add_header("X-Page", "seven")
+        .await;
+
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(body.message.as_ref(), "Invalid header value");
+}
+
+#[tokio::test]
+async fn header_parsed_distinguishes_absent_from_invalid() {
+    let server = test_server();
+
+    let absent = server.get("/headers/priority").await;
+    assert_eq!(absent.json::<ResponseBody>().data.unwrap(), "absent");
+
+    let invalid = server
+        .get("/headers/priority")
+        .add_header("X-Priority", "urgent")
+        .await;
+    assert_eq!(invalid.json::<ResponseBody>().data.unwrap(), "invalid");
+
+    let high = server
+        .get("/headers/priority")
+        .add_header("X-Priority", "high")
+        .await;
+    assert_eq!(high.json::<ResponseBody>().data.unwrap(), "high");
+}
+
+#[tokio::test]
+async fn typed_header_requires_the_header() {
+    let server = test_server();
+
+    let missing = server.get("/headers/attempts").await;
+    let body = missing.json::<ResponseBody>();
+
+    assert_eq!(missing.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(body.message.as_ref(), "Header not found");
+
+    let invalid = server
+        .get("/headers/attempts")
+        .add_header("X-Retry-Count", "many")
+        .await;
+    let body = invalid.json::<ResponseBody>();
+
+    assert_eq!(invalid.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(body.message.as_ref(), "Invalid header value");
+
+    let valid = server
+        .get("/headers/attempts")
+        .add_header("X-Retry-Count", "3")
+        .await;
+
+    assert_eq!(valid.status_code(), StatusCode::OK);
+    assert_eq!(valid.json::<ResponseBody>().data.unwrap(), 3);
+}
+
+#[tokio::test]
+async fn content_type_and_length_are_parsed() {
+    let response = test_server()
+        .post("/headers/describe")
+        .json(&json!({ "name": "sword" }))
+        .add_header("Content-Length", "16")
+        .await;
+
+    let data = response.json::<ResponseBody>().data.unwrap();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(data["content_type"], "application/json");
+    assert_eq!(data["content_length"], 16);
+}
+
+#[tokio::test]
+async fn content_getters_are_none_when_absent() {
+    let response = test_server().post("/headers/describe").await;
+    let data = response.json::<ResponseBody>().data.unwrap();
+
+    assert!(data["content_type"].is_null());
+    assert!(data["content_length"].is_null());
+}
@@ -0,0 +1,259 @@
+use std::{convert::Infallible, pin::pin};
+
+use axum::{body::Body, extract::Request};
+use axum_test::TestServer;
+use futures::StreamExt;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use sword::prelude::*;
+use tower::ServiceExt;
+
+#[derive(Deserialize)]
+struct Event {
+    id: u32,
+}
+
+#[controller("/stream")]
+struct JsonStreamController;
+
+#[routes]
+impl JsonStreamController {
+    #[post("/count")]
+    #[stream_body]
+    async fn count(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let mut events = pin!(ctx.json_array_stream::<Event>());
+        let (mut count, mut sum) = (0u64, 0u64);
+
+        while let Some(event) = events.next().await {
+            count += 1;
+            sum += u64::from(event?.id);
+        }
+
+        Ok(HttpResponse::Ok().data(json!({ "count": count, "sum": sum })))
+    }
+
+    #[post("/report")]
+    #[stream_body]
+    async fn report(&self, ctx: Context) -> HttpResponse {
+        let results = ctx
+            .json_array_stream::<Event>()
+            .map(|event| match event {
+                Ok(event) => json!(event.id),
+                Err(error) => json!(error.to_string()),
+            })
+            .collect::<Vec<Value>>()
+            .await;
+
+        HttpResponse::Ok().data(results)
+    }
+
+    #[post("/limited")]
+    #[stream_body]
+    #[body_limit("1KB")]
+    async fn limited(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let mut events = pin!(ctx.json_array_stream::<Event>());
+
+        while let Some(event) = events.next().await {
+            event?;
+        }
+
+        Ok(HttpResponse::Ok())
+    }
+
+    #[post("/buffered")]
+    async fn buffered(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        self.limited(ctx).await
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<JsonStreamController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+fn report_of(body: &'static str) -> impl Future<Output = Vec<Value>> {
+    let server = test_server();
+
+    async move {
+        let response = server
+            .post("/stream/report")
+            .text(body)
+            .content_type("application/json")
+            .await;
+
+        let data = response.json::<ResponseBody>().data.unwrap();
+        serde_json::from_value(data).unwrap()
+    }
+}
+
+#[tokio::test]
+async fn streams_every_element_of_a_large_array() {
+    let events = (0..20_000)
+        .map(|id| json!({ "id": id }))
+        .collect::<Vec<_>>();
+
+    let response = test_server().post("/stream/count").json(&events).await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        body.data.unwrap(),
+        json!({ "count": 20_000, "sum": 199_990_000u64 })
+    );
+}
+
+#[tokio::test]
+async fn empty_array_yields_no_elements() {
+    let report = report_of(" [ ] ").await;
+
+    assert!(report.is_empty());
+}
+
+#[tokio::test]
+async fn mismatched_element_is_skipped() {
+    let report = report_of(r#"[{"id": 1}, {"id": "two"}, {"id": 3}]"#).await;
+
+    assert_eq!(report.len(), 3);
+    assert_eq!(report[0], json!(1));
+    assert!(
+        report[1]
+            .as_str()
+            .unwrap()
+            .starts_with("Failed to parse request")
+    );
+    assert_eq!(report[2], json!(3));
+}
+
+#[tokio::test]
+async fn malformed_element_ends_the_stream() {
+    let report = report_of(r#"[{"id": 1}, {"id": 2, }, {"id": 3}]"#).await;
+
+    assert_eq!(report.len(), 2);
+    assert_eq!(report[0], json!(1));
+    assert!(report[1].is_string());
+}
+
+#[tokio::test]
+async fn missing_separator_ends_the_stream() {
+    let report = report_of(r#"[{"id": 1} {"id": 2}]"#).await;
+
+    assert_eq!(report.len(), 2);
+    assert_eq!(report[0], json!(1));
+    assert!(report[1].is_string());
+}
+
+#[tokio::test]
+async fn non_array_body_is_rejected() {
+    let response = test_server()
+        .post("/stream/count")
+        .json(&json!({ "id": 1 }))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response.json::<ResponseBody>().message.as_ref(),
+        "Invalid JSON array in request body"
+    );
+}
+
+#[tokio::test]
+async fn mismatched_element_fails_the_handler() {
+    let response = test_server()
+        .post("/stream/count")
+        .json(&json!([{ "id": 1 }, { "id": "two" }]))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response.json::<ResponseBody>().message.as_ref(),
+        "Invalid element in request body array"
+    );
+}
+
+/// Sends `body` in chunks of `chunk_size` bytes, without a `Content-Length`.
+async fn post_in_chunks(path: &str, body: Vec<u8>, chunk_size: usize) -> Response {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<JsonStreamController>()
+        .build()
+        .unwrap();
+
+    let chunks = body
+        .chunks(chunk_size)
+        .map(|chunk| Ok::<_, Infallible>(chunk.to_vec()))
+        .collect::<Vec<_>>();
+
+    let request = Request::post(path)
+        .header("Content-Type", "application/json")
+        .body(Body::from_stream(futures::stream::iter(chunks)))
+        .unwrap();
+
+    app.router().oneshot(request).await.unwrap()
+}
+
+#[tokio::test]
+async fn streams_arrays_larger_than_the_body_limit() {
+    let events = (0..200_000)
+        .map(|id| json!({ "id": id }))
+        .collect::<Vec<_>>();
+
+    let body = serde_json::to_vec(&events).unwrap();
+    assert!(body.len() > 1_000_000);
+
+    // An odd chunk size splits numbers and keys across chunks.
+    let response = post_in_chunks("/stream/count", body, 4093).await;
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    let body = serde_json::from_slice::<ResponseBody>(&body).unwrap();
+
+    assert_eq!(
+        body.data.unwrap(),
+        json!({ "count": 200_000, "sum": 19_999_900_000u64 })
+    );
+}
+
+#[tokio::test]
+async fn elements_split_across_chunks_are_parsed_whole() {
+    let body = br#"[{"id": 12}, 345, {"id": 6789}]"#.to_vec();
+
+    let response = post_in_chunks("/stream/report", body, 1).await;
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    let report = serde_json::from_slice::<ResponseBody>(&body)
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(report[0], json!(12));
+    assert!(report[1].is_string());
+    assert_eq!(report[2], json!(6789));
+}
+
+#[tokio::test]
+async fn route_body_limit_ends_the_stream() {
+    let events = (0..1000).map(|id| json!({ "id": id })).collect::<Vec<_>>();
+
+    let body = serde_json::to_vec(&events).unwrap();
+    let response = post_in_chunks("/stream/limited", body, 256).await;
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn buffered_routes_cant_stream_the_body() {
+    let response = test_server()
+        .post("/stream/buffered")
+        .json(&json!([{ "id": 1 }]))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+}
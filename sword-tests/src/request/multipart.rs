@@ -4,7 +4,7 @@ use axum_test::{
 };
 
 use std::fs;
-use sword::prelude::*;
+use sword::{prelude::*, web::multipart::MultipartLimits};
 
 use crate::utils::TempFile;
 
@@ -42,13 +42,47 @@ impl TestController {
 
         Ok(HttpResponse::Ok().data(fields).message("Hello, Multipart!"))
     }
+
+    #[post("/avatar")]
+    async fn avatar(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let limits = MultipartLimits::new()
+            .field("avatar", 64 * 1024)
+            .default_field(1024);
+
+        let mut multipart = ctx.multipart_with_limits(limits).await?;
+        let mut sizes = vec![];
+
+        while let Some(field) = multipart.next_field().await? {
+            let name = field.name().unwrap_or("Unnamed").to_string();
+            let data = field.bytes().await?;
+
+            sizes.push(serde_json::json!({ "name": name, "size": data.len() }));
+        }
+
+        Ok(HttpResponse::Ok().data(sizes))
+    }
+
+    #[post("/survey")]
+    async fn survey(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let limits = MultipartLimits::new().max_fields(3);
+        let mut multipart = ctx.multipart_with_limits(limits).await?;
+        let mut count = 0;
+
+        while multipart.next_field().await?.is_some() {
+            count += 1;
+        }
+
+        Ok(HttpResponse::Ok().data(count))
+    }
 }
 
 #[tokio::test]
 async fn exceed_limit() -> Result<(), Box<dyn std::error::Error>> {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
 
@@ -81,8 +115,10 @@ async fn exceed_limit() -> Result<(), Box<dyn std::error::Error>> {
 #[tokio::test]
 async fn body_limit_exactly_at_limit() -> Result<(), Box<dyn std::error::Error>> {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
 
@@ -109,8 +145,10 @@ async fn body_limit_exactly_at_limit() -> Result<(), Box<dyn std::error::Error>>
 #[tokio::test]
 async fn body_limit_just_under_limit() -> Result<(), Box<dyn std::error::Error>> {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
 
@@ -137,8 +175,10 @@ async fn body_limit_just_under_limit() -> Result<(), Box<dyn std::error::Error>>
 #[tokio::test]
 async fn body_limit_just_over_limit() -> Result<(), Box<dyn std::error::Error>> {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
 
@@ -170,8 +210,10 @@ async fn body_limit_just_over_limit() -> Result<(), Box<dyn std::error::Error>>
 async fn body_limit_multiple_fields_exceed_limit()
 -> Result<(), Box<dyn std::error::Error>> {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
 
@@ -213,8 +255,10 @@ async fn body_limit_multiple_fields_exceed_limit()
 async fn body_limit_small_fields_within_limit()
 -> Result<(), Box<dyn std::error::Error>> {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<TestController>()
-        .build();
+        .build()
+        .unwrap();
 
     let test = TestServer::new(app.router()).unwrap();
 
@@ -249,3 +293,176 @@ async fn body_limit_small_fields_within_limit()
 
     Ok(())
 }
+
+#[tokio::test]
+async fn field_within_its_limit_is_accepted()
+-> Result<(), Box<dyn std::error::Error>> {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<TestController>()
+        .build()
+        .unwrap();
+
+    let test = TestServer::new(app.router()).unwrap();
+
+    let form = MultipartForm::new()
+        .add_text("username", "sword")
+        .add_part("avatar", Part::bytes(vec![b'x'; 60 * 1024]));
+
+    let response = test.post("/avatar").multipart(form).await;
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), 200);
+    assert_eq!(
+        json.data,
+        Some(serde_json::json!([
+            { "name": "username", "size": 5 },
+            { "name": "avatar", "size": 60 * 1024 },
+        ]))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn field_over_its_limit_is_rejected_by_name()
+-> Result<(), Box<dyn std::error::Error>> {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<TestController>()
+        .build()
+        .unwrap();
+
+    let test = TestServer::new(app.router()).unwrap();
+
+    let form = MultipartForm::new()
+        .add_text("username", "sword")
+        .add_part("avatar", Part::bytes(vec![b'x'; 100 * 1024]));
+
+    let response = test.post("/avatar").multipart(form).await;
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), 413);
+    assert_eq!(
+        json.error.unwrap(),
+        "Field 'avatar' exceeds the maximum allowed size of 65.5 KB"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn field_over_default_limit_is_rejected()
+-> Result<(), Box<dyn std::error::Error>> {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<TestController>()
+        .build()
+        .unwrap();
+
+    let test = TestServer::new(app.router()).unwrap();
+
+    let form = MultipartForm::new().add_text("bio", "x".repeat(2048));
+
+    let response = test.post("/avatar").multipart(form).await;
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), 413);
+    assert_eq!(
+        json.error.unwrap(),
+        "Field 'bio' exceeds the maximum allowed size of 1.0 KB"
+    );
+
+    Ok(())
+}
+
+fn form_with_fields(count: usize) -> MultipartForm {
+    (0..count).fold(MultipartForm::new(), |form, index| {
+        form.add_text(format!("field{index}"), "x")
+    })
+}
+
+#[tokio::test]
+async fn fields_up_to_the_configured_maximum_are_accepted()
+-> Result<(), Box<dyn std::error::Error>> {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<TestController>()
+        .build()
+        .unwrap();
+
+    let test = TestServer::new(app.router()).unwrap();
+
+    let response = test.post("/avatar").multipart(form_with_fields(8)).await;
+
+    assert_eq!(response.status_code(), 200);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fields_over_the_configured_maximum_are_rejected()
+-> Result<(), Box<dyn std::error::Error>> {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<TestController>()
+        .build()
+        .unwrap();
+
+    let test = TestServer::new(app.router()).unwrap();
+
+    let response = test.post("/avatar").multipart(form_with_fields(500)).await;
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), 400);
+    assert_eq!(json.message.as_ref(), "Too many multipart fields");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn route_maximum_overrides_the_configured_one()
+-> Result<(), Box<dyn std::error::Error>> {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<TestController>()
+        .build()
+        .unwrap();
+
+    let test = TestServer::new(app.router()).unwrap();
+
+    let response = test.post("/survey").multipart(form_with_fields(3)).await;
+
+    assert_eq!(response.status_code(), 200);
+    assert_eq!(response.json::<ResponseBody>().data, Some(3.into()));
+
+    let response = test.post("/survey").multipart(form_with_fields(4)).await;
+
+    assert_eq!(response.status_code(), 400);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn configured_maximum_applies_to_plain_multipart()
+-> Result<(), Box<dyn std::error::Error>> {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<TestController>()
+        .build()
+        .unwrap();
+
+    let test = TestServer::new(app.router()).unwrap();
+
+    let response = test.post("/multipart").multipart(form_with_fields(8)).await;
+
+    assert_eq!(response.status_code(), 200);
+
+    let response = test.post("/multipart").multipart(form_with_fields(9)).await;
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), 400);
+    assert_eq!(json.message.as_ref(), "Too many multipart fields");
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use axum_test::TestServer;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use sword::{errors::ValidationErrorFormatter, prelude::*};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+fn validate_slug(slug: &str) -> Result<(), ValidationError> {
+    let is_slug = slug.chars().all(|char| {
+        char.is_ascii_lowercase() || char.is_ascii_digit() || char == '-'
+    });
+
+    match is_slug {
+        true => Ok(()),
+        false => Err(ValidationError::new("slug").with_message(
+            "Must only contain lowercase letters, digits and dashes".into(),
+        )),
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+struct ArticleParams {
+    #[validate(custom(function = "validate_slug"))]
+    slug: String,
+
+    #[validate(range(
+        min = 1,
+        max = 100,
+        message = "Page must be between 1 and 100"
+    ))]
+    page: u32,
+}
+
+#[controller("/articles")]
+struct ArticlesController;
+
+#[routes]
+impl ArticlesController {
+    #[get("/{slug}/pages/{page}")]
+    async fn page(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let params: ArticleParams = ctx.params_validator()?;
+
+        Ok(HttpResponse::Ok().data(params))
+    }
+}
+
+#[controller("/users")]
+struct UserPostsController;
+
+#[routes]
+impl UserPostsController {
+    #[get("/{user_id}/posts/{post_id}")]
+    async fn post(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let ids = ctx.param_all::<u64>()?;
+
+        Ok(HttpResponse::Ok().data(ids))
+    }
+}
+
+struct FieldNamesOnly;
+
+impl ValidationErrorFormatter for FieldNamesOnly {
+    fn format(
+        &self,
+        _: StatusCode,
+        _: &'static str,
+        errors: ValidationErrors,
+    ) -> HttpResponse {
+        let mut fields = errors
+            .field_errors()
+            .into_keys()
+            .map(|field| field.to_string())
+            .collect::<Vec<_>>();
+
+        fields.sort();
+
+        HttpResponse::UnprocessableEntity().errors(fields)
+    }
+}
+
+fn test_server(builder: ApplicationBuilder) -> TestServer {
+    let app = builder
+        .with_controller::<ArticlesController>()
+        .with_controller::<UserPostsController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn valid_params_are_parsed_to_their_types() {
+    let server = test_server(Application::builder().unwrap());
+
+    let response = server.get("/articles/hello-world/pages/3").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        response.json::<Value>()["data"],
+        json!({ "slug": "hello-world", "page": 3 })
+    );
+}
+
+#[tokio::test]
+async fn invalid_params_fail_validation() {
+    let server = test_server(Application::builder().unwrap());
+
+    let response = server.get("/articles/Hello_World/pages/500").await;
+    let body = response.json::<Value>();
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(body["message"], "Invalid request params");
+    assert_eq!(body["errors"]["slug"][0]["code"], "slug");
+    assert_eq!(
+        body["errors"]["page"][0]["message"],
+        "Page must be between 1 and 100"
+    );
+}
+
+#[tokio::test]
+async fn unparsable_params_are_a_parse_error() {
+    let server = test_server(Application::builder().unwrap());
+
+    let response = server.get("/articles/hello/pages/first").await;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response.json::<Value>()["message"],
+        "Failed to deserialize params to the target type"
+    );
+}
+
+#[tokio::test]
+async fn param_errors_use_the_registered_formatter() {
+    let builder = Application::builder()
+        .unwrap()
+        .with_validation_formatter(FieldNamesOnly);
+
+    let response = test_server(builder)
+        .get("/articles/Hello_World/pages/500")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(response.json::<Value>()["errors"], json!(["page", "slug"]));
+}
+
+#[tokio::test]
+async fn param_all_parses_every_param() {
+    let server = test_server(Application::builder().unwrap());
+
+    let response = server.get("/users/12/posts/34").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        response.json::<Value>()["data"],
+        json!({ "user_id": 12, "post_id": 34 })
+    );
+}
+
+#[tokio::test]
+async fn param_all_names_the_first_unparsable_param() {
+    let server = test_server(Application::builder().unwrap());
+
+    let response = server.get("/users/ada/posts/latest").await;
+    let body = response.json::<Value>();
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(body["message"], "Invalid parameter type");
+    assert_eq!(
+        body["error"],
+        "Failed to parse parameter 'user_id' to the required type"
+    );
+}
@@ -5,8 +5,10 @@ use validator::Validate;
 
 fn test_server() -> TestServer {
     let app = Application::builder()
+        .unwrap()
         .with_controller::<UserController>()
-        .build();
+        .build()
+        .unwrap();
 
     TestServer::new(app.router()).unwrap()
 }
@@ -71,6 +73,18 @@ struct ComplexQueryData {
     email_filter: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+struct StatusFilter {
+    status: String,
+    role: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct NestedQueryData {
+    page: Option<u32>,
+    filter: StatusFilter,
+}
+
 #[controller("/users")]
 pub struct UserController {}
 
@@ -134,6 +148,30 @@ impl UserController {
             .message("Users retrieved with complex query parameters"))
     }
 
+    #[get("/required-query")]
+    async fn get_users_with_required_query(
+        &self,
+        ctx: Context,
+    ) -> HttpResult<HttpResponse> {
+        let query: ValidableQueryData = ctx.query_required()?;
+
+        Ok(HttpResponse::Ok()
+            .data(query)
+            .message("Users retrieved with required query"))
+    }
+
+    #[get("/nested-query")]
+    async fn get_users_with_nested_query(
+        &self,
+        ctx: Context,
+    ) -> HttpResult<HttpResponse> {
+        let query: NestedQueryData = ctx.query_required()?;
+
+        Ok(HttpResponse::Ok()
+            .data(query)
+            .message("Users retrieved with nested query"))
+    }
+
     #[get("/pattern-match-query")]
     async fn get_users_with_pattern_match(
         &self,
@@ -148,6 +186,16 @@ impl UserController {
                 .message("Users retrieved with default parameters")),
         }
     }
+
+    #[get("/query-pairs")]
+    async fn get_query_pairs(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(ctx.query_pairs())
+    }
+
+    #[get("/query-all")]
+    async fn get_query_all(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(ctx.query_all("tag"))
+    }
 }
 
 #[tokio::test]
@@ -356,3 +404,130 @@ async fn complex_encoded_query_test() {
         assert_eq!(active, &true);
     }
 }
+
+#[tokio::test]
+async fn required_query_with_params_test() {
+    let app = test_server();
+    let response = app.get("/users/required-query?page=2&limit=10").await;
+
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(200_u16, response.status_code().as_u16());
+    assert!(json.data.is_some());
+
+    let data = json.data.unwrap();
+
+    assert_eq!(data.get("page").unwrap(), 2);
+    assert_eq!(data.get("limit").unwrap(), 10);
+}
+
+#[tokio::test]
+async fn required_query_without_params_test() {
+    let app = test_server();
+    let response = app.get("/users/required-query").await;
+
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(400_u16, response.status_code().as_u16());
+    assert_eq!(json.message.as_ref(), "Query parameters are required");
+}
+
+#[tokio::test]
+async fn nested_query_test() {
+    let app = test_server();
+    let response = app
+        .get("/users/nested-query?page=3&filter[status]=active&filter[role]=admin")
+        .await;
+
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(200_u16, response.status_code().as_u16());
+
+    let data = json.data.unwrap();
+    let filter = data.get("filter").unwrap();
+
+    assert_eq!(data.get("page").unwrap(), 3);
+    assert_eq!(filter.get("status").unwrap(), "active");
+    assert_eq!(filter.get("role").unwrap(), "admin");
+}
+
+#[tokio::test]
+async fn nested_query_percent_encoded_brackets_test() {
+    let app = test_server();
+    let response = app
+        .get("/users/nested-query?filter%5Bstatus%5D=pending")
+        .await;
+
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(200_u16, response.status_code().as_u16());
+
+    let data = json.data.unwrap();
+    let filter = data.get("filter").unwrap();
+
+    assert!(data.get("page").unwrap().is_null());
+    assert_eq!(filter.get("status").unwrap(), "pending");
+    assert!(filter.get("role").unwrap().is_null());
+}
+
+#[tokio::test]
+async fn nested_query_missing_nested_key_test() {
+    let app = test_server();
+    let response = app.get("/users/nested-query?page=1").await;
+
+    assert_eq!(400_u16, response.status_code().as_u16());
+}
+
+#[tokio::test]
+async fn flat_query_plus_decodes_as_space_test() {
+    let app = test_server();
+    let response = app
+        .get("/users/complex-query?search=hello+world&user_name=john%2Bdoe")
+        .await;
+
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(200_u16, response.status_code().as_u16());
+
+    let data = json.data.unwrap();
+
+    assert_eq!(data.get("search").unwrap(), "hello world");
+    assert_eq!(data.get("user_name").unwrap(), "john+doe");
+}
+
+#[tokio::test]
+async fn query_all_keeps_repeated_keys_test() {
+    let app = test_server();
+    let response = app.get("/users/query-all?tag=a&page=1&tag=b&tag=c").await;
+
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(200_u16, response.status_code().as_u16());
+    assert_eq!(json.data.unwrap(), serde_json::json!(["a", "b", "c"]));
+
+    let response = app.get("/users/query-all?page=1").await;
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(json.data.unwrap(), serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn query_pairs_preserves_encoding_and_empty_values_test() {
+    let app = test_server();
+    let response = app
+        .get("/users/query-pairs?x=&first%20name=John+Doe&tag=a&tag=b%26c")
+        .await;
+
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(200_u16, response.status_code().as_u16());
+    assert_eq!(
+        json.data.unwrap(),
+        serde_json::json!([
+            ["x", ""],
+            ["first name", "John Doe"],
+            ["tag", "a"],
+            ["tag", "b&c"],
+        ])
+    );
+}
@@ -0,0 +1,92 @@
+use std::net::SocketAddr;
+
+use axum::extract::connect_info::MockConnectInfo;
+use axum_test::TestServer;
+use serde_json::json;
+use sword::prelude::*;
+
+#[controller("/client")]
+struct ClientController;
+
+#[routes]
+impl ClientController {
+    #[get("/")]
+    async fn client(&self, ctx: Context) -> HttpResponse {
+        HttpResponse::Ok().data(json!({
+            "remote_addr": ctx.remote_addr().map(|addr| addr.to_string()),
+            "real_ip": ctx.real_ip().map(|ip| ip.to_string()),
+        }))
+    }
+}
+
+fn test_server(peer: Option<SocketAddr>) -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<ClientController>()
+        .build()
+        .unwrap();
+
+    let router = match peer {
+        Some(peer) => app.router().layer(MockConnectInfo(peer)),
+        None => app.router(),
+    };
+
+    TestServer::new(router).unwrap()
+}
+
+fn peer() -> SocketAddr {
+    "10.0.0.7:52344".parse().unwrap()
+}
+
+#[tokio::test]
+async fn remote_addr_comes_from_the_connection() {
+    let response = test_server(Some(peer())).get("/client").await;
+    let data = response.json::<ResponseBody>().data.unwrap();
+
+    assert_eq!(data["remote_addr"], "10.0.0.7:52344");
+    assert_eq!(data["real_ip"], "10.0.0.7");
+}
+
+#[tokio::test]
+async fn remote_addr_is_none_without_connection_info() {
+    let response = test_server(None).get("/client").await;
+    let data = response.json::<ResponseBody>().data.unwrap();
+
+    assert!(data["remote_addr"].is_null());
+    assert!(data["real_ip"].is_null());
+}
+
+#[tokio::test]
+async fn real_ip_prefers_the_first_forwarded_address() {
+    let response = test_server(Some(peer()))
+        .get("/client")
+        .add_header("X-Forwarded-For", "203.0.113.9, 10.0.0.1")
+        .add_header("X-Real-IP", "198.51.100.4")
+        .await;
+
+    let data = response.json::<ResponseBody>().data.unwrap();
+
+    assert_eq!(data["remote_addr"], "10.0.0.7:52344");
+    assert_eq!(data["real_ip"], "203.0.113.9");
+}
+
+#[tokio::test]
+async fn real_ip_falls_back_to_real_ip_header_and_peer() {
+    let server = test_server(Some(peer()));
+
+    let real_ip = server
+        .get("/client")
+        .add_header("X-Real-IP", "2001:db8::1")
+        .await;
+    let data = real_ip.json::<ResponseBody>().data.unwrap();
+
+    assert_eq!(data["real_ip"], "2001:db8::1");
+
+    let invalid = server
+        .get("/client")
+        .add_header("X-Forwarded-For", "unknown")
+        .await;
+    let data = invalid.json::<ResponseBody>().data.unwrap();
+
+    assert_eq!(data["real_ip"], "10.0.0.7");
+}
@@ -0,0 +1,108 @@
+use axum_test::TestServer;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use sword::prelude::*;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+struct InviteQuery {
+    #[validate(range(
+        message = "Page must be between 1 and 10",
+        min = 1,
+        max = 10
+    ))]
+    page: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+struct InviteBody {
+    #[validate(email(message = "Must be a valid email address"))]
+    email: String,
+}
+
+#[controller("/invites")]
+struct InvitesController;
+
+#[routes]
+impl InvitesController {
+    #[post("/")]
+    async fn create(&self, ctx: Context) -> HttpResult<HttpResponse> {
+        let (query, body) = ctx.validate_all::<InviteQuery, InviteBody>()?;
+
+        Ok(HttpResponse::Ok().data(json!({ "query": query, "body": body })))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<InvitesController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn valid_query_and_body_are_returned() {
+    let server = test_server();
+
+    let response = server
+        .post("/invites?page=2")
+        .json(&json!({ "email": "ada@example.com" }))
+        .await;
+
+    let body = response.json::<Value>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(body["data"]["query"]["page"], 2);
+    assert_eq!(body["data"]["body"]["email"], "ada@example.com");
+}
+
+#[tokio::test]
+async fn query_and_body_errors_are_combined() {
+    let server = test_server();
+
+    let response = server
+        .post("/invites?page=50")
+        .json(&json!({ "email": "not-an-email" }))
+        .await;
+
+    let body = response.json::<Value>();
+
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["message"], "Invalid request");
+    assert_eq!(
+        body["errors"],
+        json!({
+            "query": {
+                "page": [{
+                    "code": "range",
+                    "message": "Page must be between 1 and 10",
+                }],
+            },
+            "body": {
+                "email": [{
+                    "code": "email",
+                    "message": "Must be a valid email address",
+                }],
+            },
+        })
+    );
+}
+
+#[tokio::test]
+async fn only_the_failing_source_is_reported() {
+    let server = test_server();
+
+    let response = server
+        .post("/invites")
+        .json(&json!({ "email": "not-an-email" }))
+        .await;
+
+    let body = response.json::<Value>();
+
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(body["errors"].get("query").is_none());
+    assert!(body["errors"]["body"]["email"].is_array());
+}
@@ -0,0 +1,73 @@
+use axum_test::TestServer;
+use sword::prelude::*;
+
+#[controller("/ws")]
+struct WebSocketController;
+
+#[routes]
+impl WebSocketController {
+    #[get("/echo")]
+    async fn echo(&self, mut ctx: Context) -> HttpResult<Response> {
+        let upgrade = ctx.ws_upgrade().await?;
+
+        Ok(upgrade.on_upgrade(|mut socket| async move {
+            while let Some(Ok(message)) = socket.recv().await {
+                if socket.send(message).await.is_err() {
+                    break;
+                }
+            }
+        }))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<WebSocketController>()
+        .build()
+        .unwrap();
+
+    TestServer::builder()
+        .http_transport()
+        .build(app.router())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn websocket_upgrade_echoes_messages() {
+    let server = test_server();
+
+    let mut socket = server
+        .get_websocket("/ws/echo")
+        .await
+        .into_websocket()
+        .await;
+
+    socket.send_text("hello").await;
+    socket.assert_receive_text("hello").await;
+
+    socket.send_text("sword").await;
+    socket.assert_receive_text("sword").await;
+}
+
+#[tokio::test]
+async fn plain_request_is_not_upgraded() {
+    let response = test_server().get("/ws/echo").await;
+    let json = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(
+        json.error.unwrap(),
+        "No content type was provided, expected one of: websocket"
+    );
+}
+
+#[tokio::test]
+async fn upgrade_without_connection_header_is_rejected() {
+    let response = test_server()
+        .get("/ws/echo")
+        .add_header("Upgrade", "websocket")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
@@ -0,0 +1,83 @@
+use axum_test::TestServer;
+use serde_json::{Value, json};
+use sword::prelude::*;
+
+#[controller("/batch")]
+struct BatchController;
+
+#[routes]
+impl BatchController {
+    #[post("/mixed")]
+    async fn mixed(&self, ctx: Context) -> HttpResult<BatchResponse> {
+        let ids: Vec<u32> = ctx.body()?;
+        let mut batch = BatchResponse::new();
+
+        for id in ids {
+            if id % 2 == 0 {
+                batch.push(StatusCode::CREATED, json!({ "id": id }));
+            } else {
+                batch.push(StatusCode::NOT_FOUND, format!("Item {id} not found"));
+            }
+        }
+
+        Ok(batch)
+    }
+
+    #[get("/successful")]
+    async fn successful(&self) -> BatchResponse {
+        BatchResponse::new()
+            .item(StatusCode::OK, json!({ "id": 1 }))
+            .item(StatusCode::CREATED, json!({ "id": 2 }))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<BatchController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn mixed_batch_is_multi_status() {
+    let server = test_server();
+    let response = server.post("/batch/mixed").json(&json!([2, 3, 4])).await;
+
+    assert_eq!(response.status_code(), StatusCode::MULTI_STATUS);
+    assert_eq!(
+        response.json::<Value>(),
+        json!({
+            "code": 207,
+            "success": false,
+            "items": [
+                { "code": 201, "success": true, "body": { "id": 2 } },
+                { "code": 404, "success": false, "body": "Item 3 not found" },
+                { "code": 201, "success": true, "body": { "id": 4 } }
+            ]
+        })
+    );
+}
+
+#[tokio::test]
+async fn successful_batch_is_ok() {
+    let server = test_server();
+    let response = server.get("/batch/successful").await;
+
+    let body = response.json::<Value>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(body["code"], 200);
+    assert_eq!(body["success"], true);
+    assert_eq!(body["items"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn empty_batch_is_ok() {
+    let batch = BatchResponse::new();
+
+    assert!(batch.items().is_empty());
+    assert_eq!(batch.status(), StatusCode::OK);
+}
@@ -0,0 +1,115 @@
+use axum_test::{TestResponse, TestServer};
+use serde_json::{Value, json};
+use sword::prelude::*;
+
+#[controller("/envelope")]
+struct EnvelopeController;
+
+#[routes]
+impl EnvelopeController {
+    #[get("/message")]
+    async fn message(&self) -> HttpResponse {
+        HttpResponse::Ok().message("Saved")
+    }
+
+    #[get("/data")]
+    async fn data(&self) -> HttpResponse {
+        HttpResponse::Ok().data(json!({ "id": 1 }))
+    }
+
+    #[get("/both")]
+    async fn both(&self) -> HttpResponse {
+        HttpResponse::Ok().message("Saved").data(json!({ "id": 1 }))
+    }
+
+    #[get("/both-reversed")]
+    async fn both_reversed(&self) -> HttpResponse {
+        HttpResponse::Ok().data(json!({ "id": 1 })).message("Saved")
+    }
+
+    #[get("/null-data")]
+    async fn null_data(&self) -> HttpResponse {
+        HttpResponse::Ok().data(None::<u32>)
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<EnvelopeController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+/// The body without its `timestamp`, which changes on every request.
+fn envelope(response: &TestResponse) -> Value {
+    let mut body = response.json::<Value>();
+    let timestamp = body.as_object_mut().unwrap().remove("timestamp");
+
+    assert!(timestamp.unwrap().is_string());
+
+    body
+}
+
+#[tokio::test]
+async fn message_only_envelope_has_no_data() {
+    let server = test_server();
+    let response = server.get("/envelope/message").await;
+
+    assert_eq!(
+        envelope(&response),
+        json!({ "code": 200, "success": true, "message": "Saved" })
+    );
+}
+
+#[tokio::test]
+async fn data_only_envelope_keeps_the_default_message() {
+    let server = test_server();
+    let response = server.get("/envelope/data").await;
+
+    assert_eq!(
+        envelope(&response),
+        json!({ "code": 200, "success": true, "message": "OK", "data": { "id": 1 } })
+    );
+}
+
+#[tokio::test]
+async fn message_and_data_are_independent_of_call_order() {
+    let server = test_server();
+    let expected = json!({
+        "code": 200,
+        "success": true,
+        "message": "Saved",
+        "data": { "id": 1 },
+    });
+
+    let response = server.get("/envelope/both").await;
+    assert_eq!(envelope(&response), expected);
+
+    let response = server.get("/envelope/both-reversed").await;
+    assert_eq!(envelope(&response), expected);
+}
+
+#[tokio::test]
+async fn null_data_is_omitted() {
+    let server = test_server();
+    let response = server.get("/envelope/null-data").await;
+
+    assert_eq!(
+        envelope(&response),
+        json!({ "code": 200, "success": true, "message": "OK" })
+    );
+}
+
+#[tokio::test]
+async fn envelope_keys_are_serialized_in_alphabetical_order() {
+    let server = test_server();
+    let text = server.get("/envelope/both").await.text();
+
+    let positions = ["code", "data", "message", "success", "timestamp"]
+        .map(|key| text.find(&format!("\"{key}\":")).unwrap());
+
+    assert!(positions.is_sorted(), "unexpected key order in {text}");
+}
@@ -0,0 +1,178 @@
+use axum_test::TestServer;
+use sword::prelude::*;
+
+const IMAGE: &str = "files/png-test.png";
+const IMAGE_LEN: usize = 6185;
+
+#[controller("/media")]
+struct MediaController;
+
+#[routes]
+impl MediaController {
+    #[get("/image")]
+    async fn image(&self, ctx: Context) -> Response {
+        HttpResponse::serve_file(IMAGE, &ctx).await
+    }
+
+    #[get("/missing")]
+    async fn missing(&self, ctx: Context) -> Response {
+        HttpResponse::serve_file("files/missing.png", &ctx).await
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<MediaController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+fn image_bytes() -> Vec<u8> {
+    std::fs::read(IMAGE).unwrap()
+}
+
+#[tokio::test]
+async fn serves_the_whole_file_with_validators() {
+    let response = test_server().get("/media/image").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header("content-type"), "image/png");
+    assert_eq!(response.header("accept-ranges"), "bytes");
+    assert!(response.maybe_header("etag").is_some());
+    assert!(response.maybe_header("last-modified").is_some());
+    assert_eq!(response.as_bytes().len(), IMAGE_LEN);
+}
+
+#[tokio::test]
+async fn matching_etag_is_not_modified() {
+    let server = test_server();
+
+    let first = server.get("/media/image").await;
+    let etag = first.header("etag");
+
+    let response = server
+        .get("/media/image")
+        .add_header("If-None-Match", etag.to_str().unwrap())
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.header("etag"), etag);
+    assert!(response.as_bytes().is_empty());
+}
+
+#[tokio::test]
+async fn unmodified_date_is_not_modified() {
+    let server = test_server();
+
+    let first = server.get("/media/image").await;
+    let last_modified = first.header("last-modified");
+
+    let response = server
+        .get("/media/image")
+        .add_header("If-Modified-Since", last_modified.to_str().unwrap())
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_MODIFIED);
+
+    let stale = server
+        .get("/media/image")
+        .add_header("If-None-Match", "\"outdated\"")
+        .add_header("If-Modified-Since", last_modified.to_str().unwrap())
+        .await;
+
+    assert_eq!(stale.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn range_request_is_partial() {
+    let response = test_server()
+        .get("/media/image")
+        .add_header("Range", "bytes=100-199")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(
+        response.header("content-range"),
+        format!("bytes 100-199/{IMAGE_LEN}").as_str()
+    );
+    assert_eq!(response.as_bytes().as_ref(), &image_bytes()[100..200]);
+
+    let suffix = test_server()
+        .get("/media/image")
+        .add_header("Range", "bytes=-10")
+        .await;
+
+    assert_eq!(suffix.status_code(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(suffix.as_bytes().as_ref(), &image_bytes()[IMAGE_LEN - 10..]);
+}
+
+#[tokio::test]
+async fn conditional_takes_precedence_over_range() {
+    let server = test_server();
+    let etag = server.get("/media/image").await.header("etag");
+
+    let response = server
+        .get("/media/image")
+        .add_header("If-None-Match", etag.to_str().unwrap())
+        .add_header("Range", "bytes=0-9")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_MODIFIED);
+}
+
+#[tokio::test]
+async fn stale_if_range_serves_the_whole_file() {
+    let server = test_server();
+    let etag = server.get("/media/image").await.header("etag");
+
+    let fresh = server
+        .get("/media/image")
+        .add_header("If-Range", etag.to_str().unwrap())
+        .add_header("Range", "bytes=0-9")
+        .await;
+
+    assert_eq!(fresh.status_code(), StatusCode::PARTIAL_CONTENT);
+
+    let stale = server
+        .get("/media/image")
+        .add_header("If-Range", "\"outdated\"")
+        .add_header("Range", "bytes=0-9")
+        .await;
+
+    assert_eq!(stale.status_code(), StatusCode::OK);
+    assert_eq!(stale.as_bytes().len(), IMAGE_LEN);
+}
+
+#[tokio::test]
+async fn range_past_the_end_is_not_satisfiable() {
+    let response = test_server()
+        .get("/media/image")
+        .add_header("Range", format!("bytes={IMAGE_LEN}-"))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(
+        response.header("content-range"),
+        format!("bytes */{IMAGE_LEN}").as_str()
+    );
+}
+
+#[tokio::test]
+async fn failed_if_match_is_rejected() {
+    let response = test_server()
+        .get("/media/image")
+        .add_header("If-Match", "\"outdated\"")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::PRECONDITION_FAILED);
+}
+
+#[tokio::test]
+async fn missing_file_is_not_found() {
+    let response = test_server().get("/media/missing").await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,51 @@
+use axum_test::TestServer;
+use serde_json::{Value, json};
+use sword::prelude::*;
+
+#[controller("/json-value")]
+struct JsonValueController;
+
+#[routes]
+impl JsonValueController {
+    #[get("/object")]
+    async fn object(&self) -> Response {
+        HttpResponse::json_value(json!({ "id": 1, "tags": ["a", "b"] }))
+    }
+
+    #[get("/array")]
+    async fn array(&self) -> Response {
+        HttpResponse::json_value(json!([1, 2, 3]))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<JsonValueController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn json_value_is_the_raw_body() {
+    let server = test_server();
+    let response = server.get("/json-value/object").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header("content-type"), "application/json");
+    assert_eq!(
+        response.json::<Value>(),
+        json!({ "id": 1, "tags": ["a", "b"] })
+    );
+}
+
+#[tokio::test]
+async fn json_value_supports_non_object_values() {
+    let server = test_server();
+    let response = server.get("/json-value/array").await;
+
+    assert_eq!(response.header("content-type"), "application/json");
+    assert_eq!(response.json::<Value>(), json!([1, 2, 3]));
+}
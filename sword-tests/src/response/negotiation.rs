@@ -0,0 +1,128 @@
+use axum_test::TestServer;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use sword::prelude::*;
+
+#[derive(Serialize, Deserialize)]
+struct Item {
+    id: u32,
+    name: String,
+}
+
+#[controller("/negotiation")]
+struct NegotiationController;
+
+#[routes]
+impl NegotiationController {
+    #[get("/respond")]
+    async fn respond(&self, ctx: Context) -> Response {
+        ctx.respond(Item {
+            id: 1,
+            name: "sword".to_string(),
+        })
+    }
+
+    #[get("/created")]
+    async fn created(&self, ctx: Context) -> Response {
+        HttpResponse::Created()
+            .message("Item created")
+            .data(json!({ "id": 2 }))
+            .add_header("x-item-id", "2")
+            .negotiated(ctx.accept())
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<NegotiationController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn falls_back_to_json_without_accept() {
+    let server = test_server();
+    let response = server.get("/negotiation/respond").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header("content-type"), "application/json");
+    assert_eq!(response.header("vary"), "accept");
+
+    let body = response.json::<ResponseBody>();
+    assert_eq!(body.data, Some(json!({ "id": 1, "name": "sword" })));
+}
+
+#[tokio::test]
+async fn falls_back_to_json_for_wildcards_and_unsupported_types() {
+    let server = test_server();
+
+    for accept in ["*/*", "text/html", "application/*"] {
+        let response = server
+            .get("/negotiation/respond")
+            .add_header("accept", accept)
+            .await;
+
+        assert_eq!(response.header("content-type"), "application/json");
+        assert_eq!(response.header("vary"), "accept");
+    }
+}
+
+#[tokio::test]
+async fn encodes_msgpack_when_accepted() {
+    let server = test_server();
+
+    let response = server
+        .get("/negotiation/respond")
+        .add_header("accept", "application/msgpack")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header("content-type"), "application/msgpack");
+    assert_eq!(response.header("vary"), "accept");
+
+    let body: ResponseBody = rmp_serde::from_slice(response.as_bytes()).unwrap();
+
+    assert_eq!(body.code, 200);
+    assert_eq!(body.data, Some(json!({ "id": 1, "name": "sword" })));
+}
+
+#[tokio::test]
+async fn picks_the_format_with_the_highest_quality() {
+    let server = test_server();
+
+    let response = server
+        .get("/negotiation/respond")
+        .add_header("accept", "application/json;q=0.5, application/x-msgpack")
+        .await;
+
+    assert_eq!(response.header("content-type"), "application/msgpack");
+
+    let response = server
+        .get("/negotiation/respond")
+        .add_header("accept", "application/msgpack;q=0.2, */*;q=0.8")
+        .await;
+
+    assert_eq!(response.header("content-type"), "application/json");
+}
+
+#[tokio::test]
+async fn negotiated_keeps_status_and_headers() {
+    let server = test_server();
+
+    let response = server
+        .get("/negotiation/created")
+        .add_header("accept", "application/msgpack")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::CREATED);
+    assert_eq!(response.header("x-item-id"), "2");
+    assert_eq!(response.header("vary"), "accept");
+
+    let body: Value = rmp_serde::from_slice(response.as_bytes()).unwrap();
+
+    assert_eq!(body["message"], "Item created");
+    assert_eq!(body["data"], json!({ "id": 2 }));
+}
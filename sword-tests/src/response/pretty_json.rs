@@ -0,0 +1,82 @@
+use axum_test::TestServer;
+use serde_json::json;
+use sword::prelude::*;
+
+#[controller("/orders")]
+struct OrdersController;
+
+#[routes]
+impl OrdersController {
+    #[get("/")]
+    async fn order(&self) -> HttpResponse {
+        HttpResponse::Ok().data(json!({ "id": 7 }))
+    }
+
+    #[get("/text")]
+    async fn text(&self) -> HttpResponse {
+        HttpResponse::Ok().data("ok")
+    }
+}
+
+fn test_server(builder: ApplicationBuilder) -> TestServer {
+    let app = builder
+        .with_controller::<OrdersController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn pretty_json_indents_responses() {
+    let builder = Application::builder().unwrap().with_pretty_json(true);
+    let response = test_server(builder).get("/orders").await;
+    let text = response.text();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert!(text.contains("\n  \"data\": {\n    \"id\": 7\n  }"));
+    assert_eq!(
+        response.json::<ResponseBody>().data,
+        Some(json!({ "id": 7 }))
+    );
+}
+
+#[tokio::test]
+async fn compact_json_is_sent_on_a_single_line() {
+    let builder = Application::builder().unwrap().with_pretty_json(false);
+    let response = test_server(builder).get("/orders").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert!(!response.text().contains('\n'));
+    assert!(response.text().contains("\"data\":{\"id\":7}"));
+}
+
+#[tokio::test]
+async fn config_disables_pretty_json() {
+    let builder =
+        ApplicationBuilder::with_config_path("config/compact_json.toml").unwrap();
+
+    let response = test_server(builder).get("/orders").await;
+
+    assert!(!response.text().contains('\n'));
+}
+
+#[tokio::test]
+async fn builder_overrides_the_config() {
+    let builder = ApplicationBuilder::with_config_path("config/compact_json.toml")
+        .unwrap()
+        .with_pretty_json(true);
+
+    let response = test_server(builder).get("/orders/text").await;
+
+    assert!(response.text().contains("\n  \"data\": \"ok\""));
+}
+
+#[tokio::test]
+async fn pretty_json_defaults_to_the_build_profile() {
+    let response = test_server(Application::builder().unwrap())
+        .get("/orders")
+        .await;
+
+    assert_eq!(response.text().contains('\n'), cfg!(debug_assertions));
+}
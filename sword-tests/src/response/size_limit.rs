@@ -0,0 +1,80 @@
+use std::convert::Infallible;
+
+use axum_test::TestServer;
+use futures::{StreamExt, stream};
+use sword::prelude::*;
+
+#[controller("/items")]
+struct ItemsController;
+
+#[routes]
+impl ItemsController {
+    #[get("/page")]
+    async fn page(&self) -> HttpResponse {
+        HttpResponse::Ok().data(vec!["item"; 10])
+    }
+
+    #[get("/all")]
+    async fn all(&self) -> HttpResponse {
+        HttpResponse::Ok().data(vec!["item"; 1000])
+    }
+
+    #[get("/export")]
+    async fn export(&self) -> Response {
+        let rows = stream::iter(0..1000)
+            .map(|id| Ok::<_, Infallible>(format!("{id},item\n")));
+
+        HttpResponse::Ok().stream(rows)
+    }
+}
+
+fn test_server(config: &str) -> TestServer {
+    let app = ApplicationBuilder::with_config_path(config)
+        .unwrap()
+        .with_controller::<ItemsController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn oversized_buffered_responses_are_replaced() {
+    let response = test_server("config/response_limit.toml")
+        .get("/items/all")
+        .await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        body.message,
+        "The response exceeds the maximum allowed size".into()
+    );
+    assert!(body.data.is_none());
+}
+
+#[tokio::test]
+async fn responses_within_the_limit_are_sent() {
+    let response = test_server("config/response_limit.toml")
+        .get("/items/page")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn streaming_responses_are_exempt() {
+    let response = test_server("config/response_limit.toml")
+        .get("/items/export")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert!(response.as_bytes().len() > 2000);
+}
+
+#[tokio::test]
+async fn responses_are_unbounded_without_the_limit() {
+    let response = test_server("config/config.toml").get("/items/all").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
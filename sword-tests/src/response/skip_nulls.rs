@@ -0,0 +1,82 @@
+use axum_test::TestServer;
+use serde::Serialize;
+use serde_json::{Value, json};
+use sword::prelude::*;
+
+#[derive(Serialize)]
+struct Profile {
+    name: &'static str,
+    nickname: Option<&'static str>,
+    address: Address,
+    tags: Vec<Option<&'static str>>,
+}
+
+#[derive(Serialize)]
+struct Address {
+    city: &'static str,
+    zip: Option<&'static str>,
+}
+
+#[controller("/profiles")]
+struct ProfilesController;
+
+#[routes]
+impl ProfilesController {
+    #[get("/")]
+    async fn profile(&self) -> HttpResponse {
+        HttpResponse::Ok().data(Profile {
+            name: "Ada",
+            nickname: None,
+            address: Address {
+                city: "London",
+                zip: None,
+            },
+            tags: vec![Some("admin"), None],
+        })
+    }
+}
+
+fn test_server(config: &str) -> TestServer {
+    let app = ApplicationBuilder::with_config_path(config)
+        .unwrap()
+        .with_controller::<ProfilesController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn null_fields_are_omitted_when_enabled() {
+    let server = test_server("config/skip_nulls.toml");
+
+    let response = server.get("/profiles").await;
+    let body = response.json::<Value>();
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        body["data"],
+        json!({
+            "name": "Ada",
+            "address": { "city": "London" },
+            "tags": ["admin", null],
+        })
+    );
+}
+
+#[tokio::test]
+async fn null_fields_are_kept_by_default() {
+    let server = test_server("config/config.toml");
+
+    let response = server.get("/profiles").await;
+    let body = response.json::<Value>();
+
+    assert_eq!(body["data"]["nickname"], Value::Null);
+    assert!(body["data"].as_object().unwrap().contains_key("nickname"));
+    assert!(
+        body["data"]["address"]
+            .as_object()
+            .unwrap()
+            .contains_key("zip")
+    );
+}
@@ -0,0 +1,71 @@
+use std::{convert::Infallible, time::Duration};
+
+use axum_test::TestServer;
+use futures::{StreamExt, stream};
+use sword::{prelude::*, web::sse::Event};
+use tokio::time::sleep;
+
+#[controller("/sse")]
+struct SseController;
+
+#[routes]
+impl SseController {
+    #[get("/updates")]
+    async fn updates(&self) -> Response {
+        let events = stream::iter(1..=2).map(|id| {
+            Ok::<_, Infallible>(
+                Event::default()
+                    .event("update")
+                    .id(id.to_string())
+                    .data(format!("tick {id}")),
+            )
+        });
+
+        HttpResponse::Ok()
+            .add_header("x-feed", "updates")
+            .sse(events)
+    }
+
+    #[get("/slow")]
+    async fn slow(&self) -> Response {
+        let events = stream::iter(["first", "second"]).then(|data| async move {
+            sleep(Duration::from_millis(150)).await;
+            Ok::<_, Infallible>(Event::default().data(data))
+        });
+
+        HttpResponse::Ok().sse_with_keep_alive(events, Duration::from_millis(40))
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<SseController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn sse_response_streams_events() {
+    let response = test_server().get("/sse/updates").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header("content-type"), "text/event-stream");
+    assert_eq!(response.header("x-feed"), "updates");
+    assert_eq!(
+        response.text(),
+        "event: update\nid: 1\ndata: tick 1\n\nevent: update\nid: 2\ndata: tick 2\n\n"
+    );
+}
+
+#[tokio::test]
+async fn sse_response_sends_keep_alive_comments() {
+    let response = test_server().get("/sse/slow").await;
+    let body = response.text();
+
+    assert!(body.starts_with(":\n\n"));
+    assert!(body.contains("data: first\n\n"));
+    assert!(body.ends_with("data: second\n\n"));
+}
@@ -0,0 +1,75 @@
+use std::convert::Infallible;
+
+use axum_test::TestServer;
+use futures::{StreamExt, stream};
+use sword::prelude::*;
+
+#[controller("/stream")]
+struct StreamController;
+
+#[routes]
+impl StreamController {
+    #[get("/bytes")]
+    async fn bytes(&self) -> Response {
+        let chunks =
+            stream::iter(["first,", "second,", "third"]).map(Ok::<_, Infallible>);
+
+        HttpResponse::Ok().message("ignored").stream(chunks)
+    }
+
+    #[get("/report.csv")]
+    async fn report(&self) -> Response {
+        let rows = stream::iter(1..=3)
+            .map(|id| Ok::<_, std::io::Error>(format!("{id},row\n")));
+
+        HttpResponse::Accepted()
+            .add_header("content-type", "text/csv")
+            .add_header("x-report", "daily")
+            .stream(rows)
+    }
+
+    #[get("/items.json")]
+    async fn items(&self) -> Response {
+        let chunks = stream::iter(["[1,", "2,", "3]"]).map(Ok::<_, Infallible>);
+
+        HttpResponse::Ok().stream_with_content_type("application/json", chunks)
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<StreamController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn streamed_body_replaces_the_json_envelope() {
+    let response = test_server().get("/stream/bytes").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header("content-type"), "application/octet-stream");
+    assert!(response.maybe_header("content-length").is_none());
+    assert_eq!(response.text(), "first,second,third");
+}
+
+#[tokio::test]
+async fn streamed_body_keeps_status_and_headers() {
+    let response = test_server().get("/stream/report.csv").await;
+
+    assert_eq!(response.status_code(), StatusCode::ACCEPTED);
+    assert_eq!(response.header("content-type"), "text/csv");
+    assert_eq!(response.header("x-report"), "daily");
+    assert_eq!(response.text(), "1,row\n2,row\n3,row\n");
+}
+
+#[tokio::test]
+async fn streamed_body_uses_the_given_content_type() {
+    let response = test_server().get("/stream/items.json").await;
+
+    assert_eq!(response.header("content-type"), "application/json");
+    assert_eq!(response.text(), "[1,2,3]");
+}
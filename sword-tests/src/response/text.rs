@@ -0,0 +1,65 @@
+use axum_test::TestServer;
+use sword::prelude::*;
+
+#[controller("/text")]
+struct TextController;
+
+#[routes]
+impl TextController {
+    #[get("/plain")]
+    async fn plain(&self) -> Response {
+        HttpResponse::Ok().text("Hello")
+    }
+
+    #[get("/json")]
+    async fn json(&self) -> HttpResponse {
+        HttpResponse::Ok().data("Hello")
+    }
+
+    #[get("/created")]
+    async fn created(&self) -> Response {
+        HttpResponse::Created()
+            .add_header("x-custom", "sword")
+            .text("Resource created")
+    }
+}
+
+fn test_server() -> TestServer {
+    let app = Application::builder()
+        .unwrap()
+        .with_controller::<TextController>()
+        .build()
+        .unwrap();
+
+    TestServer::new(app.router()).unwrap()
+}
+
+#[tokio::test]
+async fn text_response_is_plain_and_unquoted() {
+    let server = test_server();
+    let response = server.get("/text/plain").await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header("content-type"), "text/plain; charset=utf-8");
+    assert_eq!(response.text(), "Hello");
+}
+
+#[tokio::test]
+async fn data_string_is_still_json() {
+    let server = test_server();
+    let response = server.get("/text/json").await;
+    let body = response.json::<ResponseBody>();
+
+    assert_eq!(response.header("content-type"), "application/json");
+    assert_eq!(body.data.unwrap(), "Hello");
+}
+
+#[tokio::test]
+async fn text_response_keeps_status_and_headers() {
+    let server = test_server();
+    let response = server.get("/text/created").await;
+
+    assert_eq!(response.status_code(), StatusCode::CREATED);
+    assert_eq!(response.header("x-custom"), "sword");
+    assert_eq!(response.text(), "Resource created");
+}
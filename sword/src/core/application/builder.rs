@@ -1,24 +1,55 @@
-use std::{convert::Infallible, time::Duration};
+use std::{
+    convert::Infallible, future::Future, path::Path, sync::Arc, time::Duration,
+};
 
 use axum::{
     extract::Request as AxumRequest,
-    middleware::from_fn_with_state as mw_with_state,
-    response::IntoResponse,
+    http::{HeaderValue, Method, StatusCode},
+    middleware::{from_fn, from_fn_with_state as mw_with_state},
+    response::{IntoResponse, Response},
     routing::{Route, Router},
 };
 
 #[cfg(feature = "shaku-di")]
 use shaku::Module;
 
-use tower::{Layer, Service};
-use tower_http::{limit::RequestBodyLimitLayer, timeout::TimeoutLayer};
+use tower::{Layer, Service, ServiceExt};
 
 #[cfg(feature = "cookies")]
 use tower_cookies::CookieManagerLayer;
 
+#[cfg(feature = "cookies")]
+use crate::web::cookies::{CookieSigningKey, CookiesConfig};
+
+#[cfg(feature = "validator")]
+use crate::{errors::ValidationErrorFormatter, web::ValidationFormatter};
+
 use crate::{
-    core::*,
-    web::{ContentTypeCheck, Controller, ResponsePrettifier},
+    core::{
+        application::{
+            rewrite::PathRewrites,
+            routes::RegisteredRoutes,
+            startup::{Startup, StartupHook},
+        },
+        *,
+    },
+    errors::{
+        ApplicationError, ConfigError, DependencyInjectionError, RequestError,
+        StateError,
+    },
+    web::{
+        BodyLimitCheck, ContentTypeCheck, Context, ContextPreload, Controller,
+        DynMiddleware, ErrorReport, ErrorReporter, ErrorStatusOverrides, HeadCheck,
+        HeaderLimits, HttpResponse, JsonEngine, LimitsConfig, MethodNotAllowed,
+        Next, NotFound, NotFoundFormat, ReadinessProbe, RegisteredJsonEngine,
+        RejectionLogger, RequestCancellation, RequestId, RequestTimeout,
+        ResponseConfig, ResponsePrettifier, ResponseSizeGuard, ServerTiming,
+        SkipNulls,
+        controller::join_paths,
+        cors::{CorsLayer, CorsPreflight},
+        logging::RequestLogger,
+        metrics::{Metrics, MetricsLayer},
+    },
 };
 
 /// Builder for constructing a Sword application with various configuration options.
@@ -35,10 +66,10 @@ use crate::{
 /// #[controller]
 /// struct HomeController;
 ///
-/// let app = Application::builder()
+/// let app = Application::builder()?
 ///     .with_controller::<HomeController>()
 ///     .with_layer(tower_http::cors::CorsLayer::permissive())
-///     .build();
+///     .build()?;
 /// ```
 #[derive(Debug, Clone)]
 pub struct ApplicationBuilder {
@@ -53,6 +84,92 @@ pub struct ApplicationBuilder {
 
     /// Optional URL prefix for all routes in the application.
     prefix: Option<String>,
+
+    metrics: Option<Metrics>,
+
+    request_id: Option<RequestId>,
+
+    server_timing: bool,
+
+    request_logger: bool,
+
+    auto_head: bool,
+
+    pretty_json: Option<bool>,
+
+    json_engine: RegisteredJsonEngine,
+
+    #[cfg(feature = "openapi")]
+    openapi_route: Option<String>,
+
+    shutdown: Option<ShutdownCoordinator>,
+
+    cors: Option<CorsLayer>,
+
+    error_statuses: ErrorStatusOverrides,
+
+    method_not_allowed: Option<MethodNotAllowed>,
+
+    not_found: NotFound,
+
+    error_reporter: Option<ErrorReporter>,
+
+    #[cfg(feature = "validator")]
+    validation_formatter: Option<ValidationFormatter>,
+
+    startup_hooks: Vec<StartupHook>,
+
+    readiness: Option<ReadinessProbe>,
+
+    rewrites: PathRewrites,
+
+    /// `#[body_limit]`s of the registered controllers, keyed by method and
+    /// path without the global prefix.
+    body_limits: Vec<(Method, String, usize)>,
+
+    /// `#[stream_body]` routes of the registered controllers, keyed like
+    /// `body_limits`.
+    streamed_routes: Vec<(Method, String)>,
+
+    /// Routes of the registered controllers, checked for conflicts.
+    routes: RegisteredRoutes,
+
+    /// `RoutesPlugin::validate_config` of the registered plugins, run on
+    /// `build()`.
+    plugin_checks: Vec<PluginCheck>,
+
+    inner_layers: Vec<DeferredLayer>,
+
+    outer_layers: Vec<DeferredLayer>,
+}
+
+type PluginCheck = (&'static str, fn(&Config) -> Result<(), ConfigError>);
+
+/// A layer registered on the builder and applied to the router on `build()`.
+#[derive(Clone)]
+struct DeferredLayer(Arc<dyn Fn(Router) -> Router + Send + Sync>);
+
+impl DeferredLayer {
+    fn new<L>(layer: L) -> Self
+    where
+        L: Layer<Route> + Clone + Send + Sync + 'static,
+        L::Service: Service<AxumRequest> + Clone + Send + Sync + 'static,
+        <L::Service as Service<AxumRequest>>::Response: IntoResponse + 'static,
+        <L::Service as Service<AxumRequest>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<AxumRequest>>::Future: Send + 'static,
+    {
+        Self(Arc::new(move |router: Router| router.layer(layer.clone())))
+    }
+
+    fn apply(&self, router: Router) -> Router {
+        (self.0)(router)
+    }
+}
+
+impl std::fmt::Debug for DeferredLayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DeferredLayer")
+    }
 }
 
 impl ApplicationBuilder {
@@ -61,7 +178,10 @@ impl ApplicationBuilder {
     /// This method initializes a new builder with:
     /// - Empty router
     /// - Fresh state container
-    /// - Configuration loaded from `config/config.toml`
+    /// - Configuration loaded from `config/config.toml`, or the file named by
+    ///   the `SWORD_CONFIG` environment variable
+    /// - A global `tracing` subscriber, if the config has a `[logging]`
+    ///   section and no global subscriber was set yet
     ///
     /// ### Returns
     ///
@@ -74,22 +194,122 @@ impl ApplicationBuilder {
     /// - The configuration file cannot be found or read
     /// - The TOML syntax is invalid
     /// - Environment variable interpolation fails
-    pub fn new() -> Self {
+    pub fn new() -> Result<Self, ApplicationError> {
+        Self::from_config(Config::new()?)
+    }
+
+    /// Creates a new application builder like `new`, loading the `.env` file
+    /// of the working directory before the configuration is interpolated.
+    ///
+    /// `${VAR}` references resolve from the `.env` file when `VAR` isn't
+    /// set in the environment, so real environment variables take
+    /// precedence. A missing `.env` file is ignored. The environment of the
+    /// process is left untouched.
+    ///
+    /// ### Errors
+    ///
+    /// Fails like `new`, and if the `.env` file can't be read or has a line
+    /// that isn't `KEY=value`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = ApplicationBuilder::with_dotenv()?
+    ///     .with_controller::<MyController>()
+    ///     .build()?;
+    /// ```
+    #[cfg(feature = "dotenv")]
+    pub fn with_dotenv() -> Result<Self, ApplicationError> {
+        Self::from_config(Config::with_dotenv()?)
+    }
+
+    /// Creates a new application builder with the configuration loaded from
+    /// `path` instead of `config/config.toml`.
+    ///
+    /// Useful to give each environment or test its own configuration file.
+    /// The default location can also be overridden with the `SWORD_CONFIG`
+    /// environment variable, which `new` reads.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `ConfigError::FileNotFound` with the given path if the file
+    /// does not exist, and otherwise fails like `new`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = ApplicationBuilder::with_config_path("config/test.toml")?
+    ///     .with_controller::<MyController>()
+    ///     .build()?;
+    /// ```
+    pub fn with_config_path(
+        path: impl AsRef<Path>,
+    ) -> Result<Self, ApplicationError> {
+        Self::from_config(Config::from_path(path.as_ref())?)
+    }
+
+    fn from_config(config: Config) -> Result<Self, ApplicationError> {
         let state = State::new();
-        let config = Config::new().expect("Configuration loading error");
 
-        state
-            .insert(config.clone())
-            .expect("Failed to insert Config into State");
+        state.insert(config.clone())?;
+
+        let feature_flags = match config.get::<FeatureFlags>() {
+            Ok(flags) => flags,
+            Err(ConfigError::KeyNotFound(_)) => FeatureFlags::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        state.insert(feature_flags)?;
+
+        #[cfg(feature = "cookies")]
+        match config.get::<CookiesConfig>() {
+            Ok(cookies) => state.insert(CookieSigningKey::from_config(&cookies))?,
+            Err(ConfigError::KeyNotFound(_)) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        match config.get::<LoggingConfig>() {
+            Ok(logging) => {
+                logging.init();
+            }
+            Err(ConfigError::KeyNotFound(_)) => {}
+            Err(e) => return Err(e.into()),
+        }
 
         let router = Router::new().with_state(state.clone());
 
-        Self {
+        Ok(Self {
             router,
             state,
             config,
             prefix: None,
-        }
+            metrics: None,
+            request_id: None,
+            server_timing: false,
+            request_logger: false,
+            auto_head: true,
+            pretty_json: None,
+            json_engine: RegisteredJsonEngine::default(),
+            #[cfg(feature = "openapi")]
+            openapi_route: None,
+            shutdown: None,
+            cors: None,
+            error_statuses: ErrorStatusOverrides::default(),
+            method_not_allowed: None,
+            not_found: NotFound::default(),
+            error_reporter: None,
+            #[cfg(feature = "validator")]
+            validation_formatter: None,
+            startup_hooks: Vec::new(),
+            readiness: None,
+            rewrites: PathRewrites::default(),
+            body_limits: Vec::new(),
+            streamed_routes: Vec::new(),
+            routes: RegisteredRoutes::default(),
+            plugin_checks: Vec::new(),
+            inner_layers: Vec::new(),
+            outer_layers: Vec::new(),
+        })
     }
 
     /// Registers a controller in the application.
@@ -102,6 +322,36 @@ impl ApplicationBuilder {
     ///
     /// * `R` - A type implementing `RouterProvider` that defines the controller's routes
     ///
+    /// A controller whose `#[routes]` impl declares no routes contributes
+    /// nothing, which is usually a refactoring mistake, so a warning is logged
+    /// under the `sword::controllers` target when one is registered.
+    ///
+    /// Routes of different controllers may overlap. Requests are matched by
+    /// specificity, never by registration order: static segments win over
+    /// `{param}` segments, which win over `{*wildcard}` segments, so
+    /// `/files/readme` is served by its own route even when another
+    /// controller declares `/files/{*path}`. The same holds for the routes
+    /// of a single controller.
+    ///
+    /// The base path of a controller can end in a wildcard, like
+    /// `#[controller("/assets/{*path}")]`, to serve a whole tree from a
+    /// `#[get("/")]` route that reads the tail with `ctx.param("path")`. Its
+    /// routes can't add segments after the wildcard. With a `version`, the
+    /// version goes first (`/v1/assets/{*path}`), and the global prefix goes
+    /// before both.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if a route conflicts with one of an already registered
+    /// controller: the same method and path, or a path Axum can't tell
+    /// apart, like `/users/{id}` and `/users/{name}`. Also panics if a route
+    /// adds segments after a wildcard base path. Use `try_with_controller`
+    /// to get the error instead.
+    ///
+    /// Only controller routes are checked. Routes mounted with
+    /// `with_router`, directly or by a plugin, are unknown to Sword, so a
+    /// conflict with one of them still panics inside Axum.
+    ///
     /// ### Example
     ///
     /// ```rust,ignore
@@ -118,20 +368,189 @@ impl ApplicationBuilder {
     ///     }
     /// }
     ///
-    /// let app = Application::builder()
+    /// let app = Application::builder()?
     ///     .with_controller::<HomeController>()
-    ///     .build();
+    ///     .build()?;
     /// ```
     pub fn with_controller<C: Controller>(self) -> Self {
-        let controller_router = C::router(self.state.clone());
+        self.try_with_controller::<C>()
+            .unwrap_or_else(|e| panic!("Failed to register controller: {e}"))
+    }
+
+    /// Registers a controller like `with_controller`, returning an error
+    /// instead of panicking when one of its routes conflicts with a route of
+    /// an already registered controller.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `ApplicationError::RouteConflict` naming both routes and
+    /// their controllers, or `ApplicationError::InvalidRoute` for a route
+    /// that adds segments after a wildcard base path.
+    pub fn try_with_controller<C: Controller>(
+        self,
+    ) -> Result<Self, ApplicationError> {
+        self.register_controller::<C>(C::router)
+    }
+
+    /// Registers a controller that is already built, instead of building it
+    /// from the application state like `with_controller` does.
+    ///
+    /// Useful for test doubles and for controllers whose dependencies are
+    /// awkward to register globally. The fields of the instance don't have
+    /// to be registered in the state, though its middlewares still read
+    /// theirs from it. Declare the controller with
+    /// `#[controller("/path", instance)]` to give it fields of any type, like
+    /// `Arc<dyn Repository>`, that can't be injected at all.
+    ///
+    /// ### Panics
+    ///
+    /// Panics in the same cases as `with_controller`. Use
+    /// `try_with_controller_instance` to get the error instead.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[controller("/users", instance)]
+    /// struct UsersController {
+    ///     repository: Arc<dyn UsersRepository>,
+    /// }
+    ///
+    /// let app = Application::builder()?
+    ///     .with_controller_instance(UsersController {
+    ///         repository: Arc::new(InMemoryRepository::default()),
+    ///     })
+    ///     .build()?;
+    /// ```
+    pub fn with_controller_instance<C: Controller>(self, controller: C) -> Self {
+        self.try_with_controller_instance(controller)
+            .unwrap_or_else(|e| panic!("Failed to register controller: {e}"))
+    }
+
+    /// Registers an already built controller like `with_controller_instance`,
+    /// returning an error instead of panicking.
+    ///
+    /// ### Errors
+    ///
+    /// Fails like `try_with_controller`.
+    pub fn try_with_controller_instance<C: Controller>(
+        self,
+        controller: C,
+    ) -> Result<Self, ApplicationError> {
+        self.register_controller::<C>(|state| C::instance_router(controller, state))
+    }
+
+    fn register_controller<C: Controller>(
+        self,
+        router: impl FnOnce(State) -> Router,
+    ) -> Result<Self, ApplicationError> {
+        let controller = std::any::type_name::<C>();
+        let mut routes = self.routes.clone();
+        let mut streamed_routes = self.streamed_routes;
+
+        for route in C::routes().iter().filter(|r| r.is_enabled(&self.state)) {
+            let path = join_paths(C::base_path(), route.path);
+            let tag = route.tag.or(C::tag());
+
+            if route.stream_body
+                && let Ok(method) = route.method.parse::<Method>()
+            {
+                streamed_routes.push((method, path.clone()));
+            }
+
+            routes.register(controller, route.method, path, tag)?;
+        }
+
+        if C::route_count() == 0 {
+            tracing::warn!(
+                target: "sword::controllers",
+                controller,
+                base_path = C::base_path(),
+                "Registered controller has no routes"
+            );
+        }
+
+        let controller_router = router(self.state.clone());
         let router = self.router.clone().merge(controller_router);
 
-        Self {
-            router,
-            state: self.state,
-            config: self.config,
-            prefix: self.prefix,
+        let mut body_limits = self.body_limits;
+
+        for route in C::body_limits() {
+            if let Ok(method) = route.method.parse::<Method>() {
+                let path = join_paths(C::base_path(), route.path);
+                body_limits.push((method, path, route.limit));
+            }
         }
+
+        Ok(Self {
+            router,
+            body_limits,
+            streamed_routes,
+            routes,
+            ..self
+        })
+    }
+
+    /// Mounts an Axum `Router` under the given path.
+    ///
+    /// This allows reusing existing Axum code inside a Sword application.
+    /// The router shares the application state, so its handlers can use
+    /// `Context` or `axum::extract::State<sword::core::State>`. The global
+    /// prefix, the layers and the not-found fallback apply to it like to
+    /// any controller, unless the router defines its own fallback.
+    ///
+    /// The routes of an Axum `Router` can't be listed, so they are not
+    /// checked for conflicts like controller routes, and are missing from
+    /// `Application::routes`. A route that conflicts with a controller route
+    /// or with another router makes Axum panic, either here or when the
+    /// conflicting controller is registered.
+    ///
+    /// ### Arguments
+    /// * `path` - The path to mount the router under, `"/"` merges it at the root.
+    /// * `router` - The Axum router to mount.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use axum::routing::get;
+    /// use sword::prelude::*;
+    ///
+    /// let legacy = axum::Router::new().route("/health", get(|| async { "OK" }));
+    ///
+    /// let app = Application::builder()?
+    ///     .with_controller::<UsersController>()
+    ///     .with_router("/legacy", legacy)
+    ///     .build()?;
+    /// ```
+    pub fn with_router(self, path: &str, router: Router<State>) -> Self {
+        let router = router.with_state(self.state.clone());
+
+        let router = match path {
+            "/" => self.router.clone().merge(router),
+            _ => self.router.clone().nest(path, router),
+        };
+
+        Self { router, ..self }
+    }
+
+    /// Registers a `RoutesPlugin` in the application.
+    ///
+    /// The plugin registers its controllers, layers and state right away,
+    /// as if they had been added to the builder directly, so the same
+    /// ordering rules apply. Its `validate_config` runs on `build()`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = Application::builder()?
+    ///     .with_plugin(AdminPlugin)
+    ///     .with_controller::<UsersController>()
+    ///     .build()?;
+    /// ```
+    pub fn with_plugin<P: RoutesPlugin>(mut self, plugin: P) -> Self {
+        self.plugin_checks
+            .push((std::any::type_name::<P>(), P::validate_config));
+
+        plugin.register(self)
     }
 
     /// Registers a middleware layer in the application.
@@ -140,6 +559,18 @@ impl ApplicationBuilder {
     /// that implement the `Layer` trait. Layers are applied to all routes
     /// in the application and can modify requests and responses.
     ///
+    /// ### Ordering
+    ///
+    /// Layers are applied when the application is built, so they wrap every
+    /// controller regardless of registration order. They sit *inside* the
+    /// built-in layers: a request passes through the built-ins (body limit,
+    /// Content-Type check, timeout, ...) first, and then through these layers
+    /// before reaching the handler. When several layers are registered, the
+    /// last one added runs first.
+    ///
+    /// Use `with_outer_layer` for layers that must see the request before
+    /// the built-ins do.
+    ///
     /// ### Arguments
     ///
     /// * `layer` - The middleware layer to add to the application
@@ -151,12 +582,12 @@ impl ApplicationBuilder {
     /// use tower_http::cors::CorsLayer;
     /// use tower_http::trace::TraceLayer;
     ///
-    /// let app = Application::builder()
+    /// let app = Application::builder()?
     ///     .with_layer(CorsLayer::permissive())
     ///     .with_layer(TraceLayer::new_for_http())
-    ///     .build();
+    ///     .build()?;
     /// ```
-    pub fn with_layer<L>(self, layer: L) -> Self
+    pub fn with_layer<L>(mut self, layer: L) -> Self
     where
         L: Layer<Route> + Clone + Send + Sync + 'static,
         L::Service: Service<AxumRequest> + Clone + Send + Sync + 'static,
@@ -164,14 +595,133 @@ impl ApplicationBuilder {
         <L::Service as Service<AxumRequest>>::Error: Into<Infallible> + 'static,
         <L::Service as Service<AxumRequest>>::Future: Send + 'static,
     {
-        let router = self.router.layer(layer);
+        self.inner_layers.push(DeferredLayer::new(layer));
+        self
+    }
 
-        Self {
-            router,
-            state: self.state,
-            config: self.config,
-            prefix: self.prefix,
-        }
+    /// Registers a middleware layer that runs before the built-in request
+    /// checks.
+    ///
+    /// Outer layers receive the request before the body limit, header limits,
+    /// Content-Type check and timeout, and see the response after them. This
+    /// is needed for layers that transform the request body, such as a
+    /// decompression layer that must run before the body limit is enforced.
+    /// When several outer layers are registered, the last one added runs
+    /// first.
+    ///
+    /// They don't wrap everything, though: error status overrides,
+    /// validation formatting, rejection logging, CORS, not found and method
+    /// not allowed responses, error reporting, `Server-Timing`, request
+    /// logging, request IDs, metrics, rewrites and the readiness probe run
+    /// around them. See `build` for the full order.
+    ///
+    /// ### Arguments
+    ///
+    /// * `layer` - The middleware layer to add in front of the built-ins
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    /// use tower_http::decompression::RequestDecompressionLayer;
+    ///
+    /// let app = Application::builder()?
+    ///     .with_controller::<UploadsController>()
+    ///     .with_outer_layer(RequestDecompressionLayer::new())
+    ///     .build()?;
+    /// ```
+    pub fn with_outer_layer<L>(mut self, layer: L) -> Self
+    where
+        L: Layer<Route> + Clone + Send + Sync + 'static,
+        L::Service: Service<AxumRequest> + Clone + Send + Sync + 'static,
+        <L::Service as Service<AxumRequest>>::Response: IntoResponse + 'static,
+        <L::Service as Service<AxumRequest>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<AxumRequest>>::Future: Send + 'static,
+    {
+        self.outer_layers.push(DeferredLayer::new(layer));
+        self
+    }
+
+    /// Registers a middleware chosen at runtime, e.g. from the configuration
+    /// or only in some environments.
+    ///
+    /// It runs for every route, around the controller and route middlewares
+    /// declared with `#[middleware]`, in the same position as the layers of
+    /// `with_layer`. The two share their order: the last one registered runs
+    /// first. Any `Middleware` can be boxed, as well as implementations of
+    /// `DynMiddleware` that carry their own data.
+    ///
+    /// ### Arguments
+    ///
+    /// * `middleware` - The middleware to run for every request
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// let mut builder = Application::builder()?.with_controller::<AdminController>();
+    ///
+    /// if production {
+    ///     builder = builder.with_boxed_middleware(Box::new(AuthMiddleware));
+    /// }
+    ///
+    /// let app = builder.build()?;
+    /// ```
+    pub fn with_boxed_middleware(self, middleware: Box<dyn DynMiddleware>) -> Self {
+        let middleware: Arc<dyn DynMiddleware> = Arc::from(middleware);
+
+        let layer =
+            mw_with_state(self.state.clone(), move |ctx: Context, next: Next| {
+                let middleware = middleware.clone();
+                async move { middleware.call(ctx, next).await }
+            });
+
+        self.with_layer(layer)
+    }
+
+    /// Registers a shared value in the application state.
+    ///
+    /// Handlers and middlewares read it with `Context::di::<T>()`, which
+    /// returns a clone and so needs `T: Clone`. To share a value that is not
+    /// `Clone`, register it as an `Arc<T>` and read it with
+    /// `Context::di::<Arc<T>>()`. Values are keyed by type, so only one value
+    /// of each type can be registered.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if a value of the same type is already registered, including
+    /// the `Config` and `FeatureFlags` registered by the framework and the
+    /// dependencies of a registered container. Use `try_with_state` to
+    /// handle this case.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = Application::builder()?
+    ///     .with_state(AppVersion("1.4.0"))
+    ///     .with_controller::<StatusController>()
+    ///     .build()?;
+    /// ```
+    pub fn with_state<T: Send + Sync + 'static>(self, state: T) -> Self {
+        self.try_with_state(state)
+            .unwrap_or_else(|e| panic!("Failed to register state: {e}"))
+    }
+
+    /// Registers a shared value in the application state like `with_state`,
+    /// returning an error instead of panicking.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `StateError::DuplicateType` if a value of the same type is
+    /// already registered. The registered value is left untouched.
+    pub fn try_with_state<T: Send + Sync + 'static>(
+        self,
+        state: T,
+    ) -> Result<Self, StateError> {
+        self.state.insert_unique(state)?;
+
+        Ok(self)
     }
 
     /// Registers the provided dependency container in the application.
@@ -182,11 +732,25 @@ impl ApplicationBuilder {
     /// register providers and services that can be resolved later.
     /// ```
     pub fn with_dependency_container(self, container: DependencyContainer) -> Self {
-        container
-            .build_all(&self.state)
-            .unwrap_or_else(|e| panic!("Failed to build dependencies: {e}"));
+        self.try_with_dependency_container(container)
+            .unwrap_or_else(|e| panic!("Failed to build dependencies: {e}"))
+    }
 
-        self
+    /// Registers a dependency container like `with_dependency_container`,
+    /// returning an error instead of panicking.
+    ///
+    /// ### Errors
+    ///
+    /// Returns the `DependencyInjectionError` of the first dependency that
+    /// can't be built, for example because one of its own dependencies is
+    /// not registered.
+    pub fn try_with_dependency_container(
+        self,
+        container: DependencyContainer,
+    ) -> Result<Self, DependencyInjectionError> {
+        container.build_all(&self.state)?;
+
+        Ok(self)
     }
 
     /// Registers a Shaku dependency injection module in the application.
@@ -205,10 +769,10 @@ impl ApplicationBuilder {
     ///
     /// * `module` - The Shaku module instance containing registered services
     ///
-    /// ### Returns
+    /// ### Panics
     ///
-    /// Returns `Ok(Self)` for method chaining, or `Err(StateError)` if the module
-    /// type is already registered.
+    /// Panics if the module can't be stored in the state. Use
+    /// `try_with_shaku_di_module` to handle this case.
     ///
     /// ### Example
     ///
@@ -240,25 +804,35 @@ impl ApplicationBuilder {
     ///
     /// let module = AppModule::builder().build();
     ///
-    /// let app = Application::builder()
+    /// let app = Application::builder()?
     ///     .with_shaku_di_module(module)
-    ///     .build();
+    ///     .build()?;
     /// ```
     #[cfg(feature = "shaku-di")]
     pub fn with_shaku_di_module<M: Sync + Send + 'static + Module>(
         self,
         module: M,
     ) -> Self {
-        self.state.insert(module).expect("Failed to insert state");
+        self.try_with_shaku_di_module(module)
+            .unwrap_or_else(|e| panic!("Failed to register Shaku module: {e}"))
+    }
+
+    /// Registers a Shaku module like `with_shaku_di_module`, returning an
+    /// error instead of panicking.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `StateError::LockError` if the state can't be written to.
+    #[cfg(feature = "shaku-di")]
+    pub fn try_with_shaku_di_module<M: Sync + Send + 'static + Module>(
+        self,
+        module: M,
+    ) -> Result<Self, StateError> {
+        self.state.insert(module)?;
 
         let router = Router::new().with_state(self.state.clone());
 
-        Self {
-            router,
-            state: self.state,
-            config: self.config,
-            prefix: self.prefix,
-        }
+        Ok(Self { router, ..self })
     }
 
     /// Sets a URL prefix for all routes in the application.
@@ -267,59 +841,865 @@ impl ApplicationBuilder {
     /// applied to all routes registered in the application.
     pub fn with_prefix<S: Into<String>>(self, prefix: S) -> Self {
         Self {
-            router: self.router,
-            state: self.state,
-            config: self.config,
             prefix: Some(prefix.into()),
+            ..self
         }
     }
 
-    /// Builds the final application instance.
-    ///
-    /// This method finalizes the application configuration and creates the
-    /// `Application` instance. It applies all configured middleware layers,
-    /// sets up request body limits, and prepares the application for running.
+    /// Enables the built-in traffic metrics middleware.
     ///
-    /// ### Built-in Middleware
+    /// The middleware wraps the request and response bodies of every request
+    /// and accumulates the number of bytes read and sent into the given
+    /// `Metrics` handle. Keep a clone of it to read the totals or register
+    /// per-request observers with `Metrics::on_request`.
     ///
-    /// The following middleware is automatically applied:
-    /// - Content-Type validation middleware
-    /// - Request body size limiting middleware
-    /// - Cookie management layer (if `cookies` feature is enabled)
-    pub fn build(self) -> Application {
-        let mut router = self.router.clone();
-        let app_config = self.config.get::<ApplicationConfig>().unwrap();
-
-        router = router
-            .layer(mw_with_state(self.state.clone(), ContentTypeCheck::layer))
-            .layer(RequestBodyLimitLayer::new(app_config.body_limit.parsed));
-
-        if let Some(timeout_secs) = app_config.request_timeout_seconds {
-            router =
-                router.layer(TimeoutLayer::new(Duration::from_secs(timeout_secs)));
+    /// ### Arguments
+    /// * `metrics` - The metrics registry that will receive the counters.
+    pub fn with_metrics(self, metrics: Metrics) -> Self {
+        Self {
+            metrics: Some(metrics),
+            ..self
         }
+    }
 
-        #[cfg(feature = "cookies")]
-        {
-            router = router.layer(CookieManagerLayer::new());
-        }
+    /// Enables the built-in `RequestId` middleware.
+    ///
+    /// Every request gets a correlation ID, taken from its `X-Request-Id`
+    /// header or generated, that handlers read with `Context::request_id` and
+    /// that is echoed in the response. The middleware wraps every other
+    /// layer except metrics, rewrites and the readiness probe, so not found
+    /// and rejected responses carry the ID as well.
+    ///
+    /// ### Arguments
+    /// * `request_id` - The middleware, configuring the header and ID format.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// let app = Application::builder()?
+    ///     .with_controller::<OrdersController>()
+    ///     .with_request_id(RequestId::new().format(RequestIdFormat::NanoId))
+    ///     .build()?;
+    /// ```
+    pub fn with_request_id(self, request_id: RequestId) -> Self {
+        Self {
+            request_id: Some(request_id),
+            ..self
+        }
+    }
+
+    /// Enables the built-in `ServerTiming` middleware.
+    ///
+    /// Every response gets a `Server-Timing` header with the `total` time of
+    /// the request and the phases recorded with `Context::record_timing`.
+    /// The middleware is off unless enabled here, and wraps every other
+    /// built-in layer except request logging, request IDs, metrics, rewrites
+    /// and the readiness probe.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// let app = Application::builder()?
+    ///     .with_controller::<OrdersController>()
+    ///     .with_server_timing()
+    ///     .build()?;
+    /// ```
+    pub fn with_server_timing(self) -> Self {
+        Self {
+            server_timing: true,
+            ..self
+        }
+    }
+
+    /// Enables the built-in `RequestLogger` middleware.
+    ///
+    /// Every request is logged once it has been handled, with its method,
+    /// path, status, latency and request ID, at the level set in the
+    /// `[request_logging]` config section. The middleware runs inside the
+    /// `RequestId` one, so the ID is the one sent in the response.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// let app = Application::builder()?
+    ///     .with_controller::<OrdersController>()
+    ///     .with_request_id(RequestId::new())
+    ///     .with_logger()
+    ///     .build()?;
+    /// ```
+    pub fn with_logger(self) -> Self {
+        Self {
+            request_logger: true,
+            ..self
+        }
+    }
+
+    /// Sets whether `GET` routes of controllers answer `HEAD` requests.
+    ///
+    /// By default, a `HEAD` request to a route that declares `GET` but not
+    /// `HEAD` runs the `GET` handler and sends its headers, including
+    /// `Content-Length`, without the body, as HTTP requires. Disabling it
+    /// answers such requests with `405 Method Not Allowed` instead, for
+    /// handlers whose side effects must not run for a `HEAD` request. Routes
+    /// with their own `#[head]` handler are not affected.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = Application::builder()?
+    ///     .with_controller::<FilesController>()
+    ///     .with_auto_head(false)
+    ///     .build()?;
+    /// ```
+    pub fn with_auto_head(self, enabled: bool) -> Self {
+        Self {
+            auto_head: enabled,
+            ..self
+        }
+    }
+
+    /// Sets whether JSON responses are indented, overriding `pretty_json` in
+    /// the `[response]` config section.
+    ///
+    /// Both default to indented JSON in debug builds and compact JSON in
+    /// release builds. When disabled, responses are sent as the handlers
+    /// built them, without being buffered again.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = Application::builder()?
+    ///     .with_controller::<OrdersController>()
+    ///     .with_pretty_json(false)
+    ///     .build()?;
+    /// ```
+    pub fn with_pretty_json(self, enabled: bool) -> Self {
+        Self {
+            pretty_json: Some(enabled),
+            ..self
+        }
+    }
+
+    /// Sets the engine that reads and writes JSON, in place of `serde_json`.
+    ///
+    /// It parses the bodies read by `Context::body`, `body_strict` and
+    /// `validate_with`, and the validators built on them, and serializes the
+    /// responses of `Context::respond`. Query strings and the `data` of an
+    /// `HttpResponse` don't go through it, see `JsonEngine`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = Application::builder()?
+    ///     .with_controller::<EventsController>()
+    ///     .with_json_engine(SimdJson)
+    ///     .build()?;
+    /// ```
+    pub fn with_json_engine<E: JsonEngine>(self, engine: E) -> Self {
+        Self {
+            json_engine: RegisteredJsonEngine(Some(Arc::new(engine))),
+            ..self
+        }
+    }
+
+    /// Serves the OpenAPI document of the controller routes at `path`, as
+    /// built by `Application::openapi_spec`.
+    ///
+    /// The route is added below the global prefix like the controllers, and
+    /// isn't listed in the document itself. `build` returns
+    /// `ApplicationError::RouteConflict` if a controller route already
+    /// answers `GET` requests on `path`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = Application::builder()?
+    ///     .with_controller::<UsersController>()
+    ///     .with_openapi_route("/openapi.json")
+    ///     .build()?;
+    /// ```
+    #[cfg(feature = "openapi")]
+    pub fn with_openapi_route(self, path: &str) -> Self {
+        Self {
+            openapi_route: Some(path.to_string()),
+            ..self
+        }
+    }
+
+    /// Enables CORS for every route of the application.
+    ///
+    /// The layer adds the CORS headers to regular responses and answers
+    /// preflight requests automatically with `204 No Content`, so routes
+    /// don't need to declare `OPTIONS` handlers. A route that does declare
+    /// its own `OPTIONS` handler keeps handling its preflight requests.
+    ///
+    /// Preflight requests to controller routes without an `OPTIONS` handler
+    /// are answered before the controller and route middlewares run, so an
+    /// authentication middleware can't reject them.
+    ///
+    /// ### Arguments
+    /// * `cors` - The `CorsLayer` describing the allowed origins, methods and
+    ///   headers, or a `Cors` policy read from the `[cors]` config section.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    /// use sword::web::cors::{Any, CorsLayer};
+    ///
+    /// let app = Application::builder()?
+    ///     .with_controller::<UsersController>()
+    ///     .with_cors(CorsLayer::new().allow_origin(Any).allow_methods(Any))
+    ///     .build()?;
+    /// ```
+    pub fn with_cors(self, cors: impl Into<CorsLayer>) -> Self {
+        Self {
+            cors: Some(cors.into()),
+            ..self
+        }
+    }
+
+    /// Overrides the status code a `RequestError` is sent with.
+    ///
+    /// The closure is called with every `RequestError` turned into a response
+    /// and returns the status to use instead of `RequestError::status_code`,
+    /// or `None` to keep the default. When several overrides are registered,
+    /// the first one returning a status wins.
+    ///
+    /// ### Arguments
+    /// * `status` - A closure mapping errors to their custom status code.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// let app = Application::builder()?
+    ///     .with_controller::<UploadsController>()
+    ///     .with_request_error_status(|error| match error {
+    ///         RequestError::UnsupportedMediaType { .. } => Some(StatusCode::BAD_REQUEST),
+    ///         _ => None,
+    ///     })
+    ///     .build()?;
+    /// ```
+    pub fn with_request_error_status<F>(mut self, status: F) -> Self
+    where
+        F: Fn(&RequestError) -> Option<StatusCode> + Send + Sync + 'static,
+    {
+        self.error_statuses.push(status);
+        self
+    }
+
+    /// Sets how validation errors are turned into responses.
+    ///
+    /// The formatter builds the response of every `RequestError::ValidatorError`,
+    /// returned by `body_validator`, `query_validator` and `params_validator`,
+    /// so it controls the JSON structure of the errors and the status code.
+    /// Without one, `DefaultValidationErrorFormatter` is used.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = Application::builder()?
+    ///     .with_controller::<UsersController>()
+    ///     .with_validation_formatter(MessagesOnly)
+    ///     .build()?;
+    /// ```
+    #[cfg(feature = "validator")]
+    pub fn with_validation_formatter<F: ValidationErrorFormatter>(
+        self,
+        formatter: F,
+    ) -> Self {
+        Self {
+            validation_formatter: Some(ValidationFormatter::new(formatter)),
+            ..self
+        }
+    }
+
+    /// Sets the response sent when a path exists but doesn't accept the
+    /// request method.
+    ///
+    /// The handler receives the methods the path accepts. Its response is
+    /// sent with the `Allow` header listing them, which is kept even if the
+    /// handler picks a status other than `405 Method Not Allowed`.
+    ///
+    /// ### Arguments
+    /// * `handler` - A function building the response from the allowed methods.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// let app = Application::builder()?
+    ///     .with_controller::<UsersController>()
+    ///     .with_method_not_allowed_handler(|allowed| {
+    ///         let allowed = allowed.iter().map(Method::as_str).collect::<Vec<_>>();
+    ///
+    ///         HttpResponse::MethodNotAllowed()
+    ///             .message(format!("Use one of: {}", allowed.join(", ")))
+    ///     })
+    ///     .build()?;
+    /// ```
+    pub fn with_method_not_allowed_handler<F>(self, handler: F) -> Self
+    where
+        F: Fn(&[Method]) -> HttpResponse + Send + Sync + 'static,
+    {
+        Self {
+            method_not_allowed: Some(MethodNotAllowed::new(handler)),
+            ..self
+        }
+    }
+
+    /// Sets the response sent when a request matches no route.
+    ///
+    /// The handler receives the request path and the format negotiated from
+    /// the `Accept` header: `NotFoundFormat::Html` when the client prefers
+    /// HTML, as browsers do, and `NotFoundFormat::Json` otherwise, including
+    /// clients that send no `Accept` header. The response varies on `Accept`.
+    /// Without a handler, the JSON envelope is always sent.
+    ///
+    /// ### Arguments
+    /// * `handler` - A function building the response from the path and format.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use axum::response::Html;
+    /// use sword::prelude::*;
+    ///
+    /// let app = Application::builder()?
+    ///     .with_controller::<PagesController>()
+    ///     .with_not_found_handler(|path, format| match format {
+    ///         NotFoundFormat::Html => (
+    ///             StatusCode::NOT_FOUND,
+    ///             Html(format!("<h1>Nothing at {path}</h1>")),
+    ///         )
+    ///             .into_response(),
+    ///         NotFoundFormat::Json => HttpResponse::NotFound().into_response(),
+    ///     })
+    ///     .build()?;
+    /// ```
+    pub fn with_not_found_handler<F>(self, handler: F) -> Self
+    where
+        F: Fn(&str, NotFoundFormat) -> Response + Send + Sync + 'static,
+    {
+        Self {
+            not_found: NotFound::new(handler),
+            ..self
+        }
+    }
+
+    /// Registers a hook called with every `5xx` response and handler panic,
+    /// to forward them to an external error tracker.
+    ///
+    /// The hook receives an `ErrorReport` with the status, the request
+    /// method and path, the request ID if `with_request_id` is enabled, and
+    /// the user set with `Context::report_user`. It runs on its own task, so
+    /// the response is sent without waiting for it. Panics are caught and
+    /// answered with a `500` envelope.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = Application::builder()?
+    ///     .with_controller::<OrdersController>()
+    ///     .with_request_id(RequestId::new())
+    ///     .on_error(|report: ErrorReport| async move {
+    ///         tracker.capture(report.status, report.path, report.panic).await;
+    ///     })
+    ///     .build()?;
+    /// ```
+    pub fn on_error<F, Fut>(self, hook: F) -> Self
+    where
+        F: Fn(ErrorReport) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            error_reporter: Some(ErrorReporter::new(hook)),
+            ..self
+        }
+    }
+
+    /// Registers a hook run when the application starts serving, before it
+    /// reports ready.
+    ///
+    /// The hooks run one after another, in registration order, once the
+    /// listener is bound, so the server already accepts connections while
+    /// they do. Use them for async initialization, such as warming caches or
+    /// connecting to a broker, and combine them with `with_readiness_probe`
+    /// to keep traffic away until they complete.
+    ///
+    /// `run` and the `serve_on_listener` methods run them. When serving
+    /// `router()` some other way, call `Application::startup` instead.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = Application::builder()?
+    ///     .with_controller::<OrdersController>()
+    ///     .on_startup(|state: State| async move {
+    ///         let cache = state.get::<Arc<Cache>>().unwrap();
+    ///         cache.warm().await;
+    ///     })
+    ///     .build()?;
+    /// ```
+    pub fn on_startup<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: Fn(State) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.startup_hooks.push(StartupHook::new(hook));
+        self
+    }
+
+    /// Serves a readiness endpoint that answers `503 Service Unavailable`
+    /// until the `on_startup` hooks have completed, and `200 OK` afterwards.
+    ///
+    /// The endpoint wraps every other layer, so it is served at the given
+    /// path regardless of the global prefix. See `ReadinessProbe` to also
+    /// hold back regular traffic until the application is ready.
+    ///
+    /// ### Arguments
+    /// * `probe` - The probe, configuring its path and whether traffic is held.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = Application::builder()?
+    ///     .with_controller::<OrdersController>()
+    ///     .with_readiness_probe(ReadinessProbe::new("/readyz"))
+    ///     .build()?;
+    /// ```
+    pub fn with_readiness_probe(self, probe: ReadinessProbe) -> Self {
+        Self {
+            readiness: Some(probe),
+            ..self
+        }
+    }
+
+    /// Registers a coordinator for background tasks that must be stopped
+    /// when the application shuts down.
+    ///
+    /// On graceful shutdown the server drains in-flight requests first and
+    /// only then signals and awaits the coordinator's tasks. The coordinator
+    /// is also stored in the state, available through `ctx.di()`.
+    ///
+    /// ### Arguments
+    /// * `coordinator` - The coordinator the background tasks are spawned with.
+    ///
+    /// ### Panics
+    /// Panics if the coordinator can't be stored in the state. Use
+    /// `try_with_shutdown_coordinator` to handle this case.
+    pub fn with_shutdown_coordinator(
+        self,
+        coordinator: ShutdownCoordinator,
+    ) -> Self {
+        self.try_with_shutdown_coordinator(coordinator)
+            .unwrap_or_else(|e| {
+                panic!("Failed to register shutdown coordinator: {e}")
+            })
+    }
+
+    /// Registers a shutdown coordinator like `with_shutdown_coordinator`,
+    /// returning an error instead of panicking.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `StateError::LockError` if the state can't be written to.
+    pub fn try_with_shutdown_coordinator(
+        self,
+        coordinator: ShutdownCoordinator,
+    ) -> Result<Self, StateError> {
+        self.state.insert(coordinator.clone())?;
+
+        Ok(Self {
+            shutdown: Some(coordinator),
+            ..self
+        })
+    }
+
+    /// Rewrites the path of matching requests before route matching.
+    ///
+    /// Controller and handler middlewares run after Axum has selected a route,
+    /// so they can't redirect a request to a different handler. Rewrites are
+    /// applied by a service placed in front of the whole router instead, which
+    /// makes them suitable for keeping legacy URLs working.
+    ///
+    /// The `from` pattern uses the route path syntax and its captures can be
+    /// referenced in `to`. The query string of the original request is kept.
+    ///
+    /// ### Arguments
+    /// * `from` - The full incoming path pattern, including any global prefix.
+    /// * `to` - The path the request is routed to instead.
+    ///
+    /// ### Panics
+    /// Panics if `from` is not a valid route pattern or conflicts with a
+    /// previously registered rewrite. Use `try_with_rewrite` to handle this
+    /// case.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let app = Application::builder()?
+    ///     .with_controller::<UsersController>()
+    ///     .with_rewrite("/v1/old", "/v1/new")
+    ///     .with_rewrite("/legacy/users/{id}", "/users/{id}")
+    ///     .build()?;
+    /// ```
+    pub fn with_rewrite(self, from: &str, to: &str) -> Self {
+        self.try_with_rewrite(from, to)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Adds a path rewrite like `with_rewrite`, returning an error instead
+    /// of panicking.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `ApplicationError::InvalidRewrite` if `from` is not a valid
+    /// route pattern or conflicts with a previously registered rewrite.
+    pub fn try_with_rewrite(
+        mut self,
+        from: &str,
+        to: &str,
+    ) -> Result<Self, ApplicationError> {
+        self.rewrites.insert(from, to).map_err(|e| {
+            ApplicationError::InvalidRewrite {
+                pattern: from.to_string(),
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(self)
+    }
+
+    /// Builds the final application instance.
+    ///
+    /// This method finalizes the application configuration and creates the
+    /// `Application` instance. It applies all configured middleware layers,
+    /// sets up request body limits, and prepares the application for running.
+    ///
+    /// ### Built-in Middleware
+    ///
+    /// The following middleware is automatically applied, from the innermost
+    /// to the outermost, between the layers registered with `with_layer`
+    /// and the ones registered with `with_outer_layer`:
+    /// - Content-Type validation middleware
+    /// - Request timeout (if `request_timeout_seconds` is set in the config)
+    /// - Cookie management layer (if `cookies` feature is enabled)
+    /// - Client disconnect detection used by `Context::cancelled`
+    /// - Null `data` fields removal (if `skip_nulls` is enabled in the `[response]` config)
+    /// - JSON indentation (if `pretty_json` is enabled in the `[response]` config
+    ///   or with `with_pretty_json`, the default in debug builds)
+    /// - Response size guard (if `max_response_bytes` is set in the `[limits]` config)
+    /// - Request body size limiting middleware
+    /// - Request header limits (if `max_header_count` or `max_header_bytes` is set
+    ///   in the `[limits]` config)
+    /// - `HEAD` rejection for `GET` routes (if disabled with `with_auto_head(false)`)
+    /// - Matched route capture for the traffic metrics (if enabled with `with_metrics`)
+    ///
+    /// And then around the layers registered with `with_outer_layer`, from
+    /// the innermost to the outermost:
+    /// - Request error status overrides (if set with `with_request_error_status`)
+    /// - Validation error formatting (if set with `with_validation_formatter`)
+    /// - Rejected requests logging (if `log_rejections` is enabled in the config)
+    /// - CORS and automatic preflight handling (if enabled with `with_cors`)
+    /// - Not found responses, negotiated if set with `with_not_found_handler`
+    /// - Custom method not allowed responses (if set with `with_method_not_allowed_handler`)
+    /// - Error reporting and panic catching (if set with `on_error`)
+    /// - `Server-Timing` header (if enabled with `with_server_timing`)
+    /// - Request logging (if enabled with `with_logger`)
+    /// - Request ID assignment (if enabled with `with_request_id`)
+    /// - Traffic metrics layer (if enabled with `with_metrics`)
+    /// - Path rewrites (if added with `with_rewrite`)
+    /// - Readiness probe (if enabled with `with_readiness_probe`)
+    ///
+    /// ### Errors
+    ///
+    /// Returns an error if the `[application]` configuration section is
+    /// missing or invalid, if the `[response]`, `[limits]` or
+    /// `[request_logging]` sections are invalid, or if the configuration a
+    /// registered plugin depends on is.
+    pub fn build(self) -> Result<Application, ApplicationError> {
+        let mut router = self.router.clone();
+        let app_config = self.config.get::<ApplicationConfig>()?;
+
+        let response_config = match self.config.get::<ResponseConfig>() {
+            Ok(config) => config,
+            Err(ConfigError::KeyNotFound(_)) => ResponseConfig::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let limits_config = match self.config.get::<LimitsConfig>() {
+            Ok(config) => config,
+            Err(ConfigError::KeyNotFound(_)) => LimitsConfig::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        self.state.insert(self.json_engine.clone())?;
+
+        for (plugin, validate_config) in &self.plugin_checks {
+            validate_config(&self.config).map_err(|source| {
+                ApplicationError::PluginConfigError { plugin, source }
+            })?;
+        }
+
+        #[cfg(feature = "openapi")]
+        if let Some(path) = &self.openapi_route {
+            let spec = crate::web::openapi::Spec::from_routes(
+                &self.routes.infos(self.prefix.as_deref()),
+            );
+
+            // Checked on a copy so the document stays out of the route list.
+            self.routes.clone().register(
+                "with_openapi_route",
+                "GET",
+                path.clone(),
+                None,
+            )?;
+
+            router = router.route(
+                path,
+                axum::routing::get(move || async move { axum::Json(spec) }),
+            );
+        }
+
+        for layer in &self.inner_layers {
+            router = layer.apply(router);
+        }
+
+        router =
+            router.layer(mw_with_state(self.state.clone(), ContentTypeCheck::layer));
+
+        if let Some(timeout_secs) = app_config.request_timeout_seconds {
+            let timeout = RequestTimeout::new(Duration::from_secs(timeout_secs));
+            router = router.layer(mw_with_state(timeout, RequestTimeout::layer));
+        }
+
+        #[cfg(feature = "cookies")]
+        {
+            router = router.layer(CookieManagerLayer::new());
+        }
 
         router = router
-            .layer(mw_with_state(self.state.clone(), ResponsePrettifier::layer));
+            .layer(mw_with_state(self.state.clone(), ContextPreload::layer))
+            .layer(from_fn(RequestCancellation::layer));
+
+        if response_config.skip_nulls {
+            router = router.layer(from_fn(SkipNulls::layer));
+        }
+
+        if self.pretty_json.unwrap_or(response_config.pretty_json) {
+            router = router.layer(from_fn(ResponsePrettifier::layer));
+        }
+
+        if let Some(limit) = limits_config.max_response_bytes {
+            let guard = ResponseSizeGuard::new(limit);
+            router = router.layer(mw_with_state(guard, ResponseSizeGuard::layer));
+        }
+
+        let route_limits = self
+            .body_limits
+            .into_iter()
+            .map(|(method, path, limit)| match &self.prefix {
+                Some(prefix) => ((method, join_paths(prefix, &path)), limit),
+                None => ((method, path), limit),
+            })
+            .collect();
+
+        let streamed_routes = self
+            .streamed_routes
+            .into_iter()
+            .map(|(method, path)| match &self.prefix {
+                Some(prefix) => (method, join_paths(prefix, &path)),
+                None => (method, path),
+            })
+            .collect();
+
+        let body_limit = BodyLimitCheck::new(
+            app_config.body_limit.parsed,
+            route_limits,
+            streamed_routes,
+            app_config.body_limit_exceeded,
+        );
+        router = router.layer(mw_with_state(body_limit, BodyLimitCheck::layer));
+
+        let header_limits = HeaderLimits::new(
+            limits_config.max_header_count,
+            limits_config.max_header_bytes,
+        );
+
+        if !header_limits.is_empty() {
+            router = router.layer(mw_with_state(header_limits, HeaderLimits::layer));
+        }
+
+        if !self.auto_head {
+            let routes = self
+                .routes
+                .get_without_head()
+                .into_iter()
+                .filter_map(|(path, methods)| {
+                    let allow = HeaderValue::try_from(methods.join(",")).ok()?;
+
+                    match &self.prefix {
+                        Some(prefix) => Some((join_paths(prefix, path), allow)),
+                        None => Some((path.to_string(), allow)),
+                    }
+                })
+                .collect();
+
+            let head_check = HeadCheck::new(routes);
+
+            if !head_check.is_empty() {
+                router = router.layer(mw_with_state(head_check, HeadCheck::layer));
+            }
+        }
+
+        if self.metrics.is_some() {
+            router = router.layer(from_fn(MetricsLayer::capture_route));
+        }
+
+        for layer in &self.outer_layers {
+            router = layer.apply(router);
+        }
+
+        if !self.error_statuses.is_empty() {
+            router = router.layer(mw_with_state(
+                self.error_statuses,
+                ErrorStatusOverrides::layer,
+            ));
+        }
+
+        #[cfg(feature = "validator")]
+        if let Some(formatter) = self.validation_formatter {
+            router =
+                router.layer(mw_with_state(formatter, ValidationFormatter::layer));
+        }
+
+        if app_config.log_rejections {
+            router = router.layer(from_fn(RejectionLogger::layer));
+        }
+
+        if let Some(cors) = self.cors {
+            let routes = self
+                .routes
+                .methods_by_path()
+                .into_iter()
+                .map(|(path, methods)| {
+                    let path = match &self.prefix {
+                        Some(prefix) => join_paths(prefix, path),
+                        None => path.to_string(),
+                    };
+
+                    (path, methods.contains(&"OPTIONS"))
+                })
+                .collect();
+
+            let preflight = CorsPreflight::new(cors, routes);
+            router = router.layer(mw_with_state(preflight, CorsPreflight::layer));
+        }
 
         if let Some(prefix) = &self.prefix {
             router = Router::new().nest(prefix, router);
         }
 
-        Application {
+        let not_found = self.not_found;
+        router = router.fallback(move |request: AxumRequest| {
+            std::future::ready(not_found.respond(&request))
+        });
+
+        // Axum adds the `Allow` header once the response leaves the route,
+        // so the handler has to wrap the whole router to read it.
+        if let Some(handler) = self.method_not_allowed {
+            let inner =
+                mw_with_state(handler, MethodNotAllowed::layer).layer(router);
+            router = Router::new().fallback_service(inner);
+        }
+
+        if let Some(reporter) = self.error_reporter {
+            router = router.layer(mw_with_state(reporter, ErrorReporter::layer));
+        }
+
+        if self.server_timing {
+            router = router.layer(ServerTiming::new());
+        }
+
+        if self.request_logger {
+            let logger = RequestLogger::from_config(&self.config)?;
+            router = router.layer(mw_with_state(logger, RequestLogger::layer));
+        }
+
+        if let Some(request_id) = self.request_id {
+            router = router.layer(request_id);
+        }
+
+        if let Some(metrics) = self.metrics {
+            router = router.layer(mw_with_state(metrics, MetricsLayer::layer));
+        }
+
+        if !self.rewrites.is_empty() {
+            let rewrites = self.rewrites;
+            let inner = router.map_request(move |request| rewrites.rewrite(request));
+
+            router = Router::new().fallback_service(inner);
+        }
+
+        let startup = Arc::new(Startup::new(self.state.clone(), self.startup_hooks));
+
+        if let Some(probe) = self.readiness {
+            router = router.layer(mw_with_state(
+                (probe, startup.clone()),
+                ReadinessProbe::layer,
+            ));
+        }
+
+        Ok(Application {
             router,
             config: self.config,
-        }
+            shutdown: self.shutdown,
+            startup,
+            routes: self.routes.infos(self.prefix.as_deref()),
+        })
     }
 }
 
+/// Panics if the configuration can't be loaded. Use `Application::builder`
+/// to get the error instead.
 impl Default for ApplicationBuilder {
     fn default() -> Self {
-        Self::new()
+        Self::new().unwrap_or_else(|e| panic!("Failed to create application: {e}"))
     }
 }
+
+/// Registers several controllers at once, in the order they are listed.
+///
+/// Expands to a `with_controller` call per controller, so it panics in the
+/// same cases. The order is kept, which makes route precedence the same as
+/// with the chained calls.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// use sword::prelude::*;
+///
+/// let builder = Application::builder()?;
+///
+/// let app = with_controllers!(
+///     builder,
+///     [UsersController, TasksController, AuthController]
+/// )
+/// .build()?;
+/// ```
+#[macro_export]
+macro_rules! with_controllers {
+    ($builder:expr, [$($controller:ty),* $(,)?]) => {
+        $builder$(.with_controller::<$controller>())*
+    };
+}
@@ -20,6 +20,9 @@ use crate::core::ConfigItem;
 /// body_limit = "10MB"
 /// request_timeout_seconds = 30
 /// graceful_shutdown = true
+/// graceful_shutdown_timeout_seconds = 30
+/// log_rejections = true
+/// body_limit_exceeded = "reject"
 /// ```
 ///
 /// ### Environment Variable Interpolation
@@ -48,13 +51,53 @@ pub struct ApplicationConfig {
     /// Parsed using the byte_unit crate for flexible size specification.
     pub body_limit: BodyLimit,
 
+    /// What to do with requests whose `Content-Length` exceeds the body
+    /// limit, `"reject"` to answer them with the `413 Payload Too Large`
+    /// JSON envelope, or `"log"` to log a warning and handle them anyway,
+    /// reading the whole body.
+    ///
+    /// In `"log"` mode no limit is enforced at all, bodies without a
+    /// `Content-Length` are only logged once they go past it, so a single
+    /// request can make the server buffer an unbounded amount of memory.
+    /// Use it only behind a proxy that caps request sizes.
+    /// Defaults to `"reject"`.
+    #[serde(default)]
+    pub body_limit_exceeded: BodyLimitExceeded,
+
     /// Optional request timeout in seconds.
     /// If set, requests taking longer than this duration will
-    /// be aborted and return a timeout error.
+    /// be aborted and answered with a `503 Service Unavailable`.
     ///
     /// If not set, there is no timeout.
     pub request_timeout_seconds: Option<u64>,
 
+    /// Whether `Context::body` should reject JSON bodies containing fields
+    /// that are not part of the target type.
+    /// Defaults to false, ignoring unknown fields like serde does.
+    #[serde(default)]
+    pub deny_unknown_fields: bool,
+
+    /// Whether to log a structured warning for every `4xx` response, with
+    /// the client IP and the rejection reason. Request bodies are never logged.
+    /// Defaults to false.
+    #[serde(default)]
+    pub log_rejections: bool,
+
+    /// Whether `Context::real_ip` should trust the `X-Forwarded-For` and
+    /// `X-Real-IP` headers. Enable it only behind a reverse proxy that sets
+    /// them, since clients can send any value.
+    /// Defaults to false.
+    #[serde(default)]
+    pub trust_proxy_headers: bool,
+
+    /// Optional maximum number of fields in a multipart form read with
+    /// `Context::multipart` or `Context::multipart_with_limits`. Forms with
+    /// more fields are rejected with `400 Bad Request` as soon as the extra
+    /// field is reached.
+    ///
+    /// If not set, the number of fields is not limited.
+    pub multipart_max_fields: Option<usize>,
+
     /// Whether to enable graceful shutdown of the server.
     /// If true, the server will finish processing ongoing requests
     /// before shutting down when a termination signal is received.
@@ -64,6 +107,14 @@ pub struct ApplicationConfig {
     #[serde(default = "default_graceful_shutdown")]
     pub graceful_shutdown: bool,
 
+    /// Optional deadline in seconds for the in-flight requests to finish
+    /// once a graceful shutdown starts.
+    /// If set, the requests still running when it expires are answered with
+    /// `503 Service Unavailable` and the server stops.
+    ///
+    /// If not set, the server waits for every request to finish.
+    pub graceful_shutdown_timeout_seconds: Option<u64>,
+
     /// Optional name of the application.
     /// This can be used for logging or display purposes.
     pub name: Option<String>,
@@ -96,10 +147,15 @@ impl ApplicationConfig {
 
         println!("Timeout: {}", timeout_display);
 
-        let shutdown_display = if self.graceful_shutdown {
-            "enabled".bright_green()
-        } else {
-            "disabled".bright_red()
+        let shutdown_display = match (
+            self.graceful_shutdown,
+            self.graceful_shutdown_timeout_seconds,
+        ) {
+            (true, Some(timeout)) => {
+                format!("enabled ({timeout} seconds timeout)").bright_green()
+            }
+            (true, None) => "enabled".bright_green(),
+            (false, _) => "disabled".bright_red(),
         };
 
         println!("Graceful Shutdown: {}", shutdown_display);
@@ -112,6 +168,19 @@ impl ApplicationConfig {
     }
 }
 
+/// The `body_limit_exceeded` behavior of the `[application]` section.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BodyLimitExceeded {
+    /// Reject the request with `413 Payload Too Large`.
+    #[default]
+    Reject,
+
+    /// Log a warning and handle the request without a body limit, which
+    /// leaves memory use per request unbounded.
+    Log,
+}
+
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct BodyLimit {
     pub raw: String,
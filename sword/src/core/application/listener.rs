@@ -0,0 +1,28 @@
+use std::io;
+
+use tokio::net::TcpListener;
+
+/// A TCP listener that is already bound and can be handed to
+/// `Application::serve_on_listener`.
+///
+/// Implemented for both `std::net::TcpListener`, as passed by socket
+/// activation or a parent process, and `tokio::net::TcpListener`.
+pub trait BoundListener {
+    /// Converts the listener into the Tokio listener the server accepts on.
+    fn into_tokio_listener(self) -> io::Result<TcpListener>;
+}
+
+impl BoundListener for TcpListener {
+    fn into_tokio_listener(self) -> io::Result<TcpListener> {
+        Ok(self)
+    }
+}
+
+impl BoundListener for std::net::TcpListener {
+    /// Tokio requires the socket to be in non-blocking mode, which socket
+    /// activation doesn't guarantee, so it is set here.
+    fn into_tokio_listener(self) -> io::Result<TcpListener> {
+        self.set_nonblocking(true)?;
+        TcpListener::from_std(self)
+    }
+}
@@ -1,15 +1,32 @@
 pub mod builder;
 mod config;
+mod listener;
+mod plugin;
+mod rewrite;
+mod routes;
+pub(crate) mod startup;
 
-pub use config::ApplicationConfig;
+pub use config::{ApplicationConfig, BodyLimitExceeded};
+pub use listener::BoundListener;
+pub use plugin::RoutesPlugin;
+pub use routes::RouteInfo;
 
-use axum::routing::Router;
-use axum_responses::http::HttpResponse;
-use tokio::net::TcpListener as Listener;
+use std::{future::IntoFuture, net::SocketAddr, sync::Arc, time::Duration};
+
+use axum::{
+    extract::connect_info::IntoMakeServiceWithConnectInfo,
+    middleware::from_fn_with_state as mw_with_state, routing::Router,
+};
+use tokio::{net::TcpListener as Listener, sync::oneshot};
 
 use crate::{
-    core::{application::builder::ApplicationBuilder, config::Config},
+    core::{
+        ShutdownCoordinator,
+        application::{builder::ApplicationBuilder, startup::Startup},
+        config::Config,
+    },
     errors::ApplicationError,
+    web::InFlightRequests,
 };
 
 /// The main application struct that holds the router and configuration.
@@ -20,6 +37,9 @@ use crate::{
 pub struct Application {
     router: Router,
     pub config: Config,
+    shutdown: Option<ShutdownCoordinator>,
+    startup: Arc<Startup>,
+    routes: Vec<RouteInfo>,
 }
 
 impl Application {
@@ -29,7 +49,9 @@ impl Application {
     /// The builder pattern allows you to configure various aspects of the
     /// application before building the final `Application` instance.
     ///
-    /// This function will panic if:
+    /// ### Errors
+    ///
+    /// This function will return an error if:
     /// - The configuration file `config/config.toml` cannot be found
     /// - The configuration file contains invalid TOML syntax
     /// - Environment variable interpolation fails
@@ -40,11 +62,11 @@ impl Application {
     /// ```rust,ignore
     /// use sword::prelude::*;
     ///
-    /// let app = Application::builder()
+    /// let app = Application::builder()?
     ///     .with_controller::<MyController>()
-    ///     .build();
+    ///     .build()?;
     /// ```
-    pub fn builder() -> ApplicationBuilder {
+    pub fn builder() -> Result<ApplicationBuilder, ApplicationError> {
         ApplicationBuilder::new()
     }
 
@@ -56,6 +78,8 @@ impl Application {
     ///
     /// If graceful shutdown is enabled in the configuration, it will handle
     /// termination signals and allow ongoing requests to complete before shutting down.
+    /// Background tasks registered in a `ShutdownCoordinator` are signaled
+    /// and awaited after the in-flight requests have drained.
     ///
     /// ### Errors
     ///
@@ -70,40 +94,29 @@ impl Application {
     /// use sword::prelude::*;
     ///
     /// #[sword::main]
-    /// async fn main() {
-    ///     let app = Application::builder()
+    /// async fn main() -> Result<(), ApplicationError> {
+    ///     let app = Application::builder()?
     ///         .with_controller::<MyController>()
-    ///         .build();
+    ///         .build()?;
     ///     
     ///     app.run().await;
+    ///
+    ///     Ok(())
     /// }
     /// ```
     pub async fn run(&self) {
-        if self
-            .config
-            .get::<ApplicationConfig>()
-            .expect("Failed to get application config")
-            .graceful_shutdown
-        {
-            self.run_with_graceful_shutdown(Self::graceful_signal())
-                .await;
-        }
-
         let listener = self.pre_run().await;
 
-        let router = self.router.clone().fallback(async || {
-            HttpResponse::NotFound().message("The requested resource was not found")
-        });
-
-        axum::serve(listener, router)
-            .await
-            .map_err(|e| ApplicationError::ServerError { source: e })
-            .expect("Internal server error");
+        self.serve_on_listener(listener).await;
     }
 
     /// Runs the application server with graceful shutdown support.
     /// Is similar to `run` but accepts a shutdown signal.
     ///
+    /// Once the signal fires and the in-flight requests have drained, the
+    /// tasks of the registered `ShutdownCoordinator`, if any, are signaled
+    /// and awaited before this method returns.
+    ///
     /// See [Axum's docs](https://docs.rs/axum/latest/axum/serve/struct.WithGracefulShutdown.html)
     /// to learn more about graceful shutdown.
     ///
@@ -129,13 +142,15 @@ impl Application {
     /// }
     ///
     /// #[sword::main]
-    /// async fn main() {
-    ///     let app = Application::builder()
+    /// async fn main() -> Result<(), ApplicationError> {
+    ///     let app = Application::builder()?
     ///         .with_controller::<AppController>()
     ///         .with_controller::<AdminController>()
-    ///         .build();
+    ///         .build()?;
     ///
     ///     app.run_with_graceful_shutdown(shutdown_signal()).await;
+    ///
+    ///     Ok(())
     /// }
     ///
     /// async fn shutdown_signal() {
@@ -167,17 +182,127 @@ impl Application {
     {
         let listener = self.pre_run().await;
 
-        let router = self.router.clone().fallback(async || {
-            HttpResponse::NotFound().message("The requested resource was not found")
-        });
+        self.serve_on_listener_with_graceful_shutdown(listener, signal)
+            .await;
+    }
 
-        axum::serve(listener, router)
-            .with_graceful_shutdown(signal)
+    /// Runs the application server on a listener that is already bound,
+    /// instead of binding to the host and port of the configuration.
+    ///
+    /// This enables socket-activated deployments, such as systemd passing
+    /// the socket to the process, zero-downtime handoffs, and tests that
+    /// bind to port `0` to get a free port. Both `std::net::TcpListener`
+    /// and `tokio::net::TcpListener` are accepted.
+    ///
+    /// It behaves like `run` otherwise: if graceful shutdown is enabled in
+    /// the configuration, termination signals are handled the same way.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// #[sword::main]
+    /// async fn main() -> Result<(), ApplicationError> {
+    ///     let app = Application::builder()?
+    ///         .with_controller::<MyController>()
+    ///         .build()?;
+    ///
+    ///     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    ///     println!("Listening on {}", listener.local_addr().unwrap());
+    ///
+    ///     app.serve_on_listener(listener).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn serve_on_listener<L: BoundListener>(&self, listener: L) {
+        let graceful_shutdown = self
+            .config
+            .get::<ApplicationConfig>()
+            .expect("Failed to get application config")
+            .graceful_shutdown;
+
+        if graceful_shutdown {
+            return self
+                .serve_on_listener_with_graceful_shutdown(
+                    listener,
+                    Self::graceful_signal(),
+                )
+                .await;
+        }
+
+        let serve = axum::serve(Self::into_listener(listener), self.make_service());
+
+        self.with_startup(serve.into_future())
             .await
             .map_err(|e| ApplicationError::ServerError { source: e })
             .expect("Internal server error");
     }
 
+    /// Runs the application server on a listener that is already bound,
+    /// with graceful shutdown support.
+    ///
+    /// This is the counterpart of `run_with_graceful_shutdown` for
+    /// `serve_on_listener`. The tasks of the registered `ShutdownCoordinator`,
+    /// if any, are signaled and awaited once the in-flight requests have
+    /// drained.
+    ///
+    /// If `graceful_shutdown_timeout_seconds` is set in the configuration,
+    /// the requests still running that long after the signal are answered
+    /// with `503 Service Unavailable`, and a warning with their count is
+    /// logged under the `sword::shutdown` target. This applies to every
+    /// graceful shutdown, including the one of `run`.
+    pub async fn serve_on_listener_with_graceful_shutdown<L, F>(
+        &self,
+        listener: L,
+        signal: F,
+    ) where
+        L: BoundListener,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let listener = Self::into_listener(listener);
+
+        let deadline = self
+            .config
+            .get::<ApplicationConfig>()
+            .ok()
+            .and_then(|config| config.graceful_shutdown_timeout_seconds)
+            .map(Duration::from_secs);
+
+        let serve = async {
+            match deadline {
+                Some(deadline) => {
+                    self.serve_with_shutdown_deadline(listener, signal, deadline)
+                        .await;
+                }
+                None => {
+                    axum::serve(listener, self.make_service())
+                        .with_graceful_shutdown(signal)
+                        .await
+                        .map_err(|e| ApplicationError::ServerError { source: e })
+                        .expect("Internal server error");
+                }
+            }
+        };
+
+        self.with_startup(serve).await;
+
+        if let Some(coordinator) = &self.shutdown {
+            coordinator.shutdown().await;
+        }
+    }
+
+    /// Runs the `on_startup` hooks and marks the application as ready for
+    /// the readiness probe.
+    ///
+    /// `run` and the `serve_on_listener` methods call it once the listener is
+    /// bound. Call it yourself when serving `router()` some other way, e.g.
+    /// in tests. Only the first call runs the hooks.
+    pub async fn startup(&self) {
+        self.startup.run().await;
+    }
+
     /// Returns a clone of the internal Axum router.
     ///
     /// This method provides access to the underlying Axum router for advanced
@@ -194,9 +319,9 @@ impl Application {
     /// ```rust,ignore
     /// use sword::prelude::*;
     ///
-    /// let app = Application::builder()
+    /// let app = Application::builder()?
     ///     .with_controller::<MyController>()
-    ///     .build();
+    ///     .build()?;
     ///
     /// let router = app.router();
     /// // Use router for testing or other purposes
@@ -205,6 +330,128 @@ impl Application {
         self.router.clone()
     }
 
+    /// Lists the routes of the registered controllers, sorted by path and
+    /// method.
+    ///
+    /// Paths include the global prefix and the controller base path. Routes
+    /// mounted with `with_router` or plugins, and routes whose `#[feature]`
+    /// flag is disabled, are not listed.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// let app = Application::builder()?
+    ///     .with_prefix("/api/v1")
+    ///     .with_controller::<UsersController>()
+    ///     .build()?;
+    ///
+    /// for route in app.routes() {
+    ///     println!("{} {} ({})", route.method, route.path, route.controller);
+    /// }
+    /// ```
+    pub fn routes(&self) -> Vec<RouteInfo> {
+        self.routes.clone()
+    }
+
+    /// Builds the OpenAPI document of the routes listed by `routes`.
+    ///
+    /// It has the paths, methods and path parameters of the routes. Add
+    /// request bodies and query parameters with `Spec::request_body` and
+    /// `Spec::query`, or serve it with `ApplicationBuilder::with_openapi_route`.
+    #[cfg(feature = "openapi")]
+    pub fn openapi_spec(&self) -> crate::web::openapi::Spec {
+        crate::web::openapi::Spec::from_routes(&self.routes)
+    }
+
+    /// Drives `serve` while the startup hooks run, stopping them if the
+    /// server exits first.
+    async fn with_startup<F: Future>(&self, serve: F) -> F::Output {
+        let startup = async {
+            self.startup().await;
+            std::future::pending::<()>().await;
+        };
+
+        tokio::select! {
+            output = serve => output,
+            () = startup => unreachable!("the startup future never completes"),
+        }
+    }
+
+    /// Serves the router with the peer address of each connection, which
+    /// is available to handlers and layers as `ConnectInfo<SocketAddr>`.
+    fn make_service(&self) -> IntoMakeServiceWithConnectInfo<Router, SocketAddr> {
+        self.router
+            .clone()
+            .into_make_service_with_connect_info::<SocketAddr>()
+    }
+
+    /// Serves with graceful shutdown, force-closing the requests that are
+    /// still running once `deadline` has elapsed since the signal.
+    async fn serve_with_shutdown_deadline<F>(
+        &self,
+        listener: Listener,
+        signal: F,
+        deadline: Duration,
+    ) where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let in_flight = InFlightRequests::new();
+
+        let service = self
+            .router
+            .clone()
+            .layer(mw_with_state(in_flight.clone(), InFlightRequests::layer))
+            .into_make_service_with_connect_info::<SocketAddr>();
+
+        let (signaled, on_signal) = oneshot::channel::<()>();
+
+        let signal = async move {
+            signal.await;
+            let _ = signaled.send(());
+        };
+
+        let server = axum::serve(listener, service)
+            .with_graceful_shutdown(signal)
+            .into_future();
+
+        let expired = async move {
+            // The sender is only dropped without sending if the server
+            // stopped before the signal, in which case `server` wins.
+            if on_signal.await.is_err() {
+                std::future::pending::<()>().await;
+            }
+
+            tokio::time::sleep(deadline).await;
+        };
+
+        tokio::select! {
+            result = server => {
+                result
+                    .map_err(|e| ApplicationError::ServerError { source: e })
+                    .expect("Internal server error");
+            }
+            () = expired => {
+                let connections = in_flight.force_close();
+
+                tracing::warn!(
+                    target: "sword::shutdown",
+                    connections,
+                    timeout_seconds = deadline.as_secs(),
+                    "Graceful shutdown timed out, force-closing connections"
+                );
+            }
+        }
+    }
+
+    fn into_listener<L: BoundListener>(listener: L) -> Listener {
+        listener
+            .into_tokio_listener()
+            .map_err(|e| ApplicationError::ServerError { source: e })
+            .expect("Failed to use the given listener")
+    }
+
     async fn pre_run(&self) -> Listener {
         let config = self
             .config
@@ -0,0 +1,64 @@
+use crate::{
+    core::{Config, application::builder::ApplicationBuilder},
+    errors::ConfigError,
+};
+
+/// A reusable bundle of controllers, layers and state that can be
+/// registered in an application with `ApplicationBuilder::with_plugin`.
+///
+/// Plugins let a library ship a set of endpoints, such as an admin
+/// dashboard or a health check suite, without the application having to
+/// know which controllers or providers they are made of. `register` receives
+/// the builder and uses the same methods an application would, so the
+/// controllers of a plugin are checked for route conflicts like any other,
+/// while routers it mounts with `with_router` are not.
+///
+/// Configuration the plugin depends on is declared in `validate_config`,
+/// which runs when the application is built, so a missing or invalid section
+/// fails `build()` instead of the first request that needs it.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// use sword::prelude::*;
+///
+/// #[config(key = "admin")]
+/// struct AdminConfig {
+///     title: String,
+/// }
+///
+/// struct AdminPlugin;
+///
+/// impl RoutesPlugin for AdminPlugin {
+///     fn validate_config(config: &Config) -> Result<(), ConfigError> {
+///         config.get::<AdminConfig>().map(|_| ())
+///     }
+///
+///     fn register(self, app: ApplicationBuilder) -> ApplicationBuilder {
+///         let container = DependencyContainer::builder()
+///             .register_provider(AuditLog::new());
+///
+///         app.with_dependency_container(container)
+///             .with_controller::<AdminUsersController>()
+///             .with_controller::<AdminStatsController>()
+///     }
+/// }
+///
+/// let app = Application::builder()?
+///     .with_plugin(AdminPlugin)
+///     .build()?;
+/// ```
+pub trait RoutesPlugin: Sized {
+    /// Checks the configuration sections the plugin depends on.
+    ///
+    /// Called by `ApplicationBuilder::build`, which fails with
+    /// `ApplicationError::PluginConfigError` if it returns an error.
+    /// Does nothing by default.
+    fn validate_config(config: &Config) -> Result<(), ConfigError> {
+        let _ = config;
+        Ok(())
+    }
+
+    /// Registers the controllers, layers and state of the plugin.
+    fn register(self, app: ApplicationBuilder) -> ApplicationBuilder;
+}
@@ -0,0 +1,67 @@
+use axum::{extract::Request as AxumRequest, http::Uri};
+
+/// Path rewrite rules applied to incoming requests before routing.
+///
+/// Patterns use the same syntax as route paths (`/users/{id}`,
+/// `/assets/{*rest}`) and captured segments can be reused in the target
+/// path with the same `{name}` placeholders.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PathRewrites {
+    rules: matchit::Router<String>,
+    len: usize,
+}
+
+impl PathRewrites {
+    pub fn insert(
+        &mut self,
+        from: &str,
+        to: &str,
+    ) -> Result<(), matchit::InsertError> {
+        self.rules.insert(from, to.to_string())?;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn rewrite(&self, mut request: AxumRequest) -> AxumRequest {
+        let Some(path) = self.resolve(request.uri().path()) else {
+            return request;
+        };
+
+        let path_and_query = match request.uri().query() {
+            Some(query) => format!("{path}?{query}"),
+            None => path,
+        };
+
+        let mut parts = request.uri().clone().into_parts();
+
+        let Ok(path_and_query) = path_and_query.parse() else {
+            return request;
+        };
+
+        parts.path_and_query = Some(path_and_query);
+
+        if let Ok(uri) = Uri::from_parts(parts) {
+            *request.uri_mut() = uri;
+        }
+
+        request
+    }
+
+    fn resolve(&self, path: &str) -> Option<String> {
+        let matched = self.rules.at(path).ok()?;
+        let mut target = matched.value.clone();
+
+        for (key, value) in matched.params.iter() {
+            target = target
+                .replace(&format!("{{*{key}}}"), value)
+                .replace(&format!("{{{key}}}"), value);
+        }
+
+        Some(target)
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use axum::http::Method;
+
+use crate::{errors::ApplicationError, web::controller::join_paths};
+
+/// A route registered by a controller, as listed by `Application::routes`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteInfo {
+    pub method: Method,
+
+    /// The full path of the route, including the global prefix and the base
+    /// path of the controller, e.g. `/api/v1/users/{id}`.
+    pub path: String,
+
+    /// The type name of the controller, e.g. `my_app::users::UsersController`.
+    pub controller: &'static str,
+
+    /// The `#[tag]` of the route, or of its controller if the route has none.
+    pub tag: Option<&'static str>,
+}
+
+/// The routes registered by controllers, checked for conflicts before the
+/// controller router is merged, which would otherwise panic inside Axum.
+///
+/// Paths are inserted in a `matchit` router, the one Axum routes with, so a
+/// conflict is reported exactly when Axum would reject the route.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RegisteredRoutes {
+    paths: matchit::Router<()>,
+    owners: HashMap<String, &'static str>,
+    methods: HashMap<(String, String), &'static str>,
+    tags: HashMap<(String, String), &'static str>,
+}
+
+impl RegisteredRoutes {
+    pub fn register(
+        &mut self,
+        controller: &'static str,
+        method: &str,
+        path: String,
+        tag: Option<&'static str>,
+    ) -> Result<(), ApplicationError> {
+        let route = format!("{method} {path}");
+
+        match self.paths.insert(path.as_str(), ()) {
+            Ok(()) => {
+                self.owners.insert(path.clone(), controller);
+            }
+
+            // The same path with another method is merged by Axum, so only
+            // the method has to be checked.
+            Err(matchit::InsertError::Conflict { with }) if with == path => {}
+
+            Err(matchit::InsertError::Conflict { with }) => {
+                let existing = self.owners.get(&with).copied().unwrap_or_default();
+
+                return Err(ApplicationError::RouteConflict {
+                    route,
+                    controller,
+                    conflicts_with: with,
+                    existing,
+                });
+            }
+
+            // The route macros reject invalid paths, but a route can still be
+            // invalid once joined to its base path, e.g. below a catch-all.
+            Err(error) => {
+                return Err(ApplicationError::InvalidRoute {
+                    route,
+                    controller,
+                    reason: error.to_string(),
+                });
+            }
+        }
+
+        let key = (method.to_string(), path);
+
+        if let Some(existing) = self.methods.get(&key) {
+            return Err(ApplicationError::RouteConflict {
+                route,
+                controller,
+                conflicts_with: format!("{} {}", key.0, key.1),
+                existing,
+            });
+        }
+
+        if let Some(tag) = tag {
+            self.tags.insert(key.clone(), tag);
+        }
+
+        self.methods.insert(key, controller);
+
+        Ok(())
+    }
+
+    /// Every registered path with the methods it declares, sorted.
+    pub fn methods_by_path(&self) -> HashMap<&str, Vec<&str>> {
+        let mut methods: HashMap<&str, Vec<&str>> = HashMap::new();
+
+        for (method, path) in self.methods.keys() {
+            methods.entry(path).or_default().push(method);
+        }
+
+        for methods in methods.values_mut() {
+            methods.sort_unstable();
+        }
+
+        methods
+    }
+
+    /// The paths that declare `GET` but not `HEAD`, with the methods they
+    /// declare, sorted.
+    pub fn get_without_head(&self) -> HashMap<&str, Vec<&str>> {
+        let mut methods = self.methods_by_path();
+
+        methods.retain(|_, methods| {
+            methods.contains(&"GET") && !methods.contains(&"HEAD")
+        });
+
+        methods
+    }
+
+    /// Every registered route, with the global prefix added to its path,
+    /// sorted by path and method.
+    pub fn infos(&self, prefix: Option<&str>) -> Vec<RouteInfo> {
+        let mut infos: Vec<RouteInfo> = self
+            .methods
+            .iter()
+            .filter_map(|(key, controller)| {
+                let (method, path) = key;
+
+                Some(RouteInfo {
+                    method: method.parse().ok()?,
+                    path: match prefix {
+                        Some(prefix) => join_paths(prefix, path),
+                        None => path.clone(),
+                    },
+                    controller,
+                    tag: self.tags.get(key).copied(),
+                })
+            })
+            .collect();
+
+        infos.sort_by(|a, b| {
+            (a.path.as_str(), a.method.as_str())
+                .cmp(&(b.path.as_str(), b.method.as_str()))
+        });
+
+        infos
+    }
+}
@@ -0,0 +1,70 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use crate::core::State;
+
+type Hook = dyn Fn(State) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync;
+
+/// A hook registered with `ApplicationBuilder::on_startup`.
+#[derive(Clone)]
+pub(crate) struct StartupHook(Arc<Hook>);
+
+impl StartupHook {
+    pub fn new<F, Fut>(hook: F) -> Self
+    where
+        F: Fn(State) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self(Arc::new(move |state| Box::pin(hook(state))))
+    }
+}
+
+impl std::fmt::Debug for StartupHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StartupHook")
+    }
+}
+
+/// The startup hooks of an application and whether they have completed,
+/// shared with the readiness probe.
+pub(crate) struct Startup {
+    state: State,
+    hooks: Vec<StartupHook>,
+    started: AtomicBool,
+    ready: AtomicBool,
+}
+
+impl Startup {
+    pub fn new(state: State, hooks: Vec<StartupHook>) -> Self {
+        Self {
+            state,
+            hooks,
+            started: AtomicBool::new(false),
+            ready: AtomicBool::new(false),
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::Acquire)
+    }
+
+    /// Runs the hooks one after another, in registration order, and marks
+    /// the application as ready. Only the first call runs them.
+    pub async fn run(&self) {
+        if self.started.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        for StartupHook(hook) in &self.hooks {
+            hook(self.state.clone()).await;
+        }
+
+        self.ready.store(true, Ordering::Release);
+    }
+}
@@ -1,7 +1,8 @@
 use super::utils;
 use serde::de::{DeserializeOwned, IntoDeserializer};
+use serde_json::Value as JsonValue;
 use std::{fs::read_to_string, path::Path, str::FromStr, sync::Arc};
-use toml::Table;
+use toml::{Table, Value as TomlValue};
 
 pub use sword_macros::config;
 
@@ -27,38 +28,110 @@ pub struct Config {
 ///     value: String,
 /// }
 /// ```
+///
+/// Add `default` to fall back to `Default::default()` when the section is
+/// absent. A section that is present but invalid still fails:
+///
+/// ```rust,ignore
+/// #[derive(Default, Deserialize)]
+/// #[config(key = "cache", default)]
+/// struct CacheConfig {
+///     ttl_seconds: u64,
+/// }
+/// ```
 pub trait ConfigItem {
     /// Returns the TOML section key for this configuration type.
     fn toml_key() -> &'static str;
+
+    /// Returns the value used when the section is absent from the
+    /// configuration, or `None` to make `Config::get` fail with
+    /// `ConfigError::KeyNotFound`.
+    ///
+    /// `#[config(key = "...", default)]` implements it with `Default`.
+    fn default_value() -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
+const DEFAULT_PATH: &str = "config/config.toml";
+
+/// Reads the file at `path`, or the default configuration file when it's
+/// `None`.
+fn read_default(path: Option<String>) -> Result<String, ConfigError> {
+    if let Some(path) = path {
+        return read_path(Path::new(&path));
+    }
+
+    let path = Path::new(DEFAULT_PATH);
+
+    if path.exists() {
+        return read_to_string(path).map_err(ConfigError::ReadError);
+    }
+
+    let not_found = || ConfigError::FileNotFound(DEFAULT_PATH.to_string());
+
+    let exe_path = std::env::current_exe().map_err(|_| not_found())?;
+    let exe_dir = exe_path.parent().ok_or_else(not_found)?;
+
+    let fallback_path = exe_dir.join(DEFAULT_PATH);
+
+    if fallback_path.exists() {
+        read_to_string(fallback_path).map_err(ConfigError::ReadError)
+    } else {
+        Err(not_found())
+    }
+}
+
+fn read_path(path: &Path) -> Result<String, ConfigError> {
+    if !path.is_file() {
+        return Err(ConfigError::FileNotFound(path.display().to_string()));
+    }
+
+    read_to_string(path).map_err(ConfigError::ReadError)
+}
+
+/// Environment variable that overrides the default configuration path.
+const CONFIG_PATH_ENV: &str = "SWORD_CONFIG";
+
 impl Config {
+    /// Loads the file named by `SWORD_CONFIG`, or `config/config.toml`
+    /// relative to the working directory or, failing that, the executable.
     pub(crate) fn new() -> Result<Self, ConfigError> {
-        let path = Path::new("config/config.toml");
+        let content = read_default(std::env::var(CONFIG_PATH_ENV).ok())?;
 
-        let content = if path.exists() {
-            read_to_string(path).map_err(ConfigError::ReadError)?
-        } else {
-            let exe_path = std::env::current_exe()
-                .map_err(|_| ConfigError::FileNotFound("config/config.toml"))?;
+        Self::parse(&content)
+    }
 
-            let exe_dir = exe_path
-                .parent()
-                .ok_or(ConfigError::FileNotFound("config/config.toml"))?;
+    /// Loads the configuration like `new`, reading the variables the
+    /// environment doesn't set from the `.env` file of the working directory.
+    #[cfg(feature = "dotenv")]
+    pub(crate) fn with_dotenv() -> Result<Self, ConfigError> {
+        let dotenv = super::dotenv::DotEnv::load()?;
+        let content = read_default(dotenv.var(CONFIG_PATH_ENV))?;
 
-            let fallback_path = exe_dir.join("config/config.toml");
+        let expanded = utils::expand_vars(&content, |name| dotenv.var(name))
+            .map_err(ConfigError::InterpolationError)?;
 
-            if fallback_path.exists() {
-                read_to_string(fallback_path).map_err(ConfigError::ReadError)?
-            } else {
-                return Err(ConfigError::FileNotFound("config/config.toml"));
-            }
-        };
+        Self::from_expanded(&expanded)
+    }
+
+    /// Loads the file at `path`, without falling back to other locations.
+    pub(crate) fn from_path(path: &Path) -> Result<Self, ConfigError> {
+        Self::parse(&read_path(path)?)
+    }
 
-        let expanded = utils::expand_env_vars(&content)
+    fn parse(content: &str) -> Result<Self, ConfigError> {
+        let expanded = utils::expand_env_vars(content)
             .map_err(ConfigError::InterpolationError)?;
 
-        let table = Table::from_str(&expanded)
+        Self::from_expanded(&expanded)
+    }
+
+    fn from_expanded(expanded: &str) -> Result<Self, ConfigError> {
+        let table = Table::from_str(expanded)
             .map_err(|e| ConfigError::ParseError(e.to_string()))?;
 
         Ok(Self {
@@ -100,7 +173,8 @@ impl Config {
     /// ```
     pub fn get<T: DeserializeOwned + ConfigItem>(&self) -> Result<T, ConfigError> {
         let Some(config_item) = self.inner.get(T::toml_key()) else {
-            return Err(ConfigError::KeyNotFound(T::toml_key().to_string()));
+            return T::default_value()
+                .ok_or_else(|| ConfigError::KeyNotFound(T::toml_key().to_string()));
         };
 
         let value = toml::Value::into_deserializer(config_item.clone());
@@ -108,6 +182,62 @@ impl Config {
         T::deserialize(value)
             .map_err(|e| ConfigError::DeserializeError(e.to_string()))
     }
+
+    /// Looks up a configuration value by its dotted key, without a typed
+    /// struct.
+    ///
+    /// Each segment of the key names a table entry, or an array index, e.g.
+    /// `"database.replicas.0.host"`. Keys that themselves contain dots can't
+    /// be reached this way. The value is returned after environment variable
+    /// interpolation, and TOML dates are returned as strings.
+    ///
+    /// ### Returns
+    ///
+    /// `Some(Value)` with the value at the key, whole tables included, or
+    /// `None` if there is nothing at the key.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// // [database]
+    /// // host = "${DB_HOST:localhost}"
+    ///
+    /// let host = config.get_value("database.host");
+    /// assert_eq!(host, Some(serde_json::json!("localhost")));
+    /// ```
+    pub fn get_value(&self, key: &str) -> Option<JsonValue> {
+        let mut segments = key.split('.');
+        let mut value = self.inner.get(segments.next()?)?;
+
+        for segment in segments {
+            value = match value {
+                TomlValue::Table(table) => table.get(segment)?,
+                TomlValue::Array(items) => {
+                    items.get(segment.parse::<usize>().ok()?)?
+                }
+                _ => return None,
+            };
+        }
+
+        Some(toml_to_json(value))
+    }
+}
+
+fn toml_to_json(value: &TomlValue) -> JsonValue {
+    match value {
+        TomlValue::String(string) => JsonValue::from(string.as_str()),
+        TomlValue::Integer(integer) => JsonValue::from(*integer),
+        TomlValue::Float(float) => JsonValue::from(*float),
+        TomlValue::Boolean(boolean) => JsonValue::from(*boolean),
+        TomlValue::Datetime(datetime) => JsonValue::from(datetime.to_string()),
+        TomlValue::Array(items) => items.iter().map(toml_to_json).collect(),
+        TomlValue::Table(table) => JsonValue::Object(
+            table
+                .iter()
+                .map(|(key, value)| (key.clone(), toml_to_json(value)))
+                .collect(),
+        ),
+    }
 }
 
 impl Default for Config {
@@ -51,6 +51,7 @@ pub struct DependencyContainer {
     pub(crate) instances: HashMap<TypeId, Dependency>,
     pub(crate) dependency_builders: HashMap<TypeId, DependencyBuilder>,
     pub(crate) dependency_graph: HashMap<TypeId, Vec<TypeId>>,
+    pub(crate) type_names: HashMap<TypeId, &'static str>,
 }
 
 impl DependencyContainer {
@@ -59,6 +60,7 @@ impl DependencyContainer {
             instances: HashMap::new(),
             dependency_builders: HashMap::new(),
             dependency_graph: HashMap::new(),
+            type_names: HashMap::new(),
         }
     }
 
@@ -82,6 +84,7 @@ impl DependencyContainer {
 
         self.dependency_graph.insert(type_id, T::dependencies());
         self.dependency_builders.insert(type_id, dependency_builder);
+        self.type_names.insert(type_id, type_name);
 
         self
     }
@@ -91,7 +94,10 @@ impl DependencyContainer {
     where
         T: Provider,
     {
-        self.instances.insert(TypeId::of::<T>(), Arc::new(provider));
+        let type_id = TypeId::of::<T>();
+
+        self.instances.insert(type_id, Arc::new(provider));
+        self.type_names.insert(type_id, std::any::type_name::<T>());
         self
     }
 
@@ -99,6 +105,10 @@ impl DependencyContainer {
         self
     }
 
+    fn type_name(&self, type_id: &TypeId) -> &'static str {
+        self.type_names.get(type_id).copied().unwrap_or("<unknown>")
+    }
+
     pub(crate) fn build_all(
         &self,
         state: &State,
@@ -109,7 +119,11 @@ impl DependencyContainer {
 
         for (type_id, instance) in &self.instances {
             state
-                .insert_dependency(*type_id, instance.clone())
+                .insert_dependency(
+                    *type_id,
+                    self.type_name(type_id),
+                    instance.clone(),
+                )
                 .map_err(|e| DependencyInjectionError::StateError {
                     type_name: format!("{:?}", type_id),
                     source: e,
@@ -151,7 +165,11 @@ impl DependencyContainer {
 
         if let Some(builder) = self.dependency_builders.get(type_id) {
             state
-                .insert_dependency(*type_id, builder(state)?)
+                .insert_dependency(
+                    *type_id,
+                    self.type_name(type_id),
+                    builder(state)?,
+                )
                 .map_err(|e| DependencyInjectionError::StateError {
                     type_name: format!("{:?}", type_id),
                     source: e,
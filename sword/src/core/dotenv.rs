@@ -0,0 +1,73 @@
+use std::{collections::HashMap, fs::read_to_string, io::ErrorKind, path::Path};
+
+use crate::errors::ConfigError;
+
+const DEFAULT_PATH: &str = ".env";
+
+/// The variables of a `.env` file, used to interpolate the configuration
+/// when they aren't set in the environment.
+#[derive(Debug, Default)]
+pub(crate) struct DotEnv {
+    vars: HashMap<String, String>,
+}
+
+impl DotEnv {
+    /// Reads the `.env` file of the working directory. A missing file reads
+    /// as an empty one.
+    pub fn load() -> Result<Self, ConfigError> {
+        match read_to_string(Path::new(DEFAULT_PATH)) {
+            Ok(content) => Self::parse(&content),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(ConfigError::ReadError(e)),
+        }
+    }
+
+    /// Looks `name` up in the environment, then in the `.env` file, so that
+    /// real environment variables take precedence.
+    pub fn var(&self, name: &str) -> Option<String> {
+        std::env::var(name)
+            .ok()
+            .or_else(|| self.vars.get(name).cloned())
+    }
+
+    /// Parses `KEY=value` lines, optionally prefixed with `export` and with
+    /// the value in single or double quotes. Blank lines and lines starting
+    /// with `#` are skipped.
+    fn parse(content: &str) -> Result<Self, ConfigError> {
+        let mut vars = HashMap::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let line = line.strip_prefix("export ").unwrap_or(line);
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(ConfigError::ParseError(format!(
+                    "Invalid .env line {}: expected KEY=value",
+                    index + 1
+                )));
+            };
+
+            vars.insert(key.trim().to_string(), unquote(value.trim()).to_string());
+        }
+
+        Ok(Self { vars })
+    }
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|value| value.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+
+    value
+}
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::ConfigItem;
+
+/// Feature flags loaded from the `[features]` section of the configuration.
+///
+/// Each entry is either a boolean toggle or a percentage rollout. Rollouts
+/// are resolved against a stable key (e.g. a user id), so the same key always
+/// gets the same answer for a given flag.
+///
+/// ```toml,ignore
+/// [features]
+/// new_checkout = true
+/// beta_search = { percentage = 50 }
+/// ```
+///
+/// Flags are usually queried through `Context::feature_enabled` and
+/// `Context::feature_enabled_for`. Unknown flags are always disabled.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct FeatureFlags {
+    flags: HashMap<String, FeatureFlag>,
+}
+
+/// A single entry of the `[features]` section.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum FeatureFlag {
+    /// Enabled or disabled for everyone.
+    Toggle(bool),
+
+    /// Enabled for the given percentage (0-100) of keys.
+    Rollout { percentage: u8 },
+}
+
+impl FeatureFlags {
+    /// Checks whether a flag is enabled regardless of any key.
+    ///
+    /// Percentage rollouts are only considered enabled here when they
+    /// cover 100% of keys. Use `is_enabled_for` to resolve them per key.
+    pub fn is_enabled(&self, name: &str) -> bool {
+        match self.flags.get(name) {
+            Some(FeatureFlag::Toggle(enabled)) => *enabled,
+            Some(FeatureFlag::Rollout { percentage }) => *percentage >= 100,
+            None => false,
+        }
+    }
+
+    /// Checks whether a flag is enabled for the given stable key.
+    ///
+    /// For percentage rollouts the flag name and key are hashed into one
+    /// of 100 buckets, so the result is stable across requests, restarts
+    /// and machines, and different flags roll out to different keys.
+    pub fn is_enabled_for(&self, name: &str, key: &str) -> bool {
+        match self.flags.get(name) {
+            Some(FeatureFlag::Toggle(enabled)) => *enabled,
+            Some(FeatureFlag::Rollout { percentage }) => {
+                rollout_bucket(name, key) < u64::from(*percentage)
+            }
+            None => false,
+        }
+    }
+}
+
+impl ConfigItem for FeatureFlags {
+    fn toml_key() -> &'static str {
+        "features"
+    }
+}
+
+/// FNV-1a is used instead of `DefaultHasher`, whose output is not
+/// guaranteed to be stable between Rust releases.
+fn rollout_bucket(name: &str, key: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let bytes = name.bytes().chain(std::iter::once(b':')).chain(key.bytes());
+
+    let hash = bytes.fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    });
+
+    hash % 100
+}
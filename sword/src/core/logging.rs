@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use tracing::Subscriber;
+use tracing_subscriber::{EnvFilter, fmt::MakeWriter};
+
+use crate::core::ConfigItem;
+
+/// Logging settings loaded from the `[logging]` section of the configuration.
+///
+/// When the section is present, `Application::builder` installs a global
+/// `tracing` subscriber with the chosen format. If a subscriber was already
+/// set, globally or scoped to the current thread with
+/// `tracing::subscriber::with_default`, for example by the user before
+/// building the application, it is left untouched.
+///
+/// ```toml,ignore
+/// [logging]
+/// format = "${LOG_FORMAT:pretty}"
+/// filter = "info,sword=debug"
+/// ```
+///
+/// Environment interpolation makes it easy to keep readable logs in
+/// development and switch to JSON in production.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct LoggingConfig {
+    /// The output format of the log lines.
+    /// Defaults to `pretty`.
+    #[serde(default)]
+    pub format: LogFormat,
+
+    /// Optional `EnvFilter` directives, such as `"info,sword=debug"`.
+    /// If not set, `RUST_LOG` is used, falling back to `info`.
+    pub filter: Option<String>,
+}
+
+/// The output format of the subscriber installed from `LoggingConfig`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Multi-line, human-readable output for local development.
+    #[default]
+    Pretty,
+
+    /// One JSON object per line, for log aggregation.
+    Json,
+}
+
+impl LoggingConfig {
+    /// Installs the configured subscriber as the global default.
+    ///
+    /// ### Returns
+    ///
+    /// `true` if the subscriber was installed, `false` if a global
+    /// subscriber or one scoped to the current thread was already set.
+    pub fn init(&self) -> bool {
+        let has_subscriber = tracing::dispatcher::get_default(|dispatch| {
+            !dispatch.is::<tracing::subscriber::NoSubscriber>()
+        });
+
+        !has_subscriber
+            && tracing::subscriber::set_global_default(self.subscriber()).is_ok()
+    }
+
+    /// Builds the configured subscriber writing to stdout, without
+    /// installing it.
+    pub fn subscriber(&self) -> Box<dyn Subscriber + Send + Sync> {
+        self.subscriber_with_writer(std::io::stdout)
+    }
+
+    /// Builds the configured subscriber writing to the given writer,
+    /// without installing it.
+    ///
+    /// Useful to scope the subscriber with `tracing::subscriber::set_default`
+    /// or to redirect the output, e.g. in tests.
+    pub fn subscriber_with_writer<W>(
+        &self,
+        writer: W,
+    ) -> Box<dyn Subscriber + Send + Sync>
+    where
+        W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+    {
+        let builder = tracing_subscriber::fmt()
+            .with_env_filter(self.env_filter())
+            .with_writer(writer);
+
+        match self.format {
+            LogFormat::Pretty => Box::new(builder.pretty().finish()),
+            LogFormat::Json => Box::new(builder.json().finish()),
+        }
+    }
+
+    fn env_filter(&self) -> EnvFilter {
+        match &self.filter {
+            Some(directives) => EnvFilter::new(directives),
+            None => EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| EnvFilter::new("info")),
+        }
+    }
+}
+
+impl ConfigItem for LoggingConfig {
+    fn toml_key() -> &'static str {
+        "logging"
+    }
+}
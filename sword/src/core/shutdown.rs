@@ -0,0 +1,190 @@
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::{
+    sync::watch,
+    task::JoinHandle,
+    time::{Instant, timeout_at},
+};
+
+/// Coordinates the shutdown of background work with the HTTP server.
+///
+/// Tasks spawned through the coordinator receive a `ShutdownSignal`. When
+/// the application shuts down gracefully, the order is always:
+///
+/// 1. The server stops accepting new connections and drains in-flight requests.
+/// 2. Every registered task is signaled.
+/// 3. The coordinator waits for the tasks, up to the optional timeout,
+///    and aborts the ones still running after it.
+///
+/// Register it with `ApplicationBuilder::with_shutdown_coordinator`. It is
+/// also stored in the application state, so handlers can spawn tasks with
+/// `ctx.di::<ShutdownCoordinator>()`.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// use sword::prelude::*;
+///
+/// let coordinator = ShutdownCoordinator::new().with_timeout(Duration::from_secs(10));
+///
+/// coordinator.spawn("cache-flusher", |mut signal| async move {
+///     loop {
+///         tokio::select! {
+///             _ = signal.wait() => break,
+///             _ = tokio::time::sleep(Duration::from_secs(60)) => flush_cache().await,
+///         }
+///     }
+///
+///     flush_cache().await;
+/// });
+///
+/// let app = Application::builder()?
+///     .with_shutdown_coordinator(coordinator)
+///     .build()?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShutdownCoordinator {
+    sender: Arc<watch::Sender<bool>>,
+    tasks: Arc<Mutex<Vec<BackgroundTask>>>,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug)]
+struct BackgroundTask {
+    name: String,
+    handle: JoinHandle<()>,
+}
+
+/// Signal received by background tasks registered in a `ShutdownCoordinator`.
+#[derive(Clone, Debug)]
+pub struct ShutdownSignal {
+    receiver: watch::Receiver<bool>,
+}
+
+/// Outcome of `ShutdownCoordinator::shutdown`.
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownReport {
+    /// Names of the tasks that finished before the timeout.
+    pub completed: Vec<String>,
+
+    /// Names of the tasks that were aborted after the timeout.
+    pub aborted: Vec<String>,
+}
+
+impl ShutdownCoordinator {
+    pub fn new() -> Self {
+        let (sender, _) = watch::channel(false);
+
+        Self {
+            sender: Arc::new(sender),
+            tasks: Arc::new(Mutex::new(Vec::new())),
+            timeout: None,
+        }
+    }
+
+    /// Sets the maximum time to wait for background tasks after they
+    /// have been signaled. Without a timeout the coordinator waits for
+    /// every task to finish.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns a new signal bound to this coordinator.
+    pub fn signal(&self) -> ShutdownSignal {
+        ShutdownSignal {
+            receiver: self.sender.subscribe(),
+        }
+    }
+
+    /// Spawns a background task that will be signaled and awaited on shutdown.
+    ///
+    /// ### Arguments
+    /// * `name` - A name used to report the task on shutdown.
+    /// * `task` - A closure receiving the `ShutdownSignal` and returning the task future.
+    pub fn spawn<F, Fut>(&self, name: impl Into<String>, task: F)
+    where
+        F: FnOnce(ShutdownSignal) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handle = tokio::spawn(task(self.signal()));
+
+        if let Ok(mut tasks) = self.tasks.lock() {
+            tasks.push(BackgroundTask {
+                name: name.into(),
+                handle,
+            });
+        }
+    }
+
+    /// Checks whether the shutdown has already been triggered.
+    pub fn is_shutting_down(&self) -> bool {
+        *self.sender.borrow()
+    }
+
+    /// Signals every registered task and waits for them to finish.
+    ///
+    /// This is called by `Application` once the HTTP server has drained,
+    /// but it can also be called manually when the server is run by other
+    /// means.
+    pub async fn shutdown(&self) -> ShutdownReport {
+        self.sender.send_replace(true);
+
+        let tasks = match self.tasks.lock() {
+            Ok(mut tasks) => std::mem::take(&mut *tasks),
+            Err(_) => Vec::new(),
+        };
+
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let mut report = ShutdownReport::default();
+
+        for BackgroundTask { name, mut handle } in tasks {
+            let finished = match deadline {
+                Some(deadline) => timeout_at(deadline, &mut handle).await.is_ok(),
+                None => {
+                    let _ = (&mut handle).await;
+                    true
+                }
+            };
+
+            if finished {
+                report.completed.push(name);
+            } else {
+                handle.abort();
+                report.aborted.push(name);
+            }
+        }
+
+        if !report.aborted.is_empty() {
+            eprintln!(
+                "Shutdown timeout reached, aborted background tasks: {}",
+                report.aborted.join(", ")
+            );
+        }
+
+        report
+    }
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownSignal {
+    /// Waits until the shutdown is triggered. Resolves immediately if it
+    /// already was.
+    pub async fn wait(&mut self) {
+        let _ = self.receiver.wait_for(|shutting_down| *shutting_down).await;
+    }
+
+    /// Checks whether the shutdown has been triggered.
+    pub fn is_shutdown(&self) -> bool {
+        *self.receiver.borrow()
+    }
+}
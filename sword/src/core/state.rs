@@ -1,7 +1,7 @@
 use std::{
     any::{Any, TypeId},
     collections::HashMap,
-    sync::{Arc, RwLock},
+    sync::{Arc, Mutex, PoisonError, RwLock},
 };
 
 use crate::errors::StateError;
@@ -15,13 +15,34 @@ use crate::errors::StateError;
 /// ```
 #[derive(Clone, Debug)]
 pub struct State {
-    inner: Arc<RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>>,
+    inner: Arc<RwLock<HashMap<TypeId, Entry>>>,
+
+    /// A lock per type initialized by `get_or_insert_with`, so each type is
+    /// initialized once without holding the lock of the whole state.
+    init_locks: Arc<Mutex<HashMap<TypeId, Arc<Mutex<()>>>>>,
+}
+
+/// A stored value and the name of its type, kept for error messages.
+#[derive(Clone, Debug)]
+struct Entry {
+    type_name: &'static str,
+    value: Arc<dyn Any + Send + Sync>,
+}
+
+impl Entry {
+    fn new<T: Send + Sync + 'static>(value: T) -> Self {
+        Self {
+            type_name: std::any::type_name::<T>(),
+            value: Arc::new(value),
+        }
+    }
 }
 
 impl State {
     pub(crate) fn new() -> Self {
         Self {
             inner: Arc::new(RwLock::new(HashMap::new())),
+            init_locks: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -32,16 +53,15 @@ impl State {
         let map = self.inner.read().map_err(|_| StateError::LockError)?;
         let type_name = std::any::type_name::<T>().to_string();
 
-        let state_ref =
-            map.get(&TypeId::of::<T>())
-                .ok_or(StateError::TypeNotFound {
-                    type_name: type_name.to_string(),
-                })?;
+        let Some(entry) = map.get(&TypeId::of::<T>()) else {
+            return Err(type_not_found(type_name, &map));
+        };
 
-        state_ref
+        entry
+            .value
             .downcast_ref::<T>()
             .cloned()
-            .ok_or(StateError::TypeNotFound { type_name })
+            .ok_or_else(|| type_not_found(type_name, &map))
     }
 
     pub fn borrow<T>(&self) -> Result<Arc<T>, StateError>
@@ -51,16 +71,71 @@ impl State {
         let map = self.inner.read().map_err(|_| StateError::LockError)?;
         let type_name = std::any::type_name::<T>().to_string();
 
-        let state_ref =
-            map.get(&TypeId::of::<T>())
-                .ok_or_else(|| StateError::TypeNotFound {
-                    type_name: type_name.clone(),
-                })?;
+        let Some(entry) = map.get(&TypeId::of::<T>()) else {
+            return Err(type_not_found(type_name, &map));
+        };
 
-        state_ref
+        entry
+            .value
             .clone()
             .downcast::<T>()
-            .map_err(|_| StateError::TypeNotFound { type_name })
+            .map_err(|_| type_not_found(type_name, &map))
+    }
+
+    /// The names of the types stored in the state, sorted.
+    ///
+    /// Useful to find out why a lookup failed, e.g. when a value was
+    /// registered as `Arc<T>` but requested as `T`.
+    pub fn registered_types(&self) -> Vec<&'static str> {
+        self.inner
+            .read()
+            .map(|map| type_names(&map))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn get_or_insert_with<T, F>(
+        &self,
+        init: F,
+    ) -> Result<Arc<T>, StateError>
+    where
+        T: Send + Sync + 'static,
+        F: FnOnce() -> T,
+    {
+        if let Ok(existing) = self.borrow::<T>() {
+            return Ok(existing);
+        }
+
+        let init_lock = self
+            .init_locks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .clone();
+
+        // A panic in a previous `init` left nothing inserted, so the lock
+        // is still usable.
+        let _guard = init_lock.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Another request may have initialized the value while this one was
+        // waiting for the lock.
+        if let Ok(existing) = self.borrow::<T>() {
+            return Ok(existing);
+        }
+
+        // Built without holding the lock of the state, so `init` can access
+        // other values of the state.
+        let value = Entry::new(init());
+
+        let mut map = self.inner.write().map_err(|_| StateError::LockError)?;
+        let type_name = std::any::type_name::<T>().to_string();
+
+        // A value inserted meanwhile by `insert` is kept.
+        let entry = map.entry(TypeId::of::<T>()).or_insert(value).value.clone();
+
+        entry
+            .downcast::<T>()
+            .map_err(|_| type_not_found(type_name, &map))
     }
 
     pub(crate) fn insert<T: Send + Sync + 'static>(
@@ -70,7 +145,25 @@ impl State {
         self.inner
             .write()
             .map_err(|_| StateError::LockError)?
-            .insert(TypeId::of::<T>(), Arc::new(state));
+            .insert(TypeId::of::<T>(), Entry::new(state));
+
+        Ok(())
+    }
+
+    /// Inserts `state` unless a value of the same type is already stored.
+    pub(crate) fn insert_unique<T: Send + Sync + 'static>(
+        &self,
+        state: T,
+    ) -> Result<(), StateError> {
+        let mut map = self.inner.write().map_err(|_| StateError::LockError)?;
+
+        if map.contains_key(&TypeId::of::<T>()) {
+            return Err(StateError::DuplicateType {
+                type_name: std::any::type_name::<T>().to_string(),
+            });
+        }
+
+        map.insert(TypeId::of::<T>(), Entry::new(state));
 
         Ok(())
     }
@@ -78,12 +171,18 @@ impl State {
     pub(crate) fn insert_dependency(
         &self,
         type_id: TypeId,
+        type_name: &'static str,
         instance: Arc<dyn Any + Send + Sync>,
     ) -> Result<(), StateError> {
+        let entry = Entry {
+            type_name,
+            value: instance,
+        };
+
         self.inner
             .write()
             .map_err(|_| StateError::LockError)?
-            .insert(type_id, instance);
+            .insert(type_id, entry);
 
         Ok(())
     }
@@ -94,3 +193,23 @@ impl Default for State {
         Self::new()
     }
 }
+
+fn type_names(map: &HashMap<TypeId, Entry>) -> Vec<&'static str> {
+    let mut names: Vec<_> = map.values().map(|entry| entry.type_name).collect();
+    names.sort_unstable();
+    names
+}
+
+/// The registered types are only listed with the `verbose-errors` feature,
+/// since they reveal the internals of the application.
+fn type_not_found(type_name: String, map: &HashMap<TypeId, Entry>) -> StateError {
+    let registered = match cfg!(feature = "verbose-errors") {
+        true => type_names(map),
+        false => Vec::new(),
+    };
+
+    StateError::TypeNotFound {
+        type_name,
+        registered,
+    }
+}
@@ -32,6 +32,15 @@ where
 }
 
 pub(crate) fn expand_env_vars(content: &str) -> Result<String, String> {
+    expand_vars(content, |name| env::var(name).ok())
+}
+
+/// Expands `${VAR}`, `${VAR:default}` and `$VAR` with the values `lookup`
+/// returns.
+pub(crate) fn expand_vars(
+    content: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String, String> {
     let re = Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*):?([^}]*)\}")
         .map_err(|e| format!("Regex error: {e}"))?;
 
@@ -42,9 +51,9 @@ pub(crate) fn expand_env_vars(content: &str) -> Result<String, String> {
         let var_name = caps.get(1).unwrap().as_str();
         let default_value = caps.get(2).map_or("", |m| m.as_str());
 
-        let replacement = match env::var(var_name) {
-            Ok(value) => value,
-            Err(_) => {
+        let replacement = match lookup(var_name) {
+            Some(value) => value,
+            None => {
                 if default_value.is_empty() {
                     return Err(format!(
                         "environment variable '{var_name}' not found"
@@ -69,7 +78,7 @@ pub(crate) fn expand_env_vars(content: &str) -> Result<String, String> {
             continue;
         }
 
-        let Ok(replacement) = env::var(var_name) else {
+        let Some(replacement) = lookup(var_name) else {
             return Err(format!("environment variable '{var_name}' not found"));
         };
 
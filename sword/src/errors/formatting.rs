@@ -47,3 +47,23 @@ pub fn format_validator_errors(e: ValidationErrors) -> Value {
 
     Value::Object(formatted_errors)
 }
+
+#[cfg(feature = "validator")]
+/// The errors of `format_validator_errors` for the query and the body, under
+/// `query` and `body` keys. Sources without errors are left out.
+pub fn format_combined_validator_errors(
+    query: Option<ValidationErrors>,
+    body: Option<ValidationErrors>,
+) -> Value {
+    let mut formatted_errors = Map::new();
+
+    if let Some(query) = query {
+        formatted_errors.insert("query".into(), format_validator_errors(query));
+    }
+
+    if let Some(body) = body {
+        formatted_errors.insert("body".into(), format_validator_errors(body));
+    }
+
+    Value::Object(formatted_errors)
+}
@@ -1,45 +1,243 @@
-use crate::{errors::*, web::HttpResponse};
+use axum::response::{IntoResponse, Response};
+use byte_unit::{Byte, UnitType};
+
+use crate::{
+    errors::*,
+    web::{HttpResponse, overridden_status, record_rejection},
+};
 
 #[cfg(feature = "validator")]
-use crate::errors::formatting::format_validator_errors;
+use crate::web::format_with_registered;
 
 impl From<RequestError> for HttpResponse {
     fn from(error: RequestError) -> HttpResponse {
+        record_request_rejection(&error);
+
+        let status =
+            overridden_status(&error).unwrap_or_else(|| error.status_code());
+
         match error {
             RequestError::ParseError(message, details) => {
-                HttpResponse::BadRequest().message(message).error(details)
+                HttpResponse::builder(status)
+                    .message(message)
+                    .error(details)
             }
 
             #[cfg(feature = "validator")]
             RequestError::ValidatorError(message, errors) => {
-                HttpResponse::BadRequest()
+                let format = |formatter: &dyn ValidationErrorFormatter, errors| {
+                    formatter.format(status, message, errors)
+                };
+
+                format_with_registered(errors, format).unwrap_or_else(|errors| {
+                    format(&DefaultValidationErrorFormatter, errors)
+                })
+            }
+
+            #[cfg(feature = "validator")]
+            RequestError::CombinedValidatorError { query, body } => {
+                let format =
+                    |formatter: &dyn ValidationErrorFormatter, (query, body)| {
+                        formatter.format_combined(
+                            status,
+                            "Invalid request",
+                            query,
+                            body,
+                        )
+                    };
+
+                format_with_registered((query, body), format).unwrap_or_else(
+                    |errors| format(&DefaultValidationErrorFormatter, errors),
+                )
+            }
+
+            RequestError::ValidationFailed(message, errors) => {
+                HttpResponse::builder(status)
                     .message(message)
-                    .errors(format_validator_errors(errors))
+                    .errors(format_rule_errors(errors))
             }
 
             RequestError::BodyIsEmpty(message) => {
-                HttpResponse::BadRequest().message(message)
+                HttpResponse::builder(status).message(message)
             }
-            RequestError::BodyTooLarge => HttpResponse::PayloadTooLarge().message(
-                "The request body exceeds the maximum allowed size by the server",
-            ),
+            RequestError::BodyTooLarge {
+                limit,
+                received,
+                field,
+            } => HttpResponse::builder(status)
+                .message(
+                    "The request body exceeds the maximum allowed size by the server",
+                )
+                .error(body_too_large_details(limit, received, field.as_deref())),
 
-            RequestError::UnsupportedMediaType(message) => {
-                HttpResponse::UnsupportedMediaType().message(message)
+            RequestError::UnsupportedMediaType { received, allowed } => {
+                HttpResponse::builder(status)
+                    .message(format!(
+                        "Only {} content types are supported.",
+                        join_media_types(allowed)
+                    ))
+                    .error(unsupported_media_type_details(received, allowed))
             }
 
             RequestError::InternalError(message) => {
                 eprintln!("Internal server error: {message}");
-                HttpResponse::InternalServerError().message("Internal server error")
+                HttpResponse::builder(status).message("Internal server error")
+            }
+
+            RequestError::Aborted { message, .. } => {
+                HttpResponse::builder(status).message(message)
             }
         }
     }
 }
 
+impl IntoResponse for RequestError {
+    fn into_response(self) -> Response {
+        HttpResponse::from(self).into_response()
+    }
+}
+
+/// Passes the variant name and its static message to the rejection logger.
+/// Parser details are left out since they may quote the request body.
+fn record_request_rejection(error: &RequestError) {
+    match error {
+        RequestError::ParseError(message, _) => {
+            record_rejection("ParseError", *message)
+        }
+
+        #[cfg(feature = "validator")]
+        RequestError::ValidatorError(message, errors) => {
+            let mut fields = errors
+                .field_errors()
+                .into_keys()
+                .map(|field| field.to_string())
+                .collect::<Vec<_>>();
+
+            fields.sort();
+
+            record_rejection(
+                "ValidatorError",
+                format!("{message}: {}", fields.join(", ")),
+            )
+        }
+
+        #[cfg(feature = "validator")]
+        RequestError::CombinedValidatorError { query, body } => {
+            let mut fields = [("query", query), ("body", body)]
+                .into_iter()
+                .filter_map(|(source, errors)| Some((source, errors.as_ref()?)))
+                .flat_map(|(source, errors)| {
+                    errors
+                        .field_errors()
+                        .into_keys()
+                        .map(move |field| format!("{source}.{field}"))
+                })
+                .collect::<Vec<_>>();
+
+            fields.sort();
+
+            record_rejection(
+                "CombinedValidatorError",
+                format!("Invalid request: {}", fields.join(", ")),
+            )
+        }
+
+        RequestError::ValidationFailed(message, errors) => record_rejection(
+            "ValidationFailed",
+            format!("{message}: {} rule(s) failed", errors.len()),
+        ),
+
+        RequestError::BodyIsEmpty(message) => {
+            record_rejection("BodyIsEmpty", *message)
+        }
+        RequestError::BodyTooLarge {
+            limit,
+            received,
+            field,
+        } => record_rejection(
+            "BodyTooLarge",
+            body_too_large_details(*limit, *received, field.as_deref()),
+        ),
+
+        RequestError::UnsupportedMediaType { received, .. } => record_rejection(
+            "UnsupportedMediaType",
+            format!(
+                "Received content type: {}",
+                received.as_deref().unwrap_or("none")
+            ),
+        ),
+
+        RequestError::InternalError(_) | RequestError::Aborted { .. } => {}
+    }
+}
+
+fn body_too_large_details(
+    limit: usize,
+    received: Option<u64>,
+    field: Option<&str>,
+) -> String {
+    let limit = format_size(limit as u64);
+
+    if let Some(field) = field {
+        return format!(
+            "Field '{field}' exceeds the maximum allowed size of {limit}"
+        );
+    }
+
+    match received {
+        Some(received) => {
+            format!(
+                "Maximum allowed size is {limit}, received {}",
+                format_size(received)
+            )
+        }
+        None => format!("Maximum allowed size is {limit}"),
+    }
+}
+
+fn format_size(bytes: u64) -> String {
+    let adjusted = Byte::from_u64(bytes).get_appropriate_unit(UnitType::Decimal);
+
+    format!("{adjusted:.1}")
+}
+
+fn unsupported_media_type_details(
+    received: Option<String>,
+    allowed: &[&str],
+) -> String {
+    let expected = allowed.join(", ");
+
+    match received {
+        Some(received) => {
+            format!("Received '{received}', expected one of: {expected}")
+        }
+        None => format!("No content type was provided, expected one of: {expected}"),
+    }
+}
+
+/// Joins media types as "a, b and c" for the human-readable message.
+fn join_media_types(allowed: &[&str]) -> String {
+    match allowed {
+        [] => String::new(),
+        [single] => single.to_string(),
+        [init @ .., last] => format!("{} and {last}", init.join(", ")),
+    }
+}
+
+/// Lays out closure validation messages like validator errors, as
+/// `{ code, message }` entries, so clients parse both the same way.
+fn format_rule_errors(errors: Vec<String>) -> serde_json::Value {
+    errors
+        .into_iter()
+        .map(|message| serde_json::json!({ "code": "custom", "message": message }))
+        .collect()
+}
+
 impl From<StateError> for HttpResponse {
     fn from(error: StateError) -> Self {
         match error {
             StateError::TypeNotFound { .. } => HttpResponse::InternalServerError(),
+            StateError::DuplicateType { .. } => HttpResponse::InternalServerError(),
             StateError::LockError => HttpResponse::InternalServerError(),
         }
     }
@@ -52,8 +250,8 @@ impl From<DependencyInjectionError> for HttpResponse {
                 eprintln!("Failed to build dependency '{type_name}': {reason}");
                 HttpResponse::InternalServerError().message("Internal server error")
             }
-            DependencyInjectionError::DependencyNotFound { type_name } => {
-                eprintln!("Dependency '{type_name}' not found in container");
+            error @ DependencyInjectionError::DependencyNotFound { .. } => {
+                eprintln!("{error}");
                 HttpResponse::InternalServerError()
                     .message("Service configuration error")
             }
@@ -1,3 +1,4 @@
+use axum::http::StatusCode;
 use thiserror::Error;
 
 mod mappers;
@@ -5,6 +6,12 @@ mod mappers;
 #[cfg(feature = "validator")]
 mod formatting;
 
+#[cfg(feature = "validator")]
+mod validation;
+
+#[cfg(feature = "validator")]
+pub use validation::{DefaultValidationErrorFormatter, ValidationErrorFormatter};
+
 #[derive(Debug, Error)]
 pub enum ApplicationError {
     #[error("Failed to bind to address {address}: {source}")]
@@ -23,28 +30,81 @@ pub enum ApplicationError {
         #[from]
         source: ConfigError,
     },
+    #[error("State Error: {source}")]
+    StateError {
+        #[from]
+        source: StateError,
+    },
+    #[error("Plugin '{plugin}' Config Error: {source}")]
+    PluginConfigError {
+        plugin: &'static str,
+        #[source]
+        source: ConfigError,
+    },
+    #[error(
+        "Route '{route}' of '{controller}' conflicts with '{conflicts_with}' of '{existing}'"
+    )]
+    RouteConflict {
+        route: String,
+        controller: &'static str,
+        conflicts_with: String,
+        existing: &'static str,
+    },
+    #[error("Route '{route}' of '{controller}' is invalid: {reason}")]
+    InvalidRoute {
+        route: String,
+        controller: &'static str,
+        reason: String,
+    },
+    #[error("Invalid rewrite pattern '{pattern}': {reason}")]
+    InvalidRewrite { pattern: String, reason: String },
 }
 
 #[derive(Debug, Error)]
 pub enum StateError {
     #[error(
-        "State type not found - ensure it is registered in the application state"
+        "State type not found - ensure it is registered in the application state{}",
+        registered_hint(registered)
     )]
-    TypeNotFound { type_name: String },
+    TypeNotFound {
+        type_name: String,
+        /// The registered types, listed with the `verbose-errors` feature.
+        registered: Vec<&'static str>,
+    },
+
+    #[error("State type '{type_name}' is already registered")]
+    DuplicateType { type_name: String },
 
     #[error("Failed to acquire lock on state")]
     LockError,
 }
 
+impl StateError {
+    /// The types that were registered when a lookup failed, sorted.
+    ///
+    /// Only listed with the `verbose-errors` feature, empty otherwise.
+    pub fn registered_types(&self) -> &[&'static str] {
+        match self {
+            StateError::TypeNotFound { registered, .. } => registered,
+            _ => &[],
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum DependencyInjectionError {
     #[error("Failed to build dependency '{type_name}'\n   ↳ Reason: {reason}")]
     BuildFailed { type_name: String, reason: String },
 
     #[error(
-        "Dependency '{type_name}' not found in dependency container\n   ↳ Ensure it's registered before use"
+        "Dependency '{type_name}' not found in dependency container\n   ↳ Ensure it's registered before use{}",
+        registered_hint(registered)
     )]
-    DependencyNotFound { type_name: String },
+    DependencyNotFound {
+        type_name: String,
+        /// The registered types, listed with the `verbose-errors` feature.
+        registered: Vec<&'static str>,
+    },
 
     #[error("Failed to inject config: {source}")]
     ConfigInjectionError {
@@ -60,6 +120,20 @@ pub enum DependencyInjectionError {
     },
 }
 
+impl DependencyInjectionError {
+    /// The types that were registered when a dependency was not found, sorted.
+    ///
+    /// Only listed with the `verbose-errors` feature, empty otherwise.
+    pub fn registered_types(&self) -> &[&'static str] {
+        match self {
+            DependencyInjectionError::DependencyNotFound { registered, .. } => {
+                registered
+            }
+            _ => &[],
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum RequestError {
     #[error("Failed to parse request: {0}")]
@@ -69,23 +143,76 @@ pub enum RequestError {
     #[error("Failed to validate request")]
     ValidatorError(&'static str, validator::ValidationErrors),
 
+    /// The query and body validation errors of `validate_all`, sent together
+    /// keyed by `query` and `body`.
+    #[cfg(feature = "validator")]
+    #[error("Failed to validate request query and body")]
+    CombinedValidatorError {
+        query: Option<validator::ValidationErrors>,
+        body: Option<validator::ValidationErrors>,
+    },
+
+    #[error("Failed to validate request: {}", .1.join(", "))]
+    ValidationFailed(&'static str, Vec<String>),
+
     #[error("Request body is empty")]
     BodyIsEmpty(&'static str),
 
     #[error("Request body is too large")]
-    BodyTooLarge,
+    BodyTooLarge {
+        limit: usize,
+        received: Option<u64>,
+        field: Option<String>,
+    },
 
-    #[error("Unsupported media type: {0}")]
-    UnsupportedMediaType(String),
+    #[error("Unsupported media type: {}", received.as_deref().unwrap_or("none"))]
+    UnsupportedMediaType {
+        received: Option<String>,
+        allowed: &'static [&'static str],
+    },
 
     #[error("Internal server error: {0}")]
     InternalError(String),
+
+    /// An early return from a handler, built with `Context::abort`.
+    #[error("Request aborted with status {status}: {message}")]
+    Aborted { status: StatusCode, message: String },
+}
+
+impl RequestError {
+    /// The status code the error is sent with by default.
+    ///
+    /// Applications can override it per variant with
+    /// `ApplicationBuilder::with_request_error_status`.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            RequestError::ParseError(..) => StatusCode::BAD_REQUEST,
+
+            #[cfg(feature = "validator")]
+            RequestError::ValidatorError(..) => StatusCode::BAD_REQUEST,
+
+            #[cfg(feature = "validator")]
+            RequestError::CombinedValidatorError { .. } => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+
+            RequestError::ValidationFailed(..) => StatusCode::UNPROCESSABLE_ENTITY,
+
+            RequestError::BodyIsEmpty(_) => StatusCode::BAD_REQUEST,
+            RequestError::BodyTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            RequestError::UnsupportedMediaType { .. } => {
+                StatusCode::UNSUPPORTED_MEDIA_TYPE
+            }
+            RequestError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            RequestError::Aborted { status, .. } => *status,
+        }
+    }
 }
 
 #[derive(Debug, Error)]
 pub enum ConfigError {
     #[error("Configuration file not found at path: {0}")]
-    FileNotFound(&'static str),
+    FileNotFound(String),
 
     #[error("Failed to read configuration file: {0}")]
     ReadError(std::io::Error),
@@ -116,3 +243,10 @@ pub enum ConfigError {
     #[error("Error getting configuration from application state: {0}")]
     GetConfigError(String),
 }
+
+fn registered_hint(registered: &[&'static str]) -> String {
+    match registered.is_empty() {
+        true => String::new(),
+        false => format!("\n   ↳ Registered types: {}", registered.join(", ")),
+    }
+}
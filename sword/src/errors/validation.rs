@@ -0,0 +1,128 @@
+use std::borrow::Cow;
+
+use axum::http::StatusCode;
+use validator::ValidationErrors;
+
+use crate::{
+    errors::formatting::{
+        format_combined_validator_errors, format_validator_errors,
+    },
+    web::HttpResponse,
+};
+
+/// Builds the response of a `RequestError::ValidatorError`, returned by
+/// `body_validator`, `query_validator` and `params_validator` when the data
+/// fails its validation rules, and of a `RequestError::CombinedValidatorError`,
+/// returned by `validate_all`.
+///
+/// Register one with `ApplicationBuilder::with_validation_formatter` to
+/// change the JSON structure or the status code of validation errors.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// use serde_json::{Map, Value};
+/// use sword::errors::ValidationErrorFormatter;
+///
+/// struct MessagesOnly;
+///
+/// impl ValidationErrorFormatter for MessagesOnly {
+///     fn format(
+///         &self,
+///         _: StatusCode,
+///         message: &'static str,
+///         errors: ValidationErrors,
+///     ) -> HttpResponse {
+///         let fields = errors
+///             .field_errors()
+///             .into_iter()
+///             .map(|(field, errors)| {
+///                 let messages = errors
+///                     .iter()
+///                     .filter_map(|error| error.message.clone())
+///                     .map(|message| Value::String(message.into()))
+///                     .collect();
+///
+///                 (field.to_string(), Value::Array(messages))
+///             })
+///             .collect::<Map<_, _>>();
+///
+///         HttpResponse::UnprocessableEntity()
+///             .message(message)
+///             .errors(fields)
+///     }
+/// }
+/// ```
+pub trait ValidationErrorFormatter: Send + Sync + 'static {
+    /// Builds the response for `errors`.
+    ///
+    /// `status` is the status the error would be sent with, `400 Bad
+    /// Request` unless overridden with `with_request_error_status`, and
+    /// `message` the one of the error, e.g. `"Invalid request body"`.
+    fn format(
+        &self,
+        status: StatusCode,
+        message: &'static str,
+        errors: ValidationErrors,
+    ) -> HttpResponse;
+
+    /// Builds the response for the errors of `validate_all`, with the errors
+    /// of the query and of the body, whichever failed.
+    ///
+    /// By default, the fields of each section are prefixed with `query.` or
+    /// `body.` and passed to `format` together, so both kinds of errors share
+    /// the same structure.
+    fn format_combined(
+        &self,
+        status: StatusCode,
+        message: &'static str,
+        query: Option<ValidationErrors>,
+        body: Option<ValidationErrors>,
+    ) -> HttpResponse {
+        let mut errors = ValidationErrors::new();
+
+        for (section, section_errors) in [("query", query), ("body", body)] {
+            let Some(section_errors) = section_errors else {
+                continue;
+            };
+
+            for (field, kind) in section_errors.into_errors() {
+                let field = Cow::Owned(format!("{section}.{field}"));
+                errors.errors_mut().insert(field, kind);
+            }
+        }
+
+        self.format(status, message, errors)
+    }
+}
+
+/// The formatter used when none is registered, listing the `code` and
+/// `message` of every error of each field under `errors`.
+pub struct DefaultValidationErrorFormatter;
+
+impl ValidationErrorFormatter for DefaultValidationErrorFormatter {
+    fn format(
+        &self,
+        status: StatusCode,
+        message: &'static str,
+        errors: ValidationErrors,
+    ) -> HttpResponse {
+        HttpResponse::builder(status)
+            .message(message)
+            .errors(format_validator_errors(errors))
+    }
+
+    /// Lists the errors of each section under `errors.query` and
+    /// `errors.body`.
+    fn format_combined(
+        &self,
+        status: StatusCode,
+        message: &'static str,
+        query: Option<ValidationErrors>,
+        body: Option<ValidationErrors>,
+    ) -> HttpResponse {
+        HttpResponse::builder(status)
+            .message(message)
+            .errors(format_combined_validator_errors(query, body))
+    }
+}
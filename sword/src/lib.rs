@@ -20,12 +20,14 @@
 //! }
 //!
 //! #[sword::main]
-//! async fn main() {
-//!     let app = Application::builder()
+//! async fn main() -> Result<(), ApplicationError> {
+//!     let app = Application::builder()?
 //!         .with_controller::<ApiController>()
-//!         .build();
+//!         .build()?;
 //!     
 //!     app.run().await;
+//!
+//!     Ok(())
 //! }
 //! ```
 //!
@@ -54,6 +56,11 @@
 //! - `cookies` - Cookie handling
 //! - `helmet` - Security headers middleware
 //! - `shaku-di` - Dependency injection
+//! - `websocket` - WebSocket upgrades
+//! - `msgpack` - MessagePack responses through content negotiation
+//! - `verbose-errors` - Registered types in state lookup errors, for development
+//! - `dotenv` - `.env` files in configuration interpolation, for development
+//! - `openapi` - OpenAPI documents of the controller routes
 //!
 
 /// The prelude module contains the most commonly used items from the Sword framework.
@@ -70,12 +77,16 @@
 /// ```
 pub mod prelude {
     pub use crate::core::{
-        Application, ApplicationConfig, Config, ConfigItem, DependencyContainer,
-        config, injectable, provider,
+        Application, ApplicationBuilder, ApplicationConfig, Config, ConfigItem,
+        DependencyContainer, FeatureFlags, LogFormat, LoggingConfig, RoutesPlugin,
+        ShutdownCoordinator, ShutdownSignal, config, injectable, provider,
     };
 
+    pub use crate::with_controllers;
+
     pub use crate::errors::{
-        ApplicationError, DependencyInjectionError, RequestError, StateError,
+        ApplicationError, ConfigError, DependencyInjectionError, RequestError,
+        StateError,
     };
 
     pub use crate::web::*;
@@ -119,9 +130,9 @@ pub mod errors;
 /// use sword::prelude::*;
 ///
 /// // Create and configure an application
-/// let app = Application::builder()
+/// let app = Application::builder()?
 ///     .with_controller::<MyController>()
-///     .build();
+///     .build()?;
 ///
 /// // Access configuration
 /// let config = app.config.get::<ApplicationConfig>().unwrap();
@@ -130,6 +141,11 @@ pub mod core {
     mod application;
     mod config;
     mod di;
+    #[cfg(feature = "dotenv")]
+    mod dotenv;
+    mod features;
+    mod logging;
+    mod shutdown;
     mod state;
     mod utils;
 
@@ -138,8 +154,15 @@ pub mod core {
 
     pub use utils::deserialize_size;
 
-    pub use application::{Application, ApplicationConfig};
+    pub(crate) use application::startup::Startup;
+    pub use application::{
+        Application, ApplicationConfig, BodyLimitExceeded, BoundListener, RouteInfo,
+        RoutesPlugin, builder::ApplicationBuilder,
+    };
     pub use config::{Config, ConfigItem, config};
+    pub use features::{FeatureFlag, FeatureFlags};
+    pub use logging::{LogFormat, LoggingConfig};
+    pub use shutdown::{ShutdownCoordinator, ShutdownReport, ShutdownSignal};
     pub use state::State;
 }
 
@@ -189,20 +212,39 @@ pub mod core {
 /// ```
 pub mod web {
     mod context;
-    mod controller;
+    pub(crate) mod controller;
     mod middleware;
+    mod response;
+
+    pub mod cors;
+    pub mod logging;
+    pub mod metrics;
+    pub mod sse;
+
+    #[cfg(feature = "openapi")]
+    pub mod openapi;
 
     pub use axum::http::{Method, StatusCode, header};
+    pub use axum::response::{IntoResponse, Response};
     pub use axum_responses::Result as HttpResult;
     pub use axum_responses::http::*;
-    pub use sword_macros::{controller, delete, get, patch, post, put, routes};
+    pub use sword_macros::{
+        body_limit, controller, delete, deprecated_route, get, head, options, patch,
+        post, put, response_timeout, route, routes, stream_body, tag,
+    };
 
     pub use crate::next;
 
     pub use context::Context;
+    pub use context::client_cert::ClientCert;
+    pub use context::request::{JsonEngine, JsonEngineError, erased_serde};
+    pub(crate) use context::request::{RegisteredJsonEngine, to_vec_with};
     pub use middleware::*;
 
-    pub use controller::{Controller, ControllerBuilder};
+    pub use controller::{
+        Controller, ControllerBuilder, ControllerRoute, RouteBodyLimit, RouteTag,
+    };
+    pub use response::{BatchItem, BatchResponse, HttpResponseExt, ResponseFormat};
 
     #[cfg(feature = "multipart")]
     pub use context::multipart;
@@ -210,6 +252,9 @@ pub mod web {
     #[cfg(feature = "cookies")]
     pub use context::cookies;
 
+    #[cfg(feature = "websocket")]
+    pub use context::websocket as ws;
+
     #[cfg(feature = "validator")]
     pub use context::request::ValidatorRequestValidation;
 }
@@ -225,12 +270,22 @@ pub mod __internal {
     pub use axum::response::{IntoResponse, Response as AxumResponse};
     pub use axum::routing::Router as AxumRouter;
     pub use axum::routing::{
-        delete as axum_delete_fn, get as axum_get_fn, patch as axum_patch_fn,
-        post as axum_post_fn, put as axum_put_fn,
+        MethodFilter as AxumMethodFilter, delete as axum_delete_fn,
+        get as axum_get_fn, head as axum_head_fn, on as axum_on_fn,
+        options as axum_options_fn, patch as axum_patch_fn, post as axum_post_fn,
+        put as axum_put_fn,
     };
 
     pub use tokio::runtime as tokio_runtime;
 
+    pub use crate::web::controller::{
+        ControllerRoutes, DeprecatedRoute, controller_route_path, controller_routes,
+        feature_enabled, join_paths, mount_controller_router, route_url,
+        with_response_timeout,
+    };
+
+    pub use inventory;
+
     #[cfg(feature = "hot-reload")]
     pub use dioxus_devtools;
     #[cfg(feature = "hot-reload")]
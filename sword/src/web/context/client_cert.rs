@@ -0,0 +1,64 @@
+use crate::web::Context;
+
+/// The certificate a client presented during a mutual-TLS handshake.
+///
+/// Sword doesn't terminate TLS itself, so the acceptor or the layer that
+/// does inserts it into the request extensions once the client certificate
+/// has been verified. It is read with `Context::client_cert`.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// // In the layer wrapping the TLS connection:
+/// let cert = ClientCert::new(
+///     "CN=billing-service,O=Acme",
+///     "3f7a0c5d9e...",
+/// );
+///
+/// request.extensions_mut().insert(cert);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientCert {
+    /// The distinguished name of the certificate subject, e.g.
+    /// `CN=billing-service,O=Acme`.
+    pub subject: String,
+
+    /// The SHA-256 fingerprint of the DER-encoded certificate, in lowercase
+    /// hexadecimal.
+    pub fingerprint: String,
+}
+
+impl ClientCert {
+    /// Creates the certificate info from its subject and fingerprint.
+    pub fn new(subject: impl Into<String>, fingerprint: impl Into<String>) -> Self {
+        Self {
+            subject: subject.into(),
+            fingerprint: fingerprint.into(),
+        }
+    }
+}
+
+impl Context {
+    /// Gets the certificate the client presented over mutual TLS.
+    ///
+    /// ### Returns
+    /// `Some(&ClientCert)` if the TLS layer verified a client certificate
+    /// for the connection, `None` for plain HTTP and for TLS connections
+    /// without one.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[post("/invoices")]
+    /// async fn create(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let Some(cert) = ctx.client_cert() else {
+    ///         return Err(HttpResponse::Unauthorized().message("Client certificate required"));
+    ///     };
+    ///
+    ///     Ok(HttpResponse::Created().data(&cert.subject))
+    /// }
+    /// ```
+    pub fn client_cert(&self) -> Option<&ClientCert> {
+        self.extensions.get::<ClientCert>()
+    }
+}
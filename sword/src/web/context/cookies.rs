@@ -1,4 +1,12 @@
-use crate::web::{Context, HttpResponse};
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use crate::{
+    core::ConfigItem,
+    errors::RequestError,
+    web::{Context, HttpResponse},
+};
 
 pub use tower_cookies::{
     Cookies, Key, PrivateCookies, SignedCookies,
@@ -8,12 +16,179 @@ pub use tower_cookies::{
     },
 };
 
+/// The `[cookies]` section of the configuration.
+///
+/// ```toml,ignore
+/// [cookies]
+/// key = "a random string of at least 64 bytes"
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct CookiesConfig {
+    key: Option<String>,
+}
+
+impl ConfigItem for CookiesConfig {
+    fn toml_key() -> &'static str {
+        "cookies"
+    }
+}
+
+/// The key of `Context::signed_cookies` and `Context::private_cookies`,
+/// parsed from the `[cookies]` config once, when the application is built.
+pub(crate) struct CookieSigningKey(Result<Arc<Key>, String>);
+
+impl CookieSigningKey {
+    pub fn from_config(config: &CookiesConfig) -> Self {
+        let Some(key) = &config.key else {
+            return Self(Err(missing_key_message()));
+        };
+
+        let key = Key::try_from(key.as_bytes()).map(Arc::new).map_err(|err| match err {
+            CookieKeyError::TooShort(len) => format!(
+                "The cookie signing key must be at least 64 bytes long, got {len}"
+            ),
+            err => format!("Invalid cookie signing key: {err}"),
+        });
+
+        Self(key)
+    }
+}
+
+/// A cookie jar that signs the cookies it adds and only returns cookies
+/// whose signature is valid, returned by `Context::signed_cookies`.
+///
+/// Signed cookies can be read by the client but not tampered with.
+pub struct SignedJar {
+    cookies: Cookies,
+    key: Arc<Key>,
+}
+
+impl SignedJar {
+    /// Gets the cookie `name` if its signature is valid.
+    pub fn get(&self, name: &str) -> Option<Cookie<'static>> {
+        self.jar().get(name)
+    }
+
+    /// Signs and adds a cookie to the response.
+    pub fn add(&self, cookie: Cookie<'static>) {
+        self.jar().add(cookie);
+    }
+
+    /// Removes a cookie from the client.
+    pub fn remove(&self, cookie: Cookie<'static>) {
+        self.jar().remove(cookie);
+    }
+
+    fn jar(&self) -> SignedCookies<'_> {
+        self.cookies.signed(&self.key)
+    }
+}
+
+/// A cookie jar that encrypts the cookies it adds and only returns cookies
+/// it can decrypt, returned by `Context::private_cookies`.
+///
+/// Private cookies can be neither read nor tampered with by the client.
+pub struct PrivateJar {
+    cookies: Cookies,
+    key: Arc<Key>,
+}
+
+impl PrivateJar {
+    /// Gets and decrypts the cookie `name`, if it is authentic.
+    pub fn get(&self, name: &str) -> Option<Cookie<'static>> {
+        self.jar().get(name)
+    }
+
+    /// Encrypts and adds a cookie to the response.
+    pub fn add(&self, cookie: Cookie<'static>) {
+        self.jar().add(cookie);
+    }
+
+    /// Removes a cookie from the client.
+    pub fn remove(&self, cookie: Cookie<'static>) {
+        self.jar().remove(cookie);
+    }
+
+    fn jar(&self) -> PrivateCookies<'_> {
+        self.cookies.private(&self.key)
+    }
+}
+
+/// Extension methods for `Cookies`.
+pub trait CookiesExt {
+    /// Removes the cookie `name` that was set with the given `path` and,
+    /// optionally, `domain`.
+    ///
+    /// Browsers only delete a cookie when the removal `Set-Cookie` carries the
+    /// same path and domain it was set with, so `Cookies::remove` with a bare
+    /// `Cookie::new(name, "")` fails to clear cookies set on a specific path.
+    /// This sends an empty cookie with those attributes, `Max-Age=0` and an
+    /// expiry date in the past.
+    ///
+    /// ### Usage
+    /// ```rust,ignore
+    /// #[post("/logout")]
+    /// async fn logout(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     ctx.cookies()?.remove_matching("session_id", "/account", None);
+    ///
+    ///     Ok(HttpResponse::Ok())
+    /// }
+    /// ```
+    fn remove_matching(&self, name: &str, path: &str, domain: Option<&str>);
+}
+
+impl CookiesExt for Cookies {
+    fn remove_matching(&self, name: &str, path: &str, domain: Option<&str>) {
+        let mut cookie = Cookie::build((name.to_string(), ""))
+            .path(path.to_string())
+            .removal();
+
+        if let Some(domain) = domain {
+            cookie = cookie.domain(domain.to_string());
+        }
+
+        self.add(cookie.build());
+    }
+}
+
 impl Context {
+    /// Gets the value of the request cookie `name`.
+    ///
+    /// The cookie is read from the jar of the cookie layer, which the
+    /// application adds when the `cookies` feature is enabled. It does not
+    /// need a mutable `Context`, which suits middlewares that just inspect a
+    /// session or auth cookie.
+    ///
+    /// ### Returns
+    ///
+    /// The value of the cookie, or `None` if it was not sent or the request
+    /// did not go through the cookie layer, for example when the `Context`
+    /// was built outside the application.
+    ///
+    /// ### Usage
+    /// ```rust,ignore
+    /// impl Middleware for RequireSession {
+    ///     async fn handle(ctx: Context, next: Next) -> MiddlewareResult {
+    ///         if ctx.cookie("session_id").is_none() {
+    ///             return Err(HttpResponse::Unauthorized());
+    ///         }
+    ///
+    ///         next!(ctx, next)
+    ///     }
+    /// }
+    /// ```
+    pub fn cookie(&self, name: &str) -> Option<String> {
+        let cookies = self.extensions.get::<Cookies>()?;
+
+        cookies.get(name).map(|cookie| cookie.value().to_string())
+    }
+
     /// Access the cookies from the request context.
     /// This method returns a reference to the `Cookies` instance, a struct that provides
     /// methods to get, set, and remove cookies.
     ///
-    /// To get a mutable reference to the cookies, use the `cookies_mut` method.
+    /// `Cookies` has interior mutability, so cookies can be added and removed
+    /// through this reference too. To read a single value, use `cookie`.
     ///
     /// The documentation for `tower_cookies::Cookies` can be found [here](https://docs.rs/tower-cookies/latest/tower_cookies/struct.Cookies.html)
     /// Also, the other cookie-related types like `Cookie`, `CookieBuilder`, `Expiration`, and `SameSite` can be found in the `tower_cookies` crate.
@@ -31,10 +206,11 @@ impl Context {
     ///     let session_cookie = cookies.get("session_id");
     ///
     ///     if let Some(cookie) = session_cookie {
-    ///         Ok(HttpResponse::Ok().body(format!("Session ID: {}", cookie.value())))
+    ///         return Ok(HttpResponse::Ok()
+    ///             .message(format!("Session ID: {}", cookie.value())));
     ///     }
     ///
-    ///     Ok(HttpResponse::Ok().body("No session cookie found"))
+    ///     Ok(HttpResponse::Ok().message("No session cookie found"))
     /// }
     /// ```
     pub fn cookies(&self) -> Result<&Cookies, HttpResponse> {
@@ -44,6 +220,71 @@ impl Context {
         })
     }
 
+    /// Gets a jar of signed cookies, keyed with the `key` of the `[cookies]`
+    /// config.
+    ///
+    /// ### Errors
+    /// Returns `RequestError::InternalError` if the key is missing or shorter
+    /// than 64 bytes, or the cookie layer is not enabled.
+    ///
+    /// ### Usage
+    /// ```rust,ignore
+    /// #[get("/preferences")]
+    /// async fn preferences(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let theme = ctx.signed_cookies()?
+    ///         .get("theme")
+    ///         .map(|cookie| cookie.value().to_string());
+    ///
+    ///     Ok(HttpResponse::Ok().data(theme))
+    /// }
+    /// ```
+    pub fn signed_cookies(&self) -> Result<SignedJar, RequestError> {
+        let (cookies, key) = self.keyed_cookies()?;
+
+        Ok(SignedJar { cookies, key })
+    }
+
+    /// Gets a jar of encrypted cookies, keyed with the `key` of the
+    /// `[cookies]` config.
+    ///
+    /// ### Errors
+    /// Returns `RequestError::InternalError` if the key is missing or shorter
+    /// than 64 bytes, or the cookie layer is not enabled.
+    ///
+    /// ### Usage
+    /// ```rust,ignore
+    /// #[post("/login")]
+    /// async fn login(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     ctx.private_cookies()?.add(Cookie::new("session_id", "abc123"));
+    ///
+    ///     Ok(HttpResponse::Ok())
+    /// }
+    /// ```
+    pub fn private_cookies(&self) -> Result<PrivateJar, RequestError> {
+        let (cookies, key) = self.keyed_cookies()?;
+
+        Ok(PrivateJar { cookies, key })
+    }
+
+    fn keyed_cookies(&self) -> Result<(Cookies, Arc<Key>), RequestError> {
+        let key = self
+            .state
+            .borrow::<CookieSigningKey>()
+            .map_err(|_| RequestError::InternalError(missing_key_message()))?
+            .0
+            .clone()
+            .map_err(RequestError::InternalError)?;
+
+        let cookies =
+            self.extensions.get::<Cookies>().cloned().ok_or_else(|| {
+                RequestError::InternalError(
+                    "Can't extract cookies. Is `CookieManagerLayer` enabled?".into(),
+                )
+            })?;
+
+        Ok((cookies, key))
+    }
+
     /// Access the cookies from the request context as a mutable reference.
     /// This method returns a mutable reference to the `Cookies` instance, allowing
     /// modification of cookies (setting or removing).
@@ -80,3 +321,7 @@ impl Context {
         })
     }
 }
+
+fn missing_key_message() -> String {
+    "No cookie signing key found. Set `key` in the `[cookies]` config".into()
+}
@@ -1,16 +1,20 @@
 use crate::{
     core::{ApplicationConfig, Config, State},
     errors::RequestError,
-    web::{Context, HttpResponse, HttpResult},
+    web::{
+        BodyLimit, Context, HttpResponse, HttpResult, StreamedBody,
+        context::request::UnreadBody,
+    },
 };
 
 use axum::{
-    body::{Body, to_bytes},
-    extract::{FromRef, FromRequest, Path, Request as AxumRequest},
+    body::{Body, Bytes, to_bytes},
+    extract::{ConnectInfo, FromRef, FromRequest, Path, Request as AxumRequest},
+    http::header::CONTENT_LENGTH,
 };
 
 use http_body_util::LengthLimitError;
-use std::collections::HashMap;
+use std::{collections::HashMap, net::SocketAddr};
 
 /// Implementation of `FromRequest` for `Context`.
 ///
@@ -40,31 +44,56 @@ where
 
         let state = State::from_ref(state);
 
-        let body_limit = state
-            .get::<Config>()?
-            .get::<ApplicationConfig>()
-            .map(|app_config| app_config.body_limit.parsed)
-            .unwrap_or(usize::MAX);
+        let app_config = state.get::<Config>()?.get::<ApplicationConfig>().ok();
 
-        let body_bytes = to_bytes(body, body_limit).await.map_err(|err| {
-            let mut current_error: &dyn std::error::Error = &err;
+        let body_limit = match parts.extensions.get::<BodyLimit>() {
+            Some(BodyLimit(limit)) => *limit,
+            None => app_config
+                .as_ref()
+                .map_or(usize::MAX, |app_config| app_config.body_limit.parsed),
+        };
 
-            loop {
-                if current_error.is::<LengthLimitError>() {
-                    return RequestError::BodyTooLarge;
-                }
+        let strict_body = app_config
+            .as_ref()
+            .is_some_and(|app_config| app_config.deny_unknown_fields);
 
-                match std::error::Error::source(current_error) {
-                    Some(source) => current_error = source,
-                    None => break,
-                }
-            }
+        let trust_proxy_headers = app_config
+            .as_ref()
+            .is_some_and(|app_config| app_config.trust_proxy_headers);
 
-            RequestError::ParseError(
-                "Failed to read request body",
-                format!("Error reading body: {err}"),
-            )
-        })?;
+        #[cfg(feature = "multipart")]
+        let multipart_max_fields = app_config
+            .as_ref()
+            .and_then(|app_config| app_config.multipart_max_fields);
+
+        // The extractor also honors `MockConnectInfo`, used to serve the
+        // router in tests without a real connection.
+        let remote_addr = {
+            use axum::extract::FromRequestParts;
+            ConnectInfo::<SocketAddr>::from_request_parts(&mut parts, &())
+                .await
+                .ok()
+                .map(|ConnectInfo(addr)| addr)
+        };
+
+        let declared_length = parts
+            .headers
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok());
+
+        let (body_bytes, unread_body) = match parts.extensions.get::<StreamedBody>()
+        {
+            Some(_) => (Bytes::new(), Some(UnreadBody::new(body))),
+            None => {
+                let body_bytes =
+                    to_bytes(body, body_limit).await.map_err(|err| {
+                        body_read_error(&err, body_limit, declared_length)
+                    })?;
+
+                (body_bytes, None)
+            }
+        };
 
         let mut headers = HashMap::new();
 
@@ -77,6 +106,12 @@ where
         Ok(Self {
             params,
             body_bytes,
+            unread_body,
+            strict_body,
+            remote_addr,
+            trust_proxy_headers,
+            #[cfg(feature = "multipart")]
+            multipart_max_fields,
             method: parts.method,
             headers,
             uri: parts.uri,
@@ -106,7 +141,10 @@ impl TryFrom<Context> for AxumRequest {
             }
         }
 
-        let body = Body::from(req.body_bytes);
+        let body = match req.unread_body.and_then(|body| body.take()) {
+            Some(body) => body,
+            None => Body::from(req.body_bytes),
+        };
 
         let mut request = builder.body(body).map_err(|_| {
             RequestError::ParseError(
@@ -120,3 +158,33 @@ impl TryFrom<Context> for AxumRequest {
         Ok(request)
     }
 }
+
+/// Maps an error reading the request body to a `RequestError`, reporting
+/// the body limit when the body went past it.
+pub(crate) fn body_read_error(
+    err: &axum::Error,
+    limit: usize,
+    declared_length: Option<u64>,
+) -> RequestError {
+    let mut current_error: &dyn std::error::Error = err;
+
+    loop {
+        if current_error.is::<LengthLimitError>() {
+            return RequestError::BodyTooLarge {
+                limit,
+                received: declared_length,
+                field: None,
+            };
+        }
+
+        match std::error::Error::source(current_error) {
+            Some(source) => current_error = source,
+            None => break,
+        }
+    }
+
+    RequestError::ParseError(
+        "Failed to read request body",
+        format!("Error reading body: {err}"),
+    )
+}
@@ -0,0 +1,48 @@
+use crate::{core::FeatureFlags, web::Context};
+
+impl Context {
+    /// Checks whether a feature flag from the `[features]` config section
+    /// is enabled.
+    ///
+    /// Percentage rollouts need a key to be resolved and are only reported
+    /// as enabled here when set to 100%. Unknown flags are disabled.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[get("/checkout")]
+    /// async fn checkout(&self, ctx: Context) -> HttpResponse {
+    ///     if ctx.feature_enabled("new_checkout") {
+    ///         return HttpResponse::Ok().message("New checkout");
+    ///     }
+    ///
+    ///     HttpResponse::Ok().message("Legacy checkout")
+    /// }
+    /// ```
+    pub fn feature_enabled(&self, name: &str) -> bool {
+        self.state
+            .borrow::<FeatureFlags>()
+            .is_ok_and(|flags| flags.is_enabled(name))
+    }
+
+    /// Checks whether a feature flag is enabled for a stable key, such as
+    /// a user or tenant id.
+    ///
+    /// Percentage rollouts always give the same answer for the same key.
+    /// Boolean flags ignore the key.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// let user_id: String = ctx.param("id")?;
+    ///
+    /// if ctx.feature_enabled_for("beta_search", &user_id) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn feature_enabled_for(&self, name: &str, key: &str) -> bool {
+        self.state
+            .borrow::<FeatureFlags>()
+            .is_ok_and(|flags| flags.is_enabled_for(name, key))
+    }
+}
@@ -1,4 +1,6 @@
+pub mod client_cert;
 pub mod extract;
+pub mod features;
 pub mod request;
 
 #[cfg(feature = "multipart")]
@@ -7,15 +9,17 @@ pub mod multipart;
 #[cfg(feature = "cookies")]
 pub mod cookies;
 
+#[cfg(feature = "websocket")]
+pub mod websocket;
+
 use axum::{
     body::Bytes,
     http::{Extensions, Method, Uri},
 };
 
 use serde::de::DeserializeOwned;
-use std::collections::HashMap;
+use std::{collections::HashMap, net::SocketAddr};
 
-#[cfg(feature = "shaku-di")]
 use std::sync::Arc;
 
 #[cfg(feature = "shaku-di")]
@@ -23,9 +27,11 @@ use shaku::{HasComponent, Interface, Module};
 
 use crate::{
     core::{Config, ConfigItem, State},
-    errors::{ConfigError, DependencyInjectionError},
+    errors::{ConfigError, DependencyInjectionError, StateError},
 };
 
+use request::UnreadBody;
+
 /// Context represents the incoming request context in the Sword framework.
 ///
 /// `Context` is the primary interface for accessing request data in Sword applications.
@@ -44,6 +50,12 @@ use crate::{
 pub struct Context {
     params: HashMap<String, String>,
     body_bytes: Bytes,
+    unread_body: Option<UnreadBody>,
+    strict_body: bool,
+    remote_addr: Option<SocketAddr>,
+    trust_proxy_headers: bool,
+    #[cfg(feature = "multipart")]
+    multipart_max_fields: Option<usize>,
     method: Method,
     headers: HashMap<String, String>,
     uri: Uri,
@@ -78,8 +90,11 @@ impl Context {
     {
         let type_name = std::any::type_name::<T>().to_string();
 
-        let value = self.state.get::<T>().map_err(|_| {
-            DependencyInjectionError::DependencyNotFound { type_name }
+        let value = self.state.get::<T>().map_err(|error| {
+            DependencyInjectionError::DependencyNotFound {
+                type_name,
+                registered: error.registered_types().to_vec(),
+            }
         })?;
 
         Ok(value)
@@ -118,9 +133,10 @@ impl Context {
     {
         let type_name = std::any::type_name::<I>().to_string();
 
-        let module = self.state.borrow::<M>().map_err(|_| {
+        let module = self.state.borrow::<M>().map_err(|error| {
             DependencyInjectionError::DependencyNotFound {
                 type_name: type_name.clone(),
+                registered: error.registered_types().to_vec(),
             }
         })?;
 
@@ -129,6 +145,36 @@ impl Context {
         Ok(interface)
     }
 
+    /// Gets a shared value from the application state, initializing it on
+    /// first access.
+    ///
+    /// If no value of type `T` is stored yet, `init` is called and its result
+    /// is inserted. `init` runs at most once even when many requests race for
+    /// it, and without holding the lock of the state, so it can read other
+    /// values of the state, e.g. with `ctx.di`. It must not request a `T`
+    /// itself, which would wait for its own initialization.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[derive(Default)]
+    /// struct Cache(RwLock<HashMap<String, String>>);
+    ///
+    /// #[get("/cached")]
+    /// async fn cached(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let cache = ctx.state_or_init(Cache::default)?;
+    ///     // ...
+    ///     Ok(HttpResponse::Ok())
+    /// }
+    /// ```
+    pub fn state_or_init<T, F>(&self, init: F) -> Result<Arc<T>, StateError>
+    where
+        T: Send + Sync + 'static,
+        F: FnOnce() -> T,
+    {
+        self.state.get_or_insert_with(init)
+    }
+
     /// Retrieves a configuration item of type `T` from the application configuration.
     ///
     /// This method provides access to configuration values loaded from TOML files.
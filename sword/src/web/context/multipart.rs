@@ -1,14 +1,199 @@
-use axum::extract::FromRequest;
+use std::collections::HashMap;
+
 pub use axum::extract::multipart::*;
+use axum::{extract::FromRequest, http::HeaderMap};
 pub use bytes;
 
+use bytes::{Bytes, BytesMut};
+
 use crate::{errors::RequestError, web::Context};
 
+/// Limits for the fields of a multipart form, used with
+/// `Context::multipart_with_limits`.
+///
+/// The size limits apply on top of the body limit of the request, so that a
+/// single field can't take the whole body budget. The number of fields is
+/// capped by `max_fields`, or else by the `multipart_max_fields` of the
+/// `[application]` config.
+///
+/// ### Example
+/// ```rust,ignore
+/// let limits = MultipartLimits::new()
+///     .field("avatar", 1024 * 1024)
+///     .default_field(64 * 1024)
+///     .max_fields(16);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MultipartLimits {
+    default: Option<usize>,
+    fields: HashMap<String, usize>,
+    max_fields: Option<usize>,
+}
+
+impl MultipartLimits {
+    /// Creates limits that don't restrict any field.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size, in bytes, of the field called `name`.
+    pub fn field(mut self, name: &str, limit: usize) -> Self {
+        self.fields.insert(name.to_string(), limit);
+        self
+    }
+
+    /// Sets the maximum size, in bytes, of the fields without their own limit.
+    pub fn default_field(self, limit: usize) -> Self {
+        Self {
+            default: Some(limit),
+            ..self
+        }
+    }
+
+    /// Sets the maximum number of fields of the form, overriding the
+    /// `multipart_max_fields` of the config.
+    pub fn max_fields(self, max_fields: usize) -> Self {
+        Self {
+            max_fields: Some(max_fields),
+            ..self
+        }
+    }
+
+    fn limit_for(&self, name: Option<&str>) -> Option<usize> {
+        name.and_then(|name| self.fields.get(name).copied())
+            .or(self.default)
+    }
+}
+
+/// A multipart form whose fields are checked against `MultipartLimits`.
+pub struct LimitedMultipart {
+    inner: Multipart,
+    limits: MultipartLimits,
+    fields_read: usize,
+}
+
+impl LimitedMultipart {
+    /// Yields the next field of the form, if any.
+    ///
+    /// ### Errors
+    /// Returns `RequestError::ParseError` when the field goes past the
+    /// maximum number of fields, before its content is read.
+    pub async fn next_field(
+        &mut self,
+    ) -> Result<Option<LimitedField<'_>>, RequestError> {
+        let Some(field) = self.inner.next_field().await? else {
+            return Ok(None);
+        };
+
+        self.fields_read += 1;
+
+        if let Some(max_fields) = self.limits.max_fields
+            && self.fields_read > max_fields
+        {
+            return Err(too_many_fields(max_fields));
+        }
+
+        let limit = self.limits.limit_for(field.name());
+
+        Ok(Some(LimitedField {
+            inner: field,
+            limit,
+            read: 0,
+        }))
+    }
+}
+
+/// A field of a `LimitedMultipart` form.
+///
+/// Its size is counted as the chunks are read, so an oversized field is
+/// rejected as soon as it goes past the limit, before it's buffered whole.
+pub struct LimitedField<'a> {
+    inner: Field<'a>,
+    limit: Option<usize>,
+    read: usize,
+}
+
+impl LimitedField<'_> {
+    /// The name of the field in the `Content-Disposition` header.
+    pub fn name(&self) -> Option<&str> {
+        self.inner.name()
+    }
+
+    /// The file name of the field in the `Content-Disposition` header.
+    pub fn file_name(&self) -> Option<&str> {
+        self.inner.file_name()
+    }
+
+    /// The content type of the field.
+    pub fn content_type(&self) -> Option<&str> {
+        self.inner.content_type()
+    }
+
+    /// The headers of the field.
+    pub fn headers(&self) -> &HeaderMap {
+        self.inner.headers()
+    }
+
+    /// Reads the next chunk of the field.
+    ///
+    /// ### Errors
+    /// Returns `RequestError::BodyTooLarge` naming the field when the chunk
+    /// takes it past its limit.
+    pub async fn chunk(&mut self) -> Result<Option<Bytes>, RequestError> {
+        let Some(chunk) = self.inner.chunk().await? else {
+            return Ok(None);
+        };
+
+        self.read += chunk.len();
+
+        if let Some(limit) = self.limit
+            && self.read > limit
+        {
+            return Err(RequestError::BodyTooLarge {
+                limit,
+                received: None,
+                field: self.inner.name().map(str::to_string),
+            });
+        }
+
+        Ok(Some(chunk))
+    }
+
+    /// Reads the whole field.
+    pub async fn bytes(mut self) -> Result<Bytes, RequestError> {
+        let mut buffer = BytesMut::new();
+
+        while let Some(chunk) = self.chunk().await? {
+            buffer.extend_from_slice(&chunk);
+        }
+
+        Ok(buffer.freeze())
+    }
+
+    /// Reads the whole field as UTF-8 text.
+    pub async fn text(self) -> Result<String, RequestError> {
+        let bytes = self.bytes().await?;
+
+        String::from_utf8(bytes.to_vec()).map_err(|err| {
+            RequestError::ParseError(
+                "Failed to parse multipart form data",
+                err.to_string(),
+            )
+        })
+    }
+}
+
 impl Context {
     /// Extracts multipart form data from the request.
     ///
+    /// The form is rejected when it has more fields than the
+    /// `multipart_max_fields` of the config, before any field is handed out.
+    /// The size of the fields is only checked against the body limit. Use
+    /// `multipart_with_limits` to limit each field.
+    ///
     /// ### Errors
-    /// Returns `RequestError::ParseError` if the multipart form data cannot be parsed.
+    /// Returns `RequestError::ParseError` if the multipart form data cannot be
+    /// parsed or has too many fields.
     ///
     /// ### Example
     /// ```rust,ignore
@@ -26,8 +211,69 @@ impl Context {
     /// }
     /// ```
     pub async fn multipart(&self) -> Result<Multipart, RequestError> {
+        if let Some(max_fields) = self.multipart_max_fields {
+            // The body is already buffered, so the fields can be counted on
+            // a first pass over it.
+            let mut form = self.multipart_form().await?;
+            let mut fields = 0;
+
+            while form.next_field().await?.is_some() {
+                fields += 1;
+
+                if fields > max_fields {
+                    return Err(too_many_fields(max_fields));
+                }
+            }
+        }
+
+        self.multipart_form().await
+    }
+
+    async fn multipart_form(&self) -> Result<Multipart, RequestError> {
         Ok(Multipart::from_request(self.clone().try_into()?, &()).await?)
     }
+
+    /// Extracts multipart form data from the request, rejecting fields that
+    /// exceed their size in `limits` and forms with more fields than its
+    /// `max_fields` or the `multipart_max_fields` of the config.
+    ///
+    /// ### Errors
+    /// Returns `RequestError::ParseError` if the multipart form data cannot be
+    /// parsed or has too many fields, and `RequestError::BodyTooLarge` naming
+    /// the field when a field is read past its limit.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// async fn upload(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let limits = MultipartLimits::new().field("avatar", 1024 * 1024);
+    ///     let mut multipart = ctx.multipart_with_limits(limits).await?;
+    ///
+    ///     while let Some(field) = multipart.next_field().await? {
+    ///         let data = field.bytes().await?;
+    ///     }
+    ///
+    ///     Ok(HttpResponse::Ok())
+    /// }
+    /// ```
+    pub async fn multipart_with_limits(
+        &self,
+        mut limits: MultipartLimits,
+    ) -> Result<LimitedMultipart, RequestError> {
+        limits.max_fields = limits.max_fields.or(self.multipart_max_fields);
+
+        Ok(LimitedMultipart {
+            inner: self.multipart_form().await?,
+            limits,
+            fields_read: 0,
+        })
+    }
+}
+
+fn too_many_fields(max_fields: usize) -> RequestError {
+    RequestError::ParseError(
+        "Too many multipart fields",
+        format!("The form exceeds the maximum of {max_fields} fields"),
+    )
 }
 
 impl From<MultipartRejection> for RequestError {
@@ -0,0 +1,132 @@
+use std::{error::Error, sync::Arc};
+
+use serde::{Serialize, de::DeserializeOwned};
+
+pub use erased_serde;
+
+/// The error of a `JsonEngine`.
+pub type JsonEngineError = Box<dyn Error + Send + Sync>;
+
+/// Reads the JSON request bodies of `Context::body`, `body_strict` and
+/// `validate_with`, and writes the responses of `Context::respond`, in place
+/// of `serde_json`.
+///
+/// Register one with `ApplicationBuilder::with_json_engine`, e.g. to use
+/// `simd-json`. The target types go through `erased_serde`, so the engine
+/// reads and writes them directly, without an intermediate `Value`.
+///
+/// Query strings are not JSON and keep their own parser, and the `data` of
+/// an `HttpResponse` is converted to a `serde_json::Value` by
+/// `axum_responses` as soon as it is set, so neither goes through the
+/// engine. Send the data with `Context::respond` to serialize it with the
+/// engine.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// use sword::web::{JsonEngine, JsonEngineError, erased_serde};
+///
+/// struct SimdJson;
+///
+/// impl JsonEngine for SimdJson {
+///     fn serialize(
+///         &self,
+///         value: &dyn erased_serde::Serialize,
+///         writer: &mut Vec<u8>,
+///     ) -> Result<(), JsonEngineError> {
+///         Ok(simd_json::to_writer(writer, value)?)
+///     }
+///
+///     fn deserialize(
+///         &self,
+///         bytes: &[u8],
+///         visit: &mut dyn FnMut(
+///             &mut dyn erased_serde::Deserializer<'_>,
+///         ) -> Result<(), erased_serde::Error>,
+///     ) -> Result<(), JsonEngineError> {
+///         let mut bytes = bytes.to_vec();
+///         let mut deserializer = simd_json::Deserializer::from_slice(&mut bytes)?;
+///
+///         visit(&mut <dyn erased_serde::Deserializer>::erase(&mut deserializer))?;
+///
+///         Ok(())
+///     }
+/// }
+///
+/// let app = Application::builder()?
+///     .with_json_engine(SimdJson)
+///     .build()?;
+/// ```
+pub trait JsonEngine: Send + Sync + 'static {
+    /// Serializes `value` as JSON, appending it to `writer`.
+    fn serialize(
+        &self,
+        value: &dyn erased_serde::Serialize,
+        writer: &mut Vec<u8>,
+    ) -> Result<(), JsonEngineError>;
+
+    /// Deserializes the single JSON value in `bytes`.
+    ///
+    /// The engine hands a deserializer over `bytes` to `visit`, which reads
+    /// the target type from it. Data left after the value must be rejected,
+    /// like `serde_json::from_slice` does.
+    fn deserialize(
+        &self,
+        bytes: &[u8],
+        visit: &mut dyn FnMut(
+            &mut dyn erased_serde::Deserializer<'_>,
+        ) -> Result<(), erased_serde::Error>,
+    ) -> Result<(), JsonEngineError>;
+}
+
+/// The engine registered with `ApplicationBuilder::with_json_engine`.
+///
+/// Always stored in the state, empty without an engine, so that looking it
+/// up on every body read doesn't go through a failed lookup.
+#[derive(Clone, Default)]
+pub(crate) struct RegisteredJsonEngine(pub Option<Arc<dyn JsonEngine>>);
+
+impl std::fmt::Debug for RegisteredJsonEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RegisteredJsonEngine")
+    }
+}
+
+/// Deserializes `bytes` with `engine`, reading the value with `read`.
+pub(crate) fn deserialize_with<T>(
+    engine: &dyn JsonEngine,
+    bytes: &[u8],
+    mut read: impl FnMut(
+        &mut dyn erased_serde::Deserializer<'_>,
+    ) -> Result<T, erased_serde::Error>,
+) -> Result<T, JsonEngineError> {
+    let mut value = None;
+
+    engine.deserialize(bytes, &mut |deserializer| {
+        value = Some(read(deserializer)?);
+        Ok(())
+    })?;
+
+    value.ok_or_else(|| "The JSON engine did not read a value".into())
+}
+
+/// Deserializes `bytes` to `T` with `engine`.
+pub(crate) fn from_slice_with<T: DeserializeOwned>(
+    engine: &dyn JsonEngine,
+    bytes: &[u8],
+) -> Result<T, JsonEngineError> {
+    deserialize_with(engine, bytes, |deserializer| {
+        erased_serde::deserialize(deserializer)
+    })
+}
+
+/// Serializes `value` to a JSON buffer with `engine`.
+pub(crate) fn to_vec_with<T: Serialize>(
+    engine: &dyn JsonEngine,
+    value: &T,
+) -> Result<Vec<u8>, JsonEngineError> {
+    let mut writer = Vec::new();
+    engine.serialize(value, &mut writer)?;
+
+    Ok(writer)
+}
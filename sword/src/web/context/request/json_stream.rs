@@ -0,0 +1,308 @@
+use std::sync::{Arc, Mutex};
+
+use axum::body::Body;
+use futures_util::{Stream, StreamExt, stream};
+use serde::de::{DeserializeOwned, IgnoredAny};
+
+use crate::{
+    errors::RequestError,
+    web::{BodyLimit, Context, context::extract::body_read_error},
+};
+
+/// The body of a `#[stream_body]` route, left unread when the `Context` was
+/// extracted.
+///
+/// The clones of a `Context` share it, and the first one to take it reads
+/// it, so a request forwarded by a middleware keeps its body.
+#[derive(Debug, Clone)]
+pub(crate) struct UnreadBody(Arc<Mutex<Option<Body>>>);
+
+impl UnreadBody {
+    pub fn new(body: Body) -> Self {
+        Self(Arc::new(Mutex::new(Some(body))))
+    }
+
+    pub fn take(&self) -> Option<Body> {
+        self.0.lock().ok()?.take()
+    }
+}
+
+impl Context {
+    /// Parses the request body as a JSON array, one element at a time, as
+    /// the body arrives.
+    ///
+    /// The route must be declared with `#[stream_body]`, so the body is not
+    /// buffered when the `Context` is extracted. Only the element being
+    /// parsed and the last chunk received are held in memory, so arrays of
+    /// any size can be ingested, up to the `#[body_limit]` of the route if
+    /// it declares one. The body can only be read once.
+    ///
+    /// An element that is valid JSON but doesn't match `T` yields an error
+    /// and the stream moves on to the next element. Malformed JSON ends the
+    /// stream after yielding the error, since the remaining elements can't
+    /// be located reliably.
+    ///
+    /// ### Errors
+    ///
+    /// The stream yields a single error if the body is empty or is not a
+    /// JSON array, and `RequestError::InternalError` if the route is not
+    /// declared with `#[stream_body]` or the body was already read. Reading
+    /// past the `#[body_limit]` of the route ends the stream with
+    /// `RequestError::BodyTooLarge`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use futures::StreamExt;
+    ///
+    /// #[post("/events/import")]
+    /// #[stream_body]
+    /// async fn import(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let mut events = std::pin::pin!(ctx.json_array_stream::<Event>());
+    ///     let mut imported = 0;
+    ///
+    ///     while let Some(event) = events.next().await {
+    ///         self.events.insert(event?).await;
+    ///         imported += 1;
+    ///     }
+    ///
+    ///     Ok(HttpResponse::Ok().data(imported))
+    /// }
+    /// ```
+    pub fn json_array_stream<T>(
+        &self,
+    ) -> impl Stream<Item = Result<T, RequestError>> + Send + 'static
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let Some(body) = self.unread_body.as_ref().and_then(UnreadBody::take) else {
+            let error = RequestError::InternalError(
+                "json_array_stream requires a #[stream_body] route with an unread body"
+                    .to_string(),
+            );
+
+            return stream::once(async { Err(error) }).left_stream();
+        };
+
+        let limit = self
+            .extensions
+            .get::<BodyLimit>()
+            .map_or(usize::MAX, |BodyLimit(limit)| *limit);
+
+        let state = Some((body.into_data_stream(), JsonArrayElements::<T>::new()));
+
+        stream::unfold(state, move |state| async move {
+            let (mut body, mut elements) = state?;
+
+            loop {
+                match elements.step() {
+                    Step::Element(element) => {
+                        return Some((element, Some((body, elements))));
+                    }
+                    Step::Done => return None,
+                    Step::NeedMore => match body.next().await {
+                        Some(Ok(chunk)) => elements.extend(&chunk),
+                        Some(Err(error)) => {
+                            return Some((
+                                Err(body_read_error(&error, limit, None)),
+                                None,
+                            ));
+                        }
+                        None => elements.ended = true,
+                    },
+                }
+            }
+        })
+        .right_stream()
+    }
+}
+
+enum Position {
+    Start,
+    FirstElement,
+    Element,
+    Separator,
+    End,
+    Done,
+}
+
+enum Step<T> {
+    Element(Result<T, RequestError>),
+    NeedMore,
+    Done,
+}
+
+/// Locates the elements of a JSON array in a body received in chunks.
+///
+/// The bytes before the current position are dropped when a chunk is
+/// added, so the buffer only holds the element being parsed.
+struct JsonArrayElements<T> {
+    buffer: Vec<u8>,
+    offset: usize,
+    ended: bool,
+    index: usize,
+    position: Position,
+    _element: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> JsonArrayElements<T> {
+    fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            offset: 0,
+            ended: false,
+            index: 0,
+            position: Position::Start,
+            _element: std::marker::PhantomData,
+        }
+    }
+
+    fn extend(&mut self, chunk: &[u8]) {
+        self.buffer.drain(..self.offset);
+        self.offset = 0;
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.buffer.get(self.offset) {
+            self.offset += 1;
+        }
+    }
+
+    fn next_byte(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.buffer.get(self.offset).copied()
+    }
+
+    fn fail(&mut self, details: String) -> Step<T> {
+        self.position = Position::Done;
+
+        Step::Element(Err(RequestError::ParseError(
+            "Invalid JSON array in request body",
+            details,
+        )))
+    }
+
+    /// Fails with `details` once the body has ended, or asks for more.
+    fn fail_at_end(&mut self, details: &str) -> Step<T> {
+        match self.ended {
+            true => self.fail(details.to_string()),
+            false => Step::NeedMore,
+        }
+    }
+
+    fn parse_element(&mut self) -> Step<T> {
+        let start = self.offset;
+        let mut elements =
+            serde_json::Deserializer::from_slice(&self.buffer[start..])
+                .into_iter::<IgnoredAny>();
+
+        // Locate the end of the element first, so an element that doesn't
+        // match `T` can be skipped without losing track of the array.
+        let located = elements.next();
+        let end = start + elements.byte_offset();
+
+        match located {
+            Some(Err(error)) if error.is_eof() && !self.ended => {
+                return Step::NeedMore;
+            }
+            Some(Err(error)) => {
+                let index = self.index;
+                return self
+                    .fail(format!("Malformed element at index {index}: {error}"));
+            }
+            _ => {}
+        }
+
+        // A number may go on in the next chunk, so an element is only
+        // complete once a byte after it has been received.
+        if end == self.buffer.len() && !self.ended {
+            return Step::NeedMore;
+        }
+
+        let index = self.index;
+
+        self.offset = end;
+        self.index += 1;
+        self.position = Position::Separator;
+
+        let element = serde_json::from_slice::<T>(&self.buffer[start..end]);
+
+        Step::Element(element.map_err(|error| {
+            RequestError::ParseError(
+                "Invalid element in request body array",
+                format!("Invalid element at index {index}: {error}"),
+            )
+        }))
+    }
+
+    fn step(&mut self) -> Step<T> {
+        loop {
+            match self.position {
+                Position::Done => return Step::Done,
+
+                Position::Start => match self.next_byte() {
+                    None if !self.ended => return Step::NeedMore,
+                    None => {
+                        self.position = Position::Done;
+                        return Step::Element(Err(RequestError::BodyIsEmpty(
+                            "Request body is empty",
+                        )));
+                    }
+                    Some(b'[') => {
+                        self.offset += 1;
+                        self.position = Position::FirstElement;
+                    }
+                    Some(_) => {
+                        return self
+                            .fail("Request body must be a JSON array".to_string());
+                    }
+                },
+
+                Position::FirstElement => match self.next_byte() {
+                    Some(b']') => {
+                        self.offset += 1;
+                        self.position = Position::End;
+                    }
+                    Some(_) => self.position = Position::Element,
+                    None => return self.fail_at_end("Unterminated JSON array"),
+                },
+
+                Position::Element => match self.next_byte() {
+                    Some(_) => return self.parse_element(),
+                    None => return self.fail_at_end("Unterminated JSON array"),
+                },
+
+                Position::Separator => match self.next_byte() {
+                    Some(b',') => {
+                        self.offset += 1;
+                        self.position = Position::Element;
+                    }
+                    Some(b']') => {
+                        self.offset += 1;
+                        self.position = Position::End;
+                    }
+                    Some(_) => {
+                        let index = self.index;
+
+                        return self.fail(format!(
+                            "Expected ',' or ']' after the element at index {}",
+                            index - 1
+                        ));
+                    }
+                    None => return self.fail_at_end("Unterminated JSON array"),
+                },
+
+                Position::End => match self.next_byte() {
+                    Some(_) => {
+                        return self.fail(
+                            "Unexpected data after the JSON array".to_string(),
+                        );
+                    }
+                    None if !self.ended => return Step::NeedMore,
+                    None => self.position = Position::Done,
+                },
+            }
+        }
+    }
+}
@@ -1,15 +1,42 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 
-use axum::http::Method;
+use axum::{
+    body::{Bytes, to_bytes},
+    extract::{FromRequestParts, MatchedPath, Request as AxumRequest},
+    http::{HeaderName, HeaderValue, Method, StatusCode},
+    response::IntoResponse,
+};
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use mime::Mime;
 use serde::de::DeserializeOwned;
 
+mod json_engine;
+mod json_stream;
+
+use json_engine::from_slice_with;
+pub use json_engine::{JsonEngine, JsonEngineError, erased_serde};
+pub(crate) use json_engine::{RegisteredJsonEngine, to_vec_with};
+pub(crate) use json_stream::UnreadBody;
+
 #[cfg(feature = "validator")]
 pub mod validator;
 
 #[cfg(feature = "validator")]
 pub use validator::ValidatorRequestValidation;
 
-use crate::{errors::RequestError, web::Context};
+use crate::{
+    errors::RequestError,
+    web::{
+        APPLICATION_FORM_URLENCODED, APPLICATION_JSON, CancellationSignal, Context,
+        CurrentRequestId, ReportedUser, Timings, is_json,
+    },
+};
 
 impl Context {
     /// Gets the complete URI of the request as a string.
@@ -40,6 +67,306 @@ impl Context {
         self.headers.get(&key.to_lowercase()).map(String::as_str)
     }
 
+    /// Gets a header by name and parses it with `FromStr`.
+    ///
+    /// ### Arguments
+    /// * `key` - The header name to search for (case-insensitive).
+    ///
+    /// ### Returns
+    /// - `None` if the header is not present
+    /// - `Some(Ok(T))` if the header is present and its value parses as `T`
+    /// - `Some(Err(RequestError))` if the header is present but can't be parsed
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[get("/items")]
+    /// async fn list(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let page: u32 = ctx.header_parsed("X-Page").transpose()?.unwrap_or(1);
+    ///
+    ///     Ok(HttpResponse::Ok().data(page))
+    /// }
+    /// ```
+    pub fn header_parsed<T: FromStr>(
+        &self,
+        key: &str,
+    ) -> Option<Result<T, RequestError>> {
+        let value = self.header(key)?;
+
+        Some(value.parse::<T>().map_err(|_| {
+            let message = "Invalid header value";
+            let details =
+                format!("Failed to parse header '{key}' to the required type");
+
+            RequestError::ParseError(message, details)
+        }))
+    }
+
+    /// Gets a required header by name and parses it with `FromStr`.
+    ///
+    /// Works like `header_parsed`, but a missing header is an error too,
+    /// which fits headers the handler can't work without.
+    ///
+    /// ### Arguments
+    /// * `key` - The header name to search for (case-insensitive).
+    ///
+    /// ### Errors
+    ///
+    /// Returns a `RequestError::ParseError` naming the header if it is
+    /// missing or its value can't be parsed as `T`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[post("/jobs")]
+    /// async fn retry(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let attempts: u8 = ctx.typed_header("X-Retry-Count")?;
+    ///
+    ///     Ok(HttpResponse::Ok().data(attempts))
+    /// }
+    /// ```
+    pub fn typed_header<T: FromStr>(&self, key: &str) -> Result<T, RequestError> {
+        self.header_parsed(key).unwrap_or_else(|| {
+            let message = "Header not found";
+            let details = format!("Header '{key}' not found in request headers");
+
+            Err(RequestError::ParseError(message, details))
+        })
+    }
+
+    /// Gets the token of a `Authorization: Bearer <token>` header.
+    ///
+    /// The scheme is matched case-insensitively.
+    ///
+    /// ### Returns
+    /// `Some(&str)` with the token, `None` if the header is missing, uses
+    /// another scheme or has an empty token. Middlewares decide how to
+    /// answer a missing token.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// impl Middleware for AuthMiddleware {
+    ///     async fn handle(ctx: Context, next: Next) -> MiddlewareResult {
+    ///         let Some(token) = ctx.bearer_token() else {
+    ///             return Err(HttpResponse::Unauthorized());
+    ///         };
+    ///
+    ///         verify(token)?;
+    ///
+    ///         next!(ctx, next)
+    ///     }
+    /// }
+    /// ```
+    pub fn bearer_token(&self) -> Option<&str> {
+        let token =
+            authorization_credentials(self.header("authorization")?, "Bearer")?;
+
+        (!token.is_empty()).then_some(token)
+    }
+
+    /// Gets the user ID and password of a `Authorization: Basic <credentials>`
+    /// header.
+    ///
+    /// The scheme is matched case-insensitively and the credentials are
+    /// base64 decoded and split at the first `:`, so passwords may contain
+    /// colons.
+    ///
+    /// ### Returns
+    /// `Some((user, password))`, or `None` if the header is missing, uses
+    /// another scheme, or its credentials aren't valid base64, UTF-8 or
+    /// don't contain a `:`.
+    pub fn basic_auth(&self) -> Option<(String, String)> {
+        let encoded =
+            authorization_credentials(self.header("authorization")?, "Basic")?;
+
+        let decoded = String::from_utf8(BASE64.decode(encoded).ok()?).ok()?;
+        let (user, password) = decoded.split_once(':')?;
+
+        Some((user.to_string(), password.to_string()))
+    }
+
+    /// Gets the parsed `Content-Type` header.
+    ///
+    /// ### Returns
+    /// `Some(Mime)` if the header is present and is a valid media type,
+    /// `None` otherwise.
+    pub fn content_type(&self) -> Option<Mime> {
+        self.header_parsed("content-type")?.ok()
+    }
+
+    /// Gets the parsed `Content-Length` header.
+    ///
+    /// ### Returns
+    /// `Some(u64)` if the header is present and is a valid length,
+    /// `None` otherwise.
+    pub fn content_length(&self) -> Option<u64> {
+        self.header_parsed("content-length")?.ok()
+    }
+
+    /// Gets the socket address of the connected peer.
+    ///
+    /// Available when the application is served with `Application::run` and
+    /// its variants. Behind a reverse proxy this is the address of the proxy,
+    /// use `real_ip` to get the one of the client.
+    ///
+    /// ### Returns
+    /// `Some(SocketAddr)` if the connection info is available, `None` otherwise,
+    /// e.g. when the router is called directly in tests.
+    pub const fn remote_addr(&self) -> Option<SocketAddr> {
+        self.remote_addr
+    }
+
+    /// Gets the IP address of the client.
+    ///
+    /// When `trust_proxy_headers` is enabled in the `[application]` config,
+    /// the first address of `X-Forwarded-For` is used, then `X-Real-IP`.
+    /// Otherwise, or if neither holds a valid address, it falls back to the
+    /// IP of `remote_addr`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[post("/login")]
+    /// async fn login(&self, ctx: Context) -> HttpResponse {
+    ///     let ip = ctx.real_ip().map(|ip| ip.to_string());
+    ///     tracing::info!(?ip, "Login attempt");
+    ///
+    ///     HttpResponse::Ok()
+    /// }
+    /// ```
+    pub fn real_ip(&self) -> Option<IpAddr> {
+        if self.trust_proxy_headers {
+            let forwarded = self
+                .header("x-forwarded-for")
+                .and_then(|value| value.split(',').next())
+                .and_then(|value| value.trim().parse().ok());
+
+            let real_ip = || {
+                self.header("x-real-ip")
+                    .and_then(|value| value.trim().parse().ok())
+            };
+
+            if let Some(ip) = forwarded.or_else(real_ip) {
+                return Some(ip);
+            }
+        }
+
+        self.remote_addr.map(|addr| addr.ip())
+    }
+
+    /// Gets the correlation ID assigned to the request by the `RequestId`
+    /// middleware.
+    ///
+    /// ### Returns
+    /// `Some(&str)` with the ID, `None` if the middleware is not enabled.
+    pub fn request_id(&self) -> Option<&str> {
+        self.extensions
+            .get::<CurrentRequestId>()
+            .map(|current| current.id.as_ref())
+    }
+
+    /// Gets the request ID as a header, to forward it to downstream services.
+    ///
+    /// The header name is the outgoing header configured on `RequestId`,
+    /// which defaults to `X-Request-Id`.
+    ///
+    /// ### Returns
+    /// `Some((HeaderName, HeaderValue))` with the header, `None` if the
+    /// middleware is not enabled.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[get("/orders/{id}")]
+    /// async fn order(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let mut request = self.http.get(self.inventory_url());
+    ///
+    ///     if let Some((name, value)) = ctx.request_id_header_value() {
+    ///         request = request.header(name, value);
+    ///     }
+    ///
+    ///     let stock = request
+    ///         .send()
+    ///         .await
+    ///         .map_err(|_| HttpResponse::BadGateway())?;
+    ///
+    ///     Ok(HttpResponse::Ok().data(stock.status().as_u16()))
+    /// }
+    /// ```
+    pub fn request_id_header_value(&self) -> Option<(HeaderName, HeaderValue)> {
+        let current = self.extensions.get::<CurrentRequestId>()?;
+        let value = HeaderValue::try_from(current.id.as_ref()).ok()?;
+
+        Some((current.header.clone(), value))
+    }
+
+    /// Builds an error that ends the request with the given status and
+    /// message, for early returns from handlers.
+    ///
+    /// The error is sent with the standard envelope, like any other
+    /// `RequestError`, and converts into `HttpResponse` with `?` or `into()`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[delete("/posts/{id}")]
+    /// async fn delete_post(&self, ctx: Context) -> Result<HttpResponse, RequestError> {
+    ///     if ctx.header("x-role") != Some("admin") {
+    ///         return Err(ctx.abort(StatusCode::FORBIDDEN, "Only admins can delete posts"));
+    ///     }
+    ///
+    ///     Ok(HttpResponse::NoContent())
+    /// }
+    /// ```
+    pub fn abort(
+        &self,
+        status: StatusCode,
+        message: impl Into<String>,
+    ) -> RequestError {
+        RequestError::Aborted {
+            status,
+            message: message.into(),
+        }
+    }
+
+    /// Records how long a phase of the request took, to be sent in the
+    /// `Server-Timing` header by the `ServerTiming` middleware.
+    ///
+    /// `name` must be an HTTP token, e.g. `db` or `cache-lookup`, otherwise
+    /// the metric is left out of the header. Does nothing while the
+    /// middleware is disabled.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[get("/orders")]
+    /// async fn orders(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let started = Instant::now();
+    ///     let orders = self.repository.find_all().await?;
+    ///
+    ///     ctx.record_timing("db", started.elapsed());
+    ///
+    ///     Ok(HttpResponse::Ok().data(orders))
+    /// }
+    /// ```
+    pub fn record_timing(&self, name: &str, duration: Duration) {
+        if let Some(timings) = self.extensions.get::<Timings>() {
+            timings.record(name, duration);
+        }
+    }
+
+    /// Sets the user of the request, passed to the hook registered with
+    /// `ApplicationBuilder::on_error` if the request ends in a server error.
+    ///
+    /// Usually called by an authentication middleware. Does nothing when no
+    /// hook is registered.
+    pub fn report_user(&self, user: impl Into<String>) {
+        if let Some(reported) = self.extensions.get::<ReportedUser>() {
+            reported.set(user.into());
+        }
+    }
+
     /// Gets an immutable reference to all request headers.
     ///
     /// ### Returns
@@ -133,6 +460,56 @@ impl Context {
         &self.params
     }
 
+    /// Parses every path parameter to the same type.
+    ///
+    /// Useful when all the parameters are of the same kind, like numeric
+    /// IDs. For parameters of different types, deserialize them to a struct
+    /// with `params_validator`.
+    ///
+    /// ### Errors
+    ///
+    /// Returns a `400 Bad Request` naming the first parameter, in the order
+    /// of the route path, that can't be parsed to `T`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[get("/users/{user_id}/posts/{post_id}")]
+    /// async fn get_user_post(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let ids = ctx.param_all::<u64>()?;
+    ///
+    ///     Ok(HttpResponse::Ok().data(ids["post_id"]))
+    /// }
+    /// ```
+    pub fn param_all<T: FromStr>(&self) -> Result<HashMap<String, T>, RequestError> {
+        let path = self
+            .extensions
+            .get::<MatchedPath>()
+            .map(MatchedPath::as_str)
+            .unwrap_or_default();
+
+        let position = |key: &str| {
+            path.find(&format!("{{{key}}}"))
+                .or_else(|| path.find(&format!("{{*{key}}}")))
+                .unwrap_or(usize::MAX)
+        };
+
+        let mut keys = self.params.keys().collect::<Vec<_>>();
+        keys.sort_by_key(|key| (position(key), *key));
+
+        keys.into_iter()
+            .map(|key| match self.params[key].parse::<T>() {
+                Ok(value) => Ok((key.clone(), value)),
+                Err(_) => Err(RequestError::ParseError(
+                    "Invalid parameter type",
+                    format!(
+                        "Failed to parse parameter '{key}' to the required type"
+                    ),
+                )),
+            })
+            .collect()
+    }
+
     /// Deserializes the request body from JSON to a specific type.
     ///
     /// This method reads the request body and attempts to parse it as JSON,
@@ -152,6 +529,8 @@ impl Context {
     ///
     /// This function will return an error if:
     /// - The request body is empty
+    /// - The content type is not `application/json` or a `+json` type, such
+    ///   as `application/merge-patch+json` (`415 Unsupported Media Type`)
     /// - The body contains invalid JSON
     /// - The JSON structure doesn't match the target type `T`
     ///
@@ -178,16 +557,207 @@ impl Context {
     /// }
     /// ```
     pub fn body<T: DeserializeOwned>(&self) -> Result<T, RequestError> {
+        if self.strict_body {
+            return self.body_strict();
+        }
+
         if self.body_bytes.is_empty() {
             return Err(RequestError::BodyIsEmpty("Request body is empty"));
         }
 
-        serde_json::from_slice(&self.body_bytes).map_err(|_| {
-            let message = "Invalid request body";
-            let details = "Failed to parse request body to the required type.";
+        ensure_json_body(self)?;
 
-            RequestError::ParseError(message, details.into())
-        })
+        match self.json_engine() {
+            Some(engine) => from_slice_with(engine.as_ref(), &self.body_bytes)
+                .map_err(|_| invalid_body_error()),
+            None => serde_json::from_slice(&self.body_bytes)
+                .map_err(|_| invalid_body_error()),
+        }
+    }
+
+    /// The engine registered with `ApplicationBuilder::with_json_engine`.
+    pub(crate) fn json_engine(&self) -> Option<Arc<dyn JsonEngine>> {
+        self.state
+            .get::<RegisteredJsonEngine>()
+            .ok()
+            .and_then(|RegisteredJsonEngine(engine)| engine)
+    }
+
+    /// Deserializes an `application/x-www-form-urlencoded` request body, as
+    /// sent by HTML form submissions, to a specific type.
+    ///
+    /// ### Errors
+    ///
+    /// This function will return an error if:
+    /// - The request body is empty
+    /// - The content type is not `application/x-www-form-urlencoded`, e.g.
+    ///   JSON, which should be read with `body()` instead
+    /// - The form fields don't match the target type `T`
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct LoginForm {
+    ///     username: String,
+    ///     password: String,
+    /// }
+    ///
+    /// #[post("/login")]
+    /// async fn login(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let form: LoginForm = ctx.form()?;
+    ///
+    ///     Ok(HttpResponse::Ok().message(format!("Welcome, {}", form.username)))
+    /// }
+    /// ```
+    pub fn form<T: DeserializeOwned>(&self) -> Result<T, RequestError> {
+        if self.body_bytes.is_empty() {
+            return Err(RequestError::BodyIsEmpty("Request body is empty"));
+        }
+
+        let is_form = self
+            .content_type()
+            .is_some_and(|mime| mime.essence_str() == APPLICATION_FORM_URLENCODED);
+
+        if !is_form {
+            return Err(RequestError::UnsupportedMediaType {
+                received: self.header("content-type").map(str::to_string),
+                allowed: &[APPLICATION_FORM_URLENCODED],
+            });
+        }
+
+        serde_urlencoded::from_bytes(&self.body_bytes)
+            .map_err(|_| invalid_body_error())
+    }
+
+    /// Deserializes the request body and checks it with an inline rule.
+    ///
+    /// Meant for checks too simple to justify a validator derive. The body is
+    /// parsed like `body()`, then passed to `f`, which returns every broken
+    /// rule as a message. A failure is sent as `422 Unprocessable Entity`,
+    /// listing the messages in `errors` with the `{ code, message }` shape of
+    /// the validator errors, using `"custom"` as the code.
+    ///
+    /// ### Errors
+    ///
+    /// This function will return an error if:
+    /// - The request body is empty or can't be deserialized to `T`
+    /// - The closure returns `Err` (`RequestError::ValidationFailed`)
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct Transfer {
+    ///     from: String,
+    ///     to: String,
+    ///     amount: u64,
+    /// }
+    ///
+    /// #[post("/transfers")]
+    /// async fn transfer(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let transfer = ctx.validate_with(|transfer: &Transfer| {
+    ///         if transfer.from == transfer.to {
+    ///             return Err(vec!["Cannot transfer to the same account".into()]);
+    ///         }
+    ///
+    ///         Ok(())
+    ///     })?;
+    ///
+    ///     Ok(HttpResponse::Created().data(transfer.amount))
+    /// }
+    /// ```
+    pub fn validate_with<T, F>(&self, f: F) -> Result<T, RequestError>
+    where
+        T: DeserializeOwned,
+        F: Fn(&T) -> Result<(), Vec<String>>,
+    {
+        let body = self.body::<T>()?;
+
+        f(&body).map_err(|errors| {
+            RequestError::ValidationFailed("Invalid request body", errors)
+        })?;
+
+        Ok(body)
+    }
+
+    /// Deserializes the request body from JSON, rejecting unknown fields.
+    ///
+    /// Works like `body()`, but fails with a `400 Bad Request` listing every
+    /// field that is not part of the target type, including nested ones
+    /// (e.g. `address.zip`). This is the per-request equivalent of serde's
+    /// `deny_unknown_fields`, without having to annotate each type.
+    ///
+    /// `body()` behaves the same way when `deny_unknown_fields = true` is set
+    /// in the `[application]` config section.
+    ///
+    /// ### Errors
+    ///
+    /// This function will return an error if:
+    /// - The request body is empty
+    /// - The content type is not JSON, like with `body()`
+    /// - The body contains invalid JSON or doesn't match the target type `T`
+    /// - The body contains fields that the target type `T` doesn't declare
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[post("/users")]
+    /// async fn create_user(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     // `{"name": "Ana", "admin": true}` is rejected with
+    ///     // "Unexpected fields in request body: admin"
+    ///     let user_data: CreateUserRequest = ctx.body_strict()?;
+    ///
+    ///     Ok(HttpResponse::Created().message("User created"))
+    /// }
+    /// ```
+    pub fn body_strict<T: DeserializeOwned>(&self) -> Result<T, RequestError> {
+        if self.body_bytes.is_empty() {
+            return Err(RequestError::BodyIsEmpty("Request body is empty"));
+        }
+
+        ensure_json_body(self)?;
+
+        let mut unknown_fields = Vec::new();
+        let mut on_unknown = |path: serde_ignored::Path| {
+            unknown_fields.push(path.to_string());
+        };
+
+        let parsed: T = match self.json_engine() {
+            Some(engine) => json_engine::deserialize_with(
+                engine.as_ref(),
+                &self.body_bytes,
+                |deserializer| {
+                    serde_ignored::deserialize(deserializer, &mut on_unknown)
+                },
+            )
+            .map_err(|_| invalid_body_error())?,
+            None => {
+                let mut deserializer =
+                    serde_json::Deserializer::from_slice(&self.body_bytes);
+
+                let parsed =
+                    serde_ignored::deserialize(&mut deserializer, &mut on_unknown)
+                        .map_err(|_| invalid_body_error())?;
+
+                // Rejects trailing data after the value, like `serde_json::from_slice`.
+                deserializer.end().map_err(|_| invalid_body_error())?;
+
+                parsed
+            }
+        };
+
+        if !unknown_fields.is_empty() {
+            let message = "Unknown fields in request body";
+            let details = format!(
+                "Unexpected fields in request body: {}",
+                unknown_fields.join(", ")
+            );
+
+            return Err(RequestError::ParseError(message, details));
+        }
+
+        Ok(parsed)
     }
 
     /// Deserializes query parameters from the URL query string to a specific type.
@@ -248,31 +818,334 @@ impl Context {
             return Ok(None);
         }
 
-        let deserializer = serde_urlencoded::Deserializer::new(
-            form_urlencoded::parse(query_string.as_bytes()),
-        );
-
-        let parsed: T =
-            serde_path_to_error::deserialize(deserializer).map_err(|_| {
-                let message = "Invalid query parameters";
-                let details =
-                    "Failed to parse query parameters to the required type.";
-                RequestError::ParseError(message, details.into())
-            })?;
+        let parsed: T = deserialize_query(query_string).ok_or_else(|| {
+            let message = "Invalid query parameters";
+            let details = "Failed to parse query parameters to the required type.";
+            RequestError::ParseError(message, details.into())
+        })?;
 
         Ok(Some(parsed))
     }
 
-    /// Checks if the request has a non-empty body.
+    /// Deserializes query parameters that the endpoint requires.
+    ///
+    /// This is the strict counterpart of `query()`. Instead of returning
+    /// `Ok(None)` when the URL has no query string, it fails with a
+    /// `400 Bad Request`, so handlers don't need the `ok_or` dance.
+    ///
+    /// ### Type Parameters
+    ///
+    /// * `T` - The type to deserialize the query parameters to (must implement `DeserializeOwned`)
+    ///
+    /// ### Errors
+    ///
+    /// This function will return an error if:
+    /// - The URL has no query string
+    /// - The query parameters cannot be deserialized to the target type `T`
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Pagination {
+    ///     page: u32,
+    ///     limit: u32,
+    /// }
+    ///
+    /// // Route: GET /users?page=1&limit=10
+    /// #[get("/users")]
+    /// async fn list(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let query: Pagination = ctx.query_required()?;
+    ///
+    ///     Ok(HttpResponse::Ok().data(query.page))
+    /// }
+    /// ```
+    pub fn query_required<T: DeserializeOwned>(&self) -> Result<T, RequestError> {
+        self.query()?.ok_or_else(|| {
+            let message = "Query parameters are required";
+            let details =
+                "This endpoint requires query parameters, but none were provided.";
+
+            RequestError::ParseError(message, details.into())
+        })
+    }
+
+    /// Gets every query parameter as a decoded `(key, value)` pair.
+    ///
+    /// Unlike `query()`, repeated keys are all kept, in the order they appear
+    /// in the URL. Keys and values are percent-decoded, `+` decodes to a
+    /// space, and empty values such as `?x=` are kept as empty strings.
+    ///
+    /// ### Returns
+    ///
+    /// The list of pairs, empty if the URL has no query string.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// // Route: GET /search?tag=a&tag=b&page=2
+    /// #[get("/search")]
+    /// async fn search(&self, ctx: Context) -> HttpResponse {
+    ///     let pairs = ctx.query_pairs();
+    ///
+    ///     HttpResponse::Ok().data(pairs)
+    /// }
+    /// ```
+    pub fn query_pairs(&self) -> Vec<(String, String)> {
+        let query_string = self.uri.query().unwrap_or("");
+
+        form_urlencoded::parse(query_string.as_bytes())
+            .into_owned()
+            .collect()
+    }
+
+    /// Gets all the values of a repeated query parameter.
+    ///
+    /// ### Arguments
+    /// * `key` - The decoded parameter name to search for (case-sensitive).
+    ///
+    /// ### Returns
+    ///
+    /// The values in URL order, empty if the parameter is absent.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// // Route: GET /posts?tag=rust&tag=web
+    /// #[get("/posts")]
+    /// async fn posts(&self, ctx: Context) -> HttpResponse {
+    ///     let tags = ctx.query_all("tag");
+    ///
+    ///     HttpResponse::Ok().data(tags)
+    /// }
+    /// ```
+    pub fn query_all(&self, key: &str) -> Vec<String> {
+        self.query_pairs()
+            .into_iter()
+            .filter(|(name, _)| name == key)
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    /// Checks if the request body is empty.
+    ///
+    /// Useful for middlewares and handlers that need to branch on body
+    /// presence before attempting to parse it.
+    ///
+    /// ### Returns
+    ///
+    /// Returns `true` if the request has no body content, `false` otherwise.
+    pub const fn is_body_empty(&self) -> bool {
+        self.body_bytes.is_empty()
+    }
+
+    /// Gets the size of the request body in bytes without parsing it.
+    ///
+    /// ### Returns
+    ///
+    /// The number of bytes in the request body, `0` if it is empty.
+    pub const fn body_len(&self) -> usize {
+        self.body_bytes.len()
+    }
+
+    /// Gets the request body exactly as it was received, without parsing it.
+    ///
+    /// The bytes are borrowed from the `Context`, so they are neither copied
+    /// nor modified, which is what signature checks like webhook HMACs need.
+    ///
+    /// ### Example
     ///
-    /// This is an internal method used by the framework to determine
-    /// if the request contains body data. It's primarily used for
-    /// internal request processing logic.
+    /// ```rust,ignore
+    /// #[post("/webhooks")]
+    /// async fn webhook(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let signature = ctx.header("x-signature").unwrap_or_default();
+    ///
+    ///     if !self.verifier.verify(ctx.body_raw(), signature) {
+    ///         return Err(ctx.abort(StatusCode::UNAUTHORIZED, "Invalid signature"));
+    ///     }
+    ///
+    ///     Ok(HttpResponse::Ok())
+    /// }
+    /// ```
+    pub const fn body_raw(&self) -> &Bytes {
+        &self.body_bytes
+    }
+
+    /// Gets the request body as a UTF-8 string, without parsing it.
     ///
     /// ### Returns
     ///
-    /// Returns `true` if the request has a body with content, `false` if empty.
-    pub(crate) const fn has_body(&self) -> bool {
-        !self.body_bytes.is_empty()
+    /// The body as a `String`, empty if the request has no body, or a
+    /// `RequestError::ParseError` if the body is not valid UTF-8.
+    pub fn body_string(&self) -> Result<String, RequestError> {
+        String::from_utf8(self.body_bytes.to_vec()).map_err(|_| {
+            let message = "Invalid request body";
+            let details = "Request body is not valid UTF-8.";
+
+            RequestError::ParseError(message, details.into())
+        })
+    }
+
+    /// Runs an Axum extractor that Sword doesn't wrap, such as `Host` or a
+    /// custom `FromRequestParts`, against the parts of this request.
+    ///
+    /// The extractor sees the method, URI, headers and extensions of the
+    /// request. Headers with non-ASCII values or repeated names are seen as
+    /// `Context::header` returns them.
+    ///
+    /// ### Errors
+    ///
+    /// Returns a `RequestError::ParseError` with the text of the rejection
+    /// as its details if the extractor rejects the request.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use axum_extra::extract::Host;
+    ///
+    /// #[get("/tenant")]
+    /// async fn tenant(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let Host(host) = ctx.extract::<Host>().await?;
+    ///
+    ///     Ok(HttpResponse::Ok().data(host))
+    /// }
+    /// ```
+    pub async fn extract<E>(&self) -> Result<E, RequestError>
+    where
+        E: FromRequestParts<()>,
+        E::Rejection: IntoResponse,
+    {
+        let mut builder = AxumRequest::builder()
+            .method(self.method.clone())
+            .uri(self.uri.clone());
+
+        for (key, value) in &self.headers {
+            if let (Ok(name), Ok(value)) =
+                (key.parse::<HeaderName>(), value.parse::<HeaderValue>())
+            {
+                builder = builder.header(name, value);
+            }
+        }
+
+        let (mut parts, ()) = builder
+            .body(())
+            .map_err(|_| {
+                RequestError::ParseError(
+                    "Failed to build axum request",
+                    "Error building request".to_string(),
+                )
+            })?
+            .into_parts();
+
+        parts.extensions = self.extensions.clone();
+
+        match E::from_request_parts(&mut parts, &()).await {
+            Ok(extracted) => Ok(extracted),
+            Err(rejection) => {
+                let body = rejection.into_response().into_body();
+                let details = to_bytes(body, REJECTION_TEXT_LIMIT)
+                    .await
+                    .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+                    .unwrap_or_default();
+
+                Err(RequestError::ParseError("Invalid request", details))
+            }
+        }
+    }
+
+    /// Returns a future that resolves when the client disconnects before
+    /// the response is produced.
+    ///
+    /// When a connection is dropped, the server stops polling the handler, so
+    /// code inside the handler simply stops running. Work moved to spawned
+    /// tasks keeps going though, and this future lets that work bail early.
+    /// It is `'static`, so it can be moved into `tokio::spawn` and used as a
+    /// branch of `tokio::select!`.
+    ///
+    /// The future never resolves if the request completes normally.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[post("/reports")]
+    /// async fn generate(&self, ctx: Context) -> HttpResponse {
+    ///     let cancelled = ctx.cancelled();
+    ///
+    ///     let task = tokio::spawn(async move {
+    ///         tokio::select! {
+    ///             _ = cancelled => None,
+    ///             report = build_report() => Some(report),
+    ///         }
+    ///     });
+    ///
+    ///     match task.await.ok().flatten() {
+    ///         Some(report) => HttpResponse::Ok().data(report),
+    ///         None => HttpResponse::InternalServerError(),
+    ///     }
+    /// }
+    /// ```
+    pub fn cancelled(&self) -> impl Future<Output = ()> + Send + 'static {
+        let signal = self.extensions.get::<CancellationSignal>().cloned();
+
+        async move {
+            match signal {
+                Some(signal) => signal.cancelled().await,
+                None => std::future::pending().await,
+            }
+        }
     }
 }
+
+/// How much of a rejection body `Context::extract` reads for its details.
+const REJECTION_TEXT_LIMIT: usize = 16 * 1024;
+
+fn invalid_body_error() -> RequestError {
+    let message = "Invalid request body";
+    let details = "Failed to parse request body to the required type.";
+
+    RequestError::ParseError(message, details.into())
+}
+
+/// Fails with `UnsupportedMediaType` unless the body is JSON, with the same
+/// content types the built-in Content-Type check accepts as JSON.
+fn ensure_json_body(ctx: &Context) -> Result<(), RequestError> {
+    if ctx.header("content-type").is_some_and(is_json) {
+        return Ok(());
+    }
+
+    Err(RequestError::UnsupportedMediaType {
+        received: ctx.header("content-type").map(str::to_string),
+        allowed: &[APPLICATION_JSON],
+    })
+}
+
+/// The credentials of an `Authorization` header value with the given
+/// scheme, compared case-insensitively.
+fn authorization_credentials<'a>(value: &'a str, scheme: &str) -> Option<&'a str> {
+    let (name, credentials) = value.trim().split_once(' ')?;
+
+    name.eq_ignore_ascii_case(scheme)
+        .then_some(credentials.trim_start())
+}
+
+#[cfg(not(feature = "nested-query"))]
+fn deserialize_query<T: DeserializeOwned>(query_string: &str) -> Option<T> {
+    let deserializer = serde_urlencoded::Deserializer::new(form_urlencoded::parse(
+        query_string.as_bytes(),
+    ));
+
+    serde_path_to_error::deserialize(deserializer).ok()
+}
+
+#[cfg(feature = "nested-query")]
+fn deserialize_query<T: DeserializeOwned>(query_string: &str) -> Option<T> {
+    // Form encoding keeps `+` decoding as a space, matching `serde_urlencoded`.
+    let config = serde_qs::Config::new().use_form_encoding(true);
+    let deserializer =
+        serde_qs::Deserializer::with_config(config, query_string.as_bytes()).ok()?;
+
+    serde_path_to_error::deserialize(deserializer).ok()
+}
@@ -16,6 +16,11 @@ pub trait ValidatorRequestValidation {
     fn params_validator<T: DeserializeOwned + Validate>(
         &self,
     ) -> Result<T, RequestError>;
+
+    fn validate_all<Q, B>(&self) -> Result<(Option<Q>, B), RequestError>
+    where
+        Q: DeserializeOwned + Validate,
+        B: DeserializeOwned + Validate;
 }
 
 #[cfg(feature = "validator")]
@@ -157,19 +162,55 @@ impl ValidatorRequestValidation for Context {
     /// This method combines path parameter parsing with validation using the
     /// `validator` crate. It first deserializes the path parameters and then
     /// runs validation rules defined on the target type.
+    ///
+    /// Parameters are deserialized like query parameters, so numeric and
+    /// boolean fields are parsed from their text. Validation errors are sent
+    /// like the ones of `body_validator`, through the formatter registered
+    /// with `with_validation_formatter`, if any.
+    ///
+    /// ### Errors
+    ///
+    /// This function will return an error if:
+    /// - A parameter can't be parsed to its field type (`RequestError::ParseError`)
+    /// - The data fails validation rules (`RequestError::ValidatorError`)
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[derive(Deserialize, Validate)]
+    /// struct ArticleParams {
+    ///     #[validate(regex(path = *SLUG_REGEX))]
+    ///     slug: String,
+    ///
+    ///     #[validate(range(min = 1))]
+    ///     page: u32,
+    /// }
+    ///
+    /// #[get("/articles/{slug}/pages/{page}")]
+    /// async fn page(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let params: ArticleParams = ctx.params_validator()?;
+    ///
+    ///     Ok(HttpResponse::Ok().data(params.slug))
+    /// }
+    /// ```
     fn params_validator<T: DeserializeOwned + Validate>(
         &self,
     ) -> Result<T, RequestError> {
-        let params = serde_json::to_value(self.params.clone()).map_err(|e| {
-            RequestError::ParseError("Failed to serialize params", e.to_string())
-        })?;
+        let encoded = form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.params())
+            .finish();
 
-        let deserialized: T = serde_json::from_value(params).map_err(|e| {
-            RequestError::ParseError(
-                "Failed to deserialize params to the target type",
-                e.to_string(),
-            )
-        })?;
+        let deserializer = serde_urlencoded::Deserializer::new(
+            form_urlencoded::parse(encoded.as_bytes()),
+        );
+
+        let deserialized: T = serde_path_to_error::deserialize(deserializer)
+            .map_err(|e| {
+                RequestError::ParseError(
+                    "Failed to deserialize params to the target type",
+                    e.to_string(),
+                )
+            })?;
 
         deserialized.validate().map_err(|error| {
             RequestError::ValidatorError("Invalid request params", error)
@@ -177,4 +218,50 @@ impl ValidatorRequestValidation for Context {
 
         Ok(deserialized)
     }
+
+    /// Deserializes and validates the query parameters and the request body
+    /// in one call.
+    ///
+    /// Both are validated before returning, so the field errors of the query
+    /// and of the body are sent together in one 422 response, under `query`
+    /// and `body` keys. Deserialization errors are returned right away, like
+    /// with `query_validator` and `body_validator`.
+    ///
+    /// ### Errors
+    ///
+    /// This function will return an error if:
+    /// - The query or the body can't be deserialized (`RequestError::ParseError`)
+    /// - The request body is empty (`RequestError::BodyIsEmpty`)
+    /// - Either fails its validation rules (`RequestError::CombinedValidatorError`)
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[post("/users")]
+    /// async fn create_user(&self, ctx: Context) -> HttpResult<HttpResponse> {
+    ///     let (query, user) = ctx.validate_all::<InviteQuery, CreateUser>()?;
+    ///
+    ///     Ok(HttpResponse::Created().data(user))
+    /// }
+    /// ```
+    fn validate_all<Q, B>(&self) -> Result<(Option<Q>, B), RequestError>
+    where
+        Q: DeserializeOwned + Validate,
+        B: DeserializeOwned + Validate,
+    {
+        let query = self.query::<Q>()?;
+        let body = self.body::<B>()?;
+
+        let query_errors = query.as_ref().and_then(|query| query.validate().err());
+        let body_errors = body.validate().err();
+
+        if query_errors.is_some() || body_errors.is_some() {
+            return Err(RequestError::CombinedValidatorError {
+                query: query_errors,
+                body: body_errors,
+            });
+        }
+
+        Ok((query, body))
+    }
 }
@@ -0,0 +1,85 @@
+pub use axum::extract::ws::*;
+
+use axum::extract::{
+    FromRequestParts, Request as AxumRequest,
+    ws::rejection::WebSocketUpgradeRejection,
+};
+
+use crate::{errors::RequestError, web::Context};
+
+const WEBSOCKET: &str = "websocket";
+
+impl Context {
+    /// Turns the request into a WebSocket upgrade handle.
+    ///
+    /// Call `on_upgrade` on the handle with the function driving the socket,
+    /// and return its response from the handler to complete the handshake.
+    /// The upgrade is taken out of the request extensions, so it can only be
+    /// done once per request.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `RequestError::UnsupportedMediaType` if the `Upgrade` header
+    /// isn't `websocket` or the `Connection` header doesn't include
+    /// `upgrade`, and `RequestError::ParseError` if the handshake headers
+    /// are otherwise invalid or the connection can't be upgraded.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    /// use sword::web::ws::Message;
+    ///
+    /// #[get("/live")]
+    /// async fn live(&self, mut ctx: Context) -> HttpResult<Response> {
+    ///     let upgrade = ctx.ws_upgrade().await?;
+    ///
+    ///     Ok(upgrade.on_upgrade(|mut socket| async move {
+    ///         while let Some(Ok(message)) = socket.recv().await {
+    ///             if socket.send(message).await.is_err() {
+    ///                 break;
+    ///             }
+    ///         }
+    ///     }))
+    /// }
+    /// ```
+    pub async fn ws_upgrade(&mut self) -> Result<WebSocketUpgrade, RequestError> {
+        if !self.is_websocket_upgrade() {
+            return Err(RequestError::UnsupportedMediaType {
+                received: self.header("Upgrade").map(str::to_string),
+                allowed: &[WEBSOCKET],
+            });
+        }
+
+        let mut context = self.clone();
+        context.extensions = std::mem::take(&mut self.extensions);
+
+        let (mut parts, _) = AxumRequest::try_from(context)?.into_parts();
+        let upgrade = WebSocketUpgrade::from_request_parts(&mut parts, &()).await;
+
+        // The extractor removed the upgrade, everything else is handed back.
+        self.extensions = parts.extensions;
+
+        Ok(upgrade?)
+    }
+
+    fn is_websocket_upgrade(&self) -> bool {
+        let upgrade = self
+            .header("Upgrade")
+            .is_some_and(|value| value.eq_ignore_ascii_case(WEBSOCKET));
+
+        let connection = self.header("Connection").is_some_and(|value| {
+            value
+                .split(',')
+                .any(|token| token.trim().eq_ignore_ascii_case("upgrade"))
+        });
+
+        upgrade && connection
+    }
+}
+
+impl From<WebSocketUpgradeRejection> for RequestError {
+    fn from(err: WebSocketUpgradeRejection) -> Self {
+        Self::ParseError("Invalid WebSocket upgrade request", err.body_text())
+    }
+}
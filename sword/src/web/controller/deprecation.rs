@@ -0,0 +1,71 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+
+use axum::{
+    extract::{OriginalUri, Request as AxumRequest, State as AxumState},
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+
+const DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+const SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+/// The layer of a `#[deprecated_route]`, which adds the `Deprecation` and
+/// `Sunset` headers to every response of the route.
+#[derive(Debug, Clone)]
+pub struct DeprecatedRoute {
+    sunset: Option<HeaderValue>,
+    log: bool,
+    hits: Arc<AtomicU64>,
+}
+
+impl DeprecatedRoute {
+    /// `sunset` is an HTTP date, already validated by the macro.
+    pub fn new(sunset: Option<&'static str>, log: bool) -> Self {
+        Self {
+            sunset: sunset.map(HeaderValue::from_static),
+            log,
+            hits: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    pub async fn layer(
+        AxumState(this): AxumState<Self>,
+        request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        if this.log {
+            let hits = this.hits.fetch_add(1, Ordering::Relaxed) + 1;
+
+            // Controllers are nested, so the URI of the request lacks their
+            // base path.
+            let path = match request.extensions().get::<OriginalUri>() {
+                Some(OriginalUri(uri)) => uri.path().to_string(),
+                None => request.uri().path().to_string(),
+            };
+
+            tracing::warn!(
+                target: "sword::deprecated",
+                method = %request.method(),
+                path = %path,
+                sunset = this.sunset.as_ref().and_then(|s| s.to_str().ok()),
+                hits,
+                "Deprecated route requested"
+            );
+        }
+
+        let mut response = next.run(request).await;
+        let headers = response.headers_mut();
+
+        headers.insert(DEPRECATION, HeaderValue::from_static("true"));
+
+        if let Some(sunset) = this.sunset {
+            headers.insert(SUNSET, sunset);
+        }
+
+        response
+    }
+}
@@ -1,13 +1,133 @@
-use crate::{core::State as SwordState, errors::DependencyInjectionError};
+mod deprecation;
+mod path;
+mod tag;
+mod timeout;
+
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{
+    core::{FeatureFlags, State as SwordState},
+    errors::DependencyInjectionError,
+};
 use axum::Router as AxumRouter;
 
+pub use deprecation::DeprecatedRoute;
+pub use path::{
+    controller_route_path, join_paths, mount_controller_router, route_url,
+};
+pub use tag::RouteTag;
+pub use timeout::with_response_timeout;
+
 pub trait Controller: ControllerBuilder {
     fn router(state: SwordState) -> AxumRouter;
+
+    /// The router of an already built controller, used instead of `router`
+    /// by `ApplicationBuilder::with_controller_instance`.
+    fn instance_router(controller: Self, state: SwordState) -> AxumRouter
+    where
+        Self: Sized;
+
+    /// The number of routes declared in the `#[routes]` impl.
+    fn route_count() -> usize;
+
+    /// The routes of the `#[routes]` impl that declare a `#[body_limit]`.
+    fn body_limits() -> &'static [RouteBodyLimit];
+
+    /// Every method and path declared in the `#[routes]` impl.
+    fn routes() -> &'static [ControllerRoute];
+}
+
+/// A method and path declared in a `#[routes]` impl, with the route path
+/// relative to the controller base path.
+#[derive(Debug, Clone, Copy)]
+pub struct ControllerRoute {
+    pub method: &'static str,
+    pub path: &'static str,
+
+    /// The feature flag declared with `#[feature]`, without which the route
+    /// isn't added.
+    pub feature: Option<&'static str>,
+
+    /// The tag declared with `#[tag]` on the route. Routes without one use
+    /// the tag of their controller.
+    pub tag: Option<&'static str>,
+
+    /// Whether the route is declared with `#[stream_body]`, so its body is
+    /// not read when the `Context` is extracted.
+    pub stream_body: bool,
+}
+
+impl ControllerRoute {
+    /// Whether the route is added to the router, which is when it has no
+    /// `#[feature]` or its flag is enabled.
+    pub fn is_enabled(&self, state: &SwordState) -> bool {
+        self.feature
+            .is_none_or(|feature| feature_enabled(state, feature))
+    }
+}
+
+/// A `#[body_limit]` declared on a route, with the route path relative to
+/// the controller base path.
+#[derive(Debug, Clone, Copy)]
+pub struct RouteBodyLimit {
+    pub method: &'static str,
+    pub path: &'static str,
+    pub limit: usize,
+}
+
+/// The routes of one `#[routes]` impl of a controller.
+///
+/// Every impl submits its routes with `inventory`, so a controller can split
+/// its handlers across several `#[routes]` impls, and the `#[controller]`
+/// macro merges them when the router is built. Generic controllers can't be
+/// collected this way and declare their routes in a single impl.
+#[doc(hidden)]
+pub struct ControllerRoutes {
+    pub controller: fn() -> TypeId,
+    pub route_count: usize,
+    pub routes: &'static [ControllerRoute],
+    pub body_limits: &'static [RouteBodyLimit],
+    pub add_routes: AddRoutes,
+}
+
+/// Adds the routes of an impl to the router, given the base path of the
+/// controller, the controller itself and the application state.
+#[doc(hidden)]
+pub type AddRoutes = fn(
+    AxumRouter<SwordState>,
+    &'static str,
+    Arc<dyn Any + Send + Sync>,
+    &SwordState,
+) -> AxumRouter<SwordState>;
+
+inventory::collect!(ControllerRoutes);
+
+/// Whether the route gated by `#[feature(name)]` is added to the router.
+#[doc(hidden)]
+pub fn feature_enabled(state: &SwordState, name: &str) -> bool {
+    state
+        .borrow::<FeatureFlags>()
+        .is_ok_and(|flags| flags.is_enabled(name))
+}
+
+/// The routes submitted by the `#[routes]` impls of `C`.
+#[doc(hidden)]
+pub fn controller_routes<C: 'static>()
+-> impl Iterator<Item = &'static ControllerRoutes> {
+    inventory::iter::<ControllerRoutes>
+        .into_iter()
+        .filter(|routes| (routes.controller)() == TypeId::of::<C>())
 }
 
 pub trait ControllerBuilder {
     fn base_path() -> &'static str;
 
+    /// The tag declared with `#[tag]` on the controller.
+    fn tag() -> Option<&'static str>;
+
     fn apply_controller_middlewares(
         router: AxumRouter,
         app_state: SwordState,
@@ -0,0 +1,112 @@
+use std::fmt::Display;
+
+use axum::Router as AxumRouter;
+
+/// Joins paths the way `Router::nest` does, so that the result matches the
+/// `MatchedPath` of the nested route.
+pub fn join_paths(base: &str, path: &str) -> String {
+    match (base.trim_end_matches('/'), path) {
+        ("", path) => path.to_string(),
+        (base, "/") => base.to_string(),
+        (base, path) => format!("{base}{path}"),
+    }
+}
+
+/// Axum can't nest a router under a path with a catch-all, so controllers
+/// whose base path has one register their routes with the full path.
+fn has_catch_all(base: &str) -> bool {
+    base.contains("{*")
+}
+
+/// The path a route is added with to the router of its controller.
+pub fn controller_route_path(base: &str, path: &str) -> String {
+    match has_catch_all(base) {
+        true => join_paths(base, path),
+        false => path.to_string(),
+    }
+}
+
+/// Mounts the router of a controller under its base path.
+pub fn mount_controller_router(base: &str, router: AxumRouter) -> AxumRouter {
+    match base {
+        "/" => router,
+        base if has_catch_all(base) => router,
+        base => AxumRouter::new().nest(base, router),
+    }
+}
+
+/// The URL of a route, with the parameters of the base path replaced by
+/// `base_params` and the ones of its path by `params`, in the order they
+/// appear, percent-encoded. Used by the `*_url` helpers generated by
+/// `#[routes]`.
+///
+/// Panics if `base_params` doesn't hold one value per parameter of the base
+/// path, as the URL would point to another route.
+pub fn route_url(
+    base: &str,
+    base_params: &[&dyn Display],
+    path: &str,
+    params: &[&dyn Display],
+) -> String {
+    let expected = base.replace("{{", "").matches('{').count();
+
+    assert!(
+        base_params.len() == expected,
+        "the base path `{base}` has {expected} parameters but {} were given, \
+         build the URL with the `*_url_in` helper",
+        base_params.len(),
+    );
+
+    join_paths(&fill_params(base, base_params), &fill_params(path, params))
+}
+
+/// Replaces the parameters of `path` by `params`, in order.
+fn fill_params(path: &str, params: &[&dyn Display]) -> String {
+    let mut url = String::with_capacity(path.len());
+    let mut params = params.iter();
+    let mut chars = path.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                url.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                url.push('}');
+            }
+            '{' => {
+                let catch_all = chars.peek() == Some(&'*');
+
+                for char in chars.by_ref() {
+                    if char == '}' {
+                        break;
+                    }
+                }
+
+                if let Some(value) = params.next() {
+                    encode_segment(&mut url, &value.to_string(), catch_all);
+                }
+            }
+            char => url.push(char),
+        }
+    }
+
+    url
+}
+
+/// Percent-encodes the characters not allowed in a path segment, keeping
+/// `/` for catch-all values.
+fn encode_segment(url: &mut String, value: &str, keep_slashes: bool) {
+    for byte in value.bytes() {
+        let allowed = byte.is_ascii_alphanumeric()
+            || b"-._~!$&'()*+,;=:@".contains(&byte)
+            || (keep_slashes && byte == b'/');
+
+        match allowed {
+            true => url.push(byte as char),
+            false => url.push_str(&format!("%{byte:02X}")),
+        }
+    }
+}
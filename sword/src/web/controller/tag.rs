@@ -0,0 +1,36 @@
+use axum::{
+    extract::{Request as AxumRequest, State as AxumState},
+    middleware::Next,
+    response::Response,
+};
+
+/// The tag of the route that handled a request, declared with `#[tag]` on
+/// the route or its controller.
+///
+/// It is inserted in the extensions of the request, for middlewares and
+/// extractors, and in the extensions of the response, where the metrics and
+/// the request logger read it once the route has run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RouteTag(pub &'static str);
+
+impl RouteTag {
+    /// The layer of a `#[tag]`. The tag of the route is applied inside the
+    /// tag of its controller, so it replaces it on the request and is kept
+    /// on the response.
+    #[doc(hidden)]
+    pub async fn layer(
+        AxumState(tag): AxumState<Self>,
+        mut request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        request.extensions_mut().insert(tag);
+
+        let mut response = next.run(request).await;
+
+        if response.extensions().get::<Self>().is_none() {
+            response.extensions_mut().insert(tag);
+        }
+
+        response
+    }
+}
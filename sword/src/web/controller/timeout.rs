@@ -0,0 +1,27 @@
+use std::{future::Future, time::Duration};
+
+use axum::response::{IntoResponse, Response};
+
+use crate::web::HttpResponse;
+
+/// Runs a handler future bounded by the `#[response_timeout]` of its route.
+///
+/// The timer starts when the handler is called, after the `Context` (and
+/// therefore the request body) has been extracted, so slow uploads don't
+/// count against it.
+pub async fn with_response_timeout<F, R>(
+    millis: u64,
+    raw: &'static str,
+    handler: F,
+) -> Response
+where
+    F: Future<Output = R>,
+    R: IntoResponse,
+{
+    match tokio::time::timeout(Duration::from_millis(millis), handler).await {
+        Ok(response) => response.into_response(),
+        Err(_) => HttpResponse::GatewayTimeout()
+            .message(format!("The handler did not respond within {raw}"))
+            .into_response(),
+    }
+}
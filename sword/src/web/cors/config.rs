@@ -0,0 +1,199 @@
+use std::time::Duration;
+
+use axum::http::{HeaderName, HeaderValue, Method, Uri};
+use serde::Deserialize;
+use tower_http::cors::{AllowHeaders, AllowMethods, AllowOrigin, Any, CorsLayer};
+
+use crate::{
+    core::{Config, ConfigItem},
+    errors::ConfigError,
+};
+
+const WILDCARD: &str = "*";
+
+/// The `[cors]` config section, as written in the TOML file.
+#[derive(Debug, Clone, Deserialize)]
+struct CorsSection {
+    #[serde(default)]
+    allowed_origins: Vec<String>,
+
+    #[serde(default)]
+    allowed_methods: Vec<String>,
+
+    #[serde(default)]
+    allowed_headers: Vec<String>,
+
+    #[serde(default)]
+    allow_credentials: bool,
+
+    max_age_seconds: Option<u64>,
+}
+
+impl ConfigItem for CorsSection {
+    fn toml_key() -> &'static str {
+        "cors"
+    }
+}
+
+/// A CORS policy read from the `[cors]` config section.
+///
+/// `"*"` allows any origin, method or header. Origins are checked when the
+/// policy is loaded, so a malformed one fails at startup instead of
+/// silently never matching. Register it with `ApplicationBuilder::with_cors`,
+/// which builds the `CorsLayer` from it.
+///
+/// ```toml,ignore
+/// [cors]
+/// allowed_origins = ["https://app.example.com", "http://localhost:5173"]
+/// allowed_methods = ["GET", "POST", "PUT", "DELETE"]
+/// allowed_headers = ["Content-Type", "Authorization"]
+/// allow_credentials = true
+/// max_age_seconds = 3600
+/// ```
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// use sword::{prelude::*, web::cors::Cors};
+///
+/// let builder = Application::builder()?;
+/// let cors = Cors::from_config(&builder.config)?;
+///
+/// let app = builder
+///     .with_controller::<UsersController>()
+///     .with_cors(cors)
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cors {
+    origins: Option<Vec<HeaderValue>>,
+    methods: Option<Vec<Method>>,
+    headers: Option<Vec<HeaderName>>,
+    credentials: bool,
+    max_age: Option<Duration>,
+}
+
+impl Cors {
+    /// Reads and validates the `[cors]` section of the configuration.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `ConfigError::KeyNotFound` if the section is missing, and
+    /// `ConfigError::InvalidValue` if an origin is not a `http` or `https`
+    /// URL without a path, a method or header name is invalid, or
+    /// `allow_credentials` is combined with a `"*"`, which browsers reject.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        let section = config.get::<CorsSection>()?;
+
+        let origins =
+            parse_list(&section.allowed_origins, "allowed_origins", parse_origin)?;
+        let methods =
+            parse_list(&section.allowed_methods, "allowed_methods", |method| {
+                Method::from_bytes(method.as_bytes())
+                    .map_err(|_| "invalid HTTP method".into())
+            })?;
+        let headers =
+            parse_list(&section.allowed_headers, "allowed_headers", |header| {
+                HeaderName::try_from(header)
+                    .map_err(|_| "invalid header name".into())
+            })?;
+
+        if section.allow_credentials {
+            let wildcards = [
+                ("allowed_origins", origins.is_none()),
+                ("allowed_methods", methods.is_none()),
+                ("allowed_headers", headers.is_none()),
+            ];
+
+            if let Some((key, _)) = wildcards.iter().find(|(_, wildcard)| *wildcard)
+            {
+                return Err(ConfigError::InvalidValue {
+                    key: format!("cors.{key}"),
+                    value: WILDCARD.to_string(),
+                    reason: "`*` can't be used with `allow_credentials`".to_string(),
+                });
+            }
+        }
+
+        Ok(Self {
+            origins,
+            methods,
+            headers,
+            credentials: section.allow_credentials,
+            max_age: section.max_age_seconds.map(Duration::from_secs),
+        })
+    }
+}
+
+impl From<Cors> for CorsLayer {
+    fn from(cors: Cors) -> Self {
+        let mut layer = CorsLayer::new()
+            .allow_origin(match cors.origins {
+                Some(origins) => AllowOrigin::list(origins),
+                None => AllowOrigin::from(Any),
+            })
+            .allow_methods(match cors.methods {
+                Some(methods) => AllowMethods::list(methods),
+                None => AllowMethods::from(Any),
+            })
+            .allow_headers(match cors.headers {
+                Some(headers) => AllowHeaders::list(headers),
+                None => AllowHeaders::from(Any),
+            })
+            .allow_credentials(cors.credentials);
+
+        if let Some(max_age) = cors.max_age {
+            layer = layer.max_age(max_age);
+        }
+
+        layer
+    }
+}
+
+/// Parses each value of a list, `None` standing for `"*"`.
+fn parse_list<T>(
+    values: &[String],
+    key: &str,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Result<Option<Vec<T>>, ConfigError> {
+    if values.iter().any(|value| value == WILDCARD) {
+        return Ok(None);
+    }
+
+    values
+        .iter()
+        .map(|value| {
+            parse(value).map_err(|reason| ConfigError::InvalidValue {
+                key: format!("cors.{key}"),
+                value: value.clone(),
+                reason,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
+}
+
+/// Parses an origin, `scheme://host[:port]`, as browsers send it in the
+/// `Origin` header.
+fn parse_origin(origin: &str) -> Result<HeaderValue, String> {
+    let uri = origin
+        .parse::<Uri>()
+        .map_err(|_| "not a valid URL".to_string())?;
+
+    match uri.scheme_str() {
+        Some("http" | "https") => {}
+        _ => return Err("the scheme must be http or https".to_string()),
+    }
+
+    if uri.host().is_none_or(str::is_empty) {
+        return Err("the origin has no host".to_string());
+    }
+
+    if !matches!(uri.path(), "" | "/") || uri.query().is_some() {
+        return Err("an origin can't have a path or query".to_string());
+    }
+
+    let origin = origin.trim_end_matches('/');
+
+    HeaderValue::from_str(origin).map_err(|_| "not a valid header value".to_string())
+}
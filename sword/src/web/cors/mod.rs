@@ -0,0 +1,8 @@
+mod config;
+mod preflight;
+
+pub use tower_http::cors::*;
+
+pub use config::Cors;
+
+pub(crate) use preflight::CorsPreflight;
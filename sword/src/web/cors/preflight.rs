@@ -0,0 +1,115 @@
+use std::{collections::HashMap, convert::Infallible, sync::Arc};
+
+use axum::{
+    body::Body,
+    extract::{MatchedPath, Request as AxumRequest, State as AxumState},
+    http::{Method, StatusCode, header},
+    middleware::Next,
+    response::Response,
+};
+use tower::{Layer, ServiceExt, service_fn};
+use tower_http::cors::CorsLayer;
+
+/// Applies the application's `CorsLayer` and answers preflight requests
+/// for routes that don't declare an `OPTIONS` handler.
+///
+/// Whether a controller route declares `OPTIONS` is read from the routes
+/// registered at build time, so the preflight of any other controller route
+/// is answered from the CORS configuration with `204 No Content` before
+/// its middlewares run. A middleware rejecting unauthenticated requests
+/// would otherwise answer the preflight without CORS headers, and the
+/// browser would block the actual request.
+///
+/// Paths that no controller registered, such as the routes of a router
+/// mounted with `with_router`, are given to the route, and the preflight is
+/// only answered when the route rejects the method with `405`.
+#[derive(Clone)]
+pub(crate) struct CorsPreflight {
+    cors: CorsLayer,
+
+    /// Whether each controller route path declares `OPTIONS`.
+    routes: Arc<HashMap<String, bool>>,
+}
+
+impl CorsPreflight {
+    pub fn new(cors: CorsLayer, routes: HashMap<String, bool>) -> Self {
+        Self {
+            cors,
+            routes: Arc::new(routes),
+        }
+    }
+
+    pub async fn layer(
+        AxumState(this): AxumState<Self>,
+        request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        // `CorsLayer` answers every `OPTIONS` request by itself, so plain
+        // `OPTIONS` requests skip it to reach the route untouched.
+        if request.method() == Method::OPTIONS && !is_preflight(&request) {
+            return next.run(request).await;
+        }
+
+        if !is_preflight(&request) {
+            return match this.cors.layer(next).oneshot(request).await {
+                Ok(response) => response,
+                Err(never) => match never {},
+            };
+        }
+
+        let declares_options = request
+            .extensions()
+            .get::<MatchedPath>()
+            .and_then(|path| this.routes.get(path.as_str()))
+            .copied();
+
+        match declares_options {
+            Some(true) => next.run(request).await,
+            Some(false) => this.answer(request).await,
+
+            None => {
+                let preflight = copy_request(&request);
+                let response = next.run(request).await;
+
+                if response.status() != StatusCode::METHOD_NOT_ALLOWED {
+                    return response;
+                }
+
+                this.answer(preflight).await
+            }
+        }
+    }
+
+    async fn answer(&self, preflight: AxumRequest) -> Response {
+        let empty = service_fn(|_: AxumRequest| async {
+            Ok::<_, Infallible>(Response::new(Body::empty()))
+        });
+
+        let mut response = match self.cors.layer(empty).oneshot(preflight).await {
+            Ok(response) => response,
+            Err(never) => match never {},
+        };
+
+        *response.status_mut() = StatusCode::NO_CONTENT;
+
+        response
+    }
+}
+
+fn copy_request(request: &AxumRequest) -> AxumRequest {
+    let mut copy = AxumRequest::new(Body::empty());
+
+    *copy.method_mut() = request.method().clone();
+    *copy.uri_mut() = request.uri().clone();
+    *copy.headers_mut() = request.headers().clone();
+
+    copy
+}
+
+fn is_preflight(request: &AxumRequest) -> bool {
+    request.method() == Method::OPTIONS
+        && request.headers().contains_key(header::ORIGIN)
+        && request
+            .headers()
+            .contains_key(header::ACCESS_CONTROL_REQUEST_METHOD)
+}
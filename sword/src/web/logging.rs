@@ -0,0 +1,148 @@
+//! Structured request logging.
+
+use std::time::Instant;
+
+use axum::{
+    extract::{Request as AxumRequest, State as AxumState},
+    middleware::Next,
+    response::Response,
+};
+use serde::Deserialize;
+
+use crate::{
+    core::{Config, ConfigItem},
+    errors::ConfigError,
+    web::{CurrentRequestId, RouteTag},
+};
+
+/// The `[request_logging]` config section.
+///
+/// ```toml,ignore
+/// [request_logging]
+/// level = "debug"
+/// include_query = true
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+struct RequestLoggingConfig {
+    #[serde(default)]
+    level: RequestLogLevel,
+
+    #[serde(default)]
+    include_query: bool,
+}
+
+impl ConfigItem for RequestLoggingConfig {
+    fn toml_key() -> &'static str {
+        "request_logging"
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum RequestLogLevel {
+    Trace,
+    Debug,
+    #[default]
+    Info,
+    Warn,
+    Error,
+}
+
+/// Built-in middleware that logs one structured line per request, under
+/// the `sword::requests` target.
+///
+/// Every line has the `method`, the `path`, the response `status`, the
+/// `latency_ms` spent in the rest of the application and the `request_id`
+/// assigned by `RequestId`, or `-` when it isn't enabled, and the `tag` of
+/// the route declared with `#[tag]`, or `-` without one. Register it with
+/// `ApplicationBuilder::with_logger`, which reads the `[request_logging]`
+/// config section:
+///
+/// ```toml,ignore
+/// [request_logging]
+/// # The level of the lines: "trace", "debug", "info", "warn" or "error".
+/// # Defaults to "info".
+/// level = "info"
+///
+/// # Whether the path includes the query string. Query strings may contain
+/// # personal data, so they are left out by default.
+/// include_query = false
+/// ```
+///
+/// The section is optional, without it every line is logged at `info`
+/// without the query string.
+#[derive(Debug, Clone)]
+pub struct RequestLogger {
+    level: RequestLogLevel,
+    include_query: bool,
+}
+
+impl RequestLogger {
+    pub(crate) fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        let section = match config.get::<RequestLoggingConfig>() {
+            Ok(section) => section,
+            Err(ConfigError::KeyNotFound(_)) => RequestLoggingConfig::default(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self {
+            level: section.level,
+            include_query: section.include_query,
+        })
+    }
+
+    pub(crate) async fn layer(
+        AxumState(this): AxumState<Self>,
+        request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        let method = request.method().clone();
+
+        let path = match (this.include_query, request.uri().path_and_query()) {
+            (true, Some(path_and_query)) => path_and_query.as_str().to_owned(),
+            _ => request.uri().path().to_owned(),
+        };
+
+        let request_id = request
+            .extensions()
+            .get::<CurrentRequestId>()
+            .map(|current| current.id.clone());
+
+        let started = Instant::now();
+        let response = next.run(request).await;
+        let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        let status = response.status().as_u16();
+        let request_id = request_id.as_deref().unwrap_or("-");
+        let tag = response
+            .extensions()
+            .get::<RouteTag>()
+            .map_or("-", |tag| tag.0);
+
+        macro_rules! log_request {
+            ($level:expr) => {
+                tracing::event!(
+                    target: "sword::requests",
+                    $level,
+                    %method,
+                    path,
+                    status,
+                    latency_ms,
+                    request_id,
+                    tag,
+                    "Request handled"
+                )
+            };
+        }
+
+        match this.level {
+            RequestLogLevel::Trace => log_request!(tracing::Level::TRACE),
+            RequestLogLevel::Debug => log_request!(tracing::Level::DEBUG),
+            RequestLogLevel::Info => log_request!(tracing::Level::INFO),
+            RequestLogLevel::Warn => log_request!(tracing::Level::WARN),
+            RequestLogLevel::Error => log_request!(tracing::Level::ERROR),
+        }
+
+        response
+    }
+}
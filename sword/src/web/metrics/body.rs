@@ -0,0 +1,143 @@
+use std::{
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use axum::body::{Body, Bytes, HttpBody};
+use http_body::{Frame, SizeHint};
+
+use super::{Metrics, RequestMetrics};
+
+/// Request body wrapper that counts the bytes read by the application.
+pub(crate) struct RequestBodyCounter {
+    inner: Body,
+    read: Arc<AtomicU64>,
+    metrics: Metrics,
+}
+
+impl RequestBodyCounter {
+    pub fn new(inner: Body, read: Arc<AtomicU64>, metrics: Metrics) -> Self {
+        Self {
+            inner,
+            read,
+            metrics,
+        }
+    }
+}
+
+impl HttpBody for RequestBodyCounter {
+    type Data = Bytes;
+    type Error = axum::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let poll = Pin::new(&mut self.inner).poll_frame(cx);
+
+        if let Poll::Ready(Some(Ok(frame))) = &poll
+            && let Some(data) = frame.data_ref()
+        {
+            let len = data.len() as u64;
+
+            self.read.fetch_add(len, Ordering::Relaxed);
+            self.metrics.add_bytes_in(len);
+        }
+
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// Response body wrapper that counts bytes as each chunk is flushed and
+/// emits the request record once the body ends or is dropped.
+pub(crate) struct ResponseBodyCounter {
+    inner: Body,
+    written: u64,
+    read: Arc<AtomicU64>,
+    pending: Option<RequestMetrics>,
+    started: Instant,
+    metrics: Metrics,
+}
+
+impl ResponseBodyCounter {
+    pub fn new(
+        inner: Body,
+        read: Arc<AtomicU64>,
+        record: RequestMetrics,
+        started: Instant,
+        metrics: Metrics,
+    ) -> Self {
+        Self {
+            inner,
+            written: 0,
+            read,
+            pending: Some(record),
+            started,
+            metrics,
+        }
+    }
+
+    fn finish(&mut self) {
+        if let Some(mut record) = self.pending.take() {
+            record.bytes_in = self.read.load(Ordering::Relaxed);
+            record.bytes_out = self.written;
+            record.duration = self.started.elapsed();
+
+            self.metrics.record(record);
+        }
+    }
+}
+
+impl HttpBody for ResponseBodyCounter {
+    type Data = Bytes;
+    type Error = axum::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let poll = Pin::new(&mut self.inner).poll_frame(cx);
+
+        match &poll {
+            Poll::Ready(Some(Ok(frame))) => {
+                if let Some(data) = frame.data_ref() {
+                    let len = data.len() as u64;
+
+                    self.written += len;
+                    self.metrics.add_bytes_out(len);
+                }
+            }
+            Poll::Ready(None) => self.finish(),
+            _ => {}
+        }
+
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl Drop for ResponseBodyCounter {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
@@ -0,0 +1,80 @@
+use std::{
+    sync::{Arc, OnceLock, atomic::AtomicU64},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    body::Body,
+    extract::{MatchedPath, Request as AxumRequest, State as AxumState},
+    middleware::Next,
+    response::Response as AxumResponse,
+};
+
+use super::{
+    Metrics, RequestMetrics,
+    body::{RequestBodyCounter, ResponseBodyCounter},
+};
+use crate::web::RouteTag;
+
+/// The template of the route that handled the request, filled in by
+/// `MetricsLayer::capture_route` once the router has matched it.
+///
+/// The metrics layer wraps the whole router, so it runs before routing and
+/// can't read `MatchedPath` itself.
+#[derive(Clone, Default)]
+struct MatchedRoute(Arc<OnceLock<String>>);
+
+pub(crate) struct MetricsLayer;
+
+impl MetricsLayer {
+    pub async fn layer(
+        AxumState(metrics): AxumState<Metrics>,
+        mut request: AxumRequest,
+        next: Next,
+    ) -> AxumResponse {
+        let started = Instant::now();
+        let read = Arc::new(AtomicU64::new(0));
+
+        let method = request.method().clone();
+        let path = request.uri().path().to_string();
+
+        let matched = MatchedRoute::default();
+        request.extensions_mut().insert(matched.clone());
+
+        let request = request.map(|body| {
+            Body::new(RequestBodyCounter::new(body, read.clone(), metrics.clone()))
+        });
+
+        let response = next.run(request).await;
+
+        let record = RequestMetrics {
+            method,
+            path,
+            route: matched.0.get().cloned(),
+            tag: response.extensions().get::<RouteTag>().map(|tag| tag.0),
+            status: response.status(),
+            bytes_in: 0,
+            bytes_out: 0,
+            duration: Duration::ZERO,
+        };
+
+        response.map(|body| {
+            Body::new(ResponseBodyCounter::new(
+                body, read, record, started, metrics,
+            ))
+        })
+    }
+
+    /// Records the matched route template for the metrics layer. Applied to
+    /// the routes, where `MatchedPath` is available.
+    pub async fn capture_route(request: AxumRequest, next: Next) -> AxumResponse {
+        if let (Some(matched), Some(path)) = (
+            request.extensions().get::<MatchedRoute>(),
+            request.extensions().get::<MatchedPath>(),
+        ) {
+            let _ = matched.0.set(path.as_str().to_string());
+        }
+
+        next.run(request).await
+    }
+}
@@ -0,0 +1,188 @@
+mod body;
+mod layer;
+mod routes;
+
+use std::{
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use axum::http::{Method, StatusCode};
+
+pub(crate) use layer::MetricsLayer;
+pub use routes::RouteStats;
+
+use routes::RouteTable;
+
+type Observer = Arc<dyn Fn(&RequestMetrics) + Send + Sync>;
+
+/// Traffic counters collected by the built-in metrics middleware.
+///
+/// Register it with `ApplicationBuilder::with_metrics` and keep a clone
+/// around to read the totals. Every request adds the number of request body
+/// bytes read by the application and the number of response body bytes
+/// sent to the client. Response bytes are counted as each chunk is flushed,
+/// so streaming responses are accounted for while they are still running.
+///
+/// Requests are also counted and timed per method, route template and
+/// status class (`2xx`, `4xx`, ...), which is enough to compute per-route
+/// error rates while keeping the number of label combinations low. Read
+/// them with `route_stats`, or with `render` in the Prometheus text format.
+/// Requests that match no route share the `unmatched` route label. Routes
+/// declared with `#[tag]` also carry a `tag` label, to aggregate them by
+/// business domain.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// use sword::prelude::*;
+/// use sword::web::metrics::Metrics;
+///
+/// let metrics = Metrics::new().on_request(|record| {
+///     println!("{} {} -> {} bytes", record.method, record.path, record.bytes_out);
+/// });
+///
+/// let app = Application::builder()?
+///     .with_metrics(metrics.clone())
+///     .build()?;
+///
+/// println!("total sent: {}", metrics.bytes_out());
+/// println!("{}", metrics.render());
+/// ```
+#[derive(Clone, Default)]
+pub struct Metrics {
+    inner: Arc<MetricsInner>,
+}
+
+#[derive(Default)]
+struct MetricsInner {
+    requests: AtomicU64,
+    bytes_in: AtomicU64,
+    bytes_out: AtomicU64,
+    exact_status_codes: AtomicBool,
+    routes: RouteTable,
+    observers: RwLock<Vec<Observer>>,
+}
+
+/// Per-request traffic record passed to `Metrics::on_request` observers.
+///
+/// It is emitted once the response body has been fully sent, or dropped
+/// if the client went away before the end of the stream.
+#[derive(Debug, Clone)]
+pub struct RequestMetrics {
+    pub method: Method,
+    pub path: String,
+    /// The template of the matched route, e.g. `/users/{id}`, or `None`
+    /// if the request matched no route.
+    pub route: Option<String>,
+    /// The `#[tag]` of the route, or `None` if it has none.
+    pub tag: Option<&'static str>,
+    pub status: StatusCode,
+    pub bytes_in: u64,
+    pub bytes_out: u64,
+    /// Time from the request reaching the middleware to the end of the
+    /// response body.
+    pub duration: Duration,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a callback invoked with the record of every completed request.
+    ///
+    /// Useful for billing or forwarding the numbers to an external system.
+    pub fn on_request<F>(self, observer: F) -> Self
+    where
+        F: Fn(&RequestMetrics) + Send + Sync + 'static,
+    {
+        if let Ok(mut observers) = self.inner.observers.write() {
+            observers.push(Arc::new(observer));
+        }
+
+        self
+    }
+
+    /// Labels the per-route statistics with the exact status code, e.g.
+    /// `404`, instead of the status class, e.g. `4xx`.
+    ///
+    /// Each distinct code adds a label combination per route, so only
+    /// enable it when that level of detail is needed.
+    pub fn exact_status_codes(self) -> Self {
+        self.inner.exact_status_codes.store(true, Ordering::Relaxed);
+        self
+    }
+
+    /// Per-route request counts and durations, sorted by route, method and
+    /// status label.
+    pub fn route_stats(&self) -> Vec<RouteStats> {
+        self.inner.routes.snapshot()
+    }
+
+    /// Renders the counters in the Prometheus text exposition format.
+    ///
+    /// Serve it from a route to let Prometheus scrape the application:
+    ///
+    /// ```rust,ignore
+    /// #[get("/metrics")]
+    /// async fn metrics(&self) -> Response {
+    ///     HttpResponse::Ok().text(self.metrics.render())
+    /// }
+    /// ```
+    pub fn render(&self) -> String {
+        routes::render(self)
+    }
+
+    /// Total number of completed requests.
+    pub fn requests(&self) -> u64 {
+        self.inner.requests.load(Ordering::Relaxed)
+    }
+
+    /// Total number of request body bytes read by the application.
+    pub fn bytes_in(&self) -> u64 {
+        self.inner.bytes_in.load(Ordering::Relaxed)
+    }
+
+    /// Total number of response body bytes sent, including partially
+    /// flushed streaming responses.
+    pub fn bytes_out(&self) -> u64 {
+        self.inner.bytes_out.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn add_bytes_in(&self, bytes: u64) {
+        self.inner.bytes_in.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_bytes_out(&self, bytes: u64) {
+        self.inner.bytes_out.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record(&self, record: RequestMetrics) {
+        self.inner.requests.fetch_add(1, Ordering::Relaxed);
+
+        let exact = self.inner.exact_status_codes.load(Ordering::Relaxed);
+        self.inner.routes.observe(&record, exact);
+
+        let Ok(observers) = self.inner.observers.read() else {
+            return;
+        };
+
+        for observer in observers.iter() {
+            observer(&record);
+        }
+    }
+}
+
+impl std::fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Metrics")
+            .field("requests", &self.requests())
+            .field("bytes_in", &self.bytes_in())
+            .field("bytes_out", &self.bytes_out())
+            .finish()
+    }
+}
@@ -0,0 +1,173 @@
+use std::{collections::BTreeMap, fmt::Write, sync::RwLock, time::Duration};
+
+use axum::http::{Method, StatusCode};
+
+use super::{Metrics, RequestMetrics};
+
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Request count and total duration of a route for one status label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteStats {
+    pub method: Method,
+    /// The route template, or `unmatched` for requests that matched no route.
+    pub route: String,
+    /// The `#[tag]` of the route, or `None` if it has none.
+    pub tag: Option<&'static str>,
+    /// The status class, e.g. `4xx`, or the exact code if
+    /// `Metrics::exact_status_codes` is enabled.
+    pub status: String,
+    pub requests: u64,
+    pub duration: Duration,
+}
+
+type RouteKey = (String, String, String);
+
+#[derive(Default)]
+struct RouteCounters {
+    /// Tags are fixed per route, so they don't need to be part of the key.
+    tag: Option<&'static str>,
+    requests: u64,
+    duration: Duration,
+}
+
+/// Aggregated counters keyed by route, method and status label.
+#[derive(Default)]
+pub(crate) struct RouteTable {
+    counters: RwLock<BTreeMap<RouteKey, RouteCounters>>,
+}
+
+impl RouteTable {
+    pub fn observe(&self, record: &RequestMetrics, exact_status_codes: bool) {
+        let route = record.route.as_deref().unwrap_or(UNMATCHED_ROUTE);
+
+        let status = match exact_status_codes {
+            true => record.status.as_u16().to_string(),
+            false => status_class(record.status).to_string(),
+        };
+
+        let key = (route.to_string(), record.method.to_string(), status);
+
+        let Ok(mut counters) = self.counters.write() else {
+            return;
+        };
+
+        let counter = counters.entry(key).or_default();
+
+        counter.tag = record.tag;
+        counter.requests += 1;
+        counter.duration += record.duration;
+    }
+
+    pub fn snapshot(&self) -> Vec<RouteStats> {
+        let Ok(counters) = self.counters.read() else {
+            return Vec::new();
+        };
+
+        counters
+            .iter()
+            .map(|((route, method, status), counter)| RouteStats {
+                method: method.parse().unwrap_or_default(),
+                route: route.clone(),
+                tag: counter.tag,
+                status: status.clone(),
+                requests: counter.requests,
+                duration: counter.duration,
+            })
+            .collect()
+    }
+}
+
+fn status_class(status: StatusCode) -> &'static str {
+    match status.as_u16() {
+        100..=199 => "1xx",
+        200..=299 => "2xx",
+        300..=399 => "3xx",
+        400..=499 => "4xx",
+        _ => "5xx",
+    }
+}
+
+pub(crate) fn render(metrics: &Metrics) -> String {
+    let mut output = String::new();
+    let stats = metrics.route_stats();
+
+    let totals = [
+        (
+            "sword_http_request_bytes_total",
+            "Request body bytes read by the application.",
+            metrics.bytes_in(),
+        ),
+        (
+            "sword_http_response_bytes_total",
+            "Response body bytes sent to the clients.",
+            metrics.bytes_out(),
+        ),
+    ];
+
+    for (name, help, value) in totals {
+        let _ = writeln!(output, "# HELP {name} {help}");
+        let _ = writeln!(output, "# TYPE {name} counter");
+        let _ = writeln!(output, "{name} {value}");
+    }
+
+    let _ = writeln!(
+        output,
+        "# HELP sword_http_requests_total Completed requests by route and status."
+    );
+    let _ = writeln!(output, "# TYPE sword_http_requests_total counter");
+
+    for stat in &stats {
+        let labels = labels(stat);
+        let _ = writeln!(
+            output,
+            "sword_http_requests_total{{{labels}}} {}",
+            stat.requests
+        );
+    }
+
+    let _ = writeln!(
+        output,
+        "# HELP sword_http_request_duration_seconds Request durations by route and status."
+    );
+    let _ = writeln!(output, "# TYPE sword_http_request_duration_seconds summary");
+
+    for stat in &stats {
+        let labels = labels(stat);
+        let seconds = stat.duration.as_secs_f64();
+
+        let _ = writeln!(
+            output,
+            "sword_http_request_duration_seconds_sum{{{labels}}} {seconds}"
+        );
+        let _ = writeln!(
+            output,
+            "sword_http_request_duration_seconds_count{{{labels}}} {}",
+            stat.requests
+        );
+    }
+
+    output
+}
+
+fn labels(stat: &RouteStats) -> String {
+    let mut labels = format!(
+        "method=\"{}\",route=\"{}\",status=\"{}\"",
+        stat.method,
+        escape(&stat.route),
+        stat.status
+    );
+
+    if let Some(tag) = stat.tag {
+        let _ = write!(labels, ",tag=\"{tag}\"");
+    }
+
+    labels
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,59 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    extract::{MatchedPath, Request as AxumRequest, State as AxumState},
+    http::{HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+/// Rejects `HEAD` requests to controller routes that only declare `GET`,
+/// when `ApplicationBuilder::with_auto_head` is disabled.
+///
+/// Axum runs `GET` handlers for `HEAD` requests and strips the body, which
+/// runs the side effects of the handler for a request that should have none.
+/// The rejection is the `405` the router sends for other methods, with the
+/// `Allow` header listing the declared methods, so a handler registered with
+/// `with_method_not_allowed_handler` applies to it too.
+#[derive(Clone)]
+pub(crate) struct HeadCheck {
+    /// The `Allow` header of each route path that declares `GET` but not
+    /// `HEAD`.
+    routes: Arc<HashMap<String, HeaderValue>>,
+}
+
+impl HeadCheck {
+    pub fn new(routes: HashMap<String, HeaderValue>) -> Self {
+        Self {
+            routes: Arc::new(routes),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+
+    pub async fn layer(
+        AxumState(this): AxumState<Self>,
+        request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        if request.method() != Method::HEAD {
+            return next.run(request).await;
+        }
+
+        let allow = request
+            .extensions()
+            .get::<MatchedPath>()
+            .and_then(|path| this.routes.get(path.as_str()));
+
+        match allow {
+            Some(allow) => (
+                StatusCode::METHOD_NOT_ALLOWED,
+                [(header::ALLOW, allow.clone())],
+            )
+                .into_response(),
+            None => next.run(request).await,
+        }
+    }
+}
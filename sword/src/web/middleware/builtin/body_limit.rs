@@ -0,0 +1,211 @@
+use std::{
+    collections::{HashMap, HashSet},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use axum::{
+    body::{Body, Bytes, HttpBody},
+    extract::{MatchedPath, Request as AxumRequest, State as AxumState},
+    http::{Method, header::CONTENT_LENGTH},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http_body::{Frame, SizeHint};
+use http_body_util::Limited;
+
+use crate::{core::BodyLimitExceeded, errors::RequestError, web::HttpResponse};
+
+/// The body limit that applies to the current request, resolved by
+/// `BodyLimitCheck` and read when the `Context` is extracted.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BodyLimit(pub usize);
+
+/// Marks requests to `#[stream_body]` routes, whose body is left unread
+/// when the `Context` is extracted.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StreamedBody;
+
+/// Enforces the `body_limit` of the configuration, or the `#[body_limit]`
+/// of the matched route when it declares one.
+///
+/// Requests whose `Content-Length` exceeds the limit are rejected before the
+/// body is read, with the JSON envelope. Other bodies are cut off once they
+/// go past it and reported when the `Context` is extracted.
+///
+/// With `body_limit_exceeded = "log"` nothing is rejected, requests whose
+/// `Content-Length` exceeds the limit are logged and their body is read in
+/// full. Bodies without a `Content-Length` are logged once they go past it.
+///
+/// `#[stream_body]` routes are marked with `StreamedBody` and only limited
+/// by their own `#[body_limit]`, since their body is not buffered.
+///
+/// Route limits can't be left to a layer on the route alone, because the
+/// built-in layers wrap the routes and read the body before route layers
+/// run, so they are resolved here from the matched path.
+#[derive(Clone)]
+pub(crate) struct BodyLimitCheck {
+    default: usize,
+    routes: Arc<HashMap<(Method, String), usize>>,
+    streamed: Arc<HashSet<(Method, String)>>,
+    exceeded: BodyLimitExceeded,
+}
+
+impl BodyLimitCheck {
+    pub fn new(
+        default: usize,
+        routes: HashMap<(Method, String), usize>,
+        streamed: HashSet<(Method, String)>,
+        exceeded: BodyLimitExceeded,
+    ) -> Self {
+        Self {
+            default,
+            routes: Arc::new(routes),
+            streamed: Arc::new(streamed),
+            exceeded,
+        }
+    }
+
+    pub async fn layer(
+        AxumState(this): AxumState<Self>,
+        mut request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        let route = this.route_of(&request);
+        let streamed = route
+            .as_ref()
+            .is_some_and(|route| this.streamed.contains(route));
+
+        let limit = match route.and_then(|route| this.routes.get(&route)) {
+            Some(limit) => *limit,
+            None if streamed => usize::MAX,
+            None => this.default,
+        };
+
+        if streamed {
+            request.extensions_mut().insert(StreamedBody);
+        }
+
+        let declared_length = request
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok());
+
+        if this.exceeded == BodyLimitExceeded::Log {
+            if let Some(received) = declared_length
+                && received > limit as u64
+            {
+                tracing::warn!(
+                    target: "sword::body_limit",
+                    method = %request.method(),
+                    path = %request.uri().path(),
+                    limit,
+                    received,
+                    "Request body exceeds the body limit"
+                );
+            }
+
+            request.extensions_mut().insert(BodyLimit(usize::MAX));
+
+            if declared_length.is_some() {
+                return next.run(request).await;
+            }
+
+            let method = request.method().to_string();
+            let path = request.uri().path().to_string();
+
+            let request = request.map(|body| {
+                Body::new(OverLimitLogger {
+                    inner: body,
+                    limit,
+                    received: 0,
+                    route: Some((method, path)),
+                })
+            });
+
+            return next.run(request).await;
+        }
+
+        if let Some(received) = declared_length
+            && received > limit as u64
+        {
+            let error = RequestError::BodyTooLarge {
+                limit,
+                received: Some(received),
+                field: None,
+            };
+
+            return HttpResponse::from(error).into_response();
+        }
+
+        request.extensions_mut().insert(BodyLimit(limit));
+
+        let request = request.map(|body| Body::new(Limited::new(body, limit)));
+
+        next.run(request).await
+    }
+
+    /// The method and matched path of the request, when some route declares
+    /// a `#[body_limit]` or `#[stream_body]`.
+    fn route_of(&self, request: &AxumRequest) -> Option<(Method, String)> {
+        if self.routes.is_empty() && self.streamed.is_empty() {
+            return None;
+        }
+
+        let path = request.extensions().get::<MatchedPath>()?;
+
+        Some((request.method().clone(), path.as_str().to_string()))
+    }
+}
+
+/// Body of unknown length read under `body_limit_exceeded = "log"`, which
+/// logs a warning the first time the bytes read go past the limit.
+struct OverLimitLogger {
+    inner: Body,
+    limit: usize,
+    received: u64,
+    route: Option<(String, String)>,
+}
+
+impl HttpBody for OverLimitLogger {
+    type Data = Bytes;
+    type Error = axum::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let poll = Pin::new(&mut self.inner).poll_frame(cx);
+
+        if let Poll::Ready(Some(Ok(frame))) = &poll
+            && let Some(data) = frame.data_ref()
+        {
+            self.received += data.len() as u64;
+
+            if self.received > self.limit as u64
+                && let Some((method, path)) = self.route.take()
+            {
+                tracing::warn!(
+                    target: "sword::body_limit",
+                    method = %method,
+                    path = %path,
+                    limit = self.limit,
+                    received = self.received,
+                    "Streamed request body exceeds the body limit"
+                );
+            }
+        }
+
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
@@ -0,0 +1,63 @@
+use std::future::pending;
+
+use axum::{extract::Request as AxumRequest, middleware::Next, response::Response};
+use tokio::sync::watch;
+
+/// Signal shared between the cancellation layer and every `Context` built
+/// for the same request.
+#[derive(Clone)]
+pub(crate) struct CancellationSignal {
+    receiver: watch::Receiver<bool>,
+}
+
+impl CancellationSignal {
+    pub async fn cancelled(mut self) {
+        if self
+            .receiver
+            .wait_for(|cancelled| *cancelled)
+            .await
+            .is_err()
+        {
+            // The request completed normally, so it will never be cancelled.
+            pending::<()>().await;
+        }
+    }
+}
+
+/// Fires the signal when dropped before the response was produced,
+/// which is what happens when hyper drops the request future after
+/// the client disconnects.
+struct CancelOnDrop {
+    sender: watch::Sender<bool>,
+    completed: bool,
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.sender.send_replace(true);
+        }
+    }
+}
+
+pub(crate) struct RequestCancellation;
+
+impl RequestCancellation {
+    pub async fn layer(mut request: AxumRequest, next: Next) -> Response {
+        let (sender, receiver) = watch::channel(false);
+
+        let mut guard = CancelOnDrop {
+            sender,
+            completed: false,
+        };
+
+        request
+            .extensions_mut()
+            .insert(CancellationSignal { receiver });
+
+        let response = next.run(request).await;
+        guard.completed = true;
+
+        response
+    }
+}
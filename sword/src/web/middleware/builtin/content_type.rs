@@ -1,12 +1,20 @@
 use crate::web::{HttpResponse, Next};
 
 use crate::{
+    errors::RequestError,
     next,
     web::{Context, MiddlewareResult},
 };
 
-const APPLICATION_JSON: &str = "application/json";
+pub(crate) const APPLICATION_JSON: &str = "application/json";
 const MULTIPART_FORM_DATA: &str = "multipart/form-data";
+pub(crate) const APPLICATION_FORM_URLENCODED: &str =
+    "application/x-www-form-urlencoded";
+const SUPPORTED_CONTENT_TYPES: &[&str] = &[
+    APPLICATION_JSON,
+    MULTIPART_FORM_DATA,
+    APPLICATION_FORM_URLENCODED,
+];
 
 pub(crate) struct ContentTypeCheck;
 
@@ -14,18 +22,33 @@ impl ContentTypeCheck {
     pub async fn layer(ctx: Context, next: Next) -> MiddlewareResult {
         let content_type = ctx.header("Content-Type").unwrap_or_default();
 
-        if !ctx.has_body() {
+        if ctx.is_body_empty() {
             return next!(ctx, next);
         }
 
-        if content_type != APPLICATION_JSON
+        if !is_json(content_type)
             && !content_type.contains(MULTIPART_FORM_DATA)
+            && !content_type.starts_with(APPLICATION_FORM_URLENCODED)
         {
-            return Err(HttpResponse::UnsupportedMediaType().message(
-                "Only application/json and multipart/form-data content types are supported.",
-            ));
+            return Err(HttpResponse::from(RequestError::UnsupportedMediaType {
+                received: ctx.header("Content-Type").map(str::to_string),
+                allowed: SUPPORTED_CONTENT_TYPES,
+            }));
         }
 
         next!(ctx, next)
     }
 }
+
+/// Whether the content type is JSON, `application/json` or a type with the
+/// `+json` suffix such as `application/problem+json`.
+///
+/// Like the rest of the check, the type is matched exactly, without
+/// parameters.
+pub(crate) fn is_json(content_type: &str) -> bool {
+    content_type == APPLICATION_JSON
+        || content_type
+            .strip_prefix("application/")
+            .and_then(|subtype| subtype.strip_suffix("+json"))
+            .is_some_and(|name| !name.is_empty() && !name.contains([';', '/', ' ']))
+}
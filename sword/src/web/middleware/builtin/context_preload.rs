@@ -0,0 +1,11 @@
+use crate::web::{Context, MiddlewareResult, Next};
+
+/// Extracts the `Context` once, before the other built-in layers, and
+/// passes the request on rebuilt from it with the body already buffered.
+pub(crate) struct ContextPreload;
+
+impl ContextPreload {
+    pub async fn layer(ctx: Context, next: Next) -> MiddlewareResult {
+        Ok(next.run(ctx.try_into()?).await)
+    }
+}
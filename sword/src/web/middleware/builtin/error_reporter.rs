@@ -0,0 +1,189 @@
+use std::{
+    any::Any,
+    future::Future,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use axum::{
+    body::{Body, HttpBody, to_bytes},
+    extract::{Request as AxumRequest, State as AxumState},
+    http::{Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use futures_util::FutureExt;
+use serde_json::Value;
+
+use crate::web::{CurrentRequestId, HttpResponse};
+
+/// Largest 5xx body read to fill `ErrorReport::message`. Larger bodies and
+/// bodies of unknown length, e.g. streams, are sent untouched.
+const MAX_REPORTED_BODY: usize = 64 * 1024;
+
+type Hook =
+    dyn Fn(ErrorReport) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync;
+
+/// A server error or panic, passed to the hook registered with
+/// `ApplicationBuilder::on_error`.
+#[derive(Debug, Clone)]
+pub struct ErrorReport {
+    /// The status of the response, `500` for panics.
+    pub status: StatusCode,
+
+    /// The method of the request.
+    pub method: Method,
+
+    /// The path of the request.
+    pub path: String,
+
+    /// The ID assigned by the `RequestId` middleware, if enabled.
+    pub request_id: Option<String>,
+
+    /// The user set with `Context::report_user`, if any.
+    pub user: Option<String>,
+
+    /// The `error` of the JSON envelope, or its `message` when it has none.
+    pub message: Option<String>,
+
+    /// The panic message, when the handler panicked.
+    pub panic: Option<String>,
+}
+
+/// The user of the current request, stored in the request extensions and
+/// set with `Context::report_user`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ReportedUser(Arc<Mutex<Option<String>>>);
+
+impl ReportedUser {
+    pub fn set(&self, user: String) {
+        if let Ok(mut current) = self.0.lock() {
+            *current = Some(user);
+        }
+    }
+
+    fn get(&self) -> Option<String> {
+        self.0.lock().ok()?.clone()
+    }
+}
+
+/// Calls the hook registered with `ApplicationBuilder::on_error` for every
+/// `5xx` response and caught panic. Panics are answered with a `500`
+/// envelope.
+///
+/// The hook is spawned on its own task, so the response is sent without
+/// waiting for it.
+#[derive(Clone)]
+pub(crate) struct ErrorReporter {
+    hook: Arc<Hook>,
+}
+
+impl ErrorReporter {
+    pub fn new<F, Fut>(hook: F) -> Self
+    where
+        F: Fn(ErrorReport) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            hook: Arc::new(move |report| Box::pin(hook(report))),
+        }
+    }
+
+    pub async fn layer(
+        AxumState(this): AxumState<Self>,
+        mut request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        let method = request.method().clone();
+        let path = request.uri().path().to_owned();
+
+        let request_id = request
+            .extensions()
+            .get::<CurrentRequestId>()
+            .map(|current| current.id.to_string());
+
+        let user = ReportedUser::default();
+        request.extensions_mut().insert(user.clone());
+
+        let outcome = AssertUnwindSafe(next.run(request)).catch_unwind().await;
+
+        let mut report = ErrorReport {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            method,
+            path,
+            request_id,
+            user: None,
+            message: None,
+            panic: None,
+        };
+
+        let response = match outcome {
+            Ok(response) if !response.status().is_server_error() => {
+                return response;
+            }
+            Ok(response) => {
+                report.status = response.status();
+
+                let buffered = response
+                    .body()
+                    .size_hint()
+                    .exact()
+                    .is_some_and(|len| len <= MAX_REPORTED_BODY as u64);
+
+                match buffered {
+                    true => {
+                        let (parts, body) = response.into_parts();
+
+                        let bytes = to_bytes(body, MAX_REPORTED_BODY)
+                            .await
+                            .unwrap_or_default();
+
+                        report.message = envelope_message(&bytes);
+
+                        Response::from_parts(parts, Body::from(bytes))
+                    }
+                    false => response,
+                }
+            }
+            Err(panic) => {
+                report.panic = Some(panic_message(panic.as_ref()));
+
+                HttpResponse::InternalServerError()
+                    .message("Internal server error")
+                    .into_response()
+            }
+        };
+
+        report.user = user.get();
+
+        tokio::spawn((this.hook)(report));
+
+        response
+    }
+}
+
+fn envelope_message(body: &[u8]) -> Option<String> {
+    let envelope = serde_json::from_slice::<Value>(body).ok()?;
+
+    ["error", "message"]
+        .into_iter()
+        .find_map(|key| envelope.get(key)?.as_str().map(str::to_string))
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    match panic.downcast_ref::<String>() {
+        Some(message) => message.clone(),
+        None => "Unknown panic".to_string(),
+    }
+}
+
+impl std::fmt::Debug for ErrorReporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ErrorReporter")
+    }
+}
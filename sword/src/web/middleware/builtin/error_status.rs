@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request as AxumRequest, State as AxumState},
+    http::StatusCode,
+    middleware::Next,
+    response::Response,
+};
+
+use crate::errors::RequestError;
+
+type StatusOverride = dyn Fn(&RequestError) -> Option<StatusCode> + Send + Sync;
+
+/// Status code overrides registered with
+/// `ApplicationBuilder::with_request_error_status`.
+#[derive(Clone, Default)]
+pub(crate) struct ErrorStatusOverrides {
+    overrides: Vec<Arc<StatusOverride>>,
+}
+
+tokio::task_local! {
+    static OVERRIDES: ErrorStatusOverrides;
+}
+
+impl ErrorStatusOverrides {
+    pub fn push<F>(&mut self, status: F)
+    where
+        F: Fn(&RequestError) -> Option<StatusCode> + Send + Sync + 'static,
+    {
+        self.overrides.push(Arc::new(status));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.overrides.is_empty()
+    }
+
+    /// Makes the overrides available to the `RequestError` mapping while
+    /// the request is handled.
+    pub async fn layer(
+        AxumState(overrides): AxumState<Self>,
+        request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        OVERRIDES.scope(overrides, next.run(request)).await
+    }
+}
+
+impl std::fmt::Debug for ErrorStatusOverrides {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ErrorStatusOverrides")
+            .field("len", &self.overrides.len())
+            .finish()
+    }
+}
+
+/// Returns the status code registered for `error` by the application, if
+/// any. The first override returning `Some` wins.
+pub(crate) fn overridden_status(error: &RequestError) -> Option<StatusCode> {
+    OVERRIDES
+        .try_with(|overrides| {
+            overrides.overrides.iter().find_map(|status| status(error))
+        })
+        .ok()
+        .flatten()
+}
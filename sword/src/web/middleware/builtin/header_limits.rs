@@ -0,0 +1,66 @@
+use axum::{
+    extract::{Request as AxumRequest, State as AxumState},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::web::HttpResponse;
+
+/// Rejects requests with more headers than `max_header_count`, or whose
+/// headers take more than `max_header_bytes`, with `431 Request Header
+/// Fields Too Large` and the JSON envelope.
+///
+/// The size of the headers is the sum of the lengths of their names and
+/// values. Hyper applies its own, larger limits before the request reaches
+/// the application.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct HeaderLimits {
+    max_count: Option<usize>,
+    max_bytes: Option<usize>,
+}
+
+impl HeaderLimits {
+    pub fn new(max_count: Option<usize>, max_bytes: Option<usize>) -> Self {
+        Self {
+            max_count,
+            max_bytes,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.max_count.is_none() && self.max_bytes.is_none()
+    }
+
+    pub async fn layer(
+        AxumState(this): AxumState<Self>,
+        request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        let headers = request.headers();
+
+        if let Some(max_count) = this.max_count
+            && headers.len() > max_count
+        {
+            return HttpResponse::RequestHeaderFieldsTooLarge()
+                .message(format!("The request has more than {max_count} headers"))
+                .into_response();
+        }
+
+        if let Some(max_bytes) = this.max_bytes {
+            let size: usize = headers
+                .iter()
+                .map(|(name, value)| name.as_str().len() + value.len())
+                .sum();
+
+            if size > max_bytes {
+                return HttpResponse::RequestHeaderFieldsTooLarge()
+                    .message(format!(
+                        "The request headers exceed the maximum size of {max_bytes} bytes"
+                    ))
+                    .into_response();
+            }
+        }
+
+        next.run(request).await
+    }
+}
@@ -0,0 +1,72 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use axum::{
+    extract::{Request as AxumRequest, State as AxumState},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tokio::sync::watch;
+
+use crate::web::HttpResponse;
+
+/// Tracks the requests being handled while a graceful shutdown with a
+/// deadline is in progress, so that the ones still running when it expires
+/// can be counted and force-closed.
+#[derive(Clone)]
+pub(crate) struct InFlightRequests {
+    count: Arc<AtomicUsize>,
+    force_close: watch::Sender<bool>,
+}
+
+/// Decrements the counter when the request completes or is dropped.
+struct InFlightGuard(Arc<AtomicUsize>);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl InFlightRequests {
+    pub fn new() -> Self {
+        Self {
+            count: Arc::new(AtomicUsize::new(0)),
+            force_close: watch::channel(false).0,
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    /// Stops every request still running, answering it with
+    /// `503 Service Unavailable`, and returns how many there were.
+    pub fn force_close(&self) -> usize {
+        let count = self.count();
+        self.force_close.send_replace(true);
+        count
+    }
+
+    pub async fn layer(
+        AxumState(this): AxumState<Self>,
+        request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        this.count.fetch_add(1, Ordering::SeqCst);
+
+        let _guard = InFlightGuard(this.count.clone());
+        let mut force_close = this.force_close.subscribe();
+
+        tokio::select! {
+            response = next.run(request) => response,
+            _ = force_close.wait_for(|closed| *closed) => {
+                HttpResponse::ServiceUnavailable()
+                    .message("The server is shutting down")
+                    .into_response()
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request as AxumRequest, State as AxumState},
+    http::{Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::web::HttpResponse;
+
+type Handler = dyn Fn(&[Method]) -> HttpResponse + Send + Sync;
+
+/// Handler registered with `ApplicationBuilder::with_method_not_allowed_handler`.
+///
+/// Replaces the empty `405 Method Not Allowed` responses produced by the
+/// router for paths that exist but don't accept the request method.
+#[derive(Clone)]
+pub(crate) struct MethodNotAllowed {
+    handler: Arc<Handler>,
+}
+
+impl MethodNotAllowed {
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(&[Method]) -> HttpResponse + Send + Sync + 'static,
+    {
+        Self {
+            handler: Arc::new(handler),
+        }
+    }
+
+    pub async fn layer(
+        AxumState(this): AxumState<Self>,
+        request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        let response = next.run(request).await;
+
+        if response.status() != StatusCode::METHOD_NOT_ALLOWED {
+            return response;
+        }
+
+        let Some(allow) = response.headers().get(header::ALLOW).cloned() else {
+            // Only the router sets `Allow` on its 405 responses, so a
+            // handler returning 405 by itself is left untouched.
+            return response;
+        };
+
+        let allowed = allow
+            .to_str()
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|method| Method::from_bytes(method.trim().as_bytes()).ok())
+            .collect::<Vec<_>>();
+
+        let mut custom = (this.handler)(&allowed).into_response();
+        custom.headers_mut().insert(header::ALLOW, allow);
+
+        custom
+    }
+}
+
+impl std::fmt::Debug for MethodNotAllowed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MethodNotAllowed")
+    }
+}
@@ -1,4 +1,14 @@
+pub(crate) mod auto_head;
+pub(crate) mod body_limit;
+pub(crate) mod cancellation;
 pub(crate) mod content_type;
+pub(crate) mod context_preload;
+pub(crate) mod error_reporter;
+pub(crate) mod error_status;
+pub(crate) mod header_limits;
+pub(crate) mod in_flight;
+pub(crate) mod method_not_allowed;
+pub(crate) mod not_found;
 
 /// Module containing various security headers that can be added to HTTP responses.
 /// These headers help protect against common web vulnerabilities.
@@ -13,3 +23,20 @@ pub(crate) mod content_type;
 pub mod helmet;
 
 pub(crate) mod prettifier;
+
+pub(crate) mod readiness;
+
+pub(crate) mod request_id;
+
+pub(crate) mod request_timeout;
+
+pub(crate) mod response_size;
+
+pub(crate) mod server_timing;
+
+pub(crate) mod skip_nulls;
+
+#[cfg(feature = "validator")]
+pub(crate) mod validation_formatter;
+
+pub(crate) mod rejections;
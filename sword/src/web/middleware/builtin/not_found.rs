@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::Request as AxumRequest,
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
+};
+
+use crate::web::HttpResponse;
+
+type Handler = dyn Fn(&str, NotFoundFormat) -> Response + Send + Sync;
+
+/// The format of a `404 Not Found` response, negotiated from the `Accept`
+/// header of the request and passed to the handler registered with
+/// `ApplicationBuilder::with_not_found_handler`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NotFoundFormat {
+    /// The client prefers `text/html` or `application/xhtml+xml`, as
+    /// browsers navigating to a page do.
+    Html,
+
+    /// Any other client, including those without an `Accept` header.
+    #[default]
+    Json,
+}
+
+impl NotFoundFormat {
+    /// Picks HTML when an HTML media type has a strictly higher quality than
+    /// every JSON media type and wildcard, and JSON otherwise.
+    pub fn from_accept(accept: Option<&str>) -> Self {
+        let Some(accept) = accept else {
+            return Self::Json;
+        };
+
+        let (mut html, mut json) = (0.0_f32, 0.0_f32);
+
+        for range in accept.split(',') {
+            let mut params = range.split(';').map(str::trim);
+            let media_type = params.next().unwrap_or_default().to_ascii_lowercase();
+
+            let quality = params
+                .filter_map(|param| param.strip_prefix("q="))
+                .find_map(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            match media_type.as_str() {
+                "text/html" | "application/xhtml+xml" | "text/*" => {
+                    html = html.max(quality);
+                }
+                "application/json" | "application/*" | "*/*" => {
+                    json = json.max(quality);
+                }
+                _ => {}
+            }
+        }
+
+        match html > json {
+            true => Self::Html,
+            false => Self::Json,
+        }
+    }
+}
+
+/// The fallback answering requests that match no route.
+///
+/// Without a handler it sends the JSON envelope regardless of the `Accept`
+/// header. With one, registered with `with_not_found_handler`, the handler
+/// picks the response from the negotiated format, and the response varies
+/// on `Accept`.
+#[derive(Clone, Default)]
+pub(crate) struct NotFound {
+    handler: Option<Arc<Handler>>,
+}
+
+impl NotFound {
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(&str, NotFoundFormat) -> Response + Send + Sync + 'static,
+    {
+        Self {
+            handler: Some(Arc::new(handler)),
+        }
+    }
+
+    pub fn respond(&self, request: &AxumRequest) -> Response {
+        let Some(handler) = &self.handler else {
+            return HttpResponse::NotFound()
+                .message("The requested resource was not found")
+                .into_response();
+        };
+
+        let accept = request
+            .headers()
+            .get(header::ACCEPT)
+            .and_then(|accept| accept.to_str().ok());
+
+        let format = NotFoundFormat::from_accept(accept);
+        let mut response = handler(request.uri().path(), format);
+
+        response
+            .headers_mut()
+            .append(header::VARY, HeaderValue::from_static("accept"));
+
+        response
+    }
+}
+
+impl std::fmt::Debug for NotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("NotFound")
+    }
+}
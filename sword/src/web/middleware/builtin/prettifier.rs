@@ -1,18 +1,51 @@
-use axum::http::StatusCode;
-use axum_responses::http::HttpResponse;
+use axum::{
+    body::{Body, to_bytes},
+    extract::Request as AxumRequest,
+    http::header::{CONTENT_LENGTH, CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+use http_body::Body as HttpBody;
+use serde_json::Value;
 
-use crate::web::{Context, MiddlewareResult, Next};
+use crate::web::{HttpResponse, Next};
 
-pub struct ResponsePrettifier;
+pub(crate) struct ResponsePrettifier;
 
 impl ResponsePrettifier {
-    pub async fn layer(ctx: Context, next: Next) -> MiddlewareResult {
-        let response = next.run(ctx.try_into()?).await;
+    /// Indents the JSON bodies of responses, when `pretty_json` is enabled.
+    ///
+    /// Only inserted into the router when enabled, so compact responses go
+    /// through untouched. Streamed bodies and bodies that aren't valid JSON
+    /// are sent as they are.
+    pub async fn layer(request: AxumRequest, next: Next) -> Response {
+        let response = next.run(request).await;
 
-        if response.status() == StatusCode::REQUEST_TIMEOUT {
-            return Err(HttpResponse::RequestTimeout());
+        let is_json = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("application/json"));
+
+        if !is_json || response.body().size_hint().exact().is_none() {
+            return response;
         }
 
-        Ok(response)
+        let (mut parts, body) = response.into_parts();
+
+        let Ok(bytes) = to_bytes(body, usize::MAX).await else {
+            return HttpResponse::InternalServerError().into_response();
+        };
+
+        let Ok(json) = serde_json::from_slice::<Value>(&bytes) else {
+            return Response::from_parts(parts, Body::from(bytes));
+        };
+
+        let Ok(encoded) = serde_json::to_vec_pretty(&json) else {
+            return HttpResponse::InternalServerError().into_response();
+        };
+
+        parts.headers.remove(CONTENT_LENGTH);
+
+        Response::from_parts(parts, Body::from(encoded))
     }
 }
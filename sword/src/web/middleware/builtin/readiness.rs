@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request as AxumRequest, State as AxumState},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::{core::Startup, web::HttpResponse};
+
+/// A readiness endpoint that answers `503 Service Unavailable` until the
+/// `on_startup` hooks of the application have completed, and `200 OK`
+/// afterwards.
+///
+/// The endpoint is served before routing, so it ignores the global prefix
+/// and path rewrites, and doesn't need a controller. With `hold_traffic`,
+/// every other request is answered with `503` as well until the application
+/// is ready.
+///
+/// Register it with `ApplicationBuilder::with_readiness_probe`.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// use sword::prelude::*;
+///
+/// let app = Application::builder()?
+///     .with_controller::<OrdersController>()
+///     .on_startup(|state: State| async move { warm_caches(state).await })
+///     .with_readiness_probe(ReadinessProbe::new("/readyz").hold_traffic())
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReadinessProbe {
+    path: String,
+    hold_traffic: bool,
+}
+
+impl ReadinessProbe {
+    /// Creates a probe served at `path`, e.g. `/readyz`.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            hold_traffic: false,
+        }
+    }
+
+    /// Answers every request with `503` until the application is ready,
+    /// not just the probe.
+    pub fn hold_traffic(self) -> Self {
+        Self {
+            hold_traffic: true,
+            ..self
+        }
+    }
+
+    pub(crate) async fn layer(
+        AxumState((this, startup)): AxumState<(Self, Arc<Startup>)>,
+        request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        let ready = startup.is_ready();
+
+        if request.uri().path() == this.path {
+            let response = match ready {
+                true => HttpResponse::Ok().message("Ready"),
+                false => HttpResponse::ServiceUnavailable()
+                    .message("The application is starting"),
+            };
+
+            return response.into_response();
+        }
+
+        if !ready && this.hold_traffic {
+            return HttpResponse::ServiceUnavailable()
+                .message("The application is starting")
+                .into_response();
+        }
+
+        next.run(request).await
+    }
+}
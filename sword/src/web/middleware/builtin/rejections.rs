@@ -0,0 +1,89 @@
+use std::{cell::RefCell, net::SocketAddr};
+
+use axum::{
+    extract::{ConnectInfo, Request as AxumRequest},
+    middleware::Next,
+    response::Response,
+};
+
+/// Why a request was rejected, as recorded by the error mapping while the
+/// request is handled.
+///
+/// Only the `RequestError` variant name and its static message are kept,
+/// never the request body or the parser details derived from it.
+struct Rejection {
+    kind: &'static str,
+    reason: String,
+}
+
+tokio::task_local! {
+    static REJECTION: RefCell<Option<Rejection>>;
+}
+
+/// Records the rejection reason of the request being handled.
+///
+/// Does nothing when rejection logging is disabled, since the task local
+/// is only set while `RejectionLogger` is running.
+pub(crate) fn record_rejection(kind: &'static str, reason: impl Into<String>) {
+    let _ = REJECTION.try_with(|slot| {
+        slot.replace(Some(Rejection {
+            kind,
+            reason: reason.into(),
+        }));
+    });
+}
+
+/// Emits a structured warning for every `4xx` response produced by a route,
+/// with the method, path, client IP and the rejection reason.
+///
+/// Enabled with `log_rejections = true` in the `[application]` config section.
+pub(crate) struct RejectionLogger;
+
+impl RejectionLogger {
+    pub async fn layer(request: AxumRequest, next: Next) -> Response {
+        let method = request.method().clone();
+        let path = request.uri().path().to_owned();
+
+        let client_ip = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip().to_string())
+            .unwrap_or_else(|| "unknown".to_owned());
+
+        let (response, rejection) = REJECTION
+            .scope(RefCell::new(None), async {
+                let response = next.run(request).await;
+                let rejection = REJECTION.with(|slot| slot.take());
+
+                (response, rejection)
+            })
+            .await;
+
+        let status = response.status();
+
+        if !status.is_client_error() {
+            return response;
+        }
+
+        let (kind, reason) = match rejection {
+            Some(Rejection { kind, reason }) => (kind, reason),
+            None => {
+                let reason = status.canonical_reason().unwrap_or_default();
+                ("-", reason.to_owned())
+            }
+        };
+
+        tracing::warn!(
+            target: "sword::rejections",
+            status = status.as_u16(),
+            %method,
+            %path,
+            %client_ip,
+            kind,
+            %reason,
+            "Request rejected"
+        );
+
+        response
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context as TaskContext, Poll},
+};
+
+use axum::{
+    extract::Request as AxumRequest,
+    http::{HeaderName, HeaderValue},
+    response::Response,
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+const DEFAULT_HEADER: &str = "x-request-id";
+const MAX_INCOMING_LEN: usize = 128;
+
+/// The ID of the current request and the header it is sent in, stored in
+/// the request extensions and read with `Context::request_id`.
+#[derive(Debug, Clone)]
+pub(crate) struct CurrentRequestId {
+    pub id: Arc<str>,
+    pub header: HeaderName,
+}
+
+/// The format of the IDs generated by `RequestId`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RequestIdFormat {
+    /// A hyphenated UUID v4, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    #[default]
+    UuidV4,
+
+    /// A 21 character, URL-safe NanoID, e.g. `V1StGXR8_Z5jdHi6B-myT`.
+    NanoId,
+}
+
+impl RequestIdFormat {
+    fn generate(self) -> String {
+        match self {
+            Self::UuidV4 => uuid::Uuid::new_v4().to_string(),
+            Self::NanoId => nanoid::nanoid!(),
+        }
+    }
+}
+
+/// Middleware that assigns a correlation ID to every request.
+///
+/// The ID is read from the `X-Request-Id` header of the request, or
+/// generated when the header is missing, empty, longer than 128 characters
+/// or not printable ASCII. It is available to handlers and middlewares with
+/// `Context::request_id` and echoed back in the same response header.
+///
+/// The incoming and outgoing header names can differ, e.g. to accept the
+/// `X-Correlation-Id` of an upstream gateway while sending `X-Request-Id`
+/// in responses and, with `Context::request_id_header_value`, to downstream
+/// services.
+///
+/// Register it with `ApplicationBuilder::with_request_id`, which places it
+/// around the other layers, except metrics, rewrites and the readiness
+/// probe, so that not found and rejected responses carry the ID too, or like
+/// any other layer with `with_layer`.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// use sword::prelude::*;
+///
+/// let app = Application::builder()?
+///     .with_controller::<OrdersController>()
+///     .with_request_id(RequestId::new().format(RequestIdFormat::NanoId))
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct RequestId {
+    incoming: HeaderName,
+    outgoing: HeaderName,
+    format: RequestIdFormat,
+}
+
+impl RequestId {
+    /// Creates the middleware with the `X-Request-Id` header and UUID v4 IDs.
+    pub fn new() -> Self {
+        Self {
+            incoming: HeaderName::from_static(DEFAULT_HEADER),
+            outgoing: HeaderName::from_static(DEFAULT_HEADER),
+            format: RequestIdFormat::default(),
+        }
+    }
+
+    /// Sets the format of the generated IDs.
+    pub fn format(self, format: RequestIdFormat) -> Self {
+        Self { format, ..self }
+    }
+
+    /// Sets the header the ID is read from and echoed in.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `name` is not a valid header name.
+    pub fn header(self, name: &str) -> Self {
+        self.incoming_header(name).outgoing_header(name)
+    }
+
+    /// Sets the request header the ID is read from.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `name` is not a valid header name.
+    pub fn incoming_header(self, name: &str) -> Self {
+        Self {
+            incoming: header_name(name),
+            ..self
+        }
+    }
+
+    /// Sets the header the ID is echoed in and propagated with, through
+    /// `Context::request_id_header_value`.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `name` is not a valid header name.
+    pub fn outgoing_header(self, name: &str) -> Self {
+        Self {
+            outgoing: header_name(name),
+            ..self
+        }
+    }
+
+    fn resolve(&self, request: &AxumRequest) -> String {
+        let incoming = request
+            .headers()
+            .get(&self.incoming)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|value| {
+                !value.is_empty()
+                    && value.len() <= MAX_INCOMING_LEN
+                    && value.bytes().all(|byte| byte.is_ascii_graphic())
+            });
+
+        match incoming {
+            Some(id) => id.to_string(),
+            None => self.format.generate(),
+        }
+    }
+}
+
+impl Default for RequestId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for RequestId {
+    type Service = RequestIdService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestIdService {
+            inner,
+            config: self.clone(),
+        }
+    }
+}
+
+/// The service built by the `RequestId` layer.
+#[derive(Debug, Clone)]
+pub struct RequestIdService<S> {
+    inner: S,
+    config: RequestId,
+}
+
+impl<S> Service<AxumRequest> for RequestIdService<S>
+where
+    S: Service<AxumRequest, Response = Response> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future =
+        Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut TaskContext<'_>,
+    ) -> Poll<Result<(), S::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: AxumRequest) -> Self::Future {
+        let id: Arc<str> = self.config.resolve(&request).into();
+        let header = self.config.outgoing.clone();
+
+        request.extensions_mut().insert(CurrentRequestId {
+            id: id.clone(),
+            header: header.clone(),
+        });
+
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            let mut response = future.await?;
+
+            if let Ok(value) = HeaderValue::try_from(id.as_ref()) {
+                response.headers_mut().insert(header, value);
+            }
+
+            Ok(response)
+        })
+    }
+}
+
+fn header_name(name: &str) -> HeaderName {
+    HeaderName::try_from(name)
+        .unwrap_or_else(|_| panic!("Invalid request ID header name '{name}'"))
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use axum::{
+    extract::{Request as AxumRequest, State as AxumState},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::web::HttpResponse;
+
+/// Enforces the `request_timeout_seconds` of the configuration.
+///
+/// Requests that are still being handled when it elapses are dropped and
+/// answered with a `503 Service Unavailable` JSON envelope, rather than the
+/// bare response of tower-http's `TimeoutLayer`.
+#[derive(Clone)]
+pub(crate) struct RequestTimeout {
+    duration: Duration,
+}
+
+impl RequestTimeout {
+    pub fn new(duration: Duration) -> Self {
+        Self { duration }
+    }
+
+    pub async fn layer(
+        AxumState(this): AxumState<Self>,
+        request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        // The deadline is polled first, so a handler finishing on the very
+        // tick it elapses still times out, as it did with `TimeoutLayer`.
+        tokio::select! {
+            biased;
+            _ = tokio::time::sleep(this.duration) => {
+                HttpResponse::ServiceUnavailable()
+                    .message("Request timed out")
+                    .into_response()
+            }
+            response = next.run(request) => response,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use axum::{
+    extract::{MatchedPath, Request as AxumRequest, State as AxumState},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http_body::Body as HttpBody;
+use serde::Deserialize;
+
+use crate::{core::ConfigItem, web::HttpResponse};
+
+/// The `[limits]` config section.
+///
+/// ```toml
+/// [limits]
+/// max_response_bytes = 5000000
+/// max_header_count = 50
+/// max_header_bytes = 8192
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct LimitsConfig {
+    pub max_response_bytes: Option<usize>,
+    pub max_header_count: Option<usize>,
+    pub max_header_bytes: Option<usize>,
+}
+
+impl ConfigItem for LimitsConfig {
+    fn toml_key() -> &'static str {
+        "limits"
+    }
+}
+
+/// Replaces buffered responses larger than `max_response_bytes` with a
+/// `500` envelope and logs the route under the `sword::limits` target, as a
+/// safety net for unbounded query results.
+///
+/// Only responses whose size is known before sending, such as the JSON
+/// envelope, are checked. Streaming responses, server-sent events and files
+/// are exempt.
+#[derive(Clone)]
+pub(crate) struct ResponseSizeGuard {
+    limit: usize,
+}
+
+impl ResponseSizeGuard {
+    pub fn new(limit: usize) -> Self {
+        Self { limit }
+    }
+
+    pub async fn layer(
+        AxumState(this): AxumState<Self>,
+        request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        let method = request.method().clone();
+        let path = request.uri().path().to_owned();
+
+        let route = request
+            .extensions()
+            .get::<MatchedPath>()
+            .map(|matched| matched.as_str().to_owned());
+
+        let response = next.run(request).await;
+
+        let Some(size) = response.body().size_hint().exact() else {
+            return response;
+        };
+
+        if size <= this.limit as u64 {
+            return response;
+        }
+
+        tracing::warn!(
+            target: "sword::limits",
+            %method,
+            %path,
+            route = route.as_deref().unwrap_or("-"),
+            limit = this.limit,
+            size,
+            "Response exceeds max_response_bytes"
+        );
+
+        HttpResponse::InternalServerError()
+            .message("The response exceeds the maximum allowed size")
+            .into_response()
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    fmt::Write,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context as TaskContext, Poll},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    extract::Request as AxumRequest,
+    http::{HeaderValue, header::HeaderName},
+    response::Response,
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+static SERVER_TIMING: HeaderName = HeaderName::from_static("server-timing");
+
+/// The timings recorded during the current request, stored in the request
+/// extensions and filled with `Context::record_timing`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Timings(Arc<Mutex<Vec<(String, Duration)>>>);
+
+impl Timings {
+    pub fn record(&self, name: &str, duration: Duration) {
+        if let Ok(mut timings) = self.0.lock() {
+            timings.push((name.to_string(), duration));
+        }
+    }
+
+    fn header_value(&self, total: Duration) -> Option<HeaderValue> {
+        let timings = self.0.lock().ok()?;
+        let mut value = String::new();
+
+        let metrics = timings
+            .iter()
+            .map(|(name, duration)| (name.as_str(), *duration))
+            .chain([("total", total)]);
+
+        for (name, duration) in metrics {
+            if !is_token(name) {
+                continue;
+            }
+
+            if !value.is_empty() {
+                value.push_str(", ");
+            }
+
+            let millis = duration.as_secs_f64() * 1000.0;
+            let _ = write!(value, "{name};dur={millis:.1}");
+        }
+
+        HeaderValue::try_from(value).ok()
+    }
+}
+
+/// Middleware that sends the time spent on each request in a `Server-Timing`
+/// response header, for browser developer tools and frontend monitoring.
+///
+/// The header always has a `total` metric, measured from the moment the
+/// request enters the middleware until the handler responds. Handlers and
+/// middlewares add their own phases with `Context::record_timing`, which
+/// does nothing while the middleware is disabled.
+///
+/// Register it with `ApplicationBuilder::with_server_timing`, which places
+/// it around the other built-in layers, or like any other layer with
+/// `with_layer`.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// use sword::prelude::*;
+///
+/// let app = Application::builder()?
+///     .with_controller::<OrdersController>()
+///     .with_server_timing()
+///     .build()?;
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ServerTiming;
+
+impl ServerTiming {
+    /// Creates the middleware.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for ServerTiming {
+    type Service = ServerTimingService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ServerTimingService { inner }
+    }
+}
+
+/// The service built by the `ServerTiming` layer.
+#[derive(Debug, Clone)]
+pub struct ServerTimingService<S> {
+    inner: S,
+}
+
+impl<S> Service<AxumRequest> for ServerTimingService<S>
+where
+    S: Service<AxumRequest, Response = Response> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future =
+        Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut TaskContext<'_>,
+    ) -> Poll<Result<(), S::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: AxumRequest) -> Self::Future {
+        let started = Instant::now();
+        let timings = Timings::default();
+
+        request.extensions_mut().insert(timings.clone());
+
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            let mut response = future.await?;
+
+            if let Some(value) = timings.header_value(started.elapsed()) {
+                response.headers_mut().append(SERVER_TIMING.clone(), value);
+            }
+
+            Ok(response)
+        })
+    }
+}
+
+/// Whether `name` is a valid metric name, which the header grammar requires
+/// to be an HTTP token.
+fn is_token(name: &str) -> bool {
+    !name.is_empty()
+        && name.bytes().all(|byte| {
+            byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte)
+        })
+}
@@ -0,0 +1,109 @@
+use axum::{
+    body::{Body, to_bytes},
+    extract::Request as AxumRequest,
+    http::header::{CONTENT_LENGTH, CONTENT_TYPE},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{core::ConfigItem, web::HttpResponse};
+
+/// The `[response]` config section.
+///
+/// ```toml
+/// [response]
+/// skip_nulls = true
+/// pretty_json = false
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ResponseConfig {
+    #[serde(default)]
+    pub skip_nulls: bool,
+
+    /// Defaults to indented JSON in debug builds and compact JSON in release
+    /// builds.
+    #[serde(default = "default_pretty_json")]
+    pub pretty_json: bool,
+}
+
+impl Default for ResponseConfig {
+    fn default() -> Self {
+        Self {
+            skip_nulls: false,
+            pretty_json: default_pretty_json(),
+        }
+    }
+}
+
+fn default_pretty_json() -> bool {
+    cfg!(debug_assertions)
+}
+
+impl ConfigItem for ResponseConfig {
+    fn toml_key() -> &'static str {
+        "response"
+    }
+}
+
+/// Omits the null fields of the `data` of JSON envelopes, like a
+/// `skip_serializing_if = "Option::is_none"` on every field.
+///
+/// Null array items are kept, so positions don't shift. Opt-in with
+/// `skip_nulls = true` in the `[response]` config section, since clients may
+/// rely on explicit nulls.
+pub(crate) struct SkipNulls;
+
+impl SkipNulls {
+    pub async fn layer(request: AxumRequest, next: Next) -> Response {
+        let response = next.run(request).await;
+
+        let is_json = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("application/json"));
+
+        if !is_json {
+            return response;
+        }
+
+        let (mut parts, body) = response.into_parts();
+
+        let Ok(bytes) = to_bytes(body, usize::MAX).await else {
+            return HttpResponse::InternalServerError().into_response();
+        };
+
+        let envelope = serde_json::from_slice::<Value>(&bytes);
+
+        let Ok(Value::Object(mut envelope)) = envelope else {
+            return Response::from_parts(parts, Body::from(bytes));
+        };
+
+        let Some(data) = envelope.get_mut("data") else {
+            return Response::from_parts(parts, Body::from(bytes));
+        };
+
+        strip_nulls(data);
+
+        let Ok(encoded) = serde_json::to_vec(&envelope) else {
+            return HttpResponse::InternalServerError().into_response();
+        };
+
+        parts.headers.remove(CONTENT_LENGTH);
+
+        Response::from_parts(parts, Body::from(encoded))
+    }
+}
+
+fn strip_nulls(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            fields.retain(|_, field| !field.is_null());
+            fields.values_mut().for_each(strip_nulls);
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_nulls),
+        _ => {}
+    }
+}
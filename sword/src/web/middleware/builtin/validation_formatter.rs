@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request as AxumRequest, State as AxumState},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::{errors::ValidationErrorFormatter, web::HttpResponse};
+
+/// The formatter registered with
+/// `ApplicationBuilder::with_validation_formatter`.
+#[derive(Clone)]
+pub(crate) struct ValidationFormatter(Arc<dyn ValidationErrorFormatter>);
+
+tokio::task_local! {
+    static FORMATTER: ValidationFormatter;
+}
+
+impl ValidationFormatter {
+    pub fn new<F: ValidationErrorFormatter>(formatter: F) -> Self {
+        Self(Arc::new(formatter))
+    }
+
+    /// Makes the formatter available to the `RequestError` mapping while the
+    /// request is handled.
+    pub async fn layer(
+        AxumState(formatter): AxumState<Self>,
+        request: AxumRequest,
+        next: Next,
+    ) -> Response {
+        FORMATTER.scope(formatter, next.run(request)).await
+    }
+}
+
+impl std::fmt::Debug for ValidationFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ValidationFormatter")
+    }
+}
+
+/// Formats `errors` with the registered formatter, if any, calling `format`
+/// with it.
+///
+/// Returns the errors back when none is registered, so that the default
+/// formatting applies.
+pub(crate) fn format_with_registered<E>(
+    errors: E,
+    format: impl FnOnce(&dyn ValidationErrorFormatter, E) -> HttpResponse,
+) -> Result<HttpResponse, E> {
+    let formatter = FORMATTER.try_with(|formatter| formatter.0.clone());
+
+    match formatter {
+        Ok(formatter) => Ok(format(formatter.as_ref(), errors)),
+        Err(_) => Err(errors),
+    }
+}
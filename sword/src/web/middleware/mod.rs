@@ -1,21 +1,56 @@
 mod builtin;
 
 use axum::response::Response as AxumResponse;
-use std::future::Future;
+use std::{future::Future, pin::Pin};
 
 use crate::web::{Context, HttpResult};
 
 #[cfg(feature = "helmet")]
 pub use builtin::helmet;
 
-pub(crate) use builtin::content_type::ContentTypeCheck;
+pub use builtin::error_reporter::ErrorReport;
+pub use builtin::not_found::NotFoundFormat;
+pub use builtin::readiness::ReadinessProbe;
+pub use builtin::request_id::{RequestId, RequestIdFormat, RequestIdService};
+pub use builtin::server_timing::{ServerTiming, ServerTimingService};
+
+pub(crate) use builtin::auto_head::HeadCheck;
+pub(crate) use builtin::body_limit::{BodyLimit, BodyLimitCheck, StreamedBody};
+pub(crate) use builtin::cancellation::{CancellationSignal, RequestCancellation};
+pub(crate) use builtin::content_type::{
+    APPLICATION_FORM_URLENCODED, APPLICATION_JSON, ContentTypeCheck, is_json,
+};
+pub(crate) use builtin::context_preload::ContextPreload;
+pub(crate) use builtin::error_reporter::{ErrorReporter, ReportedUser};
+pub(crate) use builtin::error_status::{ErrorStatusOverrides, overridden_status};
+pub(crate) use builtin::header_limits::HeaderLimits;
+pub(crate) use builtin::in_flight::InFlightRequests;
+pub(crate) use builtin::method_not_allowed::MethodNotAllowed;
+pub(crate) use builtin::not_found::NotFound;
 pub(crate) use builtin::prettifier::ResponsePrettifier;
+pub(crate) use builtin::rejections::{RejectionLogger, record_rejection};
+pub(crate) use builtin::request_id::CurrentRequestId;
+pub(crate) use builtin::request_timeout::RequestTimeout;
+pub(crate) use builtin::response_size::{LimitsConfig, ResponseSizeGuard};
+pub(crate) use builtin::server_timing::Timings;
+pub(crate) use builtin::skip_nulls::{ResponseConfig, SkipNulls};
+
+#[cfg(feature = "validator")]
+pub(crate) use builtin::validation_formatter::{
+    ValidationFormatter, format_with_registered,
+};
 
 pub use axum::middleware::Next;
 pub use sword_macros::middleware;
 
 /// `MiddlewareResult` is the result type returned by middleware handlers.
-/// It is a `Result` that contains an axum native Response in both success and error cases.
+///
+/// The success case is the Axum `Response` produced by `next!`, and the error
+/// case an `HttpResponse`, so a middleware stops the request by returning
+/// `Err(HttpResponse::Unauthorized())` like a handler does. To stop it with
+/// `Ok` instead, e.g. to return a response built elsewhere, convert the
+/// `HttpResponse` with `.into_response()`; Rust doesn't allow a `From`
+/// conversion between the two, since neither type belongs to Sword.
 pub type MiddlewareResult = HttpResult<AxumResponse>;
 
 /// Trait for build middlewares that can be used in the application.
@@ -34,6 +69,31 @@ pub type MiddlewareResult = HttpResult<AxumResponse>;
 ///     }
 /// }
 /// ```
+///
+/// ### Returning early
+///
+/// Returning before `next!` answers the request without running the rest of
+/// the chain or the handler. Both cases below send the JSON envelope.
+///
+/// ```rust,ignore
+/// use sword::prelude::*;
+///
+/// struct AuthMiddleware;
+///
+/// impl Middleware for AuthMiddleware {
+///     async fn handle(ctx: Context, next: Next) -> MiddlewareResult {
+///         let Some(token) = ctx.header("authorization") else {
+///             return Err(HttpResponse::Unauthorized().message("Missing token"));
+///         };
+///
+///         if token == "Bearer revoked" {
+///             return Ok(HttpResponse::Forbidden().message("no").into_response());
+///         }
+///
+///         next!(ctx, next)
+///     }
+/// }
+/// ```
 pub trait Middleware: Send + Sync + 'static {
     fn handle(
         ctx: Context,
@@ -68,6 +128,50 @@ pub trait MiddlewareWithConfig<C>: Send + Sync + 'static {
     ) -> impl Future<Output = MiddlewareResult> + Send;
 }
 
+/// Object-safe counterpart of `Middleware`, for middlewares chosen at
+/// runtime and registered with `ApplicationBuilder::with_boxed_middleware`.
+///
+/// Every `Middleware` implements it, so the middlewares used with
+/// `#[middleware]` can be boxed as they are. Implement it directly when the
+/// middleware needs its own data, since `call` receives `&self`.
+///
+/// ```rust,ignore
+/// use sword::prelude::*;
+///
+/// struct RequireHeader(&'static str);
+///
+/// impl DynMiddleware for RequireHeader {
+///     fn call<'a>(&'a self, ctx: Context, next: Next) -> BoxedMiddlewareFuture<'a> {
+///         Box::pin(async move {
+///             if ctx.header(self.0).is_none() {
+///                 return Err(HttpResponse::BadRequest());
+///             }
+///
+///             next!(ctx, next)
+///         })
+///     }
+/// }
+///
+/// let mut builder = Application::builder()?.with_controller::<AdminController>();
+///
+/// if production {
+///     builder = builder.with_boxed_middleware(Box::new(AuthMiddleware));
+/// }
+/// ```
+pub trait DynMiddleware: Send + Sync + 'static {
+    fn call<'a>(&'a self, ctx: Context, next: Next) -> BoxedMiddlewareFuture<'a>;
+}
+
+/// The future returned by `DynMiddleware::call`.
+pub type BoxedMiddlewareFuture<'a> =
+    Pin<Box<dyn Future<Output = MiddlewareResult> + Send + 'a>>;
+
+impl<M: Middleware> DynMiddleware for M {
+    fn call<'a>(&'a self, ctx: Context, next: Next) -> BoxedMiddlewareFuture<'a> {
+        Box::pin(M::handle(ctx, next))
+    }
+}
+
 /// A macro to simplify the next middleware call in the middleware chain.
 ///
 /// It takes the current context and the next middleware in the chain,
@@ -0,0 +1,257 @@
+//! OpenAPI documents built from the routes of the registered controllers.
+
+use std::collections::BTreeMap;
+
+use axum::http::Method;
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::core::RouteInfo;
+
+const OPENAPI_VERSION: &str = "3.1.0";
+
+/// A type that describes itself with a JSON Schema, to be referenced by the
+/// request bodies and query parameters of a `Spec`.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// use serde_json::{Value, json};
+/// use sword::web::openapi::ApiSchema;
+///
+/// struct CreateUser {
+///     name: String,
+/// }
+///
+/// impl ApiSchema for CreateUser {
+///     fn schema() -> Value {
+///         json!({
+///             "type": "object",
+///             "properties": { "name": { "type": "string" } },
+///             "required": ["name"],
+///         })
+///     }
+/// }
+/// ```
+pub trait ApiSchema {
+    /// The name of the schema in `components.schemas`.
+    /// Defaults to the name of the type, without its module path.
+    fn name() -> String {
+        short_type_name(std::any::type_name::<Self>())
+    }
+
+    /// The JSON Schema of the type.
+    fn schema() -> Value;
+}
+
+/// An OpenAPI 3.1 document, serializable to JSON with `serde_json`.
+///
+/// `Application::openapi_spec` lists every controller route with its
+/// method, its path parameters and the controller as tag. Request bodies
+/// and query parameters can't be derived from handlers, so they are added
+/// with `request_body` and `query` from types implementing `ApiSchema`.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// let spec = app
+///     .openapi_spec()
+///     .info("Users API", "1.2.0")
+///     .request_body::<CreateUser>(Method::POST, "/users")
+///     .query::<UserFilters>(Method::GET, "/users");
+///
+/// std::fs::write("openapi.json", serde_json::to_string_pretty(&spec)?)?;
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct Spec {
+    openapi: &'static str,
+    info: Info,
+    paths: BTreeMap<String, BTreeMap<String, Operation>>,
+
+    #[serde(skip_serializing_if = "Components::is_empty")]
+    components: Components,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Info {
+    title: String,
+    version: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+struct Components {
+    schemas: BTreeMap<String, Value>,
+}
+
+impl Components {
+    fn is_empty(&self) -> bool {
+        self.schemas.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Operation {
+    tags: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    parameters: Vec<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_body: Option<Value>,
+
+    responses: Value,
+}
+
+impl Spec {
+    /// Builds the document of the given routes, titled `API`, version `1.0.0`.
+    pub fn from_routes(routes: &[RouteInfo]) -> Self {
+        let mut paths: BTreeMap<String, BTreeMap<String, Operation>> =
+            BTreeMap::new();
+
+        for route in routes {
+            let (path, parameters) = openapi_path(&route.path);
+
+            let operation = Operation {
+                tags: vec![short_type_name(route.controller)],
+                parameters,
+                request_body: None,
+                responses: json!({ "default": { "description": "Response" } }),
+            };
+
+            paths
+                .entry(path)
+                .or_default()
+                .insert(route.method.as_str().to_lowercase(), operation);
+        }
+
+        Self {
+            openapi: OPENAPI_VERSION,
+            info: Info {
+                title: "API".to_string(),
+                version: "1.0.0".to_string(),
+            },
+            paths,
+            components: Components::default(),
+        }
+    }
+
+    /// Sets the title and version of the document.
+    pub fn info(mut self, title: &str, version: &str) -> Self {
+        self.info = Info {
+            title: title.to_string(),
+            version: version.to_string(),
+        };
+
+        self
+    }
+
+    /// Adds the schema of `T` to `components.schemas`.
+    pub fn schema<T: ApiSchema>(mut self) -> Self {
+        self.components.schemas.insert(T::name(), T::schema());
+        self
+    }
+
+    /// Declares `T` as the JSON request body of a route, given its method
+    /// and full path as listed by `Application::routes`.
+    ///
+    /// Routes that aren't in the document are ignored.
+    pub fn request_body<T: ApiSchema>(mut self, method: Method, path: &str) -> Self {
+        let reference = format!("#/components/schemas/{}", T::name());
+
+        if let Some(operation) = self.operation_mut(&method, path) {
+            operation.request_body = Some(json!({
+                "required": true,
+                "content": {
+                    "application/json": { "schema": { "$ref": reference } }
+                },
+            }));
+        }
+
+        self.schema::<T>()
+    }
+
+    /// Declares the properties of `T`, an object schema, as the query
+    /// parameters of a route, given its method and full path.
+    ///
+    /// Routes that aren't in the document are ignored.
+    pub fn query<T: ApiSchema>(mut self, method: Method, path: &str) -> Self {
+        let schema = T::schema();
+
+        let required: Vec<&str> = schema["required"]
+            .as_array()
+            .map(|names| names.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        let Some(properties) = schema["properties"].as_object() else {
+            return self;
+        };
+
+        if let Some(operation) = self.operation_mut(&method, path) {
+            for (name, property) in properties {
+                operation.parameters.push(json!({
+                    "name": name,
+                    "in": "query",
+                    "required": required.contains(&name.as_str()),
+                    "schema": property,
+                }));
+            }
+        }
+
+        self
+    }
+
+    fn operation_mut(
+        &mut self,
+        method: &Method,
+        path: &str,
+    ) -> Option<&mut Operation> {
+        let (path, _) = openapi_path(path);
+
+        self.paths
+            .get_mut(&path)?
+            .get_mut(&method.as_str().to_lowercase())
+    }
+}
+
+/// The OpenAPI form of a route path, where catch-alls like `{*rest}` become
+/// `{rest}`, and its path parameters.
+fn openapi_path(path: &str) -> (String, Vec<Value>) {
+    let mut parameters = Vec::new();
+
+    let segments: Vec<String> = path
+        .split('/')
+        .map(|segment| {
+            let Some(name) = segment
+                .strip_prefix('{')
+                .and_then(|segment| segment.strip_suffix('}'))
+            else {
+                return segment.to_string();
+            };
+
+            let name = name.trim_start_matches('*');
+
+            parameters.push(json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": { "type": "string" },
+            }));
+
+            format!("{{{name}}}")
+        })
+        .collect();
+
+    (segments.join("/"), parameters)
+}
+
+/// `my_app::users::UsersController<Pg>` becomes `UsersController`.
+fn short_type_name(type_name: &str) -> String {
+    let without_generics = type_name.split('<').next().unwrap_or(type_name);
+
+    without_generics
+        .rsplit("::")
+        .next()
+        .unwrap_or(without_generics)
+        .to_string()
+}
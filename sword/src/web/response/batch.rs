@@ -0,0 +1,132 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use serde_json::Value;
+
+/// A single result inside a `BatchResponse`.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchItem {
+    pub code: u16,
+    pub success: bool,
+    pub body: Value,
+}
+
+/// Builder for endpoints that process several sub-requests at once.
+///
+/// Each item keeps its own status code and body, and the response is sent
+/// as a single JSON object listing every item in insertion order:
+///
+/// ```json,ignore
+/// {
+///     "code": 207,
+///     "success": false,
+///     "items": [
+///         { "code": 201, "success": true, "body": { "id": 1 } },
+///         { "code": 404, "success": false, "body": "User not found" }
+///     ]
+/// }
+/// ```
+///
+/// The overall status is `200 OK` when every item succeeded (or there are
+/// no items) and `207 Multi-Status` as soon as one item failed.
+///
+/// ### Example
+///
+/// ```rust,ignore
+/// use sword::prelude::*;
+///
+/// #[post("/users/batch")]
+/// async fn create_many(&self, ctx: Context) -> HttpResult<BatchResponse> {
+///     let users: Vec<NewUser> = ctx.body()?;
+///     let mut batch = BatchResponse::new();
+///
+///     for user in users {
+///         match self.users.create(user).await {
+///             Ok(created) => batch.push(StatusCode::CREATED, created),
+///             Err(error) => batch.push(StatusCode::CONFLICT, error.to_string()),
+///         }
+///     }
+///
+///     Ok(batch)
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BatchResponse {
+    items: Vec<BatchItem>,
+}
+
+#[derive(Serialize)]
+struct BatchBody<'a> {
+    code: u16,
+    success: bool,
+    items: &'a [BatchItem],
+}
+
+impl BatchResponse {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an item, returning the builder for chaining.
+    ///
+    /// An item counts as successful when its status is `2xx`. If the body
+    /// can't be serialized, the item is recorded as a `500` failure instead.
+    pub fn item(mut self, status: StatusCode, body: impl Serialize) -> Self {
+        self.push(status, body);
+        self
+    }
+
+    /// Adds an item in place. See `BatchResponse::item`.
+    pub fn push(&mut self, status: StatusCode, body: impl Serialize) {
+        let item = match serde_json::to_value(body) {
+            Ok(body) => BatchItem {
+                code: status.as_u16(),
+                success: status.is_success(),
+                body,
+            },
+            Err(_) => BatchItem {
+                code: StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
+                success: false,
+                body: Value::from("Failed to serialize batch item"),
+            },
+        };
+
+        self.items.push(item);
+    }
+
+    /// The items collected so far, in insertion order.
+    pub fn items(&self) -> &[BatchItem] {
+        &self.items
+    }
+
+    /// Whether every item succeeded.
+    pub fn is_success(&self) -> bool {
+        self.items.iter().all(|item| item.success)
+    }
+
+    /// The status code the batch is sent with.
+    pub fn status(&self) -> StatusCode {
+        if self.is_success() {
+            StatusCode::OK
+        } else {
+            StatusCode::MULTI_STATUS
+        }
+    }
+}
+
+impl IntoResponse for BatchResponse {
+    fn into_response(self) -> Response {
+        let status = self.status();
+
+        let body = BatchBody {
+            code: status.as_u16(),
+            success: self.is_success(),
+            items: &self.items,
+        };
+
+        (status, Json(body)).into_response()
+    }
+}
@@ -0,0 +1,342 @@
+use std::{future::Future, path::Path, time::Duration};
+
+use axum::{
+    BoxError, Json,
+    body::{Body, Bytes},
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use futures_util::Stream;
+use serde_json::Value;
+
+use crate::web::{
+    Context, HttpResponse,
+    response::{
+        ResponseFormat,
+        file::{self, FileRequest},
+        negotiation,
+    },
+    sse::{Event, KeepAlive, Sse},
+};
+
+const TEXT_PLAIN_UTF_8: &str = "text/plain; charset=utf-8";
+const APPLICATION_JSON: &str = "application/json";
+const APPLICATION_OCTET_STREAM: &str = "application/octet-stream";
+const DEFAULT_SSE_KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+/// Extra response builders for `HttpResponse`.
+///
+/// `HttpResponse` always renders the standard JSON envelope, an object with
+/// its keys in alphabetical order:
+///
+/// - `code` and `success`, from the status code.
+/// - `message`, set with `.message()` or the reason phrase of the status.
+/// - `data`, `error` and `errors`, each present only when set with its
+///   builder to a value other than `null`.
+/// - `timestamp`, the RFC 3339 time the response was rendered.
+///
+/// `.message()` and `.data()` are independent and can be called in any
+/// order. The methods of this trait turn the envelope into a different kind
+/// of response while keeping the status code and any header added with
+/// `add_header`.
+pub trait HttpResponseExt {
+    /// Sends the given string as a raw `text/plain` body.
+    ///
+    /// Unlike `.data("Hello")`, which serializes the string as JSON inside
+    /// the envelope, the body is sent unquoted and as-is.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// #[get("/health")]
+    /// async fn health(&self) -> Response {
+    ///     HttpResponse::Ok().text("OK")
+    /// }
+    /// ```
+    fn text(self, body: impl Into<String>) -> Response;
+
+    /// Sends a `serde_json::Value` as the whole response body with a
+    /// `200 OK` status and an `application/json` content type.
+    ///
+    /// The value is not wrapped in the standard envelope, which is useful
+    /// for APIs that build their JSON dynamically and don't use it.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use serde_json::json;
+    /// use sword::prelude::*;
+    ///
+    /// #[get("/raw")]
+    /// async fn raw(&self) -> Response {
+    ///     HttpResponse::json_value(json!({ "id": 1, "tags": ["a", "b"] }))
+    /// }
+    /// ```
+    fn json_value(value: Value) -> Response;
+
+    /// Sends the items of a stream as the response body, chunk by chunk,
+    /// without holding the whole body in memory.
+    ///
+    /// The stream replaces the JSON envelope, so anything set with
+    /// `.message()` or `.data()` is discarded. The content type defaults to
+    /// `application/octet-stream` unless another one was added with
+    /// `add_header`. An added `application/json` can't be told apart from
+    /// the one of the envelope, so use `stream_with_content_type` to stream
+    /// JSON. If the stream yields an error, the connection is closed and the
+    /// client sees a truncated body.
+    ///
+    /// The built-in middlewares only look at the status and headers of the
+    /// response: the response prettifier and the request timeout never
+    /// buffer or inspect a streamed body, and the timeout stops applying
+    /// once the handler has returned.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// #[get("/report.csv")]
+    /// async fn report(&self) -> Response {
+    ///     let rows = futures::stream::iter(1..=3)
+    ///         .map(|id| Ok::<_, std::io::Error>(format!("{id},row\n")));
+    ///
+    ///     HttpResponse::Ok()
+    ///         .add_header("content-type", "text/csv")
+    ///         .stream(rows)
+    /// }
+    /// ```
+    fn stream<S, B, E>(self, body: S) -> Response
+    where
+        S: Stream<Item = Result<B, E>> + Send + 'static,
+        B: Into<Bytes> + 'static,
+        E: Into<BoxError> + 'static;
+
+    /// Sends the items of a stream as the response body like `stream`, with
+    /// the given content type.
+    ///
+    /// The content type replaces any one added with `add_header`. An invalid
+    /// header value is ignored, like with `add_header`, and the body is sent
+    /// as `application/octet-stream`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// #[get("/events.ndjson")]
+    /// async fn events(&self) -> Response {
+    ///     let lines = futures::stream::iter(1..=3)
+    ///         .map(|id| Ok::<_, std::io::Error>(format!("{{\"id\":{id}}}\n")));
+    ///
+    ///     HttpResponse::Ok().stream_with_content_type("application/x-ndjson", lines)
+    /// }
+    /// ```
+    fn stream_with_content_type<S, B, E>(
+        self,
+        content_type: &str,
+        body: S,
+    ) -> Response
+    where
+        S: Stream<Item = Result<B, E>> + Send + 'static,
+        B: Into<Bytes> + 'static,
+        E: Into<BoxError> + 'static;
+
+    /// Sends a stream of Server-Sent Events, with a `text/event-stream`
+    /// content type.
+    ///
+    /// The status code and any header added with `add_header` are kept,
+    /// while `.message()` and `.data()` are discarded like with `stream`.
+    /// A keep-alive comment is sent every 15 seconds of inactivity, so that
+    /// proxies don't close idle connections. Use `sse_with_keep_alive` to
+    /// change the interval.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use std::convert::Infallible;
+    /// use sword::{prelude::*, web::sse::Event};
+    ///
+    /// #[get("/events")]
+    /// async fn events(&self) -> Response {
+    ///     let events = futures::stream::iter(["a", "b"])
+    ///         .map(|data| Ok::<_, Infallible>(Event::default().data(data)));
+    ///
+    ///     HttpResponse::Ok().sse(events)
+    /// }
+    /// ```
+    fn sse<S, E>(self, stream: S) -> Response
+    where
+        S: Stream<Item = Result<Event, E>> + Send + 'static,
+        E: Into<BoxError>;
+
+    /// Sends a stream of Server-Sent Events like `sse`, emitting a
+    /// keep-alive comment after each `interval` without events.
+    fn sse_with_keep_alive<S, E>(self, stream: S, interval: Duration) -> Response
+    where
+        S: Stream<Item = Result<Event, E>> + Send + 'static,
+        E: Into<BoxError>;
+
+    /// Serves a file from disk, honoring the conditional and range headers
+    /// of the request.
+    ///
+    /// Responses carry an `ETag` and a `Last-Modified` header built from the
+    /// file metadata, and the status depends on the request:
+    /// - `304 Not Modified` if `If-None-Match` matches the `ETag`, or, without
+    ///   it, if the file wasn't modified after `If-Modified-Since`
+    /// - `412 Precondition Failed` if `If-Match` or `If-Unmodified-Since`
+    ///   don't hold
+    /// - `206 Partial Content` for a satisfiable single `Range`, unless an
+    ///   `If-Range` validator no longer matches the file
+    /// - `416 Range Not Satisfiable` if the range starts past the end
+    /// - `200 OK` with the whole file otherwise, or `404 Not Found`
+    ///
+    /// Conditional headers are evaluated before `Range`, so a cached copy is
+    /// revalidated with a `304` even when a range is requested. The body is
+    /// streamed from disk and its content type guessed from the extension.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// #[get("/videos/{name}")]
+    /// async fn video(&self, ctx: Context) -> HttpResult<Response> {
+    ///     let name = ctx.param::<String>("name")?;
+    ///     let path = format!("media/{name}.mp4");
+    ///
+    ///     Ok(HttpResponse::serve_file(path, &ctx).await)
+    /// }
+    /// ```
+    fn serve_file(
+        path: impl AsRef<Path> + Send,
+        ctx: &Context,
+    ) -> impl Future<Output = Response> + Send;
+
+    /// Renders the envelope in the given format, usually the one requested
+    /// by the client through `Context::accept`.
+    ///
+    /// The envelope has the same fields in every format. The status code and
+    /// any header added with `add_header` are kept, and `Vary: accept` is
+    /// added.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// use sword::prelude::*;
+    ///
+    /// #[get("/items")]
+    /// async fn items(&self, ctx: Context) -> Response {
+    ///     HttpResponse::Ok()
+    ///         .message("Items found")
+    ///         .data(["a", "b"])
+    ///         .negotiated(ctx.accept())
+    /// }
+    /// ```
+    fn negotiated(self, format: ResponseFormat) -> Response;
+}
+
+impl HttpResponseExt for HttpResponse {
+    fn text(self, body: impl Into<String>) -> Response {
+        let (mut parts, _) = self.into_response().into_parts();
+
+        parts.headers.remove(header::CONTENT_LENGTH);
+        parts.headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(TEXT_PLAIN_UTF_8),
+        );
+
+        Response::from_parts(parts, Body::from(body.into()))
+    }
+
+    fn json_value(value: Value) -> Response {
+        (StatusCode::OK, Json(value)).into_response()
+    }
+
+    fn stream<S, B, E>(self, body: S) -> Response
+    where
+        S: Stream<Item = Result<B, E>> + Send + 'static,
+        B: Into<Bytes> + 'static,
+        E: Into<BoxError> + 'static,
+    {
+        let (mut parts, _) = self.into_response().into_parts();
+
+        parts.headers.remove(header::CONTENT_LENGTH);
+
+        if parts.headers.get(header::CONTENT_TYPE)
+            == Some(&HeaderValue::from_static(APPLICATION_JSON))
+        {
+            parts.headers.insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(APPLICATION_OCTET_STREAM),
+            );
+        }
+
+        Response::from_parts(parts, Body::from_stream(body))
+    }
+
+    fn stream_with_content_type<S, B, E>(
+        self,
+        content_type: &str,
+        body: S,
+    ) -> Response
+    where
+        S: Stream<Item = Result<B, E>> + Send + 'static,
+        B: Into<Bytes> + 'static,
+        E: Into<BoxError> + 'static,
+    {
+        let mut response = self.stream(body);
+
+        if let Ok(content_type) = HeaderValue::from_str(content_type) {
+            response
+                .headers_mut()
+                .insert(header::CONTENT_TYPE, content_type);
+        }
+
+        response
+    }
+
+    fn sse<S, E>(self, stream: S) -> Response
+    where
+        S: Stream<Item = Result<Event, E>> + Send + 'static,
+        E: Into<BoxError>,
+    {
+        self.sse_with_keep_alive(stream, DEFAULT_SSE_KEEP_ALIVE)
+    }
+
+    fn sse_with_keep_alive<S, E>(self, stream: S, interval: Duration) -> Response
+    where
+        S: Stream<Item = Result<Event, E>> + Send + 'static,
+        E: Into<BoxError>,
+    {
+        let (parts, _) = self.into_response().into_parts();
+
+        let mut response = Sse::new(stream)
+            .keep_alive(KeepAlive::new().interval(interval))
+            .into_response();
+
+        *response.status_mut() = parts.status;
+
+        for (name, value) in &parts.headers {
+            if name != header::CONTENT_TYPE && name != header::CONTENT_LENGTH {
+                response.headers_mut().insert(name, value.clone());
+            }
+        }
+
+        response
+    }
+
+    fn serve_file(
+        path: impl AsRef<Path> + Send,
+        ctx: &Context,
+    ) -> impl Future<Output = Response> + Send {
+        let request = FileRequest::from_context(ctx);
+
+        async move { file::serve_file(path.as_ref(), request).await }
+    }
+
+    fn negotiated(self, format: ResponseFormat) -> Response {
+        negotiation::render(self, format)
+    }
+}
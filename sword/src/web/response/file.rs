@@ -0,0 +1,295 @@
+use std::{
+    io::SeekFrom,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use axum::{
+    body::Body,
+    http::{HeaderMap, HeaderValue, Method, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio_util::io::ReaderStream;
+
+use crate::web::{Context, HttpResponse};
+
+/// The conditional and range headers of the request, read from the
+/// `Context` before the file is opened.
+pub(crate) struct FileRequest {
+    method: Method,
+    if_match: Option<String>,
+    if_unmodified_since: Option<String>,
+    if_none_match: Option<String>,
+    if_modified_since: Option<String>,
+    range: Option<String>,
+    if_range: Option<String>,
+}
+
+impl FileRequest {
+    pub fn from_context(ctx: &Context) -> Self {
+        let header = |name: &str| ctx.header(name).map(str::to_string);
+
+        Self {
+            method: ctx.method().clone(),
+            if_match: header("if-match"),
+            if_unmodified_since: header("if-unmodified-since"),
+            if_none_match: header("if-none-match"),
+            if_modified_since: header("if-modified-since"),
+            range: header("range"),
+            if_range: header("if-range"),
+        }
+    }
+}
+
+/// The validators of the file, sent with every response and compared with
+/// the conditional headers.
+struct Validators {
+    etag: String,
+    last_modified: SystemTime,
+}
+
+impl Validators {
+    fn new(len: u64, modified: SystemTime) -> Self {
+        // HTTP dates have a one second resolution, so the sub-second part
+        // is dropped to make `If-Modified-Since` comparisons exact.
+        let secs = modified
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+
+        Self {
+            etag: format!("\"{secs:x}-{len:x}\""),
+            last_modified: UNIX_EPOCH + Duration::from_secs(secs),
+        }
+    }
+
+    fn http_date(&self) -> String {
+        httpdate::fmt_http_date(self.last_modified)
+    }
+
+    fn apply(&self, headers: &mut HeaderMap) {
+        let values = [
+            (header::ETAG, self.etag.clone()),
+            (header::LAST_MODIFIED, self.http_date()),
+        ];
+
+        for (name, value) in values {
+            if let Ok(value) = HeaderValue::try_from(value) {
+                headers.insert(name, value);
+            }
+        }
+    }
+
+    /// Strong comparison, used by `If-Match` and `If-Range`.
+    fn strong_match(&self, tags: &str) -> bool {
+        tags.split(',')
+            .map(str::trim)
+            .any(|tag| tag == "*" || tag == self.etag)
+    }
+
+    /// Weak comparison, used by `If-None-Match`.
+    fn weak_match(&self, tags: &str) -> bool {
+        tags.split(',')
+            .map(|tag| tag.trim().trim_start_matches("W/"))
+            .any(|tag| tag == "*" || tag == self.etag)
+    }
+
+    fn modified_since(&self, date: &str) -> Option<bool> {
+        httpdate::parse_http_date(date.trim())
+            .ok()
+            .map(|date| self.last_modified > date)
+    }
+}
+
+enum RangeOutcome {
+    Full,
+    Partial(u64, u64),
+    Unsatisfiable,
+}
+
+pub(crate) async fn serve_file(path: &Path, request: FileRequest) -> Response {
+    let metadata = match tokio::fs::metadata(path).await {
+        Ok(metadata) if metadata.is_file() => metadata,
+        _ => {
+            return HttpResponse::NotFound()
+                .message("The requested file was not found")
+                .into_response();
+        }
+    };
+
+    let len = metadata.len();
+    let modified = metadata.modified().unwrap_or(UNIX_EPOCH);
+    let validators = Validators::new(len, modified);
+
+    if let Some(status) = evaluate_preconditions(&request, &validators) {
+        let response = match status {
+            StatusCode::NOT_MODIFIED => {
+                (StatusCode::NOT_MODIFIED, Body::empty()).into_response()
+            }
+            _ => HttpResponse::PreconditionFailed()
+                .message("The file does not match the request preconditions")
+                .into_response(),
+        };
+
+        return with_validators(response, &validators);
+    }
+
+    let outcome = match (&request.method, &request.range) {
+        (&Method::GET, Some(range)) if range_applies(&request, &validators) => {
+            parse_range(range, len)
+        }
+        _ => RangeOutcome::Full,
+    };
+
+    let (start, end) = match outcome {
+        RangeOutcome::Full => (0, len.saturating_sub(1)),
+        RangeOutcome::Partial(start, end) => (start, end),
+        RangeOutcome::Unsatisfiable => {
+            let response = HttpResponse::RangeNotSatisfiable()
+                .message("The requested range is not satisfiable")
+                .add_header("content-range", &format!("bytes */{len}"))
+                .into_response();
+
+            return with_validators(response, &validators);
+        }
+    };
+
+    let mut file = match tokio::fs::File::open(path).await {
+        Ok(file) => file,
+        Err(_) => return HttpResponse::InternalServerError().into_response(),
+    };
+
+    if start > 0 && file.seek(SeekFrom::Start(start)).await.is_err() {
+        return HttpResponse::InternalServerError().into_response();
+    }
+
+    let content_length = if len == 0 { 0 } else { end - start + 1 };
+    let body = Body::from_stream(ReaderStream::new(file.take(content_length)));
+
+    let partial = matches!(outcome, RangeOutcome::Partial(..));
+    let status = if partial {
+        StatusCode::PARTIAL_CONTENT
+    } else {
+        StatusCode::OK
+    };
+
+    let mut response = (status, body).into_response();
+    let headers = response.headers_mut();
+
+    let content_type = mime_guess::from_path(path).first_or_octet_stream();
+
+    if let Ok(value) = HeaderValue::try_from(content_type.essence_str()) {
+        headers.insert(header::CONTENT_TYPE, value);
+    }
+
+    headers.insert(header::CONTENT_LENGTH, HeaderValue::from(content_length));
+    headers.insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+
+    if partial
+        && let Ok(value) =
+            HeaderValue::try_from(format!("bytes {start}-{end}/{len}"))
+    {
+        headers.insert(header::CONTENT_RANGE, value);
+    }
+
+    with_validators(response, &validators)
+}
+
+fn with_validators(mut response: Response, validators: &Validators) -> Response {
+    validators.apply(response.headers_mut());
+    response
+}
+
+/// Evaluates the conditional headers in the order of RFC 9110, section 13.2.2.
+///
+/// `If-Match` and `If-None-Match` take precedence over the date based
+/// `If-Unmodified-Since` and `If-Modified-Since`, which are ignored when
+/// the matching tag header is present.
+fn evaluate_preconditions(
+    request: &FileRequest,
+    validators: &Validators,
+) -> Option<StatusCode> {
+    if let Some(tags) = &request.if_match {
+        if !validators.strong_match(tags) {
+            return Some(StatusCode::PRECONDITION_FAILED);
+        }
+    } else if let Some(date) = &request.if_unmodified_since
+        && validators.modified_since(date) == Some(true)
+    {
+        return Some(StatusCode::PRECONDITION_FAILED);
+    }
+
+    let is_read = matches!(request.method, Method::GET | Method::HEAD);
+
+    if let Some(tags) = &request.if_none_match {
+        if validators.weak_match(tags) {
+            return Some(match is_read {
+                true => StatusCode::NOT_MODIFIED,
+                false => StatusCode::PRECONDITION_FAILED,
+            });
+        }
+    } else if let Some(date) = &request.if_modified_since
+        && is_read
+        && validators.modified_since(date) == Some(false)
+    {
+        return Some(StatusCode::NOT_MODIFIED);
+    }
+
+    None
+}
+
+/// Whether the `Range` header should be honored, which is the case unless
+/// an `If-Range` validator no longer matches the file.
+fn range_applies(request: &FileRequest, validators: &Validators) -> bool {
+    let Some(if_range) = request.if_range.as_deref().map(str::trim) else {
+        return true;
+    };
+
+    if if_range.starts_with('"') {
+        return if_range == validators.etag;
+    }
+
+    httpdate::parse_http_date(if_range)
+        .is_ok_and(|date| date == validators.last_modified)
+}
+
+/// Parses a single `bytes` range. Malformed headers and multiple ranges are
+/// ignored, which serves the whole file as RFC 9110 allows.
+fn parse_range(range: &str, len: u64) -> RangeOutcome {
+    let Some(spec) = range.trim().strip_prefix("bytes=") else {
+        return RangeOutcome::Full;
+    };
+
+    if spec.contains(',') {
+        return RangeOutcome::Full;
+    }
+
+    let Some((start, end)) = spec.trim().split_once('-') else {
+        return RangeOutcome::Full;
+    };
+
+    let (start, end) = match (start.trim(), end.trim()) {
+        ("", "") => return RangeOutcome::Full,
+        ("", suffix) => match suffix.parse::<u64>() {
+            Ok(0) => return RangeOutcome::Unsatisfiable,
+            Ok(suffix) => (len.saturating_sub(suffix), len.saturating_sub(1)),
+            Err(_) => return RangeOutcome::Full,
+        },
+        (start, "") => match start.parse::<u64>() {
+            Ok(start) => (start, len.saturating_sub(1)),
+            Err(_) => return RangeOutcome::Full,
+        },
+        (start, end) => match (start.parse::<u64>(), end.parse::<u64>()) {
+            (Ok(start), Ok(end)) if start <= end => {
+                (start, end.min(len.saturating_sub(1)))
+            }
+            _ => return RangeOutcome::Full,
+        },
+    };
+
+    if len == 0 || start >= len {
+        return RangeOutcome::Unsatisfiable;
+    }
+
+    RangeOutcome::Partial(start, end)
+}
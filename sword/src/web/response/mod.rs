@@ -0,0 +1,8 @@
+mod batch;
+mod ext;
+mod file;
+mod negotiation;
+
+pub use batch::{BatchItem, BatchResponse};
+pub use ext::HttpResponseExt;
+pub use negotiation::ResponseFormat;
@@ -0,0 +1,202 @@
+use axum::{
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use chrono::{SecondsFormat, Utc};
+use serde::Serialize;
+
+use crate::web::{
+    APPLICATION_JSON, Context, HttpResponse, HttpResponseExt, JsonEngine,
+    to_vec_with,
+};
+
+/// The formats `HttpResponse` can be rendered in, picked from the `Accept`
+/// header of the request with `Context::accept`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResponseFormat {
+    /// `application/json`, the format of the standard envelope.
+    #[default]
+    Json,
+
+    /// `application/msgpack`, also accepted as `application/x-msgpack`.
+    #[cfg(feature = "msgpack")]
+    MessagePack,
+}
+
+impl ResponseFormat {
+    /// Picks the format with the highest quality in an `Accept` header.
+    ///
+    /// JSON is preferred on ties and wildcards, and used when the header is
+    /// absent or lists no supported format.
+    pub fn from_accept(accept: Option<&str>) -> Self {
+        let Some(accept) = accept else {
+            return Self::Json;
+        };
+
+        let mut best = (Self::Json, 0.0_f32);
+
+        for range in accept.split(',') {
+            let mut params = range.split(';').map(str::trim);
+            let media_type = params.next().unwrap_or_default().to_ascii_lowercase();
+
+            let quality = params
+                .filter_map(|param| param.strip_prefix("q="))
+                .find_map(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            let Some(format) = Self::from_media_type(&media_type) else {
+                continue;
+            };
+
+            if quality > best.1 || (quality == best.1 && format == Self::Json) {
+                best = (format, quality);
+            }
+        }
+
+        best.0
+    }
+
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        match media_type {
+            "application/json" | "application/*" | "*/*" => Some(Self::Json),
+
+            #[cfg(feature = "msgpack")]
+            "application/msgpack" | "application/x-msgpack" => {
+                Some(Self::MessagePack)
+            }
+
+            _ => None,
+        }
+    }
+}
+
+impl Context {
+    /// Picks the response format from the `Accept` header of the request,
+    /// to be passed to `HttpResponseExt::negotiated`.
+    ///
+    /// Falls back to JSON when the header is absent, is `*/*` or lists no
+    /// supported format. MessagePack requires the `msgpack` feature.
+    pub fn accept(&self) -> ResponseFormat {
+        ResponseFormat::from_accept(self.header("accept"))
+    }
+
+    /// Sends `value` as the data of a `200 OK` envelope, encoded in the
+    /// format requested by the `Accept` header.
+    ///
+    /// In JSON, the envelope is serialized with the engine registered with
+    /// `ApplicationBuilder::with_json_engine`, if any.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore
+    /// #[get("/users/{id}")]
+    /// async fn user(&self, ctx: Context) -> HttpResult<Response> {
+    ///     let user = self.users.find(ctx.param("id")?).await?;
+    ///
+    ///     Ok(ctx.respond(user))
+    /// }
+    /// ```
+    pub fn respond<T: Serialize>(&self, value: T) -> Response {
+        let format = self.accept();
+
+        if format == ResponseFormat::Json
+            && let Some(engine) = self.json_engine()
+        {
+            return render_with_engine(engine.as_ref(), &value);
+        }
+
+        HttpResponse::Ok().data(value).negotiated(format)
+    }
+}
+
+/// The `200 OK` envelope of `Context::respond`, with the fields of the one
+/// `HttpResponse` renders, in the same order.
+#[derive(Serialize)]
+struct OkEnvelope<'a, T> {
+    code: u16,
+    data: &'a T,
+    message: &'static str,
+    success: bool,
+    timestamp: String,
+}
+
+fn render_with_engine<T: Serialize>(engine: &dyn JsonEngine, value: &T) -> Response {
+    let envelope = OkEnvelope {
+        code: StatusCode::OK.as_u16(),
+        data: value,
+        message: StatusCode::OK.canonical_reason().unwrap_or_default(),
+        success: true,
+        timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+    };
+
+    let Ok(body) = to_vec_with(engine, &envelope) else {
+        return HttpResponse::InternalServerError().into_response();
+    };
+
+    let mut response = (
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(APPLICATION_JSON),
+        )],
+        body,
+    )
+        .into_response();
+
+    response
+        .headers_mut()
+        .append(header::VARY, HeaderValue::from_static("accept"));
+
+    response
+}
+
+/// Renders the envelope of `response` in `format`, keeping its status and
+/// headers.
+///
+/// `Vary: accept` is added in every format, so caches don't serve one
+/// encoding to a client that asked for another.
+pub(crate) fn render(response: HttpResponse, format: ResponseFormat) -> Response {
+    let response = response.into_response();
+
+    let mut response = match format {
+        ResponseFormat::Json => response,
+
+        #[cfg(feature = "msgpack")]
+        ResponseFormat::MessagePack => to_msgpack(response),
+    };
+
+    response
+        .headers_mut()
+        .append(header::VARY, HeaderValue::from_static("accept"));
+
+    response
+}
+
+#[cfg(feature = "msgpack")]
+fn to_msgpack(response: Response) -> Response {
+    use axum::body::{Body, to_bytes};
+    use futures_util::FutureExt;
+
+    let (mut parts, body) = response.into_parts();
+
+    // The envelope is rendered into a buffered body, so it's available
+    // without waiting.
+    let Some(Ok(json)) = to_bytes(body, usize::MAX).now_or_never() else {
+        return HttpResponse::InternalServerError().into_response();
+    };
+
+    let encoded = serde_json::from_slice::<serde_json::Value>(&json)
+        .ok()
+        .and_then(|value| rmp_serde::to_vec_named(&value).ok());
+
+    let Some(encoded) = encoded else {
+        return HttpResponse::InternalServerError().into_response();
+    };
+
+    parts.headers.remove(header::CONTENT_LENGTH);
+    parts.headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/msgpack"),
+    );
+
+    Response::from_parts(parts, Body::from(encoded))
+}
@@ -0,0 +1,27 @@
+//! Server-Sent Events support.
+//!
+//! Build a stream of [`Event`]s and send it with `HttpResponseExt::sse`:
+//!
+//! ```rust,ignore
+//! use std::{convert::Infallible, time::Duration};
+//!
+//! use futures::{StreamExt, stream};
+//! use sword::prelude::*;
+//! use sword::web::sse::Event;
+//!
+//! #[get("/updates")]
+//! async fn updates(&self) -> Response {
+//!     let events = stream::iter(1..=3).map(|id| {
+//!         Ok::<_, Infallible>(
+//!             Event::default().event("update").id(id.to_string()).data("tick"),
+//!         )
+//!     });
+//!
+//!     HttpResponse::Ok().sse_with_keep_alive(events, Duration::from_secs(5))
+//! }
+//! ```
+//!
+//! These are the types of Axum's `sse` support, so [`Sse`] can also be
+//! returned directly for full control over the keep-alive messages.
+
+pub use axum::response::sse::{Event, KeepAlive, Sse};